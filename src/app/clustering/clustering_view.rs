@@ -0,0 +1,587 @@
+use crate::core::ai::clustering::blobs::gaussian_blobs;
+use crate::core::ai::clustering::dbscan::Dbscan;
+use crate::core::ai::clustering::gmm::Gmm;
+use crate::core::ai::clustering::kmeans::{KMeans, inertia};
+use crate::core::ai::clustering::metrics::{silhouette_samples, silhouette_score};
+use crate::core::ai::clustering::mini_batch::MiniBatchKMeans;
+use crate::core::hybrid::dataset::Dataset;
+use eframe::egui;
+use egui_plot::{Line, Plot, PlotPoints, Points};
+use ndarray::Array2;
+
+const MAX_K: usize = 8;
+const MAX_STEPS: usize = 100;
+// The canvas spans [-EXTENT, EXTENT] on both axes
+const EXTENT: f64 = 1.5;
+const CANVAS_SIZE: f32 = 420.0;
+
+const CLUSTER_COLORS: [egui::Color32; MAX_K] = [
+    egui::Color32::from_rgb(100, 200, 255),
+    egui::Color32::from_rgb(255, 200, 100),
+    egui::Color32::from_rgb(100, 255, 150),
+    egui::Color32::from_rgb(255, 100, 150),
+    egui::Color32::from_rgb(150, 120, 255),
+    egui::Color32::from_rgb(255, 150, 80),
+    egui::Color32::from_rgb(60, 200, 180),
+    egui::Color32::from_rgb(200, 120, 255),
+];
+const NOISE_COLOR: egui::Color32 = egui::Color32::from_rgb(110, 110, 125);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Source {
+    Gaussian,
+    Shape(Dataset),
+}
+
+impl Source {
+    fn name(self) -> &'static str {
+        match self {
+            Source::Gaussian => "Gaussian blobs",
+            Source::Shape(dataset) => dataset.name(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Algorithm {
+    KMeans,
+    MiniBatch,
+    Dbscan,
+    Gmm,
+}
+
+impl Algorithm {
+    const ALL: [Algorithm; 4] = [Algorithm::KMeans, Algorithm::MiniBatch, Algorithm::Dbscan, Algorithm::Gmm];
+
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::KMeans => "K-means",
+            Algorithm::MiniBatch => "Mini-batch k-means",
+            Algorithm::Dbscan => "DBSCAN",
+            Algorithm::Gmm => "Gaussian mixture",
+        }
+    }
+}
+
+enum Model {
+    // Lloyd's algorithm, animated one half-step at a time
+    KMeans { model: KMeans, assigned: bool },
+    // The latest batch is ringed on the canvas
+    MiniBatch { model: Box<MiniBatchKMeans>, batch: Vec<usize> },
+    Dbscan(Dbscan),
+    Gmm(Gmm),
+}
+
+pub struct ClusteringView {
+    source: Source,
+    num_points: usize,
+    blobs: usize,
+    noise: f64,
+    data_seed: u64,
+    algorithm: Algorithm,
+    k: usize,
+    batch_size: usize,
+    eps: f64,
+    min_points: usize,
+    init_seed: u64,
+    running: bool,
+    // Seconds between animation steps
+    interval: f64,
+    last_step: f64,
+    // Cached from the settings above
+    x: Array2<f64>,
+    model: Model,
+    converged: bool,
+    // Inertia (k-means) or log-likelihood (GMM) per step
+    history: Vec<f64>,
+    // Centroid positions after each update, one trail per centroid
+    trails: Vec<Vec<[f64; 2]>>,
+    silhouette: Option<f64>,
+    // (k, inertia, mean silhouette) of a full k-means fit per k
+    elbow: Vec<(usize, f64, f64)>,
+}
+
+impl Default for ClusteringView {
+    fn default() -> Self {
+        let x = Array2::zeros((0, 2));
+        let mut view = Self {
+            source: Source::Gaussian,
+            num_points: 240,
+            blobs: 4,
+            noise: 0.08,
+            data_seed: 0,
+            algorithm: Algorithm::KMeans,
+            k: 4,
+            batch_size: 16,
+            eps: 0.15,
+            min_points: 5,
+            init_seed: 0,
+            running: false,
+            interval: 0.4,
+            last_step: 0.0,
+            model: Model::Dbscan(Dbscan::fit(&x, 0.1, 1)),
+            x,
+            converged: false,
+            history: Vec::new(),
+            trails: Vec::new(),
+            silhouette: None,
+            elbow: Vec::new(),
+        };
+        view.regenerate();
+        view
+    }
+}
+
+// Ellipse through μ at `sigmas` standard deviations of a 2x2 covariance
+fn covariance_ellipse(mean: [f64; 2], covariance: &Array2<f64>, sigmas: f64) -> Vec<[f64; 2]> {
+    let (a, b, d) = (covariance[[0, 0]], covariance[[0, 1]], covariance[[1, 1]]);
+    let half_gap = (((a - d) / 2.0).powi(2) + b * b).sqrt();
+    let major = ((a + d) / 2.0 + half_gap).max(0.0).sqrt() * sigmas;
+    let minor = ((a + d) / 2.0 - half_gap).max(0.0).sqrt() * sigmas;
+    let angle = 0.5 * (2.0 * b).atan2(a - d);
+    (0..48)
+        .map(|i| {
+            let t = i as f64 / 48.0 * std::f64::consts::TAU;
+            let (u, v) = (major * t.cos(), minor * t.sin());
+            [mean[0] + u * angle.cos() - v * angle.sin(), mean[1] + u * angle.sin() + v * angle.cos()]
+        })
+        .collect()
+}
+
+impl ClusteringView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.x = match self.source {
+            Source::Gaussian => gaussian_blobs(self.num_points, self.blobs, self.data_seed),
+            Source::Shape(dataset) => {
+                let points = dataset.generate(self.num_points, self.noise, self.data_seed);
+                Array2::from_shape_fn((points.len(), 2), |(i, j)| points[i].features()[j])
+            }
+        };
+        self.elbow = (1..=MAX_K)
+            .map(|k| {
+                let model = KMeans::fit(&self.x, k, self.init_seed, MAX_STEPS);
+                let silhouette = if k > 1 { silhouette_score(&self.x, &model.assignments) } else { 0.0 };
+                (k, model.inertia(&self.x), silhouette)
+            })
+            .collect();
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.model = match self.algorithm {
+            Algorithm::KMeans => Model::KMeans { model: KMeans::new(&self.x, self.k, self.init_seed), assigned: false },
+            Algorithm::MiniBatch => Model::MiniBatch { model: Box::new(MiniBatchKMeans::new(&self.x, self.k, self.batch_size, self.init_seed)), batch: Vec::new() },
+            Algorithm::Dbscan => Model::Dbscan(Dbscan::fit(&self.x, self.eps, self.min_points)),
+            Algorithm::Gmm => {
+                let mut model = Gmm::new(&self.x, self.k, self.init_seed);
+                model.e_step(&self.x);
+                Model::Gmm(model)
+            }
+        };
+        // DBSCAN has nothing to animate
+        self.converged = self.algorithm == Algorithm::Dbscan;
+        self.running = false;
+        self.history.clear();
+        self.trails = self.centers().map_or_else(Vec::new, |centers| centers.rows().into_iter().map(|c| vec![[c[0], c[1]]]).collect());
+        self.update_silhouette();
+    }
+
+    fn step(&mut self) {
+        if self.converged {
+            return;
+        }
+        match &mut self.model {
+            Model::KMeans { model, assigned } => {
+                if *assigned {
+                    model.update(&self.x);
+                    *assigned = false;
+                    self.history.push(model.inertia(&self.x));
+                } else {
+                    let changed = model.assign(&self.x);
+                    *assigned = true;
+                    self.converged = changed == 0 || model.iteration >= MAX_STEPS;
+                }
+            }
+            Model::MiniBatch { model, batch } => {
+                *batch = model.step(&self.x);
+                self.history.push(inertia(&self.x, &model.centroids, &model.assignments(&self.x)));
+                self.converged = model.iteration >= MAX_STEPS;
+            }
+            Model::Dbscan(_) => {}
+            Model::Gmm(model) => {
+                let log_likelihood = model.step(&self.x);
+                model.e_step(&self.x);
+                let improvement = self.history.last().map_or(f64::INFINITY, |previous| log_likelihood - previous);
+                self.history.push(log_likelihood);
+                self.converged = improvement.abs() < 1e-6 || self.history.len() >= MAX_STEPS;
+            }
+        }
+        if let Some(centers) = self.centers() {
+            for (trail, c) in self.trails.iter_mut().zip(centers.rows()) {
+                if trail.last() != Some(&[c[0], c[1]]) {
+                    trail.push([c[0], c[1]]);
+                }
+            }
+        }
+        self.update_silhouette();
+        if self.converged {
+            self.running = false;
+        }
+    }
+
+    fn centers(&self) -> Option<Array2<f64>> {
+        match &self.model {
+            Model::KMeans { model, .. } => Some(model.centroids.clone()),
+            Model::MiniBatch { model, .. } => Some(model.centroids.clone()),
+            Model::Dbscan(_) => None,
+            Model::Gmm(model) => Some(model.means.clone()),
+        }
+    }
+
+    // Cluster per point, None for DBSCAN noise or before the first assignment
+    fn labels(&self) -> Vec<Option<usize>> {
+        match &self.model {
+            Model::KMeans { model, assigned } if model.iteration == 0 && !assigned => vec![None; self.x.nrows()],
+            Model::KMeans { model, .. } => model.assignments.iter().map(|&c| Some(c)).collect(),
+            Model::MiniBatch { model, .. } => model.assignments(&self.x).into_iter().map(Some).collect(),
+            Model::Dbscan(model) => model.labels.clone(),
+            Model::Gmm(model) => model.assignments().into_iter().map(Some).collect(),
+        }
+    }
+
+    // Over the clustered points only; needs at least two clusters
+    fn update_silhouette(&mut self) {
+        let labels = self.labels();
+        let kept: Vec<usize> = (0..labels.len()).filter(|&i| labels[i].is_some()).collect();
+        let assigned: Vec<usize> = kept.iter().map(|&i| labels[i].expect("kept")).collect();
+        let distinct = assigned.iter().collect::<std::collections::BTreeSet<_>>().len();
+        self.silhouette = (distinct >= 2).then(|| {
+            let x = self.x.select(ndarray::Axis(0), &kept);
+            silhouette_score(&x, &assigned)
+        });
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            let now = ui.input(|i| i.time);
+            if now - self.last_step >= self.interval {
+                self.step();
+                self.last_step = now;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Clustering")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Group unlabelled points: k-means, mini-batch k-means, DBSCAN and Gaussian mixtures fit by EM")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| self.render_canvas(ui));
+                ui.add_space(16.0);
+                ui.vertical(|ui| self.render_progress(ui));
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_elbow(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut reset) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Data:");
+                    let sources = std::iter::once(Source::Gaussian).chain(Dataset::ALL.into_iter().map(Source::Shape));
+                    for source in sources {
+                        regenerate |= ui.selectable_value(&mut self.source, source, source.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    match self.source {
+                        Source::Gaussian => regenerate |= ui.add(egui::Slider::new(&mut self.blobs, 1..=MAX_K).text("blobs")).changed(),
+                        Source::Shape(_) => regenerate |= ui.add(egui::Slider::new(&mut self.noise, 0.0..=0.3).text("noise")).changed(),
+                    }
+                    regenerate |= ui.add(egui::Slider::new(&mut self.num_points, 20..=400).text("points")).changed();
+                    if ui.button("🎲 Resample").clicked() {
+                        self.data_seed += 1;
+                        regenerate = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Algorithm:");
+                    for algorithm in Algorithm::ALL {
+                        reset |= ui.selectable_value(&mut self.algorithm, algorithm, algorithm.name()).changed();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    if self.algorithm == Algorithm::Dbscan {
+                        reset |= ui.add(egui::Slider::new(&mut self.eps, 0.02..=0.5).text("ε radius")).changed();
+                        ui.add_space(16.0);
+                        reset |= ui.add(egui::Slider::new(&mut self.min_points, 1..=20).text("min points")).changed();
+                    } else {
+                        reset |= ui.add(egui::Slider::new(&mut self.k, 1..=MAX_K).text("k")).changed();
+                        if self.algorithm == Algorithm::MiniBatch {
+                            ui.add_space(16.0);
+                            reset |= ui.add(egui::Slider::new(&mut self.batch_size, 1..=64).text("batch size")).changed();
+                        }
+                        ui.add_space(16.0);
+                        if ui.button("🎲 Reinitialize").on_hover_text("A different k-means++ start").clicked() {
+                            self.init_seed += 1;
+                            regenerate = true;
+                        }
+                    }
+                });
+                if self.algorithm != Algorithm::Dbscan {
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        let label = if self.running { "⏸ Pause" } else { "▶ Run" };
+                        if ui.add_enabled(!self.converged, egui::Button::new(label)).clicked() {
+                            self.running = !self.running;
+                        }
+                        if ui.add_enabled(!self.converged, egui::Button::new("⏭ Step")).clicked() {
+                            self.step();
+                        }
+                        if ui.button("↺ Reset").clicked() {
+                            reset = true;
+                        }
+                        ui.add_space(16.0);
+                        ui.add(egui::Slider::new(&mut self.interval, 0.02..=1.0).logarithmic(true).text("seconds per step"));
+                    });
+                }
+            });
+        if regenerate {
+            self.regenerate();
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    fn render_canvas(&self, ui: &mut egui::Ui) {
+        let status = match &self.model {
+            Model::KMeans { model, assigned } if !self.converged => {
+                let next = if *assigned { "move centroids to the means" } else { "assign points to the nearest centroid" };
+                format!("Iteration {}, next: {}", model.iteration, next)
+            }
+            Model::KMeans { model, .. } => format!("Converged after {} iterations", model.iteration),
+            Model::MiniBatch { model, .. } => format!("Step {} of {} ({} samples each)", model.iteration, MAX_STEPS, model.batch_size),
+            Model::Dbscan(model) => format!("{} clusters, {} noise points", model.clusters, model.noise()),
+            Model::Gmm(model) => format!("EM step {}", model.log_likelihood_history.len()),
+        };
+        Self::section_label(ui, &format!("🗺 {}", status));
+
+        let (response, painter) = ui.allocate_painter(egui::vec2(CANVAS_SIZE, CANVAS_SIZE), egui::Sense::hover());
+        let rect = response.rect;
+        painter.rect_filled(rect, 6.0, egui::Color32::from_rgb(25, 25, 35));
+        let to_screen = |p: [f64; 2]| {
+            egui::pos2(
+                rect.left() + CANVAS_SIZE * ((p[0] + EXTENT) / (2.0 * EXTENT)) as f32,
+                rect.top() + CANVAS_SIZE * ((EXTENT - p[1]) / (2.0 * EXTENT)) as f32,
+            )
+        };
+        let color = |label: Option<usize>| label.map_or(NOISE_COLOR, |c| CLUSTER_COLORS[c % MAX_K]);
+
+        if let Model::Gmm(model) = &self.model {
+            for c in 0..model.k() {
+                let mean = [model.means[[c, 0]], model.means[[c, 1]]];
+                for sigmas in [1.0, 2.0] {
+                    let outline = covariance_ellipse(mean, &model.covariances[c], sigmas).into_iter().map(to_screen).collect();
+                    let alpha = if sigmas == 1.0 { 0.9 } else { 0.45 };
+                    painter.add(egui::Shape::closed_line(outline, egui::Stroke::new(1.5, color(Some(c)).gamma_multiply(alpha))));
+                }
+            }
+        }
+
+        let labels = self.labels();
+        for (i, p) in self.x.rows().into_iter().enumerate() {
+            let center = to_screen([p[0], p[1]]);
+            let mut fill = color(labels[i]);
+            // Soft assignments fade towards grey
+            if let Model::Gmm(model) = &self.model {
+                let confidence = model.responsibilities.row(i).iter().copied().fold(0.0, f64::max);
+                fill = NOISE_COLOR.lerp_to_gamma(fill, confidence as f32);
+            }
+            let radius = match &self.model {
+                Model::Dbscan(model) if !model.core[i] => 2.5,
+                _ => 3.5,
+            };
+            painter.circle_filled(center, radius, fill);
+        }
+        if let Model::MiniBatch { batch, .. } = &self.model {
+            for &i in batch {
+                painter.circle_stroke(to_screen([self.x[[i, 0]], self.x[[i, 1]]]), 6.0, egui::Stroke::new(1.0, egui::Color32::WHITE));
+            }
+        }
+
+        for (c, trail) in self.trails.iter().enumerate() {
+            let stroke = egui::Stroke::new(1.5, color(Some(c)));
+            painter.add(egui::Shape::line(trail.iter().map(|p| to_screen(*p)).collect(), stroke));
+            if let Some(last) = trail.last() {
+                let center = to_screen(*last);
+                painter.circle_filled(center, 7.0, egui::Color32::from_rgb(25, 25, 35));
+                painter.circle_stroke(center, 7.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+                painter.circle_filled(center, 4.0, color(Some(c)));
+            }
+        }
+
+        let legend = match self.algorithm {
+            Algorithm::KMeans => "Rings are centroids, lines their path so far",
+            Algorithm::MiniBatch => "White rings mark the latest batch",
+            Algorithm::Dbscan => "Small dots are border points, grey ones noise",
+            Algorithm::Gmm => "Ellipses at 1σ and 2σ; faded points are split between components",
+        };
+        ui.label(egui::RichText::new(legend)
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn render_progress(&self, ui: &mut egui::Ui) {
+        let likelihood = self.algorithm == Algorithm::Gmm;
+        Self::section_label(ui, if likelihood { "📈 Log-likelihood per EM step" } else { "📉 Inertia Σ ||x - c(x)||² per step" });
+        if self.algorithm == Algorithm::Dbscan {
+            ui.label("DBSCAN runs in one pass; change ε or the minimum to see the clusters merge and split");
+        } else {
+            let points: PlotPoints = self.history.iter().enumerate().map(|(i, v)| [i as f64 + 1.0, *v]).collect();
+            Plot::new("clustering_progress")
+                .width(380.0)
+                .height(200.0)
+                .include_x(0.0)
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(if likelihood { "log-likelihood" } else { "inertia" }, points).color(egui::Color32::from_rgb(100, 200, 255)));
+                });
+        }
+
+        ui.add_space(8.0);
+        match self.silhouette {
+            Some(silhouette) => {
+                let color = if silhouette >= 0.5 {
+                    egui::Color32::from_rgb(100, 255, 150)
+                } else {
+                    egui::Color32::from_rgb(255, 200, 100)
+                };
+                ui.label(egui::RichText::new(format!("Silhouette: {:.3}", silhouette))
+                    .color(color)
+                    .size(16.0)
+                    .strong());
+            }
+            None => {
+                ui.label("Silhouette needs at least two clusters");
+            }
+        }
+        if let Some(value) = self.history.last() {
+            ui.label(format!("{} {:.4}", if likelihood { "Log-likelihood" } else { "Inertia" }, value));
+        }
+        if let Model::Gmm(model) = &self.model {
+            let weights: Vec<String> = model.weights.iter().map(|w| format!("{:.2}", w)).collect();
+            ui.label(format!("Mixing weights π: {}", weights.join(", ")));
+        }
+
+        // Per-point silhouettes, sorted within each cluster
+        let labels = self.labels();
+        let kept: Vec<usize> = (0..labels.len()).filter(|&i| labels[i].is_some()).collect();
+        if self.silhouette.is_some() {
+            let assigned: Vec<usize> = kept.iter().map(|&i| labels[i].expect("kept")).collect();
+            let samples = silhouette_samples(&self.x.select(ndarray::Axis(0), &kept), &assigned);
+            let mut order: Vec<usize> = (0..samples.len()).collect();
+            order.sort_by(|&a, &b| assigned[a].cmp(&assigned[b]).then(samples[b].total_cmp(&samples[a])));
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Silhouette of every point").strong());
+            Plot::new("clustering_silhouettes")
+                .width(380.0)
+                .height(160.0)
+                .include_y(-1.0)
+                .include_y(1.0)
+                .show_axes([false, true])
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .show(ui, |plot_ui| {
+                    for (c, color) in CLUSTER_COLORS.iter().enumerate() {
+                        let points: PlotPoints = order.iter().enumerate().filter(|(_, i)| assigned[**i] == c).map(|(x, i)| [x as f64, samples[*i]]).collect();
+                        plot_ui.points(Points::new(format!("cluster {}", c + 1), points).color(*color).radius(1.5));
+                    }
+                });
+        }
+    }
+
+    fn render_elbow(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📐 Choosing k: inertia (elbow) and mean silhouette of a full k-means fit");
+        let marker = |k: usize, value: f64| Points::new(format!("k = {}", k), vec![[k as f64, value]]).radius(6.0).color(egui::Color32::WHITE);
+        ui.columns(2, |columns| {
+            let inertia: PlotPoints = self.elbow.iter().map(|(k, inertia, _)| [*k as f64, *inertia]).collect();
+            Plot::new("clustering_elbow")
+                .height(200.0)
+                .include_y(0.0)
+                .x_axis_label("k")
+                .y_axis_label("inertia")
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .show(&mut columns[0], |plot_ui| {
+                    plot_ui.line(Line::new("inertia", inertia).color(egui::Color32::from_rgb(100, 200, 255)).width(2.0));
+                    if let Some((k, value, _)) = self.elbow.iter().find(|(k, _, _)| *k == self.k) {
+                        plot_ui.points(marker(*k, *value));
+                    }
+                });
+
+            let silhouettes: PlotPoints = self.elbow.iter().filter(|(k, _, _)| *k > 1).map(|(k, _, s)| [*k as f64, *s]).collect();
+            Plot::new("clustering_silhouette_k")
+                .height(200.0)
+                .include_y(0.0)
+                .include_y(1.0)
+                .x_axis_label("k")
+                .y_axis_label("silhouette")
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .show(&mut columns[1], |plot_ui| {
+                    plot_ui.line(Line::new("silhouette", silhouettes).color(egui::Color32::from_rgb(100, 255, 150)).width(2.0));
+                    if let Some((k, _, value)) = self.elbow.iter().find(|(k, _, _)| *k == self.k && *k > 1) {
+                        plot_ui.points(marker(*k, *value));
+                    }
+                });
+        });
+        if let Some((best, _, score)) = self.elbow.iter().filter(|(k, _, _)| *k > 1).max_by(|a, b| a.2.total_cmp(&b.2)) {
+            ui.label(egui::RichText::new(format!("The silhouette peaks at k = {} ({:.3}); the inertia keeps falling, so look for where it bends", best, score))
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+        }
+    }
+}
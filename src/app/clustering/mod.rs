@@ -0,0 +1 @@
+pub mod clustering_view;
@@ -0,0 +1,400 @@
+use crate::core::ai::embeddings::loader::{Format, SAMPLE, parse};
+use crate::core::ai::embeddings::projection::{Projection, classical_mds, pca};
+use crate::core::ai::embeddings::space::{Embeddings, Metric};
+use crate::core::ai::embeddings::tsne::Tsne;
+use eframe::egui;
+use ndarray::Array2;
+
+// Projections and t-SNE are O(n²) or worse, so big files are cut short
+const MAX_WORDS: usize = 500;
+const CANVAS_SIZE: f32 = 520.0;
+const CLICK_RADIUS: f32 = 12.0;
+const TSNE_ITERATIONS: usize = 1000;
+const TSNE_STEPS_PER_FRAME: usize = 5;
+
+// Indices of a, b and c, then the ranked answers
+type Analogy = ([usize; 3], Vec<(usize, f64)>);
+
+pub struct EmbeddingsView {
+    path: String,
+    message: String,
+    embeddings: Embeddings,
+    metric: Metric,
+    projection: Projection,
+    perplexity: f64,
+    neighbors: usize,
+    // Cached 2-D layout, one row per word
+    projected: Array2<f64>,
+    // PCA: share of the variance the two axes explain
+    explained: Option<f64>,
+    tsne: Option<Tsne>,
+    divergence: f64,
+    running: bool,
+    selected: Option<usize>,
+    search: String,
+    analogy: [String; 3],
+    // The last query, or why it failed
+    analogy_result: Option<Result<Analogy, String>>,
+}
+
+impl Default for EmbeddingsView {
+    fn default() -> Self {
+        let mut view = Self {
+            path: "vectors.txt".to_string(),
+            message: String::new(),
+            embeddings: parse(SAMPLE, Format::Text).expect("the sample parses"),
+            metric: Metric::Cosine,
+            projection: Projection::Pca,
+            perplexity: 5.0,
+            neighbors: 5,
+            projected: Array2::zeros((0, 2)),
+            explained: None,
+            tsne: None,
+            divergence: 0.0,
+            running: false,
+            selected: None,
+            search: String::new(),
+            analogy: ["man".to_string(), "king".to_string(), "woman".to_string()],
+            analogy_result: None,
+        };
+        view.message = format!("Built-in sample: {} words, {} dimensions", view.embeddings.len(), view.embeddings.dimension());
+        view.reproject();
+        view
+    }
+}
+
+impl EmbeddingsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn set_embeddings(&mut self, embeddings: Embeddings) {
+        self.embeddings = embeddings;
+        self.selected = None;
+        self.analogy_result = None;
+        self.reproject();
+    }
+
+    fn open(&mut self) {
+        let result = std::fs::read_to_string(&self.path)
+            .map_err(|e| e.to_string())
+            .and_then(|source| parse(&source, Format::from_path(&self.path)).map_err(|e| e.to_string()));
+        match result {
+            Ok(mut embeddings) => {
+                let total = embeddings.len();
+                if total > MAX_WORDS {
+                    embeddings.words.truncate(MAX_WORDS);
+                    embeddings.vectors = embeddings.vectors.slice(ndarray::s![..MAX_WORDS, ..]).to_owned();
+                }
+                self.message = format!("Opened {}: {} of {} words, {} dimensions", self.path, embeddings.len(), total, embeddings.dimension());
+                self.set_embeddings(embeddings);
+            }
+            Err(error) => {
+                tracing::error!("Failed to open {}: {}", self.path, error);
+                self.message = format!("⚠ {}: {}", self.path, error);
+            }
+        }
+    }
+
+    fn reproject(&mut self) {
+        self.explained = None;
+        self.tsne = None;
+        self.running = false;
+        match self.projection {
+            Projection::Pca => {
+                let (scores, variances) = pca(&self.embeddings.vectors, 2);
+                let total_variance = {
+                    let mean = self.embeddings.vectors.mean_axis(ndarray::Axis(0)).expect("at least one word");
+                    let n = (self.embeddings.len().max(2) - 1) as f64;
+                    (&self.embeddings.vectors - &mean).mapv(|x| x * x).sum() / n
+                };
+                self.explained = Some(variances.sum() / total_variance.max(1e-12));
+                self.projected = scores;
+            }
+            Projection::Mds => self.projected = classical_mds(&self.embeddings.distance_matrix(self.metric), 2),
+            Projection::Tsne => {
+                let tsne = Tsne::new(&self.embeddings.distance_matrix(self.metric), self.perplexity, 0);
+                self.projected = tsne.embedding.clone();
+                self.tsne = Some(tsne);
+                self.running = true;
+            }
+        }
+        // Fewer than two components (a single word) still needs two columns
+        if self.projected.ncols() < 2 {
+            let mut padded = Array2::zeros((self.projected.nrows(), 2));
+            padded.slice_mut(ndarray::s![.., ..self.projected.ncols()]).assign(&self.projected);
+            self.projected = padded;
+        }
+    }
+
+    fn solve_analogy(&mut self) {
+        let indices: Vec<Option<usize>> = self.analogy.iter().map(|word| self.embeddings.index(word)).collect();
+        self.analogy_result = Some(match indices.iter().position(Option::is_none) {
+            Some(missing) => Err(format!("\"{}\" is not in the vocabulary", self.analogy[missing].trim())),
+            None => {
+                let [a, b, c] = [indices[0].unwrap(), indices[1].unwrap(), indices[2].unwrap()];
+                Ok(([a, b, c], self.embeddings.analogy(a, b, c, self.metric, self.neighbors)))
+            }
+        });
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running
+            && let Some(tsne) = &mut self.tsne {
+            for _ in 0..TSNE_STEPS_PER_FRAME {
+                self.divergence = tsne.step();
+            }
+            self.projected = tsne.embedding.clone();
+            if tsne.iteration >= TSNE_ITERATIONS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Embeddings")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Word vectors projected to 2-D, with nearest neighbours and vector analogies")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| self.render_projection(ui));
+                ui.add_space(16.0);
+                ui.vertical(|ui| {
+                    self.render_neighbors(ui);
+                    ui.add_space(16.0);
+                    self.render_analogy(ui);
+                });
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut reproject = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.add(egui::TextEdit::singleline(&mut self.path).desired_width(260.0))
+                        .on_hover_text("\"word f1 f2 ...\" per line (GloVe / word2vec text), or word,f1,f2,... in a .csv");
+                    if ui.button("📂 Open").clicked() {
+                        self.open();
+                    }
+                    if ui.button("📚 Sample").clicked() {
+                        self.set_embeddings(parse(SAMPLE, Format::Text).expect("the sample parses"));
+                        self.message = format!("Built-in sample: {} words, {} dimensions", self.embeddings.len(), self.embeddings.dimension());
+                    }
+                    ui.add_space(12.0);
+                    let color = if self.message.starts_with('⚠') {
+                        egui::Color32::from_rgb(255, 100, 150)
+                    } else {
+                        egui::Color32::from_rgb(160, 160, 180)
+                    };
+                    ui.label(egui::RichText::new(&self.message).color(color));
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Projection:");
+                    for projection in Projection::ALL {
+                        reproject |= ui.selectable_value(&mut self.projection, projection, projection.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    ui.label("Metric:");
+                    for metric in Metric::ALL {
+                        if ui.selectable_value(&mut self.metric, metric, metric.name()).changed() {
+                            // PCA ignores the metric, the other projections start over
+                            reproject |= self.projection != Projection::Pca;
+                            if self.analogy_result.is_some() {
+                                self.solve_analogy();
+                            }
+                        }
+                    }
+                    ui.add_space(16.0);
+                    ui.add(egui::Slider::new(&mut self.neighbors, 1..=15).text("neighbours"));
+                });
+                if self.projection == Projection::Tsne {
+                    ui.horizontal(|ui| {
+                        reproject |= ui.add(egui::Slider::new(&mut self.perplexity, 2.0..=50.0).text("perplexity")).changed();
+                        ui.add_space(16.0);
+                        let label = if self.running { "⏸ Pause" } else { "▶ Run" };
+                        if ui.button(label).clicked() {
+                            self.running = !self.running;
+                        }
+                        if ui.button("↺ Restart").clicked() {
+                            reproject = true;
+                        }
+                        if let Some(tsne) = &self.tsne {
+                            ui.add_space(16.0);
+                            ui.label(format!("iteration {}, KL(P‖Q) = {:.4}, learning rate {:.0}", tsne.iteration, self.divergence, tsne.learning_rate));
+                        }
+                    });
+                }
+            });
+        if reproject {
+            self.reproject();
+        }
+    }
+
+    fn render_projection(&mut self, ui: &mut egui::Ui) {
+        let title = match self.explained {
+            Some(explained) => format!("🗺 {} ({:.0}% of the variance)", self.projection.name(), 100.0 * explained),
+            None => format!("🗺 {}", self.projection.name()),
+        };
+        Self::section_label(ui, &title);
+        let (response, painter) = ui.allocate_painter(egui::vec2(CANVAS_SIZE, CANVAS_SIZE), egui::Sense::click());
+        let rect = response.rect;
+        painter.rect_filled(rect, 6.0, egui::Color32::from_rgb(25, 25, 35));
+
+        // Fit the layout into the canvas with a margin, keeping the aspect ratio
+        let (mut low, mut high) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+        for row in self.projected.rows() {
+            for k in 0..2 {
+                low[k] = low[k].min(row[k]);
+                high[k] = high[k].max(row[k]);
+            }
+        }
+        let span = (high[0] - low[0]).max(high[1] - low[1]).max(1e-9);
+        let center = [(low[0] + high[0]) / 2.0, (low[1] + high[1]) / 2.0];
+        let scale = 0.85 * CANVAS_SIZE as f64 / span;
+        let to_screen = |i: usize| {
+            rect.center() + egui::vec2(
+                ((self.projected[[i, 0]] - center[0]) * scale) as f32,
+                -((self.projected[[i, 1]] - center[1]) * scale) as f32,
+            )
+        };
+
+        let neighbors = self.selected.map(|i| self.embeddings.neighbors(i, self.metric, self.neighbors)).unwrap_or_default();
+        if let Some(selected) = self.selected {
+            for (i, _) in &neighbors {
+                painter.line_segment([to_screen(selected), to_screen(*i)], egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 255, 150).gamma_multiply(0.5)));
+            }
+        }
+        if let Some(Ok(([a, b, c], answers))) = &self.analogy_result
+            && let Some((answer, _)) = answers.first() {
+            let amber = egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 200, 100));
+            painter.arrow(to_screen(*a), to_screen(*b) - to_screen(*a), amber);
+            painter.arrow(to_screen(*c), to_screen(*answer) - to_screen(*c), amber);
+        }
+
+        for (i, word) in self.embeddings.words.iter().enumerate() {
+            let position = to_screen(i);
+            let (color, radius) = if Some(i) == self.selected {
+                (egui::Color32::WHITE, 5.0)
+            } else if neighbors.iter().any(|(n, _)| *n == i) {
+                (egui::Color32::from_rgb(100, 255, 150), 4.5)
+            } else {
+                (egui::Color32::from_rgb(100, 200, 255), 3.5)
+            };
+            painter.circle_filled(position, radius, color);
+            painter.text(position + egui::vec2(6.0, 0.0), egui::Align2::LEFT_CENTER, word, egui::FontId::proportional(11.0), color.gamma_multiply(0.85));
+        }
+
+        if response.clicked()
+            && let Some(pos) = response.interact_pointer_pos() {
+            let nearest = (0..self.embeddings.len())
+                .map(|i| (i, to_screen(i).distance(pos)))
+                .filter(|(_, distance)| *distance <= CLICK_RADIUS)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            self.selected = nearest.map(|(i, _)| i);
+        }
+        ui.label(egui::RichText::new("Click a word to list its neighbours; analogy arrows are amber")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn score_label(&self, score: f64) -> String {
+        match self.metric {
+            Metric::Cosine => format!("cos {:+.3}", score),
+            Metric::Euclidean => format!("dist {:.3}", score),
+        }
+    }
+
+    fn render_neighbors(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🔍 Nearest neighbours");
+        ui.horizontal(|ui| {
+            let response = ui.add(egui::TextEdit::singleline(&mut self.search).hint_text("word").desired_width(160.0));
+            if (response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) || ui.button("Find").clicked() {
+                self.selected = self.embeddings.index(&self.search);
+            }
+        });
+        ui.add_space(6.0);
+        let Some(selected) = self.selected else {
+            ui.label(egui::RichText::new("No word selected").color(egui::Color32::from_rgb(160, 160, 180)));
+            return;
+        };
+        ui.label(egui::RichText::new(&self.embeddings.words[selected]).strong().size(16.0));
+        egui::Grid::new("embedding_neighbors").striped(true).show(ui, |ui| {
+            for (rank, (i, score)) in self.embeddings.neighbors(selected, self.metric, self.neighbors).into_iter().enumerate() {
+                ui.label(format!("{}.", rank + 1));
+                ui.label(egui::RichText::new(&self.embeddings.words[i]).color(egui::Color32::from_rgb(100, 255, 150)));
+                ui.monospace(self.score_label(score));
+                ui.end_row();
+            }
+        });
+    }
+
+    fn render_analogy(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🧮 Analogy: a is to b as c is to ?");
+        let mut solve = false;
+        ui.horizontal(|ui| {
+            for (word, label) in self.analogy.iter_mut().zip(["a", "b", "c"]) {
+                ui.label(label);
+                let response = ui.add(egui::TextEdit::singleline(word).desired_width(90.0));
+                solve |= response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            }
+            solve |= ui.button("Solve").clicked();
+        });
+        if solve {
+            self.solve_analogy();
+        }
+        ui.add_space(6.0);
+        match &self.analogy_result {
+            None => {
+                ui.label(egui::RichText::new("Finds the words nearest b - a + c, e.g. man : king :: woman : ?")
+                    .color(egui::Color32::from_rgb(160, 160, 180))
+                    .size(12.0));
+            }
+            Some(Err(error)) => {
+                ui.label(egui::RichText::new(format!("⚠ {}", error)).color(egui::Color32::from_rgb(255, 100, 150)));
+            }
+            Some(Ok(([a, b, c], answers))) => {
+                let words = &self.embeddings.words;
+                ui.label(format!("{} - {} + {} ≈", words[*b], words[*a], words[*c]));
+                egui::Grid::new("embedding_analogy").striped(true).show(ui, |ui| {
+                    for (rank, (i, score)) in answers.iter().enumerate() {
+                        ui.label(format!("{}.", rank + 1));
+                        let color = if rank == 0 { egui::Color32::from_rgb(255, 200, 100) } else { egui::Color32::from_rgb(200, 200, 210) };
+                        ui.label(egui::RichText::new(&words[*i]).color(color));
+                        ui.monospace(self.score_label(*score));
+                        ui.end_row();
+                    }
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::app::widgets::bloch_sphere::BlochSphere;
+use crate::app::widgets::circuit_diagram::CircuitDiagram;
+use crate::core::hybrid::dataset::{Dataset, LabeledPoint};
+use crate::core::hybrid::feature_maps::FeatureMap;
+use crate::core::quantum::backend::Backend;
+use eframe::egui;
+use egui_plot::{Legend, Plot, Points};
+use ndarray::Array2;
+
+const NUM_POINTS: usize = 24;
+const GRAM_SIZE: f32 = 320.0;
+
+pub struct FeatureMapView {
+    dataset: Dataset,
+    noise: f64,
+    seed: u64,
+    map: FeatureMap,
+    // Features are multiplied by this before encoding
+    scale: f64,
+    selected: usize,
+    // Cached from the settings above; points are sorted by label
+    points: Vec<LabeledPoint>,
+    gram: Array2<f64>,
+}
+
+impl Default for FeatureMapView {
+    fn default() -> Self {
+        let mut view = Self {
+            dataset: Dataset::Moons,
+            noise: 0.05,
+            seed: 0,
+            map: FeatureMap::ZZ { reps: 2 },
+            scale: 1.5,
+            selected: 0,
+            points: Vec::new(),
+            gram: Array2::zeros((0, 0)),
+        };
+        view.regenerate();
+        view
+    }
+}
+
+// Black → cyan for kernel values in [0, 1]
+fn kernel_color(k: f64) -> egui::Color32 {
+    let t = k.clamp(0.0, 1.0) as f32;
+    egui::Color32::from_rgb((20.0 + 80.0 * t) as u8, (20.0 + 180.0 * t) as u8, (35.0 + 220.0 * t) as u8)
+}
+
+impl FeatureMapView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.points = self.dataset.generate(NUM_POINTS, self.noise, self.seed);
+        // Class blocks make the kernel's structure visible
+        self.points.sort_by_key(|p| p.label);
+        self.selected = self.selected.min(self.points.len() - 1);
+        self.update_gram();
+    }
+
+    fn encoded(&self, point: &LabeledPoint) -> Vec<f64> {
+        point.features().iter().map(|v| self.scale * v).collect()
+    }
+
+    fn update_gram(&mut self) {
+        let inputs: Vec<Vec<f64>> = self.points.iter().map(|p| self.encoded(p)).collect();
+        self.gram = self.map.gram_matrix(&inputs);
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Quantum Feature Maps")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Encode classical points as quantum states; state overlaps define a kernel")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_dataset(&mut columns[0]);
+                self.render_encoding(&mut columns[1]);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_gram(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut reencode) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Dataset:");
+                    for dataset in Dataset::ALL {
+                        regenerate |= ui.selectable_value(&mut self.dataset, dataset, dataset.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    regenerate |= ui.add(egui::Slider::new(&mut self.noise, 0.0..=0.3).text("noise")).changed();
+                    if ui.button("🎲 Resample").clicked() {
+                        self.seed += 1;
+                        regenerate = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Encoding:");
+                    for map in FeatureMap::ALL {
+                        let selected = self.map.name() == map.name();
+                        if ui.selectable_label(selected, map.name()).clicked() && !selected {
+                            self.map = map;
+                            reencode = true;
+                        }
+                    }
+                    if let Some(mut depth) = self.map.depth() {
+                        ui.add_space(16.0);
+                        if ui.add(egui::Slider::new(&mut depth, 1..=4).text("repetitions")).changed() {
+                            self.map = self.map.with_depth(depth);
+                            reencode = true;
+                        }
+                    }
+                    ui.add_space(16.0);
+                    reencode |= ui.add(egui::Slider::new(&mut self.scale, 0.25..=std::f64::consts::PI).text("feature scale")).changed();
+                });
+            });
+        if regenerate {
+            self.regenerate();
+        } else if reencode {
+            self.update_gram();
+        }
+    }
+
+    fn render_dataset(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📊 Data (click a point to encode it)");
+        let class = |label: bool| self.points.iter().filter(move |p| p.label == label).map(|p| [p.x, p.y]).collect::<Vec<_>>();
+        let selected = &self.points[self.selected];
+        let response = Plot::new("feature_map_data")
+            .height(300.0)
+            .data_aspect(1.0)
+            .legend(Legend::default())
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.points(Points::new("Class 0", class(false)).radius(5.0).color(egui::Color32::from_rgb(100, 200, 255)));
+                plot_ui.points(Points::new("Class 1", class(true)).radius(5.0).color(egui::Color32::from_rgb(255, 200, 100)));
+                plot_ui.points(Points::new("Encoded", vec![[selected.x, selected.y]])
+                    .radius(9.0)
+                    .filled(false)
+                    .color(egui::Color32::WHITE));
+                plot_ui.pointer_coordinate()
+            });
+        if response.response.clicked()
+            && let Some(pointer) = response.inner {
+            let distance = |p: &LabeledPoint| (p.x - pointer.x).powi(2) + (p.y - pointer.y).powi(2);
+            if let Some((index, _)) = self.points.iter().enumerate().min_by(|a, b| distance(a.1).total_cmp(&distance(b.1))) {
+                self.selected = index;
+            }
+        }
+    }
+
+    fn render_encoding(&self, ui: &mut egui::Ui) {
+        let point = &self.points[self.selected];
+        let x = self.encoded(point);
+        Self::section_label(ui, &format!("🔮 {} encoding of ({:+.2}, {:+.2})", self.map.name(), point.x, point.y));
+        let state = self.map.state(&x);
+        ui.horizontal_wrapped(|ui| {
+            for q in 0..state.num_qubits() {
+                BlochSphere::new(state.bloch_vector(q)).label(format!("q{}", q)).size(150.0).show(ui);
+            }
+        });
+        let entangled = (0..state.num_qubits()).any(|q| {
+            let [bx, by, bz] = state.bloch_vector(q);
+            (bx * bx + by * by + bz * bz).sqrt() < 1.0 - 1e-6
+        });
+        if entangled {
+            ui.label(egui::RichText::new("Arrows inside the sphere: the qubits are entangled, so each one alone is mixed")
+                .color(egui::Color32::from_rgb(255, 200, 100))
+                .size(12.0));
+        }
+        ui.add_space(8.0);
+        egui::ScrollArea::horizontal().id_salt("feature_map_circuit").show(ui, |ui| {
+            CircuitDiagram::new(&self.map.circuit(&x)).show(ui);
+        });
+    }
+
+    fn render_gram(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🧮 Kernel matrix k(x, x') = |⟨φ(x)|φ(x')⟩|²");
+        let n = self.points.len();
+        let boundary = self.points.iter().filter(|p| !p.label).count();
+        ui.horizontal(|ui| {
+            let cell = GRAM_SIZE / n as f32;
+            let (rect, response) = ui.allocate_exact_size(egui::vec2(GRAM_SIZE, GRAM_SIZE), egui::Sense::hover());
+            let painter = ui.painter();
+            for ((i, j), &k) in self.gram.indexed_iter() {
+                let min = rect.min + egui::vec2(cell * j as f32, cell * i as f32);
+                painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(cell, cell)), 0.0, kernel_color(k));
+            }
+            // Class boundary
+            let split = cell * boundary as f32;
+            let stroke = egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 100, 150));
+            painter.line_segment([rect.min + egui::vec2(split, 0.0), rect.min + egui::vec2(split, GRAM_SIZE)], stroke);
+            painter.line_segment([rect.min + egui::vec2(0.0, split), rect.min + egui::vec2(GRAM_SIZE, split)], stroke);
+            if let Some(pos) = response.hover_pos() {
+                let j = (((pos.x - rect.left()) / cell) as usize).min(n - 1);
+                let i = (((pos.y - rect.top()) / cell) as usize).min(n - 1);
+                response.on_hover_text(format!("k(x{}, x{}) = {:.4}", i, j, self.gram[[i, j]]));
+            }
+
+            ui.add_space(16.0);
+            ui.vertical(|ui| {
+                let mean = |same: bool| {
+                    let values: Vec<f64> = self.gram
+                        .indexed_iter()
+                        .filter(|((i, j), _)| i != j && (self.points[*i].label == self.points[*j].label) == same)
+                        .map(|(_, &k)| k)
+                        .collect();
+                    values.iter().sum::<f64>() / values.len().max(1) as f64
+                };
+                let (within, between) = (mean(true), mean(false));
+                ui.label(format!("Mean kernel within a class: {:.3}", within));
+                ui.label(format!("Mean kernel across classes: {:.3}", between));
+
+                // Kernel-target alignment ⟨K, yyᵀ⟩ / (‖K‖ ‖yyᵀ‖) with y = ±1
+                let sign = |p: &LabeledPoint| if p.label { 1.0 } else { -1.0 };
+                let overlap: f64 = self.gram.indexed_iter().map(|((i, j), &k)| k * sign(&self.points[i]) * sign(&self.points[j])).sum();
+                let norm = self.gram.iter().map(|k| k * k).sum::<f64>().sqrt();
+                let alignment = overlap / (norm * n as f64);
+                ui.label(egui::RichText::new(format!("Kernel-target alignment: {:.3}", alignment))
+                    .color(egui::Color32::from_rgb(100, 200, 255))
+                    .strong());
+                ui.add_space(8.0);
+                let (verdict, color) = if within - between > 0.1 {
+                    ("Same-class points look alike: this kernel separates the classes", egui::Color32::from_rgb(100, 255, 150))
+                } else {
+                    ("Classes overlap in feature space: try another encoding or scale", egui::Color32::from_rgb(255, 200, 100))
+                };
+                ui.label(egui::RichText::new(verdict).color(color).size(13.0));
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new(format!("Rows and columns: class 0 (0–{}), then class 1", boundary.saturating_sub(1)))
+                    .color(egui::Color32::from_rgb(160, 160, 180))
+                    .size(12.0));
+            });
+        });
+    }
+}
@@ -0,0 +1,500 @@
+use crate::app::widgets::decision_boundary::DecisionBoundary;
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::regression::linear_regression::TrainingConfig;
+use crate::core::hybrid::dataset::{Dataset, LabeledPoint};
+use crate::core::hybrid::pipeline::{Pipeline, PipelineError, StageSpec};
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::gradients::GradientMethod;
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+use ndarray::{Array1, Array2, array};
+
+const NUM_POINTS: usize = 40;
+const MAX_EPOCHS: usize = 300;
+const GRID: usize = 24;
+const EXTENT: f64 = 1.4;
+const NODE_SIZE: egui::Vec2 = egui::vec2(130.0, 56.0);
+const GRAPH_HEIGHT: f32 = 110.0;
+const MAX_STAGES: usize = 6;
+const MAX_WIDTH: usize = 8;
+const MAX_QUBITS: usize = 4;
+
+// An edit made from the node graph, applied after it is drawn
+enum StageAction {
+    Edit(usize),
+    Move(usize, usize),
+    Remove(usize),
+    Insert(usize, StageSpec),
+}
+
+// One sample pushed through the built pipeline and back
+struct Trace {
+    tensors: Vec<Array1<f64>>,
+    grads: Vec<Array1<f64>>,
+    output: f64,
+    loss: f64,
+    grad_output: f64,
+}
+
+pub struct HybridInferenceView {
+    dataset: Dataset,
+    seed: u64,
+    specs: Vec<StageSpec>,
+    // Stage whose settings are open below the graph
+    editing: Option<usize>,
+    method: GradientMethod,
+    learning_rate: f64,
+    running: bool,
+    // Sample whose tensors the graph shows
+    selected: usize,
+    // Cached from the settings above
+    points: Vec<LabeledPoint>,
+    x_train: Array2<f64>,
+    y_train: Array2<f64>,
+    // Last pipeline built from valid specs
+    pipeline: Pipeline,
+    error: Option<PipelineError>,
+    boundary: Array2<f64>,
+}
+
+impl Default for HybridInferenceView {
+    fn default() -> Self {
+        let mut view = Self {
+            dataset: Dataset::Moons,
+            seed: 0,
+            specs: vec![
+                StageSpec::Scaler,
+                StageSpec::Dense { outputs: 2, activation: Activation::Tanh },
+                StageSpec::Quantum { qubits: 2, layers: 2 },
+                StageSpec::Dense { outputs: 1, activation: Activation::Sigmoid },
+            ],
+            editing: None,
+            method: GradientMethod::Adjoint,
+            learning_rate: 0.5,
+            running: false,
+            selected: 0,
+            points: Vec::new(),
+            x_train: Array2::zeros((0, 2)),
+            y_train: Array2::zeros((0, 1)),
+            pipeline: Pipeline::new(Vec::new()),
+            error: None,
+            boundary: Array2::zeros((0, 0)),
+        };
+        view.regenerate();
+        view
+    }
+}
+
+fn format_tensor(tensor: &Array1<f64>) -> String {
+    let values: Vec<String> = tensor.iter().map(|v| format!("{:+.3}", v)).collect();
+    format!("[{}]", values.join(", "))
+}
+
+impl HybridInferenceView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.points = self.dataset.generate(NUM_POINTS, 0.05, self.seed);
+        self.x_train = Array2::from_shape_fn((self.points.len(), 2), |(i, j)| self.points[i].features()[j]);
+        self.y_train = Array2::from_shape_fn((self.points.len(), 1), |(i, _)| if self.points[i].label { 1.0 } else { 0.0 });
+        self.reset();
+    }
+
+    // Rebuilds from the specs; invalid specs keep the last pipeline and report why
+    fn reset(&mut self) {
+        self.running = false;
+        match Pipeline::build(&self.specs, &self.x_train, self.method, self.seed) {
+            Ok(pipeline) => {
+                self.pipeline = pipeline;
+                self.error = None;
+                self.update_boundary();
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    fn update_boundary(&mut self) {
+        self.boundary = self.pipeline
+            .predict(DecisionBoundary::grid(GRID, EXTENT))
+            .into_shape_with_order((GRID, GRID))
+            .expect("one prediction per cell");
+    }
+
+    fn train_epoch(&mut self) {
+        let config = TrainingConfig {
+            learning_rate: self.learning_rate,
+            epochs: 1,
+            verbose: false,
+        };
+        self.pipeline.train(self.x_train.clone(), self.y_train.clone(), config);
+        self.update_boundary();
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            self.train_epoch();
+            if self.pipeline.loss_history.len() >= MAX_EPOCHS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Hybrid Inference")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Chain classical and quantum stages, check their widths and train the whole pipeline end to end by backpropagation")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            self.render_graph(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_boundary(&mut columns[0]);
+                self.render_loss(&mut columns[1]);
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut reset) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Dataset:");
+                    for dataset in Dataset::ALL {
+                        regenerate |= ui.selectable_value(&mut self.dataset, dataset, dataset.name()).changed();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Quantum gradient:");
+                    for method in GradientMethod::ALL {
+                        reset |= ui.selectable_value(&mut self.method, method, method.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    ui.add(egui::Slider::new(&mut self.learning_rate, 0.01..=2.0).logarithmic(true).text("learning rate"));
+                });
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let valid = self.error.is_none();
+                    let label = if self.running { "⏸ Pause" } else { "▶ Train" };
+                    if ui.add_enabled(valid, egui::Button::new(label)).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.add_enabled(valid, egui::Button::new("⏭ Epoch")).clicked() {
+                        self.train_epoch();
+                    }
+                    if ui.button("↺ Reset").clicked() {
+                        reset = true;
+                    }
+                    ui.add_space(16.0);
+                    ui.add(egui::Slider::new(&mut self.selected, 0..=self.points.len() - 1).text("inspected sample"));
+                });
+            });
+        if regenerate {
+            self.regenerate();
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    fn trace(&self) -> Option<Trace> {
+        // Tensors only exist once the specs have been built
+        if self.error.is_some() {
+            return None;
+        }
+        let point = &self.points[self.selected];
+        let label = if point.label { 1.0 } else { 0.0 };
+        let tensors = self.pipeline.trace(&array![point.x, point.y]);
+        let output = tensors.last().expect("output")[0];
+        let (loss, grad_output) = Pipeline::loss(output, label);
+        let grads = self.pipeline.backward(&tensors, &array![grad_output]);
+        Some(Trace { tensors, grads, output, loss, grad_output })
+    }
+
+    fn render_graph(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🔗 Pipeline (click a stage to edit it, right-click to move or remove it)");
+        let point = &self.points[self.selected];
+        let label = if point.label { 1.0 } else { 0.0 };
+        let trace = self.trace();
+        let failed = self.error.as_ref().map(|error| error.stage);
+        let mut actions = Vec::new();
+
+        let nodes = self.specs.len() + 2;
+        let width = ui.available_width();
+        let (response, painter) = ui.allocate_painter(egui::vec2(width, GRAPH_HEIGHT), egui::Sense::hover());
+        let rect = response.rect;
+        let node_width = ((width - 12.0 * (nodes - 1) as f32) / nodes as f32).min(NODE_SIZE.x);
+        let gap = (width - node_width * nodes as f32) / (nodes - 1) as f32;
+        let node_rect = |i: usize| {
+            egui::Rect::from_min_size(
+                egui::pos2(rect.left() + i as f32 * (node_width + gap), rect.center().y - NODE_SIZE.y / 2.0),
+                egui::vec2(node_width, NODE_SIZE.y),
+            )
+        };
+
+        for i in 0..nodes - 1 {
+            let (from, to) = (node_rect(i).right_center(), node_rect(i + 1).left_center());
+            let color = if failed == Some(i) {
+                egui::Color32::from_rgb(255, 100, 150)
+            } else {
+                egui::Color32::from_rgb(100, 160, 240)
+            };
+            painter.arrow(from, to - from, egui::Stroke::new(2.0, color));
+        }
+
+        for i in 0..nodes {
+            let node = node_rect(i);
+            let stage = (1..nodes - 1).contains(&i).then(|| i - 1);
+            let (title, fill) = match stage {
+                None if i == 0 => ("Input".to_string(), egui::Color32::from_rgb(40, 45, 60)),
+                None => ("Output".to_string(), egui::Color32::from_rgb(40, 45, 60)),
+                Some(s) if failed == Some(s) => (self.specs[s].name(), egui::Color32::from_rgb(100, 40, 60)),
+                Some(s) if matches!(self.specs[s], StageSpec::Quantum { .. }) => (self.specs[s].name(), egui::Color32::from_rgb(60, 50, 100)),
+                Some(s) => (self.specs[s].name(), egui::Color32::from_rgb(35, 55, 75)),
+            };
+            painter.rect_filled(node, 8.0, fill);
+            if stage.is_some() && stage == self.editing {
+                painter.rect_stroke(node, 8.0, egui::Stroke::new(2.0, egui::Color32::WHITE), egui::StrokeKind::Inside);
+            }
+            painter.text(node.center() - egui::vec2(0.0, 9.0), egui::Align2::CENTER_CENTER, &title, egui::FontId::proportional(12.0), egui::Color32::WHITE);
+            // The tensor a node hands on (its input, for the output node)
+            let summary = match &trace {
+                Some(trace) if i == nodes - 1 => format!("P = {:.3}", trace.output),
+                Some(trace) => format!("{} values", trace.tensors[i.min(nodes - 2)].len()),
+                None => "—".to_string(),
+            };
+            painter.text(node.center() + egui::vec2(0.0, 10.0), egui::Align2::CENTER_CENTER, summary, egui::FontId::monospace(11.0), egui::Color32::from_rgb(160, 160, 180));
+
+            let sense = if stage.is_some() { egui::Sense::click() } else { egui::Sense::hover() };
+            let interaction = ui.interact(node, ui.id().with(("pipeline_node", i)), sense);
+            if let Some(s) = stage {
+                if interaction.clicked() {
+                    actions.push(StageAction::Edit(s));
+                }
+                interaction.context_menu(|ui| {
+                    if ui.add_enabled(s > 0, egui::Button::new("◀ Move left")).clicked() {
+                        actions.push(StageAction::Move(s, s - 1));
+                    }
+                    if ui.add_enabled(s + 1 < self.specs.len(), egui::Button::new("Move right ▶")).clicked() {
+                        actions.push(StageAction::Move(s, s + 1));
+                    }
+                    if ui.add_enabled(self.specs.len() > 1, egui::Button::new("🗑 Remove")).clicked() {
+                        actions.push(StageAction::Remove(s));
+                    }
+                });
+            }
+            interaction.on_hover_ui(|ui| {
+                ui.label(egui::RichText::new(&title).strong());
+                let Some(trace) = &trace else {
+                    ui.label("Fix the highlighted stage to trace a sample");
+                    return;
+                };
+                if i == 0 {
+                    ui.label(format!("x = {}", format_tensor(&trace.tensors[0])));
+                    ui.label(format!("label = {}", label));
+                    return;
+                }
+                if i == nodes - 1 {
+                    ui.label(format!("P(class 1) = {:.4}", trace.output));
+                    ui.label(format!("Cross-entropy = {:.4}", trace.loss));
+                    ui.label(format!("∂L/∂P = {:+.4}", trace.grad_output));
+                    return;
+                }
+                let stage = &self.pipeline.stages[i - 1];
+                ui.label(format!("Built as {}", stage.name()));
+                ui.label(format!("in  = {}", format_tensor(&trace.tensors[i - 1])));
+                ui.label(format!("out = {}", format_tensor(&trace.tensors[i])));
+                let params = stage.params();
+                if params.is_empty() {
+                    ui.label("No trainable parameters");
+                } else {
+                    let norm = trace.grads[i - 1].iter().map(|g| g * g).sum::<f64>().sqrt();
+                    ui.label(format!("{} parameters, |∂L/∂θ| = {:.4}", params.len(), norm));
+                }
+                if let Some(state) = stage.state(&trace.tensors[i - 1]) {
+                    ui.add_space(4.0);
+                    ui.label("Prepared state:");
+                    ProbabilityBars::new("pipeline_state", &state.probabilities(), state.num_qubits())
+                        .height(100.0)
+                        .show(ui);
+                    for q in 0..state.num_qubits() {
+                        let [x, y, z] = state.bloch_vector(q);
+                        ui.label(format!("q{} Bloch ({:+.2}, {:+.2}, {:+.2})", q, x, y, z));
+                    }
+                }
+            });
+        }
+        ui.label(egui::RichText::new(format!(
+            "Sample {} at ({:+.2}, {:+.2}), class {}: blue arrows carry activations forward, gradients flow back the same way",
+            self.selected,
+            point.x,
+            point.y,
+            label
+        ))
+        .color(egui::Color32::from_rgb(160, 160, 180))
+        .size(12.0));
+
+        ui.add_space(8.0);
+        self.render_stage_editor(ui, &mut actions);
+        if let Some(error) = &self.error {
+            ui.label(egui::RichText::new(format!("⚠ {}", error)).color(egui::Color32::from_rgb(255, 100, 150)));
+        }
+
+        if actions.is_empty() {
+            return;
+        }
+        for action in actions {
+            match action {
+                StageAction::Edit(s) => self.editing = (self.editing != Some(s)).then_some(s),
+                StageAction::Move(from, to) => {
+                    self.specs.swap(from, to);
+                    self.editing = Some(to);
+                }
+                StageAction::Remove(s) => {
+                    self.specs.remove(s);
+                    self.editing = None;
+                }
+                StageAction::Insert(at, spec) => {
+                    self.specs.insert(at, spec);
+                    self.editing = Some(at);
+                }
+            }
+        }
+        self.reset();
+    }
+
+    // Settings of the stage picked in the graph, and buttons to add stages after it
+    fn render_stage_editor(&mut self, ui: &mut egui::Ui, actions: &mut Vec<StageAction>) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                if let Some(s) = self.editing.filter(|&s| s < self.specs.len()) {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(format!("Stage {}:", s + 1)).strong());
+                        match &mut self.specs[s] {
+                            StageSpec::Scaler => {
+                                ui.label("standardizes each feature with the training data's mean and spread");
+                            }
+                            StageSpec::Dense { outputs, activation } => {
+                                changed |= ui.add(egui::Slider::new(outputs, 1..=MAX_WIDTH).text("outputs")).changed();
+                                egui::ComboBox::from_id_salt("pipeline_activation")
+                                    .selected_text(activation.name())
+                                    .show_ui(ui, |ui| {
+                                        for kind in Activation::ALL {
+                                            changed |= ui.selectable_value(activation, kind, kind.name()).changed();
+                                        }
+                                    });
+                            }
+                            StageSpec::Quantum { qubits, layers } => {
+                                changed |= ui.add(egui::Slider::new(qubits, 1..=MAX_QUBITS).text("qubits")).changed();
+                                changed |= ui.add(egui::Slider::new(layers, 1..=4).text("layers")).changed();
+                            }
+                        }
+                    });
+                    ui.add_space(4.0);
+                }
+                ui.horizontal(|ui| {
+                    let at = self.editing.map_or(self.specs.len(), |s| s + 1).min(self.specs.len());
+                    ui.label(if self.editing.is_some() { "Add after it:" } else { "Add at the end:" });
+                    let room = self.specs.len() < MAX_STAGES;
+                    for spec in [
+                        StageSpec::Dense { outputs: 2, activation: Activation::Tanh },
+                        StageSpec::Quantum { qubits: 2, layers: 1 },
+                        StageSpec::Scaler,
+                    ] {
+                        let name = match spec {
+                            StageSpec::Scaler => "+ Scaler",
+                            StageSpec::Dense { .. } => "+ Dense",
+                            StageSpec::Quantum { .. } => "+ Quantum",
+                        };
+                        if ui.add_enabled(room, egui::Button::new(name)).clicked() {
+                            actions.push(StageAction::Insert(at, spec));
+                        }
+                    }
+                    if !room {
+                        ui.label(egui::RichText::new(format!("at most {} stages", MAX_STAGES))
+                            .color(egui::Color32::from_rgb(160, 160, 180))
+                            .size(12.0));
+                    }
+                });
+            });
+        if changed {
+            self.reset();
+        }
+    }
+
+    fn render_boundary(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🗺 Decision boundary");
+        let predicted: Vec<bool> = self.pipeline.predict(self.x_train.clone()).iter().map(|p| *p > 0.5).collect();
+        let accuracy = predicted.iter().zip(&self.points).filter(|(p, point)| **p == point.label).count() as f64 / self.points.len() as f64;
+        DecisionBoundary::new(&self.boundary, &self.points, EXTENT)
+            .predicted(&predicted)
+            .highlighted(&[self.selected])
+            .size(320.0)
+            .show(ui);
+        let color = if accuracy >= 0.9 {
+            egui::Color32::from_rgb(100, 255, 150)
+        } else {
+            egui::Color32::from_rgb(255, 200, 100)
+        };
+        ui.label(egui::RichText::new(format!("Training accuracy: {:.0}%", 100.0 * accuracy))
+            .color(color)
+            .strong());
+        ui.label(egui::RichText::new("The inspected sample is outlined in white")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn render_loss(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📉 Cross-entropy loss per epoch");
+        let losses: PlotPoints = self.pipeline.loss_history.iter().enumerate().map(|(i, l)| [i as f64 + 1.0, *l]).collect();
+        Plot::new("pipeline_loss")
+            .height(280.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("Loss", losses).color(egui::Color32::from_rgb(100, 200, 255)));
+            });
+        let parameters = self.pipeline.params().len();
+        ui.label(format!("{} epochs, {} trainable parameters across all stages", self.pipeline.loss_history.len(), parameters));
+    }
+}
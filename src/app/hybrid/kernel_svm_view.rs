@@ -0,0 +1,270 @@
+use crate::app::widgets::decision_boundary::DecisionBoundary;
+use crate::core::ai::svm::kernel::{Kernel, LinearKernel, RbfKernel};
+use crate::core::ai::svm::support_vector_machine::{Svm, SvmConfig};
+use crate::core::hybrid::dataset::{Dataset, LabeledPoint};
+use crate::core::hybrid::feature_maps::FeatureMap;
+use crate::core::hybrid::quantum_kernel::QuantumKernel;
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Plot};
+use ndarray::Array2;
+
+const NUM_POINTS: usize = 40;
+const GRID: usize = 24;
+const EXTENT: f64 = 1.4;
+const CANVAS_SIZE: f32 = 260.0;
+
+// One trained classifier and what the view shows about it
+struct Fit {
+    svm: Svm,
+    boundary: Array2<f64>,
+    predicted: Vec<bool>,
+    train_accuracy: f64,
+    test_accuracy: f64,
+}
+
+pub struct KernelSvmView {
+    dataset: Dataset,
+    noise: f64,
+    seed: u64,
+    feature_map: FeatureMap,
+    scale: f64,
+    gamma: f64,
+    c: f64,
+    // Cached from the settings above: quantum, RBF, linear
+    train: Vec<LabeledPoint>,
+    test: Vec<LabeledPoint>,
+    fits: Vec<Fit>,
+}
+
+impl Default for KernelSvmView {
+    fn default() -> Self {
+        let mut view = Self {
+            dataset: Dataset::Circles,
+            noise: 0.05,
+            seed: 0,
+            feature_map: FeatureMap::ZZ { reps: 1 },
+            scale: 1.5,
+            gamma: 2.0,
+            c: 10.0,
+            train: Vec::new(),
+            test: Vec::new(),
+            fits: Vec::new(),
+        };
+        view.regenerate();
+        view
+    }
+}
+
+fn features(points: &[LabeledPoint]) -> Array2<f64> {
+    Array2::from_shape_fn((points.len(), 2), |(i, j)| points[i].features()[j])
+}
+
+fn labels(points: &[LabeledPoint]) -> Array2<f64> {
+    Array2::from_shape_fn((points.len(), 1), |(i, _)| if points[i].label { 1.0 } else { 0.0 })
+}
+
+fn accuracy(predictions: &Array2<f64>, points: &[LabeledPoint]) -> f64 {
+    let correct = predictions.iter().zip(points).filter(|(p, point)| (**p > 0.5) == point.label).count();
+    correct as f64 / points.len() as f64
+}
+
+impl KernelSvmView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.train = self.dataset.generate(NUM_POINTS, self.noise, self.seed);
+        // Held-out points from the same distribution
+        self.test = self.dataset.generate(NUM_POINTS, self.noise, self.seed + 1000);
+        self.refit();
+    }
+
+    fn refit(&mut self) {
+        let kernels: [Box<dyn Kernel>; 3] = [
+            Box::new(QuantumKernel { feature_map: self.feature_map, scale: self.scale }),
+            Box::new(RbfKernel { gamma: self.gamma }),
+            Box::new(LinearKernel),
+        ];
+        let (x_train, x_test) = (features(&self.train), features(&self.test));
+        self.fits = kernels
+            .into_iter()
+            .map(|kernel| {
+                let mut svm = Svm::new(kernel);
+                svm.train(x_train.clone(), labels(&self.train), SvmConfig { c: self.c, ..SvmConfig::default() });
+                // Squash f(x) so that the margin f = ±1 shows as clearly blue / amber
+                let boundary = svm
+                    .decision_function(&DecisionBoundary::grid(GRID, EXTENT))
+                    .mapv(|f| 0.5 + 0.5 * f.tanh())
+                    .into_shape_with_order((GRID, GRID))
+                    .expect("one value per cell");
+                let train_predictions = svm.predict(x_train.clone());
+                let test_predictions = svm.predict(x_test.clone());
+                Fit {
+                    predicted: train_predictions.iter().map(|p| *p > 0.5).collect(),
+                    train_accuracy: accuracy(&train_predictions, &self.train),
+                    test_accuracy: accuracy(&test_predictions, &self.test),
+                    boundary,
+                    svm,
+                }
+            })
+            .collect();
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Quantum Kernel SVM")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("The same SMO-trained support vector machine with a quantum fidelity kernel, an RBF kernel and a linear kernel")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(3, |columns| {
+                for (fit, column) in self.fits.iter().zip(columns.iter_mut()) {
+                    Self::render_fit(column, fit, &self.train);
+                }
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_comparison(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut refit) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Dataset:");
+                    for dataset in Dataset::ALL {
+                        regenerate |= ui.selectable_value(&mut self.dataset, dataset, dataset.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    regenerate |= ui.add(egui::Slider::new(&mut self.noise, 0.0..=0.3).text("noise")).changed();
+                    if ui.button("🎲 Resample").clicked() {
+                        self.seed += 1;
+                        regenerate = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Quantum encoding:");
+                    for map in FeatureMap::ALL {
+                        let selected = self.feature_map.name() == map.name();
+                        if ui.selectable_label(selected, map.name()).clicked() && !selected {
+                            self.feature_map = map;
+                            refit = true;
+                        }
+                    }
+                    if let Some(mut depth) = self.feature_map.depth() {
+                        ui.add_space(16.0);
+                        if ui.add(egui::Slider::new(&mut depth, 1..=4).text("repetitions")).changed() {
+                            self.feature_map = self.feature_map.with_depth(depth);
+                            refit = true;
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    refit |= ui.add(egui::Slider::new(&mut self.scale, 0.25..=std::f64::consts::PI).text("feature scale")).changed();
+                    ui.add_space(16.0);
+                    refit |= ui.add(egui::Slider::new(&mut self.gamma, 0.1..=20.0).logarithmic(true).text("RBF γ")).changed();
+                    ui.add_space(16.0);
+                    refit |= ui.add(egui::Slider::new(&mut self.c, 0.1..=100.0).logarithmic(true).text("C")).changed();
+                });
+            });
+        if regenerate {
+            self.regenerate();
+        } else if refit {
+            self.refit();
+        }
+    }
+
+    fn render_fit(ui: &mut egui::Ui, fit: &Fit, train: &[LabeledPoint]) {
+        Self::section_label(ui, &fit.svm.kernel().name());
+        DecisionBoundary::new(&fit.boundary, train, EXTENT)
+            .predicted(&fit.predicted)
+            .highlighted(&fit.svm.support)
+            .size(CANVAS_SIZE)
+            .show(ui);
+        ui.add_space(6.0);
+        ui.label(format!("Train accuracy: {:.0}%", 100.0 * fit.train_accuracy));
+        let color = if fit.test_accuracy >= 0.9 {
+            egui::Color32::from_rgb(100, 255, 150)
+        } else {
+            egui::Color32::from_rgb(255, 200, 100)
+        };
+        ui.label(egui::RichText::new(format!("Test accuracy: {:.0}%", 100.0 * fit.test_accuracy))
+            .color(color)
+            .strong());
+        ui.label(format!("{} support vectors, b = {:+.3}", fit.svm.support.len(), fit.svm.bias));
+    }
+
+    fn render_comparison(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🏁 Held-out accuracy");
+        let colors = [
+            egui::Color32::from_rgb(150, 170, 255),
+            egui::Color32::from_rgb(100, 200, 255),
+            egui::Color32::from_rgb(160, 160, 180),
+        ];
+        let bars: Vec<Bar> = self.fits
+            .iter()
+            .zip(colors)
+            .enumerate()
+            .map(|(i, (fit, color))| {
+                Bar::new(i as f64, 100.0 * fit.test_accuracy)
+                    .name(fit.svm.kernel().name())
+                    .width(0.6)
+                    .fill(color)
+            })
+            .collect();
+        let names: Vec<String> = self.fits.iter().map(|fit| fit.svm.kernel().name()).collect();
+        Plot::new("kernel_svm_comparison")
+            .height(200.0)
+            .include_y(0.0)
+            .include_y(100.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .x_axis_formatter(move |mark, _| {
+                let index = mark.value.round();
+                if (mark.value - index).abs() < 1e-6 && index >= 0.0 {
+                    names.get(index as usize).cloned().unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("Test accuracy (%)", bars));
+            });
+        ui.label(egui::RichText::new("White outlines mark support vectors; pink rings are misclassified training points")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod feature_map_view;
+pub mod hybrid_inference_view;
+pub mod kernel_svm_view;
+pub mod qaoa_view;
+pub mod qnn_view;
+pub mod vqe_view;
@@ -0,0 +1,418 @@
+use crate::core::ai::optim::optimizer::OptimizerKind;
+use crate::core::quantum::qaoa::{BETA_RANGE, GAMMA_RANGE, Graph, MAX_NODES, Qaoa, landscape};
+use eframe::egui;
+use egui_plot::{HLine, Legend, Line, Plot, PlotPoints};
+use ndarray::Array2;
+
+const MAX_ITERATIONS: usize = 300;
+const LANDSCAPE_RESOLUTION: usize = 32;
+const NODE_RADIUS: f32 = 14.0;
+const CANVAS_HEIGHT: f32 = 300.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Highlight {
+    Qaoa,
+    Optimum,
+}
+
+pub struct QaoaView {
+    graph: Graph,
+    // Node centres in [0, 1] x [0, 1] canvas coordinates
+    positions: Vec<egui::Pos2>,
+    selected: Option<usize>,
+    new_weight: f64,
+    layers: usize,
+    optimizer: OptimizerKind,
+    step_size: f64,
+    highlight: Highlight,
+    running: bool,
+    // Cached from the graph and settings above
+    qaoa: Qaoa,
+    optimum: (f64, Vec<usize>),
+    landscape: Array2<f64>,
+}
+
+impl Default for QaoaView {
+    fn default() -> Self {
+        let graph = Graph::ring(5);
+        let mut view = Self {
+            positions: circle_layout(graph.num_nodes),
+            qaoa: Qaoa::new(graph.clone(), 1, OptimizerKind::Cobyla, 0.5, 0),
+            optimum: (0.0, Vec::new()),
+            landscape: Array2::zeros((0, 0)),
+            graph,
+            selected: None,
+            new_weight: 1.0,
+            layers: 1,
+            optimizer: OptimizerKind::Cobyla,
+            step_size: 0.5,
+            highlight: Highlight::Qaoa,
+            running: false,
+        };
+        view.graph_changed();
+        view
+    }
+}
+
+fn circle_layout(n: usize) -> Vec<egui::Pos2> {
+    (0..n)
+        .map(|i| {
+            let angle = std::f32::consts::TAU * i as f32 / n as f32 - std::f32::consts::FRAC_PI_2;
+            egui::pos2(0.5 + 0.38 * angle.cos(), 0.5 + 0.38 * angle.sin())
+        })
+        .collect()
+}
+
+// Dark blue → cyan → amber
+fn heat_color(t: f64) -> egui::Color32 {
+    let t = t.clamp(0.0, 1.0) as f32;
+    let lerp = |a: u8, b: u8, s: f32| (a as f32 + (b as f32 - a as f32) * s) as u8;
+    let (from, to, s) = if t < 0.5 {
+        ((30, 30, 70), (100, 200, 255), 2.0 * t)
+    } else {
+        ((100, 200, 255), (255, 200, 100), 2.0 * t - 1.0)
+    };
+    egui::Color32::from_rgb(lerp(from.0, to.0, s), lerp(from.1, to.1, s), lerp(from.2, to.2, s))
+}
+
+fn bitstring(bits: usize, n: usize) -> String {
+    (0..n).rev().map(|q| if bits >> q & 1 == 1 { '1' } else { '0' }).collect()
+}
+
+impl QaoaView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn reset(&mut self) {
+        self.qaoa = Qaoa::new(self.graph.clone(), self.layers, self.optimizer, self.step_size, 0);
+        self.running = false;
+    }
+
+    fn graph_changed(&mut self) {
+        self.optimum = self.graph.max_cut();
+        self.landscape = landscape(&self.graph, LANDSCAPE_RESOLUTION);
+        self.reset();
+    }
+
+    fn load(&mut self, graph: Graph) {
+        self.positions = circle_layout(graph.num_nodes);
+        self.graph = graph;
+        self.selected = None;
+        self.graph_changed();
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            self.qaoa.step();
+            if self.qaoa.history.len() > MAX_ITERATIONS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Quantum Approximate Optimization")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Alternate cost and mixer layers to concentrate probability on large cuts of a weighted graph")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_editor(&mut columns[0]);
+                self.render_result(&mut columns[1]);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_landscape(&mut columns[0]);
+                self.render_convergence(&mut columns[1]);
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    changed |= ui.add(egui::Slider::new(&mut self.layers, 1..=4).text("layers p")).changed();
+                    ui.add_space(16.0);
+                    ui.label("Optimizer:");
+                    for optimizer in OptimizerKind::ALL {
+                        changed |= ui.selectable_value(&mut self.optimizer, optimizer, optimizer.name()).on_hover_text(optimizer.description()).changed();
+                    }
+                    ui.add_space(16.0);
+                    let text = if self.optimizer.uses_gradient() { "learning rate" } else { "step size" };
+                    changed |= ui.add(egui::Slider::new(&mut self.step_size, 0.01..=1.0).logarithmic(true).text(text)).changed();
+                });
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let label = if self.running { "⏸ Pause" } else { "▶ Optimize" };
+                    if ui.button(label).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.button("⏭ Step").clicked() {
+                        self.qaoa.step();
+                    }
+                    if ui.button("↺ Reset").clicked() {
+                        changed = true;
+                    }
+                });
+            });
+        if changed {
+            self.reset();
+        }
+    }
+
+    fn render_editor(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🕸 Graph");
+        ui.horizontal_wrapped(|ui| {
+            if ui.button("Ring 4").clicked() {
+                self.load(Graph::ring(4));
+            }
+            if ui.button("Ring 6").clicked() {
+                self.load(Graph::ring(6));
+            }
+            if ui.button("K4").clicked() {
+                self.load(Graph::complete(4));
+            }
+            if ui.button("🎲 Random").clicked() {
+                let mut graph = Graph::new(6);
+                for i in 0..6 {
+                    for j in i + 1..6 {
+                        if rand::random::<f64>() < 0.45 {
+                            graph.set_edge(i, j, 1.0);
+                        }
+                    }
+                }
+                self.load(graph);
+            }
+            if ui.button("🗑 Clear").clicked() {
+                self.load(Graph::new(0));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.add(egui::Slider::new(&mut self.new_weight, 0.5..=3.0).step_by(0.5).text("new edge weight"));
+        });
+        ui.add_space(4.0);
+
+        let size = egui::vec2(ui.available_width(), CANVAS_HEIGHT);
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::click());
+        let rect = response.rect;
+        painter.rect_filled(rect, 6.0, egui::Color32::from_rgb(25, 25, 35));
+        let to_screen = |p: egui::Pos2| rect.min + egui::vec2(p.x * rect.width(), p.y * rect.height());
+
+        let cut = match self.highlight {
+            Highlight::Qaoa => self.qaoa.most_likely(),
+            Highlight::Optimum => self.optimum.1.first().copied().unwrap_or(0),
+        };
+        let side = |node: usize| cut >> node & 1 == 1;
+
+        for &(i, j, w) in &self.graph.edges {
+            let (a, b) = (to_screen(self.positions[i]), to_screen(self.positions[j]));
+            let stroke = if side(i) != side(j) {
+                egui::Stroke::new(1.5 + 1.5 * w as f32, egui::Color32::from_rgb(100, 255, 150))
+            } else {
+                egui::Stroke::new(1.0 + 1.0 * w as f32, egui::Color32::from_rgb(80, 80, 100))
+            };
+            painter.line_segment([a, b], stroke);
+            if (w - 1.0).abs() > 1e-9 {
+                painter.text(a + (b - a) * 0.5, egui::Align2::CENTER_BOTTOM, format!("{}", w), egui::FontId::monospace(11.0), egui::Color32::from_rgb(200, 200, 220));
+            }
+        }
+        for (node, &p) in self.positions.iter().enumerate() {
+            let center = to_screen(p);
+            let fill = if side(node) {
+                egui::Color32::from_rgb(255, 200, 100)
+            } else {
+                egui::Color32::from_rgb(100, 200, 255)
+            };
+            painter.circle_filled(center, NODE_RADIUS, fill);
+            if self.selected == Some(node) {
+                painter.circle_stroke(center, NODE_RADIUS + 3.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+            }
+            painter.text(center, egui::Align2::CENTER_CENTER, format!("{}", node), egui::FontId::proportional(13.0), egui::Color32::from_rgb(20, 20, 30));
+        }
+
+        let pointer = response.interact_pointer_pos();
+        let hit = pointer.and_then(|pos| self.positions.iter().position(|&p| to_screen(p).distance(pos) <= NODE_RADIUS + 2.0));
+        let mut changed = false;
+        if response.clicked()
+            && let Some(pos) = pointer {
+            match (hit, self.selected) {
+                (Some(node), Some(selected)) if node == selected => self.selected = None,
+                (Some(node), Some(selected)) => {
+                    if self.graph.edge(node, selected).is_some() {
+                        self.graph.remove_edge(node, selected);
+                    } else {
+                        self.graph.set_edge(node, selected, self.new_weight);
+                    }
+                    self.selected = None;
+                    changed = true;
+                }
+                (Some(node), None) => self.selected = Some(node),
+                (None, _) if self.graph.num_nodes < MAX_NODES => {
+                    self.graph.add_node();
+                    let local = pos - rect.min;
+                    self.positions.push(egui::pos2(local.x / rect.width(), local.y / rect.height()));
+                    self.selected = None;
+                    changed = true;
+                }
+                (None, _) => self.selected = None,
+            }
+        }
+        if response.secondary_clicked()
+            && let Some(node) = hit {
+            self.graph.remove_node(node);
+            self.positions.remove(node);
+            self.selected = None;
+            changed = true;
+        }
+        response.on_hover_text("Click empty space to add a node, click two nodes to toggle an edge, right-click a node to delete it");
+        if changed {
+            self.graph_changed();
+        }
+    }
+
+    fn render_result(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "✂ Best cut");
+        let n = self.graph.num_nodes;
+        if self.graph.edges.is_empty() {
+            ui.label(egui::RichText::new("Add some edges to have something to cut")
+                .color(egui::Color32::from_rgb(160, 160, 180)));
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label("Show on graph:");
+            ui.selectable_value(&mut self.highlight, Highlight::Qaoa, "QAOA's most likely cut");
+            ui.selectable_value(&mut self.highlight, Highlight::Optimum, "Brute-force optimum");
+        });
+        ui.add_space(8.0);
+
+        let (best, cuts) = &self.optimum;
+        let likely = self.qaoa.most_likely();
+        let value = self.graph.cut_value(likely);
+        ui.label(egui::RichText::new(format!("QAOA: |{}⟩ cuts {:.1}", bitstring(likely, n), value))
+            .color(egui::Color32::from_rgb(100, 200, 255))
+            .size(16.0)
+            .strong());
+        ui.label(egui::RichText::new(format!(
+            "Optimum: {:.1} ({})",
+            best,
+            cuts.iter().map(|&c| format!("|{}⟩", bitstring(c, n))).collect::<Vec<_>>().join(" ")
+        ))
+        .size(13.0));
+        let (verdict, color) = if (value - best).abs() < 1e-9 {
+            ("✓ The most likely measurement is a maximum cut", egui::Color32::from_rgb(100, 255, 150))
+        } else {
+            ("The most likely measurement is not yet optimal", egui::Color32::from_rgb(255, 200, 100))
+        };
+        ui.label(egui::RichText::new(verdict).color(color).size(13.0));
+
+        ui.add_space(8.0);
+        let probabilities = self.qaoa.probabilities();
+        let optimal: f64 = cuts.iter().map(|&c| probabilities[c]).sum();
+        ui.label(format!("⟨C⟩ = {:.4}, approximation ratio {:.3}", self.qaoa.expected_cut(), self.qaoa.expected_cut() / best));
+        ui.label(format!("P(optimal cut) = {:.1}%  (uniform guessing: {:.1}%)", 100.0 * optimal, 100.0 * cuts.len() as f64 / (1 << n) as f64));
+        ui.label(format!("{} iterations, {} evaluations", self.qaoa.history.len() - 1, self.qaoa.evaluations));
+        ui.add_space(4.0);
+        let params = self.qaoa.params.as_slice().expect("contiguous parameters");
+        for (layer, pair) in params.chunks(2).enumerate() {
+            ui.label(egui::RichText::new(format!("layer {}: γ = {:+.3}, β = {:+.3}", layer + 1, pair[0], pair[1]))
+                .code()
+                .color(egui::Color32::from_rgb(200, 200, 220)));
+        }
+    }
+
+    fn render_landscape(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🗺 p = 1 landscape ⟨C⟩(γ, β)");
+        let grid = &self.landscape;
+        let (low, high) = grid.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        let span = (high - low).max(1e-12);
+
+        let side = ui.available_width().min(320.0);
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(side, side), egui::Sense::hover());
+        let painter = ui.painter();
+        let cell = side / LANDSCAPE_RESOLUTION as f32;
+        // β increases upwards
+        for ((row, col), &v) in grid.indexed_iter() {
+            let min = egui::pos2(rect.left() + cell * col as f32, rect.bottom() - cell * (row + 1) as f32);
+            painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(cell + 0.5, cell + 0.5)), 0.0, heat_color((v - low) / span));
+        }
+        let to_screen = |gamma: f64, beta: f64| {
+            egui::pos2(
+                rect.left() + side * (gamma / GAMMA_RANGE) as f32,
+                rect.bottom() - side * (beta / BETA_RANGE) as f32,
+            )
+        };
+        if self.qaoa.layers == 1 {
+            let (gamma, beta) = (self.qaoa.params[0].rem_euclid(GAMMA_RANGE), self.qaoa.params[1].rem_euclid(BETA_RANGE));
+            painter.circle_stroke(to_screen(gamma, beta), 6.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+        }
+        if let Some(pos) = response.hover_pos() {
+            let gamma = GAMMA_RANGE * ((pos.x - rect.left()) / side) as f64;
+            let beta = BETA_RANGE * ((rect.bottom() - pos.y) / side) as f64;
+            let col = ((gamma / GAMMA_RANGE) * (LANDSCAPE_RESOLUTION - 1) as f64).round() as usize;
+            let row = ((beta / BETA_RANGE) * (LANDSCAPE_RESOLUTION - 1) as f64).round() as usize;
+            if let Some(v) = grid.get((row, col)) {
+                response.on_hover_text(format!("γ = {:.2}, β = {:.2}: ⟨C⟩ = {:.3}", gamma, beta, v));
+            }
+        }
+        ui.label(egui::RichText::new(format!(
+            "γ → 0 … π, β ↑ 0 … π/2; range {:.2} … {:.2}{}",
+            low,
+            high,
+            if self.qaoa.layers == 1 { ", ○ = current parameters" } else { "" }
+        ))
+        .color(egui::Color32::from_rgb(160, 160, 180))
+        .size(12.0));
+    }
+
+    fn render_convergence(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📈 Expected cut vs iteration");
+        let values: PlotPoints = self.qaoa.history.iter().enumerate().map(|(i, v)| [i as f64, *v]).collect();
+        Plot::new("qaoa_convergence")
+            .height(280.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_y(0.0)
+            .include_y(self.optimum.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("⟨C⟩", values).color(egui::Color32::from_rgb(100, 200, 255)));
+                plot_ui.hline(HLine::new("Maximum cut", self.optimum.0).color(egui::Color32::from_rgb(100, 255, 150)));
+                plot_ui.hline(HLine::new("Random cut", self.graph.total_weight() / 2.0).color(egui::Color32::from_rgb(255, 200, 100)));
+            });
+    }
+}
@@ -0,0 +1,275 @@
+use crate::app::widgets::circuit_diagram::CircuitDiagram;
+use crate::app::widgets::decision_boundary::DecisionBoundary;
+use crate::core::ai::regression::linear_regression::TrainingConfig;
+use crate::core::hybrid::dataset::{Dataset, LabeledPoint};
+use crate::core::hybrid::feature_maps::FeatureMap;
+use crate::core::hybrid::qnn::Qnn;
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+use ndarray::Array2;
+
+const NUM_POINTS: usize = 40;
+const MAX_EPOCHS: usize = 200;
+const GRID: usize = 28;
+// The boundary plot spans [-EXTENT, EXTENT] on both axes
+const EXTENT: f64 = 1.4;
+const CANVAS_SIZE: f32 = 340.0;
+
+pub struct QnnView {
+    dataset: Dataset,
+    noise: f64,
+    feature_map: FeatureMap,
+    scale: f64,
+    layers: usize,
+    learning_rate: f64,
+    seed: u64,
+    running: bool,
+    // Cached from the settings above
+    points: Vec<LabeledPoint>,
+    x_train: Array2<f64>,
+    y_train: Array2<f64>,
+    model: Qnn,
+    // P(class 1) over the GRID x GRID canvas, row 0 at the top
+    boundary: Array2<f64>,
+}
+
+impl Default for QnnView {
+    fn default() -> Self {
+        let mut view = Self {
+            dataset: Dataset::Circles,
+            noise: 0.05,
+            feature_map: FeatureMap::ReUploading { layers: 2 },
+            scale: 1.5,
+            layers: 2,
+            learning_rate: 0.5,
+            seed: 0,
+            running: false,
+            points: Vec::new(),
+            x_train: Array2::zeros((0, 2)),
+            y_train: Array2::zeros((0, 1)),
+            model: Qnn::new(FeatureMap::Angle, 1.0, 1, 2, 0),
+            boundary: Array2::zeros((0, 0)),
+        };
+        view.regenerate();
+        view
+    }
+}
+
+impl QnnView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.points = self.dataset.generate(NUM_POINTS, self.noise, self.seed);
+        self.x_train = Array2::from_shape_fn((self.points.len(), 2), |(i, j)| self.points[i].features()[j]);
+        self.y_train = Array2::from_shape_fn((self.points.len(), 1), |(i, _)| if self.points[i].label { 1.0 } else { 0.0 });
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.model = Qnn::new(self.feature_map, self.scale, self.layers, 2, self.seed);
+        self.running = false;
+        self.update_boundary();
+    }
+
+    fn update_boundary(&mut self) {
+        self.boundary = self.model.predict(DecisionBoundary::grid(GRID, EXTENT)).into_shape_with_order((GRID, GRID)).expect("one prediction per cell");
+    }
+
+    fn train_epoch(&mut self) {
+        let config = TrainingConfig {
+            learning_rate: self.learning_rate,
+            epochs: 1,
+            verbose: false,
+        };
+        self.model.train(self.x_train.clone(), self.y_train.clone(), config);
+        self.update_boundary();
+    }
+
+    fn accuracy(&self) -> f64 {
+        let predictions = self.model.predict(self.x_train.clone());
+        let correct = predictions.iter().zip(self.y_train.iter()).filter(|(p, y)| (**p > 0.5) == (**y > 0.5)).count();
+        correct as f64 / self.points.len() as f64
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            self.train_epoch();
+            if self.model.loss_history.len() >= MAX_EPOCHS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Quantum Neural Networks")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Encode a point, apply trainable rotations, read ⟨Z⟩ as a class probability; train with parameter-shift gradients")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_boundary(&mut columns[0]);
+                self.render_training(&mut columns[1]);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_circuit(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut reset) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Dataset:");
+                    for dataset in Dataset::ALL {
+                        regenerate |= ui.selectable_value(&mut self.dataset, dataset, dataset.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    regenerate |= ui.add(egui::Slider::new(&mut self.noise, 0.0..=0.3).text("noise")).changed();
+                    if ui.button("🎲 Resample").clicked() {
+                        self.seed += 1;
+                        regenerate = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Encoding:");
+                    for map in FeatureMap::ALL {
+                        let selected = self.feature_map.name() == map.name();
+                        if ui.selectable_label(selected, map.name()).clicked() && !selected {
+                            self.feature_map = map;
+                            reset = true;
+                        }
+                    }
+                    if let Some(mut depth) = self.feature_map.depth() {
+                        ui.add_space(16.0);
+                        if ui.add(egui::Slider::new(&mut depth, 1..=4).text("repetitions")).changed() {
+                            self.feature_map = self.feature_map.with_depth(depth);
+                            reset = true;
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    reset |= ui.add(egui::Slider::new(&mut self.scale, 0.25..=std::f64::consts::PI).text("feature scale")).changed();
+                    ui.add_space(16.0);
+                    reset |= ui.add(egui::Slider::new(&mut self.layers, 1..=5).text("ansatz layers")).changed();
+                    ui.add_space(16.0);
+                    ui.add(egui::Slider::new(&mut self.learning_rate, 0.01..=2.0).logarithmic(true).text("learning rate"));
+                });
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let label = if self.running { "⏸ Pause" } else { "▶ Train" };
+                    if ui.button(label).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.button("⏭ Epoch").clicked() {
+                        self.train_epoch();
+                    }
+                    if ui.button("↺ Reset weights").clicked() {
+                        reset = true;
+                    }
+                });
+            });
+        if regenerate {
+            self.regenerate();
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    fn render_boundary(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🗺 Decision boundary P(class 1)");
+        let predicted: Vec<bool> = self.model.predict(self.x_train.clone()).iter().map(|p| *p > 0.5).collect();
+        DecisionBoundary::new(&self.boundary, &self.points, EXTENT)
+            .predicted(&predicted)
+            .size(CANVAS_SIZE)
+            .show(ui);
+        ui.label(egui::RichText::new("Blue = class 0, amber = class 1; pink rings mark misclassified points")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn render_training(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📉 Cross-entropy loss per epoch");
+        let losses: PlotPoints = self.model.loss_history.iter().enumerate().map(|(i, l)| [i as f64 + 1.0, *l]).collect();
+        Plot::new("qnn_loss")
+            .height(240.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("Loss", losses).color(egui::Color32::from_rgb(100, 200, 255)));
+            });
+
+        ui.add_space(8.0);
+        let accuracy = self.accuracy();
+        let color = if accuracy >= 0.9 {
+            egui::Color32::from_rgb(100, 255, 150)
+        } else {
+            egui::Color32::from_rgb(255, 200, 100)
+        };
+        ui.label(egui::RichText::new(format!("Training accuracy: {:.0}%", 100.0 * accuracy))
+            .color(color)
+            .size(16.0)
+            .strong());
+        if let Some(loss) = self.model.loss_history.last() {
+            ui.label(format!("Epoch {}, loss {:.4}", self.model.loss_history.len(), loss));
+        } else {
+            ui.label("Untrained: press ▶ Train");
+        }
+        let qubits = self.feature_map.num_qubits(2);
+        let shifts = 2 * self.model.weights.len() * self.points.len();
+        ui.label(format!(
+            "{} qubits, {} trainable weights, {} shifted circuit runs per epoch",
+            qubits,
+            self.model.weights.len(),
+            shifts
+        ));
+    }
+
+    fn render_circuit(&self, ui: &mut egui::Ui) {
+        let point = &self.points[0];
+        Self::section_label(ui, &format!("🔌 Model circuit for the point ({:+.2}, {:+.2})", point.x, point.y));
+        ui.label(egui::RichText::new("Feature map first, then the trainable layers; qubit 0 is measured")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+        ui.add_space(6.0);
+        egui::ScrollArea::horizontal().id_salt("qnn_circuit").show(ui, |ui| {
+            CircuitDiagram::new(&self.model.circuit(&point.features())).show(ui);
+        });
+    }
+}
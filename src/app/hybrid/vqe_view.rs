@@ -0,0 +1,266 @@
+use crate::app::widgets::circuit_diagram::CircuitDiagram;
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::core::ai::optim::optimizer::OptimizerKind;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::gradients::GradientMethod;
+use crate::core::quantum::vqe::{Ansatz, Molecule, Vqe};
+use eframe::egui;
+use egui_plot::{HLine, Legend, Line, Plot, PlotPoints};
+
+const MAX_ITERATIONS: usize = 500;
+// Optimizer steps per frame while running
+const STEPS_PER_FRAME: usize = 2;
+// 1 kcal/mol, the usual target for chemistry
+const CHEMICAL_ACCURACY: f64 = 1.6e-3;
+
+pub struct VqeView {
+    molecule: Molecule,
+    ansatz: Ansatz,
+    layers: usize,
+    optimizer: OptimizerKind,
+    step_size: f64,
+    gradient: GradientMethod,
+    seed: u64,
+    running: bool,
+    // Cached from the settings above
+    vqe: Vqe,
+    exact: f64,
+    hartree_fock: f64,
+}
+
+impl Default for VqeView {
+    fn default() -> Self {
+        let mut view = Self {
+            molecule: Molecule::H2,
+            ansatz: Ansatz::DoubleExcitation,
+            layers: 1,
+            optimizer: OptimizerKind::Cobyla,
+            step_size: 0.5,
+            gradient: GradientMethod::ParameterShift,
+            seed: 0,
+            running: false,
+            vqe: Vqe::new(Molecule::H2, Ansatz::DoubleExcitation, OptimizerKind::Cobyla, 0.5, 0),
+            exact: 0.0,
+            hartree_fock: 0.0,
+        };
+        view.reset();
+        view
+    }
+}
+
+impl VqeView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn reset(&mut self) {
+        if let Ansatz::HardwareEfficient { .. } = self.ansatz {
+            self.ansatz = Ansatz::HardwareEfficient { layers: self.layers };
+        }
+        self.vqe = Vqe::new(self.molecule, self.ansatz, self.optimizer, self.step_size, self.seed);
+        self.vqe.gradient = self.gradient;
+        self.exact = self.vqe.hamiltonian.ground_energy();
+        self.hartree_fock = self.vqe.hamiltonian.expectation(&Ansatz::DoubleExcitation.state(&ndarray::array![0.0]));
+        self.running = false;
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            for _ in 0..STEPS_PER_FRAME {
+                self.vqe.step();
+            }
+            if self.vqe.history.len() > MAX_ITERATIONS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("⚛ Variational Quantum Eigensolver")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("A parameterized circuit prepares a trial state; a classical optimizer lowers ⟨H⟩ towards the ground energy")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_convergence(&mut columns[0]);
+                self.render_result(&mut columns[1]);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_ansatz(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Molecule:");
+                    for molecule in Molecule::ALL {
+                        changed |= ui.selectable_value(&mut self.molecule, molecule, molecule.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    ui.label("Ansatz:");
+                    for ansatz in [Ansatz::DoubleExcitation, Ansatz::HardwareEfficient { layers: self.layers }] {
+                        let selected = std::mem::discriminant(&self.ansatz) == std::mem::discriminant(&ansatz);
+                        if ui.selectable_label(selected, ansatz.name()).clicked() && !selected {
+                            self.ansatz = ansatz;
+                            changed = true;
+                        }
+                    }
+                    if let Ansatz::HardwareEfficient { .. } = self.ansatz {
+                        changed |= ui.add(egui::Slider::new(&mut self.layers, 1..=4).text("layers")).changed();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Optimizer:");
+                    for optimizer in OptimizerKind::ALL {
+                        changed |= ui.selectable_value(&mut self.optimizer, optimizer, optimizer.name()).on_hover_text(optimizer.description()).changed();
+                    }
+                    ui.add_space(16.0);
+                    let text = if self.optimizer.uses_gradient() { "learning rate" } else { "step size" };
+                    changed |= ui.add(egui::Slider::new(&mut self.step_size, 0.01..=1.0).logarithmic(true).text(text)).changed();
+                });
+                if self.optimizer.uses_gradient() {
+                    ui.horizontal(|ui| {
+                        ui.label("Gradient:");
+                        for method in GradientMethod::ALL {
+                            if ui.selectable_value(&mut self.gradient, method, method.name()).changed() {
+                                // Takes effect from the next step, no restart needed
+                                self.vqe.gradient = method;
+                            }
+                        }
+                    });
+                }
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let label = if self.running { "⏸ Pause" } else { "▶ Run" };
+                    if ui.button(label).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.button("⏭ Step").clicked() {
+                        self.vqe.step();
+                    }
+                    if ui.button("↺ Reset").clicked() {
+                        changed = true;
+                    }
+                    if ui.button("🎲 New start").on_hover_text("Different random initial parameters and SPSA directions").clicked() {
+                        self.seed += 1;
+                        changed = true;
+                    }
+                });
+            });
+        if changed {
+            self.reset();
+        }
+    }
+
+    fn render_convergence(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📉 Energy vs iteration");
+        let energies: PlotPoints = self.vqe.history.iter().enumerate().map(|(i, e)| [i as f64, *e]).collect();
+        Plot::new("vqe_convergence")
+            .height(280.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_y(self.exact - 0.01)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("⟨H⟩ (Ha)", energies).color(egui::Color32::from_rgb(100, 200, 255)));
+                plot_ui.hline(HLine::new("Exact ground energy", self.exact).color(egui::Color32::from_rgb(100, 255, 150)));
+                plot_ui.hline(HLine::new("Hartree–Fock", self.hartree_fock).color(egui::Color32::from_rgb(255, 200, 100)));
+            });
+    }
+
+    fn render_result(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("🎯 Current estimate for {}", self.vqe.molecule.name()));
+        let error = self.vqe.energy() - self.exact;
+        ui.label(egui::RichText::new(format!("E = {:.6} Ha", self.vqe.energy()))
+            .color(egui::Color32::from_rgb(100, 200, 255))
+            .size(18.0)
+            .strong());
+        let color = if error < CHEMICAL_ACCURACY {
+            egui::Color32::from_rgb(100, 255, 150)
+        } else {
+            egui::Color32::from_rgb(255, 200, 100)
+        };
+        ui.label(egui::RichText::new(format!(
+            "{:.3} mHa above the exact {:.6} Ha{}",
+            1000.0 * error,
+            self.exact,
+            if error < CHEMICAL_ACCURACY { " — chemical accuracy" } else { "" }
+        ))
+        .color(color)
+        .size(13.0));
+        ui.label(format!(
+            "Correlation energy recovered: {:.1}%",
+            100.0 * (self.hartree_fock - self.vqe.energy()) / (self.hartree_fock - self.exact)
+        ));
+        ui.label(format!(
+            "{} iterations, {} energy evaluations, {} parameters",
+            self.vqe.history.len() - 1,
+            self.vqe.evaluations,
+            self.vqe.template.num_parameters()
+        ));
+
+        ui.add_space(8.0);
+        let state = self.vqe.state();
+        ProbabilityBars::new("vqe_probabilities", &state.probabilities(), state.num_qubits())
+            .height(160.0)
+            .show(ui);
+
+        ui.add_space(8.0);
+        ui.collapsing("Parameters", |ui| {
+            for (name, value) in self.vqe.template.parameter_names().iter().zip(&self.vqe.params) {
+                ui.label(egui::RichText::new(format!("{} = {:+.4}", name, value)).code());
+            }
+        });
+        ui.collapsing(format!("Hamiltonian ({} Pauli terms)", self.vqe.hamiltonian.terms.len()), |ui| {
+            for (c, p) in &self.vqe.hamiltonian.terms {
+                ui.label(egui::RichText::new(format!("{:+.6}  {}", c, p.label())).code());
+            }
+        });
+    }
+
+    fn render_ansatz(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("🔌 {} ansatz at the current parameters", self.ansatz.name()));
+        ui.label(egui::RichText::new("Spin orbitals q0, q1 start filled (Hartree–Fock); q2, q3 are the empty antibonding pair")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+        ui.add_space(6.0);
+        egui::ScrollArea::horizontal().id_salt("vqe_ansatz").show(ui, |ui| {
+            CircuitDiagram::new(&self.vqe.ansatz.circuit(&self.vqe.params)).show(ui);
+        });
+    }
+}
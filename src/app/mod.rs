@@ -1,2 +1,9 @@
+pub mod clustering;
+pub mod embeddings;
+pub mod hybrid;
 pub mod myapp;
-pub mod regression;
\ No newline at end of file
+pub mod nn;
+pub mod optim;
+pub mod quantum;
+pub mod regression;
+pub mod widgets;
\ No newline at end of file
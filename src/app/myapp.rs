@@ -1,5 +1,22 @@
 // app/myapp.rs
 use eframe::{self, egui};
+use crate::app::clustering::clustering_view::ClusteringView;
+use crate::app::embeddings::embeddings_view::EmbeddingsView;
+use crate::app::hybrid::feature_map_view::FeatureMapView;
+use crate::app::hybrid::hybrid_inference_view::HybridInferenceView;
+use crate::app::hybrid::kernel_svm_view::KernelSvmView;
+use crate::app::hybrid::qaoa_view::QaoaView;
+use crate::app::hybrid::qnn_view::QnnView;
+use crate::app::hybrid::vqe_view::VqeView;
+use crate::app::nn::activation_functions_view::ActivationFunctionsView;
+use crate::app::nn::attention_view::AttentionView;
+use crate::app::nn::neural_network_view::NeuralNetworkView;
+use crate::app::optim::gradient_descent_view::GradientDescentView;
+use crate::app::quantum::algorithms_view::AlgorithmsView;
+use crate::app::quantum::circuit_composer_view::CircuitComposerView;
+use crate::app::quantum::circuit_debugger_view::CircuitDebuggerView;
+use crate::app::quantum::entanglement_view::EntanglementView;
+use crate::app::quantum::shor_view::ShorView;
 use crate::app::regression::linear_regression_view::LinearRegressionView;
 
 #[derive(Clone, Debug)]
@@ -21,6 +38,23 @@ pub struct MyApp {
     filtered_categories: Vec<Category>,
      current_view: Option<String>,  
     lr_view: LinearRegressionView,  
+    clustering_view: ClusteringView,
+    nn_view: NeuralNetworkView,
+    embeddings_view: EmbeddingsView,
+    activations_view: ActivationFunctionsView,
+    gd_view: GradientDescentView,
+    attention_view: AttentionView,
+    circuit_view: CircuitComposerView,
+    debugger_view: CircuitDebuggerView,
+    entanglement_view: EntanglementView,
+    algorithms_view: AlgorithmsView,
+    shor_view: ShorView,
+    vqe_view: VqeView,
+    qaoa_view: QaoaView,
+    feature_map_view: FeatureMapView,
+    qnn_view: QnnView,
+    kernel_svm_view: KernelSvmView,
+    hybrid_inference_view: HybridInferenceView,
 }
 
 impl MyApp {
@@ -34,26 +68,30 @@ impl MyApp {
                         title: "Linear Regression".to_string(),
                         description: "Visualize Linear Regression".to_string(),
                     },
-                    // MenuItem {
-                    //     title: "Neural Networks".to_string(),
-                    //     description: "Visualize neural network architectures".to_string(),
-                    // },
-                    // MenuItem {
-                    //     title: "Embeddings".to_string(),
-                    //     description: "Vector space representations".to_string(),
-                    // },
-                    // MenuItem {
-                    //     title: "Activation Functions".to_string(),
-                    //     description: "Explore ReLU, Sigmoid, Tanh".to_string(),
-                    // },
-                    // MenuItem {
-                    //     title: "Gradient Descent".to_string(),
-                    //     description: "Optimization visualization".to_string(),
-                    // },
-                    // MenuItem {
-                    //     title: "Attention Mechanisms".to_string(),
-                    //     description: "Transformer attention patterns".to_string(),
-                    // },
+                    MenuItem {
+                        title: "Clustering".to_string(),
+                        description: "K-means, DBSCAN and Gaussian mixtures".to_string(),
+                    },
+                    MenuItem {
+                        title: "Neural Networks".to_string(),
+                        description: "Visualize neural network architectures".to_string(),
+                    },
+                    MenuItem {
+                        title: "Embeddings".to_string(),
+                        description: "Vector space representations".to_string(),
+                    },
+                    MenuItem {
+                        title: "Activation Functions".to_string(),
+                        description: "Explore ReLU, Sigmoid, Tanh".to_string(),
+                    },
+                    MenuItem {
+                        title: "Gradient Descent".to_string(),
+                        description: "Optimization visualization".to_string(),
+                    },
+                    MenuItem {
+                        title: "Attention Mechanisms".to_string(),
+                        description: "Transformer attention patterns".to_string(),
+                    },
                 ],
             },
             Category {
@@ -68,14 +106,26 @@ impl MyApp {
                     //     title: "Quantum Gates".to_string(),
                     //     description: "Pauli, Hadamard, CNOT gates".to_string(),
                     // },
-                    // MenuItem {
-                    //     title: "Quantum Circuits".to_string(),
-                    //     description: "Build and simulate circuits".to_string(),
-                    // },
-                    // MenuItem {
-                    //     title: "Entanglement".to_string(),
-                    //     description: "Bell states and correlations".to_string(),
-                    // },
+                    MenuItem {
+                        title: "Quantum Circuits".to_string(),
+                        description: "Build and simulate circuits".to_string(),
+                    },
+                    MenuItem {
+                        title: "Circuit Debugger".to_string(),
+                        description: "Step through the state moment by moment".to_string(),
+                    },
+                    MenuItem {
+                        title: "Entanglement".to_string(),
+                        description: "Bell states and correlations".to_string(),
+                    },
+                    MenuItem {
+                        title: "Quantum Algorithms".to_string(),
+                        description: "Grover, QFT, phase estimation and more".to_string(),
+                    },
+                    MenuItem {
+                        title: "Shor's Algorithm".to_string(),
+                        description: "Factoring by quantum order finding".to_string(),
+                    },
                     // MenuItem {
                     //     title: "Bloch Sphere".to_string(),
                     //     description: "Qubit state visualization".to_string(),
@@ -102,6 +152,10 @@ impl MyApp {
                         title: "Quantum Feature Maps".to_string(),
                         description: "Classical to quantum encoding".to_string(),
                     },
+                    MenuItem {
+                        title: "Quantum Kernel SVM".to_string(),
+                        description: "Fidelity kernels vs classical".to_string(),
+                    },
                     MenuItem {
                         title: "Hybrid Inference".to_string(),
                         description: "Classical-Quantum pipelines".to_string(),
@@ -118,6 +172,23 @@ impl MyApp {
             filtered_categories,
              current_view: None, 
             lr_view: LinearRegressionView::new(),  
+            clustering_view: ClusteringView::new(),
+            nn_view: NeuralNetworkView::new(),
+            embeddings_view: EmbeddingsView::new(),
+            activations_view: ActivationFunctionsView::new(),
+            gd_view: GradientDescentView::new(),
+            attention_view: AttentionView::new(),
+            circuit_view: CircuitComposerView::new(),
+            debugger_view: CircuitDebuggerView::new(),
+            entanglement_view: EntanglementView::new(),
+            algorithms_view: AlgorithmsView::new(),
+            shor_view: ShorView::new(),
+            vqe_view: VqeView::new(),
+            qaoa_view: QaoaView::new(),
+            feature_map_view: FeatureMapView::new(),
+            qnn_view: QnnView::new(),
+            kernel_svm_view: KernelSvmView::new(),
+            hybrid_inference_view: HybridInferenceView::new(),
         }
     }
 
@@ -346,6 +417,58 @@ impl MyApp {
             Some(view) if view == "Linear Regression" => {
                 self.lr_view.render(ui);
             },
+            Some(view) if view == "Clustering" => {
+                self.clustering_view.render(ui);
+            },
+            Some(view) if view == "Neural Networks" => {
+                self.nn_view.render(ui);
+            },
+            Some(view) if view == "Embeddings" => {
+                self.embeddings_view.render(ui);
+            },
+            Some(view) if view == "Activation Functions" => {
+                self.activations_view.render(ui);
+            },
+            Some(view) if view == "Gradient Descent" => {
+                self.gd_view.set_regression_data(self.lr_view.training_data());
+                self.gd_view.render(ui);
+            },
+            Some(view) if view == "Attention Mechanisms" => {
+                self.attention_view.render(ui);
+            },
+            Some(view) if view == "Quantum Circuits" => {
+                self.circuit_view.render(ui);
+            },
+            Some(view) if view == "Circuit Debugger" => {
+                self.debugger_view.render(ui, self.circuit_view.circuit());
+            },
+            Some(view) if view == "Entanglement" => {
+                self.entanglement_view.render(ui);
+            },
+            Some(view) if view == "Quantum Algorithms" => {
+                self.algorithms_view.render(ui);
+            },
+            Some(view) if view == "Shor's Algorithm" => {
+                self.shor_view.render(ui);
+            },
+            Some(view) if view == "Variational Quantum Eigensolver" => {
+                self.vqe_view.render(ui);
+            },
+            Some(view) if view == "Quantum Approximate Optimization" => {
+                self.qaoa_view.render(ui);
+            },
+            Some(view) if view == "Quantum Feature Maps" => {
+                self.feature_map_view.render(ui);
+            },
+            Some(view) if view == "Quantum Neural Networks" => {
+                self.qnn_view.render(ui);
+            },
+            Some(view) if view == "Quantum Kernel SVM" => {
+                self.kernel_svm_view.render(ui);
+            },
+            Some(view) if view == "Hybrid Inference" => {
+                self.hybrid_inference_view.render(ui);
+            },
             _ => {
                 // Existing welcome screen code
                 self.render_welcome_screen(ui);
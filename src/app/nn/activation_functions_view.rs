@@ -0,0 +1,293 @@
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::activations::softmax::{softmax, softmax_jacobian};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints};
+use ndarray::Array1;
+
+const SAMPLES: usize = 400;
+const LOGITS: usize = 4;
+// Gradients outside [1e-3, 1e3] count as vanished or exploded
+const HEALTHY_DECADES: f64 = 3.0;
+
+// One colour per entry of Activation::ALL
+const PALETTE: [egui::Color32; 10] = [
+    egui::Color32::from_rgb(160, 160, 180),
+    egui::Color32::from_rgb(100, 200, 255),
+    egui::Color32::from_rgb(80, 140, 255),
+    egui::Color32::from_rgb(150, 120, 255),
+    egui::Color32::from_rgb(200, 120, 255),
+    egui::Color32::from_rgb(255, 100, 150),
+    egui::Color32::from_rgb(255, 150, 80),
+    egui::Color32::from_rgb(255, 200, 100),
+    egui::Color32::from_rgb(100, 255, 150),
+    egui::Color32::from_rgb(60, 200, 180),
+];
+
+pub struct ActivationFunctionsView {
+    // Which of Activation::ALL are overlaid
+    shown: [bool; 10],
+    range: f64,
+    // Deep scalar chain a_l = f(w a_(l-1) + b) for the vanishing-gradient plot
+    depth: usize,
+    weight: f64,
+    bias: f64,
+    input: f64,
+    logits: [f64; LOGITS],
+    temperature: f64,
+}
+
+impl Default for ActivationFunctionsView {
+    fn default() -> Self {
+        let mut shown = [false; 10];
+        for activation in [Activation::ReLU, Activation::Gelu, Activation::Sigmoid, Activation::Tanh] {
+            shown[Self::index(activation)] = true;
+        }
+        Self {
+            shown,
+            range: 5.0,
+            depth: 20,
+            weight: 1.0,
+            bias: 0.0,
+            input: 0.5,
+            logits: [2.0, 1.0, 0.5, -1.0],
+            temperature: 1.0,
+        }
+    }
+}
+
+impl ActivationFunctionsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn index(activation: Activation) -> usize {
+        Activation::ALL.iter().position(|a| *a == activation).expect("listed in ALL")
+    }
+
+    fn shown(&self) -> impl Iterator<Item = (Activation, egui::Color32)> + '_ {
+        Activation::ALL.into_iter().zip(PALETTE).filter(|(activation, _)| self.shown[Self::index(*activation)])
+    }
+
+    // |∂a_l / ∂x| for l = 1..=depth along the chain
+    fn chained_gradients(&self, activation: Activation) -> Vec<f64> {
+        let (mut a, mut gradient) = (self.input, 1.0);
+        (0..self.depth)
+            .map(|_| {
+                let z = self.weight * a + self.bias;
+                gradient *= self.weight * activation.derivative(z);
+                a = activation.apply(z);
+                gradient.abs()
+            })
+            .collect()
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Activation Functions")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("The non-linearities between layers, their derivatives, and what those derivatives do to gradients in deep networks")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_functions(&mut columns[0], false);
+                self.render_functions(&mut columns[1], true);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_vanishing(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_softmax(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("Overlay:");
+                    for (activation, color) in Activation::ALL.into_iter().zip(PALETTE) {
+                        let shown = &mut self.shown[Self::index(activation)];
+                        let text = egui::RichText::new(activation.name()).color(color);
+                        if ui.selectable_label(*shown, text).clicked() {
+                            *shown = !*shown;
+                        }
+                    }
+                });
+                ui.add(egui::Slider::new(&mut self.range, 1.0..=10.0).text("z range ±"));
+            });
+    }
+
+    fn render_functions(&self, ui: &mut egui::Ui, derivative: bool) {
+        let (title, id) = if derivative { ("📈 Derivatives f'(z)", "activation_derivatives") } else { ("📈 Functions f(z)", "activation_functions") };
+        Self::section_label(ui, title);
+        Plot::new(id)
+            .height(280.0)
+            .legend(Legend::default())
+            .include_y(-1.0)
+            .include_y(1.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                for (activation, color) in self.shown() {
+                    let points: PlotPoints = (0..=SAMPLES)
+                        .map(|i| {
+                            let z = -self.range + 2.0 * self.range * i as f64 / SAMPLES as f64;
+                            [z, if derivative { activation.derivative(z) } else { activation.apply(z) }]
+                        })
+                        .collect();
+                    plot_ui.line(Line::new(activation.name(), points).color(color).width(2.0));
+                }
+            });
+    }
+
+    fn render_vanishing(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🔻 Vanishing and exploding gradients");
+        ui.label(egui::RichText::new("A chain of one-neuron layers a_l = f(w a_(l-1) + b): backprop multiplies w f'(z) once per layer")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.add(egui::Slider::new(&mut self.depth, 1..=50).text("layers"));
+            ui.add_space(16.0);
+            ui.add(egui::Slider::new(&mut self.weight, 0.1..=3.0).text("w"));
+            ui.add_space(16.0);
+            ui.add(egui::Slider::new(&mut self.bias, -2.0..=2.0).text("b"));
+            ui.add_space(16.0);
+            ui.add(egui::Slider::new(&mut self.input, -3.0..=3.0).text("x"));
+        });
+        ui.add_space(8.0);
+
+        let chains: Vec<(Activation, egui::Color32, Vec<f64>)> =
+            self.shown().map(|(activation, color)| (activation, color, self.chained_gradients(activation))).collect();
+        ui.columns(2, |columns| {
+            Plot::new("vanishing_gradients")
+                .height(260.0)
+                .legend(Legend::default())
+                .include_x(0.0)
+                .include_y(0.0)
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .y_axis_label("log₁₀ |∂a_l / ∂x|")
+                .x_axis_label("layer l")
+                .show(&mut columns[0], |plot_ui| {
+                    for (activation, color, gradients) in &chains {
+                        // Exactly zero (a dead ReLU) has no logarithm, so it is drawn at the floor
+                        let points: PlotPoints = gradients
+                            .iter()
+                            .enumerate()
+                            .map(|(l, g)| [l as f64 + 1.0, g.log10().max(-30.0)])
+                            .collect();
+                        plot_ui.line(Line::new(activation.name(), points).color(*color).width(2.0));
+                    }
+                });
+
+            let ui = &mut columns[1];
+            ui.label(egui::RichText::new(format!("Gradient reaching the input after {} layers", self.depth)).strong());
+            ui.add_space(6.0);
+            egui::Grid::new("vanishing_summary").striped(true).show(ui, |ui| {
+                for (activation, color, gradients) in &chains {
+                    let last = *gradients.last().expect("at least one layer");
+                    let decades = last.log10();
+                    let (verdict, verdict_color) = if decades < -HEALTHY_DECADES {
+                        ("vanished", egui::Color32::from_rgb(255, 200, 100))
+                    } else if decades > HEALTHY_DECADES {
+                        ("exploded", egui::Color32::from_rgb(255, 100, 150))
+                    } else {
+                        ("healthy", egui::Color32::from_rgb(100, 255, 150))
+                    };
+                    ui.label(egui::RichText::new(activation.name()).color(*color));
+                    ui.monospace(format!("{:.3e}", last));
+                    ui.label(egui::RichText::new(verdict).color(verdict_color));
+                    ui.end_row();
+                }
+            });
+            ui.add_space(6.0);
+            ui.label(egui::RichText::new("Sigmoid's slope is at most 0.25, so with w = 1 its gradient shrinks at least 4× per layer")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+        });
+    }
+
+    fn render_softmax(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🎯 Softmax");
+        ui.horizontal(|ui| {
+            for (i, logit) in self.logits.iter_mut().enumerate() {
+                ui.add(egui::Slider::new(logit, -5.0..=5.0).text(format!("z{}", i + 1)));
+            }
+        });
+        ui.add(egui::Slider::new(&mut self.temperature, 0.1..=10.0).logarithmic(true).text("temperature T"));
+        ui.add_space(8.0);
+
+        let logits = Array1::from(self.logits.to_vec());
+        let probabilities = softmax(logits.view(), self.temperature);
+        ui.columns(2, |columns| {
+            let bars: Vec<Bar> = probabilities
+                .iter()
+                .enumerate()
+                .map(|(i, p)| Bar::new(i as f64 + 1.0, *p).name(format!("z{}", i + 1)).width(0.6).fill(egui::Color32::from_rgb(100, 200, 255)))
+                .collect();
+            Plot::new("softmax_probabilities")
+                .height(200.0)
+                .include_y(0.0)
+                .include_y(1.0)
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .show(&mut columns[0], |plot_ui| {
+                    plot_ui.bar_chart(BarChart::new("softmax(z / T)", bars));
+                });
+
+            let ui = &mut columns[1];
+            ui.label(egui::RichText::new("Jacobian ∂s_i / ∂z_j at T = 1").strong());
+            ui.add_space(6.0);
+            let jacobian = softmax_jacobian(&softmax(logits.view(), 1.0));
+            egui::Grid::new("softmax_jacobian").striped(true).show(ui, |ui| {
+                for row in jacobian.rows() {
+                    for value in row {
+                        ui.monospace(format!("{:+.3}", value));
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.add_space(6.0);
+            ui.label(egui::RichText::new("Every output depends on every logit; each row sums to zero because the probabilities always sum to one")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+        });
+    }
+}
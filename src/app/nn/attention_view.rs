@@ -0,0 +1,383 @@
+use crate::app::widgets::decision_boundary::class_color;
+use crate::core::ai::attention::multi_head::{MultiHeadAttention, MultiHeadOutput};
+use crate::core::ai::attention::positional::sinusoidal_encoding;
+use crate::core::ai::attention::scaled_dot_product::causal_mask;
+use eframe::egui;
+use ndarray::{Array2, s};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::hash::{Hash, Hasher};
+
+const MAX_TOKENS: usize = 8;
+const CELL: f32 = 30.0;
+const LABEL_WIDTH: f32 = 56.0;
+
+pub struct AttentionView {
+    text: String,
+    heads: usize,
+    head_dim: usize,
+    positional: bool,
+    temperature: f64,
+    embedding_seed: u64,
+    weight_seed: u64,
+    // Head whose Q, K and V are shown
+    head: usize,
+    // Show Q Kᵀ / √d_k instead of the softmax weights
+    show_scores: bool,
+    // Cached from the settings above
+    tokens: Vec<String>,
+    embeddings: Array2<f64>,
+    // true = the query (row) may attend to the key (column)
+    mask: Array2<bool>,
+    layer: MultiHeadAttention,
+    input: Array2<f64>,
+    forward: MultiHeadOutput,
+}
+
+impl Default for AttentionView {
+    fn default() -> Self {
+        let layer = MultiHeadAttention::new(8, 2, 0);
+        let forward = layer.forward(&Array2::zeros((0, 8)), None, 1.0);
+        let mut view = Self {
+            text: "the cat sat on the mat".to_string(),
+            heads: 2,
+            head_dim: 4,
+            positional: true,
+            temperature: 1.0,
+            embedding_seed: 0,
+            weight_seed: 0,
+            head: 0,
+            show_scores: false,
+            tokens: Vec::new(),
+            embeddings: Array2::zeros((0, 8)),
+            mask: Array2::from_elem((0, 0), true),
+            layer,
+            input: Array2::zeros((0, 8)),
+            forward,
+        };
+        view.retokenize();
+        view
+    }
+}
+
+// Sequential colour for attention weights in [0, 1]
+fn weight_color(w: f64) -> egui::Color32 {
+    egui::Color32::from_rgb(25, 25, 35).lerp_to_gamma(egui::Color32::from_rgb(100, 255, 150), w.clamp(0.0, 1.0) as f32)
+}
+
+// Diverging colour for projections: blue negative, amber positive
+fn value_color(x: f64) -> egui::Color32 {
+    class_color(0.5 + 0.5 * x.tanh())
+}
+
+// Matrix as coloured cells with row labels on the left and column labels
+// on top; blocked cells are crossed out. Returns the clicked cell.
+fn heatmap(
+    ui: &mut egui::Ui,
+    matrix: &Array2<f64>,
+    rows: &[String],
+    columns: &[String],
+    color: impl Fn(f64) -> egui::Color32,
+    blocked: Option<&Array2<bool>>,
+) -> Option<(usize, usize)> {
+    let (n, m) = matrix.dim();
+    let size = egui::vec2(LABEL_WIDTH + CELL * m as f32, CELL * (n + 1) as f32);
+    let (response, painter) = ui.allocate_painter(size, egui::Sense::click());
+    let origin = response.rect.min + egui::vec2(LABEL_WIDTH, CELL);
+    let label_color = egui::Color32::from_rgb(160, 160, 180);
+    let font = egui::FontId::proportional(11.0);
+    for (j, label) in columns.iter().enumerate() {
+        let position = origin + egui::vec2(CELL * (j as f32 + 0.5), -4.0);
+        painter.text(position, egui::Align2::CENTER_BOTTOM, label, font.clone(), label_color);
+    }
+    for (i, label) in rows.iter().enumerate() {
+        let position = origin + egui::vec2(-6.0, CELL * (i as f32 + 0.5));
+        painter.text(position, egui::Align2::RIGHT_CENTER, label, font.clone(), label_color);
+    }
+    for ((i, j), &value) in matrix.indexed_iter() {
+        let cell = egui::Rect::from_min_size(origin + egui::vec2(CELL * j as f32, CELL * i as f32), egui::vec2(CELL, CELL)).shrink(1.0);
+        painter.rect_filled(cell, 2.0, color(value));
+        if blocked.is_some_and(|mask| !mask[[i, j]]) {
+            let stroke = egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 100, 150));
+            painter.line_segment([cell.left_top(), cell.right_bottom()], stroke);
+            painter.line_segment([cell.right_top(), cell.left_bottom()], stroke);
+        }
+    }
+
+    let cell_at = |pos: egui::Pos2| {
+        let offset = pos - origin;
+        let (i, j) = ((offset.y / CELL).floor(), (offset.x / CELL).floor());
+        (i >= 0.0 && j >= 0.0 && (i as usize) < n && (j as usize) < m).then_some((i as usize, j as usize))
+    };
+    let clicked = response.clicked().then(|| response.interact_pointer_pos().and_then(cell_at)).flatten();
+    if let Some((i, j)) = response.hover_pos().and_then(cell_at) {
+        response.on_hover_text(format!("{} → {}: {:+.3}", rows[i], columns[j], matrix[[i, j]]));
+    }
+    clicked
+}
+
+impl AttentionView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn d_model(&self) -> usize {
+        self.heads * self.head_dim
+    }
+
+    fn retokenize(&mut self) {
+        self.tokens = self.text.split_whitespace().take(MAX_TOKENS).map(str::to_string).collect();
+        self.mask = Array2::from_elem((self.tokens.len(), self.tokens.len()), true);
+        self.randomize_embeddings();
+    }
+
+    // Seeded by the word, so a repeated word gets the same embedding and
+    // only the positional encoding tells the copies apart
+    fn randomize_embeddings(&mut self) {
+        let d_model = self.d_model();
+        self.embeddings = Array2::zeros((self.tokens.len(), d_model));
+        for (i, token) in self.tokens.iter().enumerate() {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            (token.to_lowercase(), self.embedding_seed).hash(&mut hasher);
+            let mut rng = StdRng::seed_from_u64(hasher.finish());
+            self.embeddings.row_mut(i).mapv_inplace(|_| rng.random_range(-1.0..1.0));
+        }
+        self.rebuild();
+    }
+
+    fn rebuild(&mut self) {
+        self.layer = MultiHeadAttention::new(self.d_model(), self.heads, self.weight_seed);
+        self.head = self.head.min(self.heads - 1);
+        self.update();
+    }
+
+    fn update(&mut self) {
+        self.input = self.embeddings.clone();
+        if self.positional {
+            self.input += &sinusoidal_encoding(self.tokens.len(), self.d_model());
+        }
+        self.forward = self.layer.forward(&self.input, Some(&self.mask), self.temperature);
+    }
+
+    fn toggle_mask(&mut self, (i, j): (usize, usize)) {
+        self.mask[[i, j]] = !self.mask[[i, j]];
+        self.update();
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Attention Mechanisms")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("softmax(Q Kᵀ / √d_k) V: every token decides how much to read from every other token")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            if self.tokens.is_empty() {
+                ui.label("Type a few words to attend over");
+                return;
+            }
+
+            self.render_input(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_projections(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_heads(ui);
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut retokenize, mut embeddings, mut rebuild, mut update) = (false, false, false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Tokens:");
+                    retokenize |= ui.add(egui::TextEdit::singleline(&mut self.text).desired_width(300.0))
+                        .on_hover_text(format!("Split on spaces, at most {} tokens", MAX_TOKENS))
+                        .changed();
+                    if ui.button("🎲 Embeddings").clicked() {
+                        self.embedding_seed += 1;
+                        embeddings = true;
+                    }
+                    if ui.button("🎲 Weights").clicked() {
+                        self.weight_seed += 1;
+                        rebuild = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    embeddings |= ui.add(egui::Slider::new(&mut self.heads, 1..=4).text("heads")).changed();
+                    ui.add_space(16.0);
+                    embeddings |= ui.add(egui::Slider::new(&mut self.head_dim, 1..=8).text("d_k per head")).changed();
+                    ui.add_space(16.0);
+                    update |= ui.checkbox(&mut self.positional, "Positional encoding").changed();
+                    ui.add_space(16.0);
+                    update |= ui.add(egui::Slider::new(&mut self.temperature, 0.05..=5.0).logarithmic(true).text("temperature")).changed();
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Mask:");
+                    let n = self.tokens.len();
+                    if ui.button("None").clicked() {
+                        self.mask = Array2::from_elem((n, n), true);
+                        update = true;
+                    }
+                    if ui.button("Causal").clicked() {
+                        self.mask = causal_mask(n);
+                        update = true;
+                    }
+                    let blocked = self.mask.iter().filter(|allowed| !**allowed).count();
+                    ui.label(egui::RichText::new(format!("{} of {} pairs blocked; click a cell of a heatmap below to toggle it", blocked, n * n))
+                        .color(egui::Color32::from_rgb(160, 160, 180)));
+                });
+            });
+        if retokenize {
+            self.retokenize();
+        } else if embeddings {
+            self.randomize_embeddings();
+        } else if rebuild {
+            self.rebuild();
+        } else if update {
+            self.update();
+        }
+    }
+
+    fn token_labels(&self) -> Vec<String> {
+        self.tokens.iter().enumerate().map(|(i, token)| format!("{}:{}", i, token)).collect()
+    }
+
+    fn render_input(&mut self, ui: &mut egui::Ui) {
+        let title = if self.positional { "🔤 Input X = embedding + positional encoding" } else { "🔤 Input X = embedding" };
+        Self::section_label(ui, title);
+        let dimensions: Vec<String> = (0..self.d_model()).map(|j| j.to_string()).collect();
+        heatmap(ui, &self.input, &self.token_labels(), &dimensions, value_color, None);
+
+        ui.add_space(8.0);
+        let mut changed = false;
+        egui::CollapsingHeader::new("✏ Edit embeddings").id_salt("attention_edit").show(ui, |ui| {
+            egui::Grid::new("attention_embeddings").show(ui, |ui| {
+                for (i, token) in self.tokens.iter().enumerate() {
+                    ui.label(token);
+                    for value in self.embeddings.row_mut(i) {
+                        changed |= ui.add(egui::DragValue::new(value).speed(0.02).range(-3.0..=3.0).fixed_decimals(2)).changed();
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.label(egui::RichText::new("Editing the words or the sizes draws fresh embeddings")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+        });
+        if changed {
+            self.update();
+        }
+    }
+
+    fn render_projections(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🔑 Queries, keys and values");
+        ui.horizontal(|ui| {
+            ui.label("Head:");
+            for h in 0..self.heads {
+                ui.selectable_value(&mut self.head, h, format!("{}", h + 1));
+            }
+        });
+        ui.add_space(8.0);
+        let columns = self.layer.head_columns(self.head);
+        let dimensions: Vec<String> = columns.clone().map(|j| j.to_string()).collect();
+        let labels = self.token_labels();
+        ui.horizontal_top(|ui| {
+            for (name, matrix) in [("Q = X W_q", &self.forward.q), ("K = X W_k", &self.forward.k), ("V = X W_v", &self.forward.v)] {
+                ui.vertical(|ui| {
+                    ui.label(egui::RichText::new(name).strong());
+                    heatmap(ui, &matrix.slice(s![.., columns.clone()]).to_owned(), &labels, &dimensions, value_color, None);
+                });
+                ui.add_space(12.0);
+            }
+        });
+        ui.label(egui::RichText::new(format!(
+            "d_model = {} split into {} heads of d_k = {}; scores are divided by √d_k = {:.2}",
+            self.layer.d_model(),
+            self.heads,
+            self.layer.head_dim(),
+            (self.layer.head_dim() as f64).sqrt()
+        ))
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn render_heads(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🔥 Attention per head (row = query, column = key)");
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.show_scores, false, "Weights");
+            ui.selectable_value(&mut self.show_scores, true, "Raw scores Q Kᵀ / √d_k");
+        });
+        ui.add_space(8.0);
+        let labels = self.token_labels();
+        let mut clicked = None;
+        ui.horizontal_wrapped(|ui| {
+            for (h, head) in self.forward.heads.iter().enumerate() {
+                ui.vertical(|ui| {
+                    ui.label(egui::RichText::new(format!("Head {}", h + 1)).strong());
+                    let cell = if self.show_scores {
+                        heatmap(ui, &head.scores, &labels, &labels, value_color, Some(&self.mask))
+                    } else {
+                        heatmap(ui, &head.weights, &labels, &labels, weight_color, Some(&self.mask))
+                    };
+                    clicked = clicked.or(cell);
+                    // Which key each query reads most from
+                    let focus: Vec<String> = head.weights.rows().into_iter().enumerate()
+                        .filter_map(|(i, row)| {
+                            let (j, w) = row.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+                            (*w > 0.0).then(|| format!("{} → {} ({:.0}%)", self.tokens[i], self.tokens[j], 100.0 * w))
+                        })
+                        .collect();
+                    ui.label(egui::RichText::new(focus.join("\n"))
+                        .color(egui::Color32::from_rgb(160, 160, 180))
+                        .size(11.0));
+                });
+                ui.add_space(16.0);
+            }
+        });
+        if let Some(cell) = clicked {
+            self.toggle_mask(cell);
+        }
+
+        ui.add_space(16.0);
+        Self::section_label(ui, "📤 Output = concat(heads) W_o");
+        let dimensions: Vec<String> = (0..self.d_model()).map(|j| j.to_string()).collect();
+        heatmap(ui, &self.forward.output, &labels, &dimensions, value_color, None);
+        ui.label(egui::RichText::new("Lower the temperature to sharpen each row towards one key; raise it to spread the weight evenly")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod activation_functions_view;
+pub mod attention_view;
+pub mod neural_network_view;
@@ -0,0 +1,454 @@
+use crate::app::widgets::decision_boundary::{DecisionBoundary, class_color};
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::nn::initializer::Initializer;
+use crate::core::ai::nn::loss::Loss;
+use crate::core::ai::nn::network::{Forward, NeuralNetwork};
+use crate::core::ai::regression::linear_regression::TrainingConfig;
+use crate::core::hybrid::dataset::{Dataset, LabeledPoint};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints};
+use ndarray::{Array2, s};
+
+const NUM_POINTS: usize = 100;
+const MAX_EPOCHS: usize = 3000;
+// Full-batch steps per frame while training runs
+const EPOCHS_PER_FRAME: usize = 10;
+const GRID: usize = 32;
+const EXTENT: f64 = 1.4;
+const CANVAS_SIZE: f32 = 340.0;
+// Each neuron is drawn as a THUMBNAIL x THUMBNAIL map of its output
+const THUMBNAIL: usize = 12;
+const NEURON_SIZE: f32 = 36.0;
+const NEURON_SPACING: f32 = 46.0;
+const HISTOGRAM_BINS: usize = 20;
+// How close (in pixels) the pointer must be to trace a data point
+const HOVER_RADIUS: f32 = 10.0;
+
+pub struct NeuralNetworkView {
+    dataset: Dataset,
+    noise: f64,
+    seed: u64,
+    // Widths of the hidden layers
+    hidden: Vec<usize>,
+    activation: Activation,
+    initializer: Initializer,
+    loss: Loss,
+    learning_rate: f64,
+    running: bool,
+    // Cached from the settings above
+    points: Vec<LabeledPoint>,
+    x_train: Array2<f64>,
+    y_train: Array2<f64>,
+    network: NeuralNetwork,
+    boundary: Array2<f64>,
+    // Every neuron's activations over the thumbnail grid, per layer
+    neuron_maps: Vec<Array2<f64>>,
+    // (layer, neuron) whose histogram is shown; layer 0 is the input
+    selected: Option<(usize, usize)>,
+    // Data point under the pointer on the boundary plot
+    hovered: Option<usize>,
+}
+
+impl Default for NeuralNetworkView {
+    fn default() -> Self {
+        let mut view = Self {
+            dataset: Dataset::Circles,
+            noise: 0.05,
+            seed: 0,
+            hidden: vec![6, 4],
+            activation: Activation::Tanh,
+            initializer: Initializer::Xavier,
+            loss: Loss::CrossEntropy,
+            learning_rate: 0.5,
+            running: false,
+            points: Vec::new(),
+            x_train: Array2::zeros((0, 2)),
+            y_train: Array2::zeros((0, 1)),
+            network: NeuralNetwork::new(&[2, 1], Activation::Sigmoid, Activation::Sigmoid, Initializer::Xavier, Loss::CrossEntropy, 0),
+            boundary: Array2::zeros((0, 0)),
+            neuron_maps: Vec::new(),
+            selected: None,
+            hovered: None,
+        };
+        view.regenerate();
+        view
+    }
+}
+
+impl NeuralNetworkView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn regenerate(&mut self) {
+        self.points = self.dataset.generate(NUM_POINTS, self.noise, self.seed);
+        self.x_train = Array2::from_shape_fn((self.points.len(), 2), |(i, j)| self.points[i].features()[j]);
+        self.y_train = Array2::from_shape_fn((self.points.len(), 1), |(i, _)| if self.points[i].label { 1.0 } else { 0.0 });
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        let sizes: Vec<usize> = std::iter::once(2).chain(self.hidden.iter().copied()).chain(std::iter::once(1)).collect();
+        // A sigmoid output reads as P(class 1) under either loss
+        self.network = NeuralNetwork::new(&sizes, self.activation, Activation::Sigmoid, self.initializer, self.loss, self.seed);
+        self.running = false;
+        self.selected = None;
+        self.update_boundary();
+    }
+
+    fn update_boundary(&mut self) {
+        self.boundary = self.network
+            .predict(DecisionBoundary::grid(GRID, EXTENT))
+            .into_shape_with_order((GRID, GRID))
+            .expect("one prediction per cell");
+        self.neuron_maps = self.network.forward(&DecisionBoundary::grid(THUMBNAIL, EXTENT)).activations;
+    }
+
+    fn train(&mut self, epochs: usize) {
+        let config = TrainingConfig {
+            learning_rate: self.learning_rate,
+            epochs,
+            verbose: false,
+        };
+        self.network.train(self.x_train.clone(), self.y_train.clone(), config);
+        self.update_boundary();
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            self.train(EPOCHS_PER_FRAME);
+            if self.network.loss_history.len() >= MAX_EPOCHS {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Neural Networks")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("A feed-forward network of Dense layers trained by backpropagation and gradient descent")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            self.render_architecture(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_boundary(&mut columns[0]);
+                self.render_histogram(&mut columns[1]);
+                columns[1].add_space(16.0);
+                self.render_loss(&mut columns[1]);
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let (mut regenerate, mut reset) = (false, false);
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Dataset:");
+                    for dataset in Dataset::ALL {
+                        regenerate |= ui.selectable_value(&mut self.dataset, dataset, dataset.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    regenerate |= ui.add(egui::Slider::new(&mut self.noise, 0.0..=0.3).text("noise")).changed();
+                    if ui.button("🎲 Resample").clicked() {
+                        self.seed += 1;
+                        regenerate = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Hidden layers:");
+                    for width in &mut self.hidden {
+                        reset |= ui.add(egui::DragValue::new(width).range(1..=8).suffix(" neurons")).changed();
+                    }
+                    if self.hidden.len() < 4 && ui.button("➕").clicked() {
+                        self.hidden.push(4);
+                        reset = true;
+                    }
+                    if !self.hidden.is_empty() && ui.button("➖").clicked() {
+                        self.hidden.pop();
+                        reset = true;
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Activation:");
+                    let before = self.activation;
+                    egui::ComboBox::from_id_salt("hidden_activation")
+                        .selected_text(self.activation.name())
+                        .show_ui(ui, |ui| {
+                            for activation in Activation::ALL {
+                                ui.selectable_value(&mut self.activation, activation, activation.name());
+                            }
+                        });
+                    reset |= self.activation != before;
+                    ui.add_space(16.0);
+                    ui.label("Initializer:");
+                    for initializer in Initializer::ALL {
+                        reset |= ui.selectable_value(&mut self.initializer, initializer, initializer.name()).changed();
+                    }
+                    ui.add_space(16.0);
+                    ui.label("Loss:");
+                    for loss in Loss::ALL {
+                        reset |= ui.selectable_value(&mut self.loss, loss, loss.name()).changed();
+                    }
+                });
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.add(egui::Slider::new(&mut self.learning_rate, 0.001..=3.0).logarithmic(true).text("learning rate"));
+                    ui.add_space(16.0);
+                    let label = if self.running { "⏸ Pause" } else { "▶ Train" };
+                    if ui.button(label).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.button("⏭ Epoch").clicked() {
+                        self.train(1);
+                    }
+                    if ui.button("↺ Reset").clicked() {
+                        reset = true;
+                    }
+                });
+            });
+        if regenerate {
+            self.regenerate();
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    fn layer_widths(&self) -> Vec<usize> {
+        std::iter::once(self.network.layers[0].inputs())
+            .chain(self.network.layers.iter().map(|layer| layer.outputs()))
+            .collect()
+    }
+
+    // Maps a neuron's thumbnail activations onto the class colours
+    fn neuron_color(&self, layer: usize, value: f64, largest: f64) -> egui::Color32 {
+        if layer > 0 && self.network.layers[layer - 1].activation == Activation::Sigmoid {
+            class_color(value)
+        } else {
+            class_color(0.5 + 0.5 * value / largest)
+        }
+    }
+
+    fn render_architecture(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🕸 Architecture (click a neuron for its activations, hover a data point to trace it)");
+        let widths = self.layer_widths();
+        let traced: Option<Forward> = self.hovered.map(|i| self.network.forward(&self.x_train.slice(s![i..i + 1, ..]).to_owned()));
+
+        let tallest = widths.iter().copied().max().unwrap_or(1);
+        let size = egui::vec2(ui.available_width(), tallest as f32 * NEURON_SPACING + 8.0);
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+        let rect = response.rect;
+        let columns = (widths.len() - 1).max(1) as f32;
+        let center = |layer: usize, neuron: usize| {
+            let x = rect.left() + NEURON_SIZE + (rect.width() - 2.0 * NEURON_SIZE) * layer as f32 / columns;
+            let y = rect.center().y + (neuron as f32 - (widths[layer] - 1) as f32 / 2.0) * NEURON_SPACING;
+            egui::pos2(x, y)
+        };
+
+        // Edges: blue positive, amber negative, thickness by |w|; a traced
+        // point lights each one up by the signal w * a it carries
+        for (l, layer) in self.network.layers.iter().enumerate() {
+            let largest = layer.weights.iter().fold(1e-9_f64, |m, w| m.max(w.abs()));
+            for ((i, j), &w) in layer.weights.indexed_iter() {
+                let strength = w.abs() / largest;
+                let opacity = match &traced {
+                    Some(forward) => (w * forward.activations[l][[0, i]]).abs().min(largest) / largest,
+                    None => 0.3 + 0.7 * strength,
+                };
+                let color = if w >= 0.0 {
+                    egui::Color32::from_rgb(100, 200, 255)
+                } else {
+                    egui::Color32::from_rgb(255, 200, 100)
+                };
+                painter.line_segment(
+                    [center(l, i), center(l + 1, j)],
+                    egui::Stroke::new(0.5 + 3.0 * strength as f32, color.gamma_multiply(opacity.max(0.05) as f32)),
+                );
+            }
+        }
+
+        let mut clicked = None;
+        for (l, &width) in widths.iter().enumerate() {
+            let map = &self.neuron_maps[l];
+            for j in 0..width {
+                let bounds = egui::Rect::from_center_size(center(l, j), egui::vec2(NEURON_SIZE, NEURON_SIZE));
+                let column = map.column(j);
+                let largest = column.iter().fold(1e-9_f64, |m, a| m.max(a.abs()));
+                let cell = NEURON_SIZE / THUMBNAIL as f32;
+                for (k, &a) in column.iter().enumerate() {
+                    let min = bounds.min + egui::vec2(cell * (k % THUMBNAIL) as f32, cell * (k / THUMBNAIL) as f32);
+                    painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(cell + 0.5, cell + 0.5)), 0.0, self.neuron_color(l, a, largest));
+                }
+                let (stroke, label) = match &traced {
+                    Some(forward) => {
+                        let a = forward.activations[l][[0, j]];
+                        (egui::Stroke::new(2.5, self.neuron_color(l, a, largest)), Some(format!("{:+.2}", a)))
+                    }
+                    None => (egui::Stroke::new(1.0, egui::Color32::from_rgb(60, 60, 80)), None),
+                };
+                painter.rect_stroke(bounds, 3.0, stroke, egui::StrokeKind::Outside);
+                if self.selected == Some((l, j)) {
+                    painter.rect_stroke(bounds.expand(3.0), 4.0, egui::Stroke::new(1.5, egui::Color32::WHITE), egui::StrokeKind::Outside);
+                }
+                if let Some(label) = label {
+                    painter.text(bounds.center_bottom() + egui::vec2(0.0, 2.0), egui::Align2::CENTER_TOP, label, egui::FontId::monospace(9.0), egui::Color32::WHITE);
+                }
+                let neuron = ui.interact(bounds, ui.id().with(("neuron", l, j)), egui::Sense::click());
+                if neuron.clicked() {
+                    clicked = Some((l, j));
+                }
+                let title = match l {
+                    0 => format!("Input {}", if j == 0 { "x" } else { "y" }),
+                    l if l == widths.len() - 1 => "Output P(class 1)".to_string(),
+                    l => format!("Hidden layer {}, neuron {}", l, j + 1),
+                };
+                if l > 0 {
+                    neuron.on_hover_text(format!("{}\nbias {:+.3}", title, self.network.layers[l - 1].bias[j]));
+                } else {
+                    neuron.on_hover_text(title);
+                }
+            }
+        }
+        if clicked.is_some() {
+            self.selected = if clicked == self.selected { None } else { clicked };
+        }
+    }
+
+    fn render_boundary(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🗺 Decision boundary");
+        let predicted: Vec<bool> = self.network.predict(self.x_train.clone()).iter().map(|p| *p > 0.5).collect();
+        let correct = predicted.iter().zip(&self.points).filter(|(p, point)| **p == point.label).count();
+        let accuracy = correct as f64 / self.points.len() as f64;
+        let highlighted: Vec<usize> = self.hovered.into_iter().collect();
+        let response = DecisionBoundary::new(&self.boundary, &self.points, EXTENT)
+            .predicted(&predicted)
+            .highlighted(&highlighted)
+            .size(CANVAS_SIZE)
+            .show(ui);
+        let hovered = response.hover_pos().and_then(|pos| {
+            let to_screen = |x: f64, y: f64| {
+                response.rect.min + egui::vec2(
+                    CANVAS_SIZE * ((x + EXTENT) / (2.0 * EXTENT)) as f32,
+                    CANVAS_SIZE * ((EXTENT - y) / (2.0 * EXTENT)) as f32,
+                )
+            };
+            self.points
+                .iter()
+                .map(|point| to_screen(point.x, point.y).distance(pos))
+                .enumerate()
+                .filter(|(_, distance)| *distance <= HOVER_RADIUS)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(i, _)| i)
+        });
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            ui.ctx().request_repaint();
+        }
+        ui.add_space(6.0);
+        let color = if accuracy >= 0.9 {
+            egui::Color32::from_rgb(100, 255, 150)
+        } else {
+            egui::Color32::from_rgb(255, 200, 100)
+        };
+        ui.label(egui::RichText::new(format!("Training accuracy: {:.0}%", 100.0 * accuracy))
+            .color(color)
+            .strong());
+    }
+
+    fn render_histogram(&self, ui: &mut egui::Ui) {
+        let Some((layer, neuron)) = self.selected else {
+            Self::section_label(ui, "📊 Neuron activations");
+            ui.label(egui::RichText::new("Click a neuron to see how it responds across the dataset")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+            return;
+        };
+        Self::section_label(ui, &format!("📊 Activations of layer {} neuron {} over the dataset", layer, neuron + 1));
+        let forward = self.network.forward(&self.x_train);
+        let values = forward.activations[layer].column(neuron).to_owned();
+        let (low, high) = values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        let width = ((high - low) / HISTOGRAM_BINS as f64).max(1e-6);
+        let mut counts = [[0usize; HISTOGRAM_BINS]; 2];
+        for (value, point) in values.iter().zip(&self.points) {
+            let bin = (((value - low) / width) as usize).min(HISTOGRAM_BINS - 1);
+            counts[point.label as usize][bin] += 1;
+        }
+        let bars = |label: usize, color: egui::Color32| -> Vec<Bar> {
+            (0..HISTOGRAM_BINS)
+                .map(|bin| Bar::new(low + width * (bin as f64 + 0.5), counts[label][bin] as f64).width(width * 0.9).fill(color))
+                .collect()
+        };
+        let class_0 = BarChart::new("Class 0", bars(0, egui::Color32::from_rgb(100, 200, 255)));
+        let class_1 = BarChart::new("Class 1", bars(1, egui::Color32::from_rgb(255, 200, 100))).stack_on(&[&class_0]);
+        Plot::new("neuron_histogram")
+            .height(180.0)
+            .legend(Legend::default())
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(class_0);
+                plot_ui.bar_chart(class_1);
+            });
+    }
+
+    fn render_loss(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("📉 {} loss per epoch", self.loss.name()));
+        let losses: PlotPoints = self.network.loss_history.iter().enumerate().map(|(i, l)| [i as f64 + 1.0, *l]).collect();
+        Plot::new("neural_network_loss")
+            .height(280.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("Loss", losses).color(egui::Color32::from_rgb(100, 200, 255)));
+            });
+        let architecture: Vec<String> = self.layer_widths().iter().map(|width| width.to_string()).collect();
+        ui.label(format!(
+            "{} epochs, architecture {}, {} parameters",
+            self.network.loss_history.len(),
+            architecture.join("-"),
+            self.network.num_params()
+        ));
+        if self.initializer == Initializer::Zeros {
+            ui.label(egui::RichText::new("Zero weights: every hidden neuron gets the same gradient, so they never become different")
+                .color(egui::Color32::from_rgb(255, 200, 100))
+                .size(12.0));
+        }
+    }
+}
@@ -0,0 +1,499 @@
+use crate::core::ai::optim::optimizer::{Objective, Optimizer, OptimizerKind};
+use crate::core::ai::optim::test_functions::TestFunction;
+use crate::core::ai::regression::linear_regression::MseObjective;
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+use ndarray::{Array1, Array2, array};
+
+const GRID: usize = 80;
+const CANVAS_SIZE: f32 = 480.0;
+const CONTOUR_LEVELS: usize = 14;
+const MAX_STEPS: usize = 1000;
+// Racers further than this many spans outside the plot have diverged
+const ESCAPE_SPANS: f64 = 2.0;
+
+// One colour per entry of OptimizerKind::ALL
+const PALETTE: [egui::Color32; 6] = [
+    egui::Color32::from_rgb(160, 160, 180),
+    egui::Color32::from_rgb(150, 120, 255),
+    egui::Color32::from_rgb(100, 255, 150),
+    egui::Color32::from_rgb(255, 255, 255),
+    egui::Color32::from_rgb(255, 100, 150),
+    egui::Color32::from_rgb(100, 200, 255),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Surface {
+    Function(TestFunction),
+    // Half the MSE of the Linear Regression view's points over (w, b)
+    Regression,
+}
+
+struct Racer {
+    kind: OptimizerKind,
+    color: egui::Color32,
+    enabled: bool,
+    step_size: f64,
+    optimizer: Box<dyn Optimizer>,
+    params: Array1<f64>,
+    path: Vec<[f64; 2]>,
+    values: Vec<f64>,
+    escaped: bool,
+}
+
+pub struct GradientDescentView {
+    surface: Surface,
+    x_data: Array2<f64>,
+    y_data: Array2<f64>,
+    start: [f64; 2],
+    racers: Vec<Racer>,
+    running: bool,
+    steps_per_frame: usize,
+    // Cached per surface: ln(1 + f - min) on the (GRID + 1)² vertices, row 0 at
+    // the top, and the contour segments in canvas fractions
+    heights: Array2<f64>,
+    contours: Vec<[[f32; 2]; 2]>,
+}
+
+impl Default for GradientDescentView {
+    fn default() -> Self {
+        let mut view = Self {
+            surface: Surface::Function(TestFunction::Rosenbrock),
+            x_data: Array2::zeros((0, 1)),
+            y_data: Array2::zeros((0, 1)),
+            start: [0.0, 0.0],
+            racers: Vec::new(),
+            running: false,
+            steps_per_frame: 5,
+            heights: Array2::zeros((0, 0)),
+            contours: Vec::new(),
+        };
+        view.select_surface(view.surface);
+        view
+    }
+}
+
+// Least-squares line through the data, if x varies
+fn least_squares(x: &Array2<f64>, y: &Array2<f64>) -> Option<[f64; 2]> {
+    let (mean_x, mean_y) = (x.mean()?, y.mean()?);
+    let covariance: f64 = x.iter().zip(y).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = x.iter().map(|x| (x - mean_x).powi(2)).sum();
+    (variance > 1e-12).then(|| {
+        let w = covariance / variance;
+        [w, mean_y - w * mean_x]
+    })
+}
+
+// Pairs up the level crossings on a cell's edges (top, right, bottom, left)
+fn contour_cell(corners: [(f64, [f32; 2]); 4], level: f64, segments: &mut Vec<[[f32; 2]; 2]>) {
+    let mut crossings = Vec::with_capacity(4);
+    for k in 0..4 {
+        let ((a, pa), (b, pb)) = (corners[k], corners[(k + 1) % 4]);
+        if (a < level) != (b < level) {
+            let t = ((level - a) / (b - a)) as f32;
+            crossings.push([pa[0] + t * (pb[0] - pa[0]), pa[1] + t * (pb[1] - pa[1])]);
+        }
+    }
+    for pair in crossings.chunks_exact(2) {
+        segments.push([pair[0], pair[1]]);
+    }
+}
+
+impl GradientDescentView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Keeps the regression surface in step with the Linear Regression view
+    pub fn set_regression_data(&mut self, (x, y): (Array2<f64>, Array2<f64>)) {
+        if x == self.x_data && y == self.y_data {
+            return;
+        }
+        self.x_data = x;
+        self.y_data = y;
+        if self.surface == Surface::Regression {
+            if least_squares(&self.x_data, &self.y_data).is_some() {
+                self.select_surface(Surface::Regression);
+            } else {
+                self.select_surface(Surface::Function(TestFunction::Rosenbrock));
+            }
+        }
+    }
+
+    fn value(&self, x: f64, y: f64) -> f64 {
+        match self.surface {
+            Surface::Function(function) => function.value(x, y),
+            Surface::Regression => MseObjective { x: &self.x_data, y: &self.y_data }.value(&array![x, y]),
+        }
+    }
+
+    // [x_min, x_max, y_min, y_max]
+    fn bounds(&self) -> [f64; 4] {
+        match self.surface {
+            Surface::Function(function) => function.bounds(),
+            Surface::Regression => {
+                let [w, b] = least_squares(&self.x_data, &self.y_data).unwrap_or([0.0, 0.0]);
+                let (w_span, b_span) = ((2.0 * w.abs()).max(2.0), (2.0 * b.abs()).max(4.0));
+                [w - w_span, w + w_span, b - b_span, b + b_span]
+            }
+        }
+    }
+
+    fn minima(&self) -> Vec<[f64; 2]> {
+        match self.surface {
+            Surface::Function(function) => function.minima(),
+            Surface::Regression => least_squares(&self.x_data, &self.y_data).into_iter().collect(),
+        }
+    }
+
+    fn minimum_value(&self) -> Option<f64> {
+        self.minima().first().map(|[x, y]| self.value(*x, *y))
+    }
+
+    fn name(&self) -> &'static str {
+        match self.surface {
+            Surface::Function(function) => function.name(),
+            Surface::Regression => "Linear regression",
+        }
+    }
+
+    fn formula(&self) -> &'static str {
+        match self.surface {
+            Surface::Function(function) => function.formula(),
+            Surface::Regression => "½ mean (w x + b - y)² over the Linear Regression points",
+        }
+    }
+
+    // A stable learning rate, or a fraction of the plot for distance-based steps
+    fn default_step(&self, kind: OptimizerKind) -> f64 {
+        let learning_rate = match self.surface {
+            Surface::Function(function) => function.learning_rate(),
+            Surface::Regression => {
+                // 1 / largest eigenvalue of the Hessian [[mean x², mean x], [mean x, 1]]
+                let (xx, x) = (self.x_data.mapv(|x| x * x).mean().unwrap_or(1.0), self.x_data.mean().unwrap_or(0.0));
+                let largest = (xx + 1.0) / 2.0 + (((xx - 1.0) / 2.0).powi(2) + x * x).sqrt();
+                1.0 / largest
+            }
+        };
+        let [x_min, x_max, ..] = self.bounds();
+        match kind {
+            OptimizerKind::GradientDescent | OptimizerKind::Momentum | OptimizerKind::Spsa => learning_rate,
+            OptimizerKind::Adam => 0.02 * (x_max - x_min),
+            OptimizerKind::Cobyla | OptimizerKind::NelderMead => 0.1 * (x_max - x_min),
+        }
+    }
+
+    fn select_surface(&mut self, surface: Surface) {
+        self.surface = surface;
+        self.start = match surface {
+            Surface::Function(function) => function.start(),
+            Surface::Regression => {
+                let [x_min, x_max, y_min, y_max] = self.bounds();
+                [x_min + 0.1 * (x_max - x_min), y_max - 0.1 * (y_max - y_min)]
+            }
+        };
+        let enabled = |kind: OptimizerKind| kind.uses_gradient() || kind == OptimizerKind::NelderMead;
+        self.racers = OptimizerKind::ALL
+            .into_iter()
+            .zip(PALETTE)
+            .map(|(kind, color)| Racer {
+                kind,
+                color,
+                enabled: enabled(kind),
+                step_size: self.default_step(kind),
+                optimizer: kind.build(1.0, 0),
+                params: Array1::zeros(2),
+                path: Vec::new(),
+                values: Vec::new(),
+                escaped: false,
+            })
+            .collect();
+        self.rebuild_heights();
+        self.reset();
+    }
+
+    fn rebuild_heights(&mut self) {
+        let [x_min, x_max, y_min, y_max] = self.bounds();
+        let raw = Array2::from_shape_fn((GRID + 1, GRID + 1), |(row, col)| {
+            let x = x_min + (x_max - x_min) * col as f64 / GRID as f64;
+            let y = y_max - (y_max - y_min) * row as f64 / GRID as f64;
+            self.value(x, y)
+        });
+        let lowest = raw.iter().copied().fold(f64::INFINITY, f64::min);
+        // Log heights so that the valleys show as clearly as the walls
+        self.heights = raw.mapv(|f| (f - lowest).ln_1p());
+        let highest = self.heights.iter().copied().fold(0.0, f64::max);
+
+        self.contours.clear();
+        let fraction = |k: usize| k as f32 / GRID as f32;
+        for k in 0..CONTOUR_LEVELS {
+            let level = highest * (k as f64 + 0.5) / CONTOUR_LEVELS as f64;
+            for row in 0..GRID {
+                for col in 0..GRID {
+                    let corners = [
+                        (self.heights[[row, col]], [fraction(col), fraction(row)]),
+                        (self.heights[[row, col + 1]], [fraction(col + 1), fraction(row)]),
+                        (self.heights[[row + 1, col + 1]], [fraction(col + 1), fraction(row + 1)]),
+                        (self.heights[[row + 1, col]], [fraction(col), fraction(row + 1)]),
+                    ];
+                    contour_cell(corners, level, &mut self.contours);
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        let start = self.start;
+        let value = self.value(start[0], start[1]);
+        for racer in &mut self.racers {
+            racer.optimizer = racer.kind.build(racer.step_size, 0);
+            racer.params = array![start[0], start[1]];
+            racer.path = vec![start];
+            racer.values = vec![value];
+            racer.escaped = false;
+        }
+        self.running = false;
+    }
+
+    fn step(&mut self) {
+        let [x_min, x_max, y_min, y_max] = self.bounds();
+        let (x_margin, y_margin) = (ESCAPE_SPANS * (x_max - x_min), ESCAPE_SPANS * (y_max - y_min));
+        let mut function = match self.surface {
+            Surface::Function(function) => Some(function),
+            Surface::Regression => None,
+        };
+        let mut mse = MseObjective { x: &self.x_data, y: &self.y_data };
+        for racer in self.racers.iter_mut().filter(|racer| racer.enabled && !racer.escaped) {
+            let objective: &mut dyn Objective = match &mut function {
+                Some(function) => function,
+                None => &mut mse,
+            };
+            let value = racer.optimizer.step(objective, &mut racer.params);
+            let (x, y) = (racer.params[0], racer.params[1]);
+            racer.path.push([x, y]);
+            racer.values.push(value);
+            let inside = x > x_min - x_margin && x < x_max + x_margin && y > y_min - y_margin && y < y_max + y_margin;
+            racer.escaped = !(value.is_finite() && inside);
+        }
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        if self.running {
+            for _ in 0..self.steps_per_frame {
+                self.step();
+            }
+            let steps = self.racers.iter().map(|racer| racer.values.len()).max().unwrap_or(0);
+            if steps > MAX_STEPS || self.racers.iter().all(|racer| !racer.enabled || racer.escaped) {
+                self.running = false;
+            }
+            ui.ctx().request_repaint();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧠 Gradient Descent")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Optimizers racing across classic loss landscapes; click the surface to choose where they start")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| self.render_landscape(ui));
+                ui.add_space(16.0);
+                ui.vertical(|ui| {
+                    self.render_racers(ui);
+                    ui.add_space(16.0);
+                    self.render_progress(ui);
+                });
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut selected = None;
+        let mut reset = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("Surface:");
+                    for function in TestFunction::ALL {
+                        let surface = Surface::Function(function);
+                        if ui.selectable_label(self.surface == surface, function.name()).clicked() {
+                            selected = Some(surface);
+                        }
+                    }
+                    let fit = least_squares(&self.x_data, &self.y_data);
+                    let regression = ui.add_enabled(fit.is_some(), egui::Button::selectable(self.surface == Surface::Regression, "Linear regression MSE"));
+                    if regression.on_disabled_hover_text("Add at least two points with different x in the Linear Regression view").clicked() {
+                        selected = Some(Surface::Regression);
+                    }
+                });
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let label = if self.running { "⏸ Pause" } else { "▶ Race" };
+                    if ui.button(label).clicked() {
+                        self.running = !self.running;
+                    }
+                    if ui.button("⏭ Step").clicked() {
+                        self.step();
+                    }
+                    if ui.button("↺ Reset").clicked() {
+                        reset = true;
+                    }
+                    ui.add_space(16.0);
+                    ui.add(egui::Slider::new(&mut self.steps_per_frame, 1..=50).text("steps per frame"));
+                });
+            });
+        if let Some(surface) = selected {
+            self.select_surface(surface);
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    fn render_landscape(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("🗺 {}: {}", self.name(), self.formula()));
+        let (response, painter) = ui.allocate_painter(egui::vec2(CANVAS_SIZE, CANVAS_SIZE), egui::Sense::click());
+        let rect = response.rect;
+        let [x_min, x_max, y_min, y_max] = self.bounds();
+        let to_screen = |[x, y]: [f64; 2]| {
+            egui::pos2(
+                rect.left() + CANVAS_SIZE * ((x - x_min) / (x_max - x_min)) as f32,
+                rect.top() + CANVAS_SIZE * ((y_max - y) / (y_max - y_min)) as f32,
+            )
+        };
+        let to_data = |pos: egui::Pos2| {
+            [
+                x_min + (x_max - x_min) * ((pos.x - rect.left()) / CANVAS_SIZE) as f64,
+                y_max - (y_max - y_min) * ((pos.y - rect.top()) / CANVAS_SIZE) as f64,
+            ]
+        };
+
+        // Heights: deep blue valleys up to amber peaks
+        let highest = self.heights.iter().copied().fold(1e-12, f64::max);
+        let cell = CANVAS_SIZE / GRID as f32;
+        for row in 0..GRID {
+            for col in 0..GRID {
+                let mean = (self.heights[[row, col]] + self.heights[[row, col + 1]] + self.heights[[row + 1, col]] + self.heights[[row + 1, col + 1]]) / 4.0;
+                let t = (mean / highest) as f32;
+                let color = egui::Color32::from_rgb((20.0 + 210.0 * t) as u8, (30.0 + 130.0 * t) as u8, (90.0 - 40.0 * t) as u8);
+                let min = rect.min + egui::vec2(cell * col as f32, cell * row as f32);
+                painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(cell + 0.5, cell + 0.5)), 0.0, color);
+            }
+        }
+        let contour = egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(255, 255, 255, 50));
+        for [a, b] in &self.contours {
+            let point = |[u, v]: [f32; 2]| rect.min + egui::vec2(u * CANVAS_SIZE, v * CANVAS_SIZE);
+            painter.line_segment([point(*a), point(*b)], contour);
+        }
+        for minimum in self.minima() {
+            painter.text(to_screen(minimum), egui::Align2::CENTER_CENTER, "★", egui::FontId::proportional(14.0), egui::Color32::from_rgb(255, 255, 150));
+        }
+
+        // Trajectories, clipped to the canvas
+        let clipped = painter.with_clip_rect(rect);
+        for racer in self.racers.iter().filter(|racer| racer.enabled) {
+            let points: Vec<egui::Pos2> = racer.path.iter().map(|p| to_screen(*p)).collect();
+            clipped.add(egui::Shape::line(points.clone(), egui::Stroke::new(2.0, racer.color)));
+            if let Some(last) = points.last() {
+                clipped.circle_filled(*last, 4.0, racer.color);
+            }
+        }
+        painter.circle_stroke(to_screen(self.start), 6.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+
+        if response.clicked()
+            && let Some(pos) = response.interact_pointer_pos() {
+            self.start = to_data(pos);
+            self.reset();
+        }
+        if let Some(pos) = response.hover_pos() {
+            let [x, y] = to_data(pos);
+            response.on_hover_text(format!("({:.3}, {:.3})\nf = {:.4}", x, y, self.value(x, y)));
+        }
+        let axes = if self.surface == Surface::Regression { "w horizontally, b vertically" } else { "x horizontally, y vertically" };
+        ui.label(egui::RichText::new(format!("{}; ★ marks the global minima, the white ring the start", axes))
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(12.0));
+    }
+
+    fn render_racers(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🏁 Racers");
+        let mut restart = false;
+        egui::Grid::new("gradient_descent_racers").striped(true).show(ui, |ui| {
+            ui.label("");
+            ui.label("step size");
+            ui.label("steps");
+            ui.label("f");
+            ui.end_row();
+            for racer in &mut self.racers {
+                restart |= ui.checkbox(&mut racer.enabled, egui::RichText::new(racer.kind.name()).color(racer.color)).changed();
+                let text = if racer.kind.uses_gradient() { "lr" } else { "step" };
+                restart |= ui.add(egui::Slider::new(&mut racer.step_size, 1e-4..=2.0).logarithmic(true).text(text)).changed();
+                ui.monospace(format!("{}", racer.values.len() - 1));
+                let value = racer.values.last().copied().unwrap_or(f64::NAN);
+                if racer.escaped {
+                    ui.label(egui::RichText::new("diverged").color(egui::Color32::from_rgb(255, 100, 150)));
+                } else {
+                    ui.monospace(format!("{:.4e}", value));
+                }
+                ui.end_row();
+            }
+        });
+        if restart {
+            self.reset();
+        }
+    }
+
+    fn render_progress(&self, ui: &mut egui::Ui) {
+        let minimum = self.minimum_value();
+        let title = if minimum.is_some() { "📉 log₁₀ (f - f*) per step" } else { "📉 f per step" };
+        Self::section_label(ui, title);
+        Plot::new("gradient_descent_progress")
+            .width(460.0)
+            .height(240.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                for racer in self.racers.iter().filter(|racer| racer.enabled) {
+                    let points: PlotPoints = racer
+                        .values
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, value)| value.is_finite())
+                        .map(|(k, value)| match minimum {
+                            Some(minimum) => [k as f64, (value - minimum).max(1e-16).log10()],
+                            None => [k as f64, *value],
+                        })
+                        .collect();
+                    plot_ui.line(Line::new(racer.kind.name(), points).color(racer.color));
+                }
+            });
+    }
+}
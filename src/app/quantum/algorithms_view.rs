@@ -0,0 +1,576 @@
+use crate::app::widgets::StateVisual;
+use crate::app::widgets::circuit_diagram::CircuitDiagram;
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::core::quantum::algorithms::{
+    DjOracle, bernstein_vazirani, deutsch_jozsa, grover, grover_success_probability, inverse_qft,
+    optimal_grover_iterations, phase_estimation, qft, simon, simon_secret, teleportation,
+};
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::Circuit;
+use crate::core::quantum::executor::{Execution, clbit_string, execute_with_snapshots, sample_counts};
+use crate::core::quantum::state_vector::StateVector;
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Line, Plot, PlotPoints, Points, VLine};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+
+const SHOTS: usize = 512;
+const MAX_GROVER_ITERATIONS: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Algorithm {
+    DeutschJozsa,
+    BernsteinVazirani,
+    Simon,
+    Grover,
+    Qft,
+    InverseQft,
+    PhaseEstimation,
+    Teleportation,
+}
+
+impl Algorithm {
+    const ALL: [Algorithm; 8] = [
+        Algorithm::DeutschJozsa,
+        Algorithm::BernsteinVazirani,
+        Algorithm::Simon,
+        Algorithm::Grover,
+        Algorithm::Qft,
+        Algorithm::InverseQft,
+        Algorithm::PhaseEstimation,
+        Algorithm::Teleportation,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::DeutschJozsa => "Deutsch–Jozsa",
+            Algorithm::BernsteinVazirani => "Bernstein–Vazirani",
+            Algorithm::Simon => "Simon",
+            Algorithm::Grover => "Grover",
+            Algorithm::Qft => "QFT",
+            Algorithm::InverseQft => "Inverse QFT",
+            Algorithm::PhaseEstimation => "Phase Estimation",
+            Algorithm::Teleportation => "Teleportation",
+        }
+    }
+
+    // The walkthrough, one line per stage of the circuit
+    fn stages(self) -> &'static [&'static str] {
+        match self {
+            Algorithm::DeutschJozsa => &[
+                "Put the ancilla in |−⟩ and the inputs in a uniform superposition.",
+                "Query the oracle once: f(x) kicks back as a phase (−1)^f(x) on |x⟩.",
+                "Hadamards fold the phases back; a constant f interferes into |0…0⟩.",
+                "Measure: all zeros means constant, anything else means balanced.",
+            ],
+            Algorithm::BernsteinVazirani => &[
+                "Prepare |−⟩ on the ancilla and a uniform superposition on the inputs.",
+                "One query of f(x) = s·x marks each |x⟩ with the phase (−1)^(s·x).",
+                "Hadamards turn that phase pattern into the basis state |s⟩.",
+                "Measure: the reading is the secret string itself.",
+            ],
+            Algorithm::Simon => &[
+                "Superpose the inputs and copy them into the output register.",
+                "The oracle XORs s into the output so that f(x) = f(x ⊕ s).",
+                "Hadamards on the inputs leave only strings y with s·y = 0.",
+                "Measure several times and solve the linear system for s.",
+            ],
+            Algorithm::Grover => &[
+                "Start in the uniform superposition over all N states.",
+                "Oracle: flip the sign of the marked states.",
+                "Diffuser: reflect every amplitude about the mean.",
+                "Each oracle + diffuser pair rotates the state towards the marked states by 2θ.",
+                "Stop near π/4·√(N/M) iterations, before the rotation overshoots.",
+            ],
+            Algorithm::Qft => &[
+                "Load the basis state |x⟩ with X gates.",
+                "For each qubit from the top: a Hadamard, then controlled phases π/2^k.",
+                "Swap the qubits so that q0 is again the lowest bit.",
+                "The result has equal magnitudes and phases 2π·x·y/N.",
+            ],
+            Algorithm::InverseQft => &[
+                "Build a uniform superposition with phases 2π·k·y/N.",
+                "The inverse QFT runs the QFT backwards with conjugated phases.",
+                "All of the amplitude gathers on |k⟩, so one reading gives the frequency.",
+            ],
+            Algorithm::PhaseEstimation => &[
+                "Prepare the eigenstate |1⟩ of U = P(2πφ) and superpose the counting qubits.",
+                "Counting qubit k controls U^(2^k), kicking back the phase 2π·φ·2^k.",
+                "The inverse QFT turns those phases into the binary digits of φ.",
+                "Measure: reading / 2^t is the best t-bit estimate of φ.",
+            ],
+            Algorithm::Teleportation => &[
+                "Alice prepares the state to send on q0.",
+                "Alice and Bob share the Bell pair (q1, q2).",
+                "Alice entangles q0 with her half and measures both qubits.",
+                "Bob applies X if c1 = 1 and Z if c0 = 1; q2 now holds the original state.",
+            ],
+        }
+    }
+}
+
+pub struct AlgorithmsView {
+    algorithm: Algorithm,
+    // Input qubits (oracle algorithms) or register size (Grover, QFT)
+    num_qubits: usize,
+    // Balanced-oracle mask for Deutsch–Jozsa, secret string otherwise
+    secret: u64,
+    dj_constant: Option<bool>,
+    marked: usize,
+    iterations: usize,
+    // Basis state for the QFT, frequency for the inverse QFT
+    input: usize,
+    precision: usize,
+    phase: f64,
+    theta: f64,
+    phi: f64,
+    seed: u64,
+    visual: StateVisual,
+    // Cached from the settings above
+    circuit: Circuit,
+    execution: Execution<StateVector>,
+    counts: BTreeMap<String, usize>,
+    step: usize,
+}
+
+impl Default for AlgorithmsView {
+    fn default() -> Self {
+        let mut view = Self {
+            algorithm: Algorithm::DeutschJozsa,
+            num_qubits: 3,
+            secret: 0b101,
+            dj_constant: None,
+            marked: 5,
+            iterations: 2,
+            input: 1,
+            precision: 4,
+            phase: 0.3125,
+            theta: 1.1,
+            phi: 0.7,
+            seed: 0,
+            visual: StateVisual::PhaseDisks,
+            circuit: Circuit::new(1),
+            execution: execute_with_snapshots(&Circuit::new(1), &mut StdRng::seed_from_u64(0)),
+            counts: BTreeMap::new(),
+            step: 0,
+        };
+        view.rebuild();
+        view
+    }
+}
+
+impl AlgorithmsView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn qubit_range(&self) -> std::ops::RangeInclusive<usize> {
+        match self.algorithm {
+            Algorithm::DeutschJozsa | Algorithm::BernsteinVazirani => 1..=6,
+            Algorithm::Simon => 2..=4,
+            Algorithm::Grover => 2..=5,
+            Algorithm::Qft | Algorithm::InverseQft => 1..=5,
+            Algorithm::PhaseEstimation | Algorithm::Teleportation => 1..=1,
+        }
+    }
+
+    fn build_circuit(&self) -> Circuit {
+        let n = self.num_qubits;
+        match self.algorithm {
+            Algorithm::DeutschJozsa => {
+                let oracle = match self.dj_constant {
+                    Some(value) => DjOracle::Constant(value),
+                    None => DjOracle::Balanced(self.secret),
+                };
+                deutsch_jozsa(n, oracle)
+            }
+            Algorithm::BernsteinVazirani => bernstein_vazirani(n, self.secret),
+            Algorithm::Simon => simon(n, self.secret),
+            Algorithm::Grover => grover(n, &[self.marked], self.iterations),
+            Algorithm::Qft => qft(n, self.input),
+            Algorithm::InverseQft => inverse_qft(n, self.input),
+            Algorithm::PhaseEstimation => phase_estimation(self.precision, self.phase),
+            Algorithm::Teleportation => teleportation(self.theta, self.phi),
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let range = self.qubit_range();
+        self.num_qubits = self.num_qubits.clamp(*range.start(), *range.end());
+        let size = 1usize << self.num_qubits;
+        self.secret &= (size - 1) as u64;
+        // Simon needs s ≠ 0 for a 2-to-1 function; a balanced DJ oracle needs a non-zero mask
+        if self.secret == 0 && self.algorithm != Algorithm::BernsteinVazirani {
+            self.secret = 1;
+        }
+        self.marked %= size;
+        self.input %= size;
+
+        self.circuit = self.build_circuit();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        self.execution = execute_with_snapshots(&self.circuit, &mut rng);
+        self.counts = if self.circuit.num_clbits() > 0 {
+            sample_counts::<StateVector, _>(&self.circuit, SHOTS, &mut rng)
+        } else {
+            BTreeMap::new()
+        };
+        self.step = self.step.min(self.execution.moments.len());
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🧮 Quantum Algorithms")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("The textbook algorithms, built gate by gate and stepped through moment by moment")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+            self.render_walkthrough(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_state(&mut columns[0]);
+                self.render_result(&mut columns[1]);
+            });
+
+            if self.algorithm == Algorithm::Grover {
+                ui.add_space(16.0);
+                ui.separator();
+                ui.add_space(16.0);
+                self.render_grover_curve(ui);
+            }
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    // One toggle per bit of `value`, highest bit on the left like basis labels
+    fn bit_toggles(ui: &mut egui::Ui, label: &str, value: &mut u64, bits: usize) -> bool {
+        let mut changed = false;
+        ui.label(label);
+        for bit in (0..bits).rev() {
+            let set = *value >> bit & 1 == 1;
+            if ui.selectable_label(set, if set { "1" } else { "0" }).on_hover_text(format!("bit {}", bit)).clicked() {
+                *value ^= 1 << bit;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for algorithm in Algorithm::ALL {
+                        if ui.selectable_value(&mut self.algorithm, algorithm, algorithm.name()).changed() {
+                            self.step = 0;
+                            changed = true;
+                        }
+                    }
+                });
+                ui.add_space(6.0);
+
+                ui.horizontal_wrapped(|ui| {
+                    let range = self.qubit_range();
+                    if range.start() != range.end() {
+                        let text = match self.algorithm {
+                            Algorithm::Grover | Algorithm::Qft | Algorithm::InverseQft => "qubits",
+                            _ => "input qubits",
+                        };
+                        changed |= ui.add(egui::Slider::new(&mut self.num_qubits, range).text(text)).changed();
+                        ui.add_space(12.0);
+                    }
+                    let size = 1usize << self.num_qubits;
+                    match self.algorithm {
+                        Algorithm::DeutschJozsa => {
+                            for (value, name) in [(Some(false), "f = 0"), (Some(true), "f = 1"), (None, "balanced f = s·x")] {
+                                changed |= ui.selectable_value(&mut self.dj_constant, value, name).changed();
+                            }
+                            if self.dj_constant.is_none() {
+                                ui.add_space(12.0);
+                                changed |= Self::bit_toggles(ui, "s =", &mut self.secret, self.num_qubits);
+                            }
+                        }
+                        Algorithm::BernsteinVazirani | Algorithm::Simon => {
+                            changed |= Self::bit_toggles(ui, "secret s =", &mut self.secret, self.num_qubits);
+                        }
+                        Algorithm::Grover => {
+                            changed |= ui.add(egui::Slider::new(&mut self.marked, 0..=size - 1).text("marked state")).changed();
+                            changed |= ui
+                                .add(egui::Slider::new(&mut self.iterations, 0..=MAX_GROVER_ITERATIONS).text("iterations"))
+                                .changed();
+                            let optimal = optimal_grover_iterations(self.num_qubits, 1);
+                            if ui.button(format!("⭐ Optimal ({})", optimal)).clicked() {
+                                self.iterations = optimal;
+                                changed = true;
+                            }
+                        }
+                        Algorithm::Qft | Algorithm::InverseQft => {
+                            let text = if self.algorithm == Algorithm::Qft { "input |x⟩" } else { "frequency k" };
+                            changed |= ui.add(egui::Slider::new(&mut self.input, 0..=size - 1).text(text)).changed();
+                        }
+                        Algorithm::PhaseEstimation => {
+                            changed |= ui.add(egui::Slider::new(&mut self.precision, 2..=6).text("counting qubits")).changed();
+                            changed |= ui.add(egui::Slider::new(&mut self.phase, 0.0..=1.0).text("phase φ")).changed();
+                        }
+                        Algorithm::Teleportation => {
+                            changed |= ui.add(egui::Slider::new(&mut self.theta, 0.0..=PI).text("θ")).changed();
+                            changed |= ui.add(egui::Slider::new(&mut self.phi, -PI..=PI).text("φ")).changed();
+                        }
+                    }
+                });
+            });
+        if changed {
+            self.rebuild();
+        }
+    }
+
+    fn render_walkthrough(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("🧭 {} walkthrough", self.algorithm.name()));
+        for (i, stage) in self.algorithm.stages().iter().enumerate() {
+            ui.label(egui::RichText::new(format!("{}. {}", i + 1, stage))
+                .color(egui::Color32::from_rgb(190, 190, 210))
+                .size(13.0));
+        }
+        ui.add_space(10.0);
+
+        let last = self.execution.moments.len();
+        ui.horizontal(|ui| {
+            if ui.button("⏮").clicked() {
+                self.step = 0;
+            }
+            if ui.button("◀").clicked() {
+                self.step = self.step.saturating_sub(1);
+            }
+            if ui.button("▶").clicked() {
+                self.step = (self.step + 1).min(last);
+            }
+            if ui.button("⏭").clicked() {
+                self.step = last;
+            }
+            ui.add(egui::Slider::new(&mut self.step, 0..=last).text(format!("of {} moments", last)));
+            ui.add_space(12.0);
+            if ui.button("🎲 New shot").on_hover_text("Re-run the measurements shown in the walkthrough").clicked() {
+                self.seed += 1;
+                self.rebuild();
+            }
+        });
+        ui.add_space(6.0);
+
+        egui::ScrollArea::horizontal().id_salt("algorithm_circuit").show(ui, |ui| {
+            CircuitDiagram::new(&self.circuit).applied(self.step).show(ui);
+        });
+    }
+
+    fn render_state(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, &format!("🔬 State after moment {}", self.step));
+        let state = &self.execution.snapshots[self.step];
+        ProbabilityBars::new("algorithm_probabilities", &state.probabilities(), state.num_qubits())
+            .height(180.0)
+            .show(ui);
+        if self.circuit.num_clbits() > 0 {
+            ui.label(egui::RichText::new(format!("Classical bits: {}", clbit_string(&self.execution.clbits[self.step])))
+                .color(egui::Color32::from_rgb(255, 200, 100))
+                .size(13.0)
+                .code());
+        }
+        ui.add_space(8.0);
+        self.visual.picker(ui);
+        self.visual.show(ui, "algorithm_state_visual", state, 220.0);
+    }
+
+    // The most frequent reading as an integer, c[0] the lowest bit
+    fn top_reading(&self) -> Option<u64> {
+        self.counts
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .and_then(|(key, _)| u64::from_str_radix(key, 2).ok())
+    }
+
+    fn render_result(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🎯 Result");
+        let verdict = |ui: &mut egui::Ui, text: String, good: bool| {
+            let color = if good { egui::Color32::from_rgb(100, 255, 150) } else { egui::Color32::from_rgb(255, 200, 100) };
+            ui.label(egui::RichText::new(text).color(color).size(14.0).strong());
+        };
+        let n = self.num_qubits;
+        let bits = |value: u64, width: usize| format!("{:0width$b}", value, width = width);
+
+        match self.algorithm {
+            Algorithm::DeutschJozsa => {
+                let zeros = self.counts.get(&"0".repeat(n)).copied().unwrap_or(0);
+                let constant = zeros == SHOTS;
+                verdict(
+                    ui,
+                    format!("{} of {} shots read all zeros → f is {}", zeros, SHOTS, if constant { "constant" } else { "balanced" }),
+                    constant == self.dj_constant.is_some(),
+                );
+            }
+            Algorithm::BernsteinVazirani => {
+                if let Some(reading) = self.top_reading() {
+                    verdict(ui, format!("Read s = {} in a single query", bits(reading, n)), reading == self.secret);
+                }
+            }
+            Algorithm::Simon => {
+                let samples: Vec<u64> = self.counts.keys().filter_map(|key| u64::from_str_radix(key, 2).ok()).collect();
+                ui.label(format!("{} distinct readings y, each with s·y = 0 (mod 2)", samples.len()));
+                match simon_secret(&samples, n) {
+                    Some(s) => verdict(ui, format!("Solved: s = {}", bits(s, n)), s == self.secret),
+                    None => verdict(ui, "Not enough independent readings yet".to_string(), false),
+                }
+            }
+            Algorithm::Grover => {
+                let key = bits(self.marked as u64, n);
+                let hits = self.counts.get(&key).copied().unwrap_or(0);
+                let expected = grover_success_probability(n, 1, self.iterations);
+                verdict(
+                    ui,
+                    format!("|{}⟩ found in {:.1}% of shots (theory {:.1}%)", key, 100.0 * hits as f64 / SHOTS as f64, 100.0 * expected),
+                    expected > 0.5,
+                );
+            }
+            Algorithm::Qft => {
+                let final_state = self.execution.snapshots.last().expect("snapshots start with the initial state");
+                let step = 360.0 * self.input as f64 / (1 << n) as f64;
+                ui.label(format!(
+                    "Every |y⟩ has magnitude 1/√{} and the phase advances by {:.1}° per step of y.",
+                    1 << n,
+                    step
+                ));
+                ProbabilityBars::new("qft_result", &final_state.probabilities(), n).height(140.0).show(ui);
+                return;
+            }
+            Algorithm::InverseQft => {
+                if let Some(reading) = self.top_reading() {
+                    verdict(ui, format!("Read k = {}", reading), reading == self.input as u64);
+                }
+            }
+            Algorithm::PhaseEstimation => {
+                if let Some(reading) = self.top_reading() {
+                    let estimate = reading as f64 / (1u64 << self.precision) as f64;
+                    let error = (estimate - self.phase).abs().min(1.0 - (estimate - self.phase).abs());
+                    verdict(
+                        ui,
+                        format!("Read {} → φ ≈ {:.4} (true {:.4})", bits(reading, self.precision), estimate, self.phase),
+                        error <= 1.0 / (1u64 << self.precision) as f64,
+                    );
+                }
+            }
+            Algorithm::Teleportation => {
+                let final_state = self.execution.snapshots.last().expect("snapshots start with the initial state");
+                let [x, y, z] = final_state.bloch_vector(2);
+                let target = [self.theta.sin() * self.phi.cos(), self.theta.sin() * self.phi.sin(), self.theta.cos()];
+                // Pure-state fidelity (1 + r·r') / 2
+                let fidelity = (1.0 + x * target[0] + y * target[1] + z * target[2]) / 2.0;
+                ui.label(format!("Bob's qubit q2: ({:+.3}, {:+.3}, {:+.3})", x, y, z));
+                verdict(ui, format!("Fidelity with Alice's state: {:.4}", fidelity), fidelity > 0.999);
+            }
+        }
+
+        ui.add_space(8.0);
+        self.render_counts(ui);
+    }
+
+    fn render_counts(&self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new(format!("🎲 Measurement counts ({} shots)", SHOTS))
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+
+        let keys: Vec<String> = self.counts.keys().cloned().collect();
+        let bars: Vec<Bar> = self.counts
+            .iter()
+            .enumerate()
+            .map(|(i, (key, &count))| Bar::new(i as f64, count as f64)
+                .name(key)
+                .width(0.7)
+                .fill(egui::Color32::from_rgb(180, 140, 255)))
+            .collect();
+
+        Plot::new("algorithm_counts")
+            .height(200.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .x_axis_formatter(move |mark, _| {
+                let i = mark.value.round();
+                if (mark.value - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < keys.len() {
+                    keys[i as usize].clone()
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("Counts", bars));
+            });
+    }
+
+    fn render_grover_curve(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📈 Success probability vs iterations");
+        let n = self.num_qubits;
+        let optimal = optimal_grover_iterations(n, 1);
+        ui.label(egui::RichText::new(format!(
+            "sin²((2k + 1)θ) with sin θ = 1/√{}: it peaks after {} iterations, then the state rotates past the marked state.",
+            1 << n,
+            optimal
+        ))
+        .color(egui::Color32::from_rgb(160, 160, 180))
+        .size(12.0));
+
+        // Continuous in k for the shape, dots at the iteration counts a circuit can run
+        let curve: PlotPoints = (0..=MAX_GROVER_ITERATIONS * 20)
+            .map(|i| {
+                let k = i as f64 / 20.0;
+                let theta = (1.0 / (1u64 << n) as f64).sqrt().asin();
+                [k, ((2.0 * k + 1.0) * theta).sin().powi(2)]
+            })
+            .collect();
+        let dots: PlotPoints = (0..=MAX_GROVER_ITERATIONS)
+            .map(|k| [k as f64, grover_success_probability(n, 1, k)])
+            .collect();
+
+        Plot::new("grover_curve")
+            .height(220.0)
+            .legend(Legend::default())
+            .include_y(0.0)
+            .include_y(1.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("P(marked)", curve).color(egui::Color32::from_rgb(100, 200, 255)));
+                plot_ui.points(Points::new("Whole iterations", dots).radius(4.0).color(egui::Color32::from_rgb(180, 140, 255)));
+                plot_ui.vline(VLine::new("Optimal", optimal as f64).color(egui::Color32::from_rgb(100, 255, 150)));
+                plot_ui.vline(VLine::new("Current", self.iterations as f64).color(egui::Color32::from_rgb(255, 200, 100)));
+            });
+    }
+}
@@ -0,0 +1,1449 @@
+use crate::app::quantum::noise_panel::NoisePanel;
+use crate::app::quantum::observable_panel::ObservablePanel;
+use crate::app::widgets::phase_color;
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::app::widgets::{StateVisual, show_city};
+use crate::core::quantum::backend::{Backend, BackendKind};
+use crate::core::quantum::circuit::{Circuit, Instruction, Operation, Register};
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::executor::{clbit_string, run_shot, sample_counts};
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::qasm::{Version, parser, writer};
+use crate::core::quantum::state_vector::{StateVector, basis_label};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+const CELL: f32 = 52.0;
+const LABEL_WIDTH: f32 = 56.0;
+const MIN_COLUMNS: usize = 10;
+const MAX_QUBITS: usize = 6;
+const SHOTS: usize = 1024;
+// Every noisy shot is a density-matrix run
+const NOISY_SHOTS: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PaletteGate {
+    H,
+    X,
+    Y,
+    Z,
+    S,
+    Sdg,
+    T,
+    Tdg,
+    SX,
+    Rx,
+    Ry,
+    Rz,
+    Phase,
+    U,
+    CX,
+    CZ,
+    Swap,
+    Toffoli,
+    Measure,
+    Reset,
+    Barrier,
+}
+
+impl PaletteGate {
+    const SINGLE: [PaletteGate; 9] = [
+        PaletteGate::H,
+        PaletteGate::X,
+        PaletteGate::Y,
+        PaletteGate::Z,
+        PaletteGate::S,
+        PaletteGate::Sdg,
+        PaletteGate::T,
+        PaletteGate::Tdg,
+        PaletteGate::SX,
+    ];
+    const ROTATIONS: [PaletteGate; 5] = [
+        PaletteGate::Rx,
+        PaletteGate::Ry,
+        PaletteGate::Rz,
+        PaletteGate::Phase,
+        PaletteGate::U,
+    ];
+    const MULTI: [PaletteGate; 4] = [PaletteGate::CX, PaletteGate::CZ, PaletteGate::Swap, PaletteGate::Toffoli];
+    const NON_UNITARY: [PaletteGate; 3] = [PaletteGate::Measure, PaletteGate::Reset, PaletteGate::Barrier];
+
+    fn label(self) -> &'static str {
+        match self {
+            PaletteGate::CX => "CX",
+            PaletteGate::CZ => "CZ",
+            PaletteGate::Toffoli => "CCX",
+            PaletteGate::Measure => "M",
+            PaletteGate::Reset => "|0⟩",
+            PaletteGate::Barrier => "Barrier",
+            other => other.base_gate().map_or("", |gate| gate.name()),
+        }
+    }
+
+    // Base gate placed on the target wire; rotations start at π/2
+    fn base_gate(self) -> Option<Gate> {
+        let gate = match self {
+            PaletteGate::H => Gate::H,
+            PaletteGate::X | PaletteGate::CX | PaletteGate::Toffoli => Gate::X,
+            PaletteGate::Y => Gate::Y,
+            PaletteGate::Z | PaletteGate::CZ => Gate::Z,
+            PaletteGate::S => Gate::S,
+            PaletteGate::Sdg => Gate::Sdg,
+            PaletteGate::T => Gate::T,
+            PaletteGate::Tdg => Gate::Tdg,
+            PaletteGate::SX => Gate::SX,
+            PaletteGate::Rx => Gate::Rx(FRAC_PI_2),
+            PaletteGate::Ry => Gate::Ry(FRAC_PI_2),
+            PaletteGate::Rz => Gate::Rz(FRAC_PI_2),
+            PaletteGate::Phase => Gate::Phase(FRAC_PI_2),
+            PaletteGate::U => Gate::U(FRAC_PI_2, 0.0, PI),
+            PaletteGate::Swap => Gate::Swap,
+            PaletteGate::Measure | PaletteGate::Reset | PaletteGate::Barrier => return None,
+        };
+        Some(gate)
+    }
+
+    // Extra wires the gate connects to (controls, or the second SWAP wire)
+    fn num_links(self) -> usize {
+        match self {
+            PaletteGate::CX | PaletteGate::CZ | PaletteGate::Swap => 1,
+            PaletteGate::Toffoli => 2,
+            _ => 0,
+        }
+    }
+
+    // Instruction on `wires`: the box wire first, then the links
+    fn instruction(self, wires: &[usize]) -> Instruction {
+        match (self, self.base_gate()) {
+            (PaletteGate::Measure, _) => Instruction::Measure { qubit: wires[0], clbit: wires[0] },
+            (PaletteGate::Reset, _) => Instruction::Reset(wires[0]),
+            (PaletteGate::Swap, _) => Instruction::Gate(Operation::new(Gate::Swap, wires.to_vec())),
+            (_, Some(gate)) => Instruction::Gate(Operation::controlled(gate, wires[1..].to_vec(), vec![wires[0]])),
+            (_, None) => Instruction::Barrier(wires.to_vec()),
+        }
+    }
+
+    fn color(self) -> egui::Color32 {
+        match self {
+            PaletteGate::H => egui::Color32::from_rgb(230, 90, 110),
+            PaletteGate::X | PaletteGate::Y | PaletteGate::Z | PaletteGate::SX => egui::Color32::from_rgb(90, 140, 240),
+            PaletteGate::S | PaletteGate::Sdg | PaletteGate::T | PaletteGate::Tdg => egui::Color32::from_rgb(150, 110, 230),
+            PaletteGate::Rx | PaletteGate::Ry | PaletteGate::Rz | PaletteGate::Phase | PaletteGate::U => {
+                egui::Color32::from_rgb(240, 170, 70)
+            }
+            PaletteGate::CX | PaletteGate::CZ | PaletteGate::Swap | PaletteGate::Toffoli => egui::Color32::from_rgb(70, 190, 170),
+            PaletteGate::Measure | PaletteGate::Reset | PaletteGate::Barrier => egui::Color32::from_rgb(170, 170, 190),
+        }
+    }
+}
+
+// Same colour families as the palette, for any instruction (imported ones too)
+fn instruction_color(instruction: &Instruction) -> egui::Color32 {
+    let kind = match instruction {
+        Instruction::Gate(op) if !op.controls.is_empty() || op.gate == Gate::Swap => PaletteGate::CX,
+        Instruction::Gate(op) => match op.gate {
+            Gate::H => PaletteGate::H,
+            Gate::X | Gate::Y | Gate::Z | Gate::SX | Gate::SXdg => PaletteGate::X,
+            Gate::S | Gate::Sdg | Gate::T | Gate::Tdg => PaletteGate::S,
+            _ => PaletteGate::Rx,
+        },
+        _ => PaletteGate::Measure,
+    };
+    kind.color()
+}
+
+#[derive(Clone, Debug)]
+struct PlacedGate {
+    // Never a Conditional; the condition is kept beside it
+    instruction: Instruction,
+    // (classical register, value) the instruction is conditioned on
+    condition: Option<(usize, u64)>,
+    column: usize,
+}
+
+impl PlacedGate {
+    fn new(instruction: Instruction, column: usize) -> Self {
+        match instruction {
+            Instruction::Conditional { register, value, instruction } => Self {
+                instruction: *instruction,
+                condition: Some((register, value)),
+                column,
+            },
+            instruction => Self {
+                instruction,
+                condition: None,
+                column,
+            },
+        }
+    }
+
+    // Box wire first, then the link wires (controls, second SWAP target, barrier span)
+    fn wires(&self) -> Vec<usize> {
+        match &self.instruction {
+            Instruction::Gate(op) => [op.targets[0]]
+                .into_iter()
+                .chain(op.controls.iter().copied())
+                .chain(op.targets[1..].iter().copied())
+                .collect(),
+            Instruction::Measure { qubit, .. } | Instruction::Reset(qubit) => vec![*qubit],
+            Instruction::Barrier(qubits) => qubits.clone(),
+            other => other.qubits(),
+        }
+    }
+
+    // Inverse of wires()
+    fn set_wires(&mut self, wires: &[usize]) {
+        match &mut self.instruction {
+            Instruction::Gate(op) => {
+                let num_controls = op.controls.len();
+                op.targets[0] = wires[0];
+                op.controls = wires[1..1 + num_controls].to_vec();
+                op.targets[1..].copy_from_slice(&wires[1 + num_controls..]);
+            }
+            Instruction::Measure { qubit, .. } | Instruction::Reset(qubit) => *qubit = wires[0],
+            Instruction::Barrier(qubits) => *qubits = wires.to_vec(),
+            Instruction::If { .. } | Instruction::While { .. } => {
+                let old = self.instruction.qubits();
+                self.instruction.map_qubits(&|q| wires[old.iter().position(|&o| o == q).unwrap_or(0)]);
+            }
+            Instruction::Conditional { .. } => {}
+        }
+    }
+
+    fn is_block(&self) -> bool {
+        matches!(self.instruction, Instruction::If { .. } | Instruction::While { .. })
+    }
+
+    fn target(&self) -> usize {
+        self.wires()[0]
+    }
+
+    fn rows(&self) -> (usize, usize) {
+        let wires = self.wires();
+        (wires.iter().copied().min().unwrap_or(0), wires.iter().copied().max().unwrap_or(0))
+    }
+
+    fn to_instruction(&self, num_clbits: usize, num_cregs: usize) -> Instruction {
+        let mut instruction = self.instruction.clone();
+        if let Instruction::Measure { clbit, .. } = &mut instruction {
+            *clbit = (*clbit).min(num_clbits.saturating_sub(1));
+        }
+        match self.condition {
+            Some((register, value)) if register < num_cregs => Instruction::Conditional {
+                register,
+                value,
+                instruction: Box::new(instruction),
+            },
+            _ => instruction,
+        }
+    }
+}
+
+// Payload carried by egui drag-and-drop while a gate is being dragged
+#[derive(Clone, Copy, Debug)]
+enum DragPayload {
+    Palette(PaletteGate),
+    Move(usize),
+}
+
+enum CanvasAction {
+    Drop(DragPayload, usize, usize),
+    Relink(usize, usize, usize),
+    Select(usize),
+    Remove(usize),
+    Deselect,
+}
+
+pub struct CircuitComposerView {
+    num_qubits: usize,
+    placed: Vec<PlacedGate>,
+    selected: Option<usize>,
+    status: String,
+    backend: BackendKind,
+    noise: NoisePanel,
+    observable: ObservablePanel,
+    // Registers of an opened QASM file; None means q[n] (+ c[n] when needed)
+    registers: Option<(Vec<Register>, Vec<Register>)>,
+    qasm_path: String,
+    qasm_message: String,
+    // Dialect used when saving; opening detects it from the header
+    qasm_version: Version,
+    // Source of the opened file and its `input` values, re-parsed when an input changes
+    qasm_source: Option<String>,
+    qasm_inputs: Vec<(String, f64)>,
+    // Seed of the displayed shot's measurement outcomes
+    seed: u64,
+    // Cached simulation of the compiled circuit; the state vector is
+    // always kept as the ideal reference for the density-matrix backend
+    circuit: Circuit,
+    state: StateVector,
+    clbits: Vec<bool>,
+    counts: BTreeMap<String, usize>,
+    density: Option<DensityMatrix>,
+    // Noisy counts skipped while a noise slider drags, resampled on release
+    counts_stale: bool,
+    visual: StateVisual,
+}
+
+impl Default for CircuitComposerView {
+    fn default() -> Self {
+        let mut view = Self {
+            num_qubits: 2,
+            placed: Vec::new(),
+            selected: None,
+            status: String::new(),
+            backend: BackendKind::StateVector,
+            noise: NoisePanel::new(),
+            observable: ObservablePanel::new(),
+            registers: None,
+            qasm_path: "circuit.qasm".to_string(),
+            qasm_message: String::new(),
+            qasm_version: Version::V2,
+            qasm_source: None,
+            qasm_inputs: Vec::new(),
+            seed: 0,
+            circuit: Circuit::new(2),
+            state: StateVector::new(2),
+            clbits: Vec::new(),
+            counts: BTreeMap::new(),
+            density: None,
+            counts_stale: false,
+            visual: StateVisual::Amplitudes,
+        };
+        view.load_bell();
+        view
+    }
+}
+
+impl CircuitComposerView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn circuit(&self) -> &Circuit {
+        &self.circuit
+    }
+
+    // `row` followed by the `count` nearest other wires, above first
+    fn default_wires(&self, row: usize, count: usize) -> Option<Vec<usize>> {
+        let candidates = (0..row).rev().chain(row + 1..self.num_qubits);
+        let wires: Vec<usize> = [row].into_iter().chain(candidates.take(count)).collect();
+        (wires.len() == count + 1).then_some(wires)
+    }
+
+    fn conflicts(&self, index: usize) -> bool {
+        let gate = &self.placed[index];
+        let (lo, hi) = gate.rows();
+        self.placed.iter().enumerate().any(|(i, other)| {
+            let (olo, ohi) = other.rows();
+            i != index && other.column == gate.column && olo <= hi && lo <= ohi
+        })
+    }
+
+    // Keeps the gate where it is and pushes everything else in its column
+    // (and to the right) one column further if they overlap
+    fn resolve_conflicts(&mut self, index: usize) {
+        if self.conflicts(index) {
+            let column = self.placed[index].column;
+            for (i, other) in self.placed.iter_mut().enumerate() {
+                if i != index && other.column >= column {
+                    other.column += 1;
+                }
+            }
+        }
+        self.compact_columns();
+    }
+
+    // Removes empty columns so the circuit stays left-aligned
+    fn compact_columns(&mut self) {
+        let mut used: Vec<usize> = self.placed.iter().map(|g| g.column).collect();
+        used.sort_unstable();
+        used.dedup();
+        for gate in &mut self.placed {
+            gate.column = used.iter().position(|&c| c == gate.column).unwrap_or(0);
+        }
+    }
+
+    fn drop_gate(&mut self, payload: DragPayload, column: usize, row: usize) {
+        match payload {
+            DragPayload::Palette(kind) => {
+                let wires = if kind == PaletteGate::Barrier {
+                    self.default_wires(row, self.num_qubits - 1)
+                } else {
+                    self.default_wires(row, kind.num_links())
+                };
+                let Some(wires) = wires else {
+                    self.status = format!("{} needs {} qubits", kind.label(), kind.num_links() + 1);
+                    return;
+                };
+                self.placed.push(PlacedGate::new(kind.instruction(&wires), column));
+                let index = self.placed.len() - 1;
+                self.resolve_conflicts(index);
+                self.selected = Some(index);
+            }
+            DragPayload::Move(index) => {
+                let wires = self.placed[index].wires();
+                let shift = row as isize - wires[0] as isize;
+                let shifted: Vec<isize> = wires.iter().map(|&w| w as isize + shift).collect();
+                let wires = if shifted.iter().all(|&w| w >= 0 && (w as usize) < self.num_qubits) {
+                    Some(shifted.into_iter().map(|w| w as usize).collect())
+                } else {
+                    self.default_wires(row, wires.len() - 1)
+                };
+                let gate = &mut self.placed[index];
+                gate.column = column;
+                if let Some(wires) = wires {
+                    gate.set_wires(&wires);
+                }
+                self.resolve_conflicts(index);
+                self.selected = Some(index);
+            }
+        }
+        self.status.clear();
+        self.rebuild();
+    }
+
+    fn relink(&mut self, index: usize, link: usize, row: usize) {
+        let mut wires = self.placed[index].wires();
+        if wires.contains(&row) {
+            return;
+        }
+        wires[link + 1] = row;
+        self.placed[index].set_wires(&wires);
+        self.resolve_conflicts(index);
+        self.rebuild();
+    }
+
+    fn remove_gate(&mut self, index: usize) {
+        self.placed.remove(index);
+        self.selected = None;
+        self.compact_columns();
+        self.rebuild();
+    }
+
+    fn set_num_qubits(&mut self, num_qubits: usize) {
+        self.num_qubits = num_qubits;
+        self.registers = None;
+        self.qasm_source = None;
+        self.qasm_inputs.clear();
+        // Blocks read bits of the imported registers, which are dropped
+        let circuit = &self.circuit;
+        self.placed.retain(|g| {
+            g.wires().iter().all(|&w| w < num_qubits) && (!g.is_block() || circuit.clbits(&g.instruction).iter().all(|&b| b < num_qubits))
+        });
+        self.selected = None;
+        self.compact_columns();
+        self.rebuild();
+    }
+
+    // Imported registers, or q[n]; a c[n] register is added once
+    // something needs classical bits
+    fn registers(&self) -> (Vec<Register>, Vec<Register>) {
+        let (qregs, mut cregs) = self
+            .registers
+            .clone()
+            .unwrap_or_else(|| (vec![Register::new("q", self.num_qubits)], Vec::new()));
+        let needs_bits = self
+            .placed
+            .iter()
+            .any(|g| g.condition.is_some() || g.is_block() || matches!(g.instruction, Instruction::Measure { .. }));
+        if needs_bits && cregs.is_empty() {
+            cregs.push(Register::new("c", self.num_qubits));
+        }
+        (qregs, cregs)
+    }
+
+    // Compiles the grid into a core circuit and re-simulates it
+    fn rebuild(&mut self) {
+        let mut order: Vec<&PlacedGate> = self.placed.iter().collect();
+        order.sort_by_key(|g| (g.column, g.target()));
+
+        let (qregs, cregs) = self.registers();
+        let mut circuit = Circuit::with_registers(qregs, cregs);
+        let (num_clbits, num_cregs) = (circuit.num_clbits(), circuit.cregs().len());
+        for gate in order {
+            circuit.push(gate.to_instruction(num_clbits, num_cregs));
+        }
+
+        // The same seed drives every run so all views agree on the outcomes
+        let rng = || StdRng::seed_from_u64(self.seed);
+        let shot = run_shot::<StateVector, _>(&circuit, &mut rng());
+        let noise = self.noise.model();
+        self.density = match self.backend {
+            BackendKind::StateVector => None,
+            BackendKind::DensityMatrix => Some(noise.run(&circuit, &mut rng()).state),
+        };
+        let noisy = self.density.is_some() && !noise.is_noiseless();
+        self.counts_stale = noisy && !circuit.is_unitary() && self.noise.is_dragging();
+        if !self.counts_stale {
+            self.counts = if circuit.is_unitary() {
+                BTreeMap::new()
+            } else if noisy {
+                // Noisy shots, so gate noise and readout error reach the counts
+                noise.sample_counts(&circuit, NOISY_SHOTS, &mut rng())
+            } else {
+                sample_counts::<StateVector, _>(&circuit, SHOTS, &mut rng())
+            };
+        }
+
+        self.circuit = circuit;
+        self.state = shot.state;
+        self.clbits = shot.clbits;
+    }
+
+    fn load_preset(&mut self, num_qubits: usize, gates: Vec<(PaletteGate, usize, Vec<usize>)>) {
+        self.num_qubits = num_qubits;
+        self.registers = None;
+        self.qasm_source = None;
+        self.qasm_inputs.clear();
+        self.placed = gates
+            .into_iter()
+            .map(|(kind, column, wires)| PlacedGate::new(kind.instruction(&wires), column))
+            .collect();
+        self.selected = None;
+        self.status.clear();
+        self.rebuild();
+    }
+
+    fn load_bell(&mut self) {
+        self.load_preset(2, vec![(PaletteGate::H, 0, vec![0]), (PaletteGate::CX, 1, vec![1, 0])]);
+    }
+
+    fn load_ghz(&mut self) {
+        self.load_preset(
+            3,
+            vec![
+                (PaletteGate::H, 0, vec![0]),
+                (PaletteGate::CX, 1, vec![1, 0]),
+                (PaletteGate::CX, 2, vec![2, 1]),
+            ],
+        );
+    }
+
+    fn load_uniform(&mut self) {
+        let n = self.num_qubits;
+        self.load_preset(n, (0..n).map(|q| (PaletteGate::H, 0, vec![q])).collect());
+    }
+
+    // Lays a circuit out on the grid: each instruction goes one column past
+    // anything already on the wires it spans or the bits it touches
+    fn load_circuit(&mut self, circuit: Circuit) -> Result<(), String> {
+        let n = circuit.num_qubits();
+        if n > MAX_QUBITS {
+            return Err(format!("{} qubits is more than the composer's {}", n, MAX_QUBITS));
+        }
+        let mut next_row = vec![0; n];
+        let mut next_bit = vec![0; circuit.num_clbits()];
+        self.placed.clear();
+        // Blocks without qubits cannot change the state
+        for instruction in circuit.instructions().iter().filter(|i| !i.qubits().is_empty()) {
+            let gate = PlacedGate::new(instruction.clone(), 0);
+            let (lo, hi) = gate.rows();
+            let bits = circuit.clbits(instruction);
+            let column = (lo..=hi)
+                .map(|row| next_row[row])
+                .chain(bits.iter().map(|&b| next_bit[b]))
+                .max()
+                .unwrap_or(0);
+            next_row[lo..=hi].fill(column + 1);
+            for b in bits {
+                next_bit[b] = column + 1;
+            }
+            self.placed.push(PlacedGate { column, ..gate });
+        }
+
+        self.num_qubits = n;
+        self.registers = Some((circuit.qregs().to_vec(), circuit.cregs().to_vec()));
+        self.selected = None;
+        self.status.clear();
+        self.rebuild();
+        Ok(())
+    }
+
+    // Parses `source` with the current input values and lays it out
+    fn load_qasm(&mut self, source: String) -> Result<(), String> {
+        let inputs: HashMap<String, f64> = self.qasm_inputs.iter().cloned().collect();
+        let program = parser::parse(&source, &inputs).map_err(|e| e.to_string())?;
+        self.load_circuit(program.circuit)?;
+        self.qasm_version = program.version;
+        self.qasm_source = Some(source);
+        self.qasm_inputs = program.inputs;
+        Ok(())
+    }
+
+    fn open_qasm(&mut self) {
+        self.qasm_inputs.clear();
+        let result = std::fs::read_to_string(&self.qasm_path)
+            .map_err(|e| e.to_string())
+            .and_then(|source| self.load_qasm(source));
+        self.qasm_message = match result {
+            Ok(()) => format!("Opened {} ({})", self.qasm_path, self.qasm_version.name()),
+            Err(error) => {
+                tracing::error!("Failed to open {}: {}", self.qasm_path, error);
+                format!("⚠ {}: {}", self.qasm_path, error)
+            }
+        };
+    }
+
+    fn save_qasm(&mut self) {
+        let result = writer::write(&self.circuit, self.qasm_version)
+            .and_then(|source| std::fs::write(&self.qasm_path, source).map_err(|e| e.to_string()));
+        self.qasm_message = match result {
+            Ok(()) => format!("Saved {}", self.qasm_path),
+            Err(error) => {
+                tracing::error!("Failed to save {}: {}", self.qasm_path, error);
+                format!("⚠ {}: {}", self.qasm_path, error)
+            }
+        };
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🔌 Quantum Circuits")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Drag gates onto the wires and watch the state update live")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            // Two column layout
+            ui.horizontal(|ui| {
+                // Left panel - Palette and controls
+                ui.vertical(|ui| {
+                    ui.set_width(300.0);
+                    self.render_controls(ui);
+                });
+
+                ui.add_space(16.0);
+
+                // Right panel - Circuit canvas
+                ui.vertical(|ui| {
+                    self.render_canvas(ui);
+                });
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_state(ui);
+        });
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new("⚙️ Circuit")
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+
+        ui.add_space(8.0);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Qubits:");
+                    let mut n = self.num_qubits;
+                    if ui.add(egui::DragValue::new(&mut n).range(1..=MAX_QUBITS)).changed() {
+                        self.set_num_qubits(n);
+                    }
+                });
+
+                ui.add_space(4.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Backend:");
+                    let before = self.backend;
+                    // Noise only exists on the density-matrix backend, so it stays locked while noise is on
+                    ui.add_enabled_ui(!self.noise.enabled, |ui| {
+                        egui::ComboBox::from_id_salt("composer_backend")
+                            .selected_text(self.backend.name())
+                            .show_ui(ui, |ui| {
+                                for kind in BackendKind::ALL {
+                                    ui.selectable_value(&mut self.backend, kind, kind.name());
+                                }
+                            });
+                    })
+                    .response
+                    .on_disabled_hover_text("Noise needs the density-matrix backend; disable it below to switch");
+                    if self.backend != before {
+                        self.rebuild();
+                    }
+                });
+
+                ui.add_space(8.0);
+
+                ui.horizontal_wrapped(|ui| {
+                    if ui.button("Bell").clicked() {
+                        self.load_bell();
+                    }
+                    if ui.button("GHZ").clicked() {
+                        self.load_ghz();
+                    }
+                    if ui.button("Uniform").clicked() {
+                        self.load_uniform();
+                    }
+                    if ui.button("🗑 Clear").clicked() {
+                        self.placed.clear();
+                        self.selected = None;
+                        self.rebuild();
+                    }
+                });
+            });
+
+        ui.add_space(20.0);
+
+        ui.label(egui::RichText::new("🧩 Gate Palette")
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+
+        ui.add_space(8.0);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                for (title, group) in [
+                    ("Single qubit", &PaletteGate::SINGLE[..]),
+                    ("Rotations", &PaletteGate::ROTATIONS[..]),
+                    ("Multi qubit", &PaletteGate::MULTI[..]),
+                    ("Measurement", &PaletteGate::NON_UNITARY[..]),
+                ] {
+                    ui.label(egui::RichText::new(title)
+                        .color(egui::Color32::from_rgb(140, 160, 200))
+                        .size(12.0));
+                    ui.horizontal_wrapped(|ui| {
+                        for &kind in group {
+                            let id = ui.id().with(("palette", kind.label()));
+                            ui.dnd_drag_source(id, DragPayload::Palette(kind), |ui| {
+                                egui::Frame::NONE
+                                    .fill(kind.color())
+                                    .corner_radius(4.0)
+                                    .inner_margin(egui::Margin::symmetric(8, 4))
+                                    .show(ui, |ui| {
+                                        ui.label(egui::RichText::new(kind.label())
+                                            .color(egui::Color32::BLACK)
+                                            .strong());
+                                    });
+                            });
+                        }
+                    });
+                    ui.add_space(6.0);
+                }
+
+                ui.label(egui::RichText::new("Drag a gate onto a wire. Drag a ● handle to rewire, click a gate to edit its angle or condition, right-click to delete.")
+                    .color(egui::Color32::from_rgb(140, 140, 160))
+                    .size(11.0));
+            });
+
+        if !self.status.is_empty() {
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new(&self.status)
+                .color(egui::Color32::from_rgb(255, 120, 120))
+                .size(12.0));
+        }
+
+        ui.add_space(20.0);
+
+        self.render_qasm(ui);
+
+        ui.add_space(20.0);
+
+        if self.noise.render(ui, &self.circuit) {
+            // Mixed states need the density-matrix backend
+            if self.noise.enabled {
+                self.backend = BackendKind::DensityMatrix;
+            }
+            self.rebuild();
+        } else if self.counts_stale && !self.noise.is_dragging() {
+            self.rebuild();
+        }
+    }
+
+    fn render_qasm(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new("📄 OpenQASM")
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+
+        ui.add_space(8.0);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.add(egui::TextEdit::singleline(&mut self.qasm_path).desired_width(200.0));
+                });
+                ui.horizontal(|ui| {
+                    if ui.button("📂 Open").clicked() {
+                        self.open_qasm();
+                    }
+                    if ui.button("💾 Save").clicked() {
+                        self.save_qasm();
+                    }
+                    egui::ComboBox::from_id_salt("composer_qasm_version")
+                        .selected_text(self.qasm_version.name())
+                        .show_ui(ui, |ui| {
+                            for version in Version::ALL {
+                                ui.selectable_value(&mut self.qasm_version, version, version.name());
+                            }
+                        });
+                });
+
+                // `input float` parameters of the opened file
+                let mut changed = false;
+                for (name, value) in &mut self.qasm_inputs {
+                    changed |= ui.add(egui::Slider::new(value, -TAU..=TAU).text(name.as_str())).changed();
+                }
+                if changed
+                    && let Some(source) = self.qasm_source.clone()
+                    && let Err(error) = self.load_qasm(source) {
+                    self.qasm_message = format!("⚠ {}", error);
+                }
+
+                if !self.qasm_message.is_empty() {
+                    let color = if self.qasm_message.starts_with('⚠') {
+                        egui::Color32::from_rgb(255, 120, 120)
+                    } else {
+                        egui::Color32::from_rgb(100, 255, 150)
+                    };
+                    ui.label(egui::RichText::new(&self.qasm_message).color(color).size(12.0));
+                }
+
+                ui.collapsing("Source", |ui| match writer::write(&self.circuit, self.qasm_version) {
+                    Ok(source) => {
+                        ui.add(egui::TextEdit::multiline(&mut source.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY));
+                    }
+                    Err(error) => {
+                        ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(255, 120, 120)));
+                    }
+                });
+            });
+    }
+
+    fn render_canvas(&mut self, ui: &mut egui::Ui) {
+        let columns = (self.placed.iter().map(|g| g.column + 2).max().unwrap_or(0)).max(MIN_COLUMNS);
+        let size = egui::vec2(LABEL_WIDTH + CELL * columns as f32, CELL * self.num_qubits as f32);
+
+        let mut actions = Vec::new();
+
+        egui::ScrollArea::horizontal().id_salt("circuit_canvas_scroll").show(ui, |ui| {
+            let (canvas, painter) = ui.allocate_painter(size, egui::Sense::click());
+            let rect = canvas.rect;
+            painter.rect_filled(rect, 6.0, egui::Color32::from_rgb(22, 22, 30));
+
+            let wire_y = |row: usize| rect.top() + CELL * (row as f32 + 0.5);
+            let column_x = |column: usize| rect.left() + LABEL_WIDTH + CELL * (column as f32 + 0.5);
+            let cell_at = |pos: egui::Pos2| -> Option<(usize, usize)> {
+                if !rect.contains(pos) || pos.x < rect.left() + LABEL_WIDTH {
+                    return None;
+                }
+                let column = ((pos.x - rect.left() - LABEL_WIDTH) / CELL) as usize;
+                let row = ((pos.y - rect.top()) / CELL) as usize;
+                (row < self.num_qubits).then_some((column, row))
+            };
+
+            let wire_color = egui::Color32::from_rgb(90, 90, 120);
+            for row in 0..self.num_qubits {
+                let y = wire_y(row);
+                painter.text(
+                    egui::pos2(rect.left() + 12.0, y),
+                    egui::Align2::LEFT_CENTER,
+                    format!("q{}", row),
+                    egui::FontId::monospace(14.0),
+                    egui::Color32::from_rgb(160, 170, 210),
+                );
+                painter.line_segment(
+                    [egui::pos2(rect.left() + LABEL_WIDTH - 8.0, y), egui::pos2(rect.right() - 8.0, y)],
+                    egui::Stroke::new(1.5, wire_color),
+                );
+            }
+
+            // Drop preview
+            if let (Some(_), Some(pos)) = (canvas.dnd_hover_payload::<DragPayload>(), ui.ctx().pointer_interact_pos())
+                && let Some((column, row)) = cell_at(pos) {
+                let center = egui::pos2(column_x(column), wire_y(row));
+                painter.rect_stroke(
+                    egui::Rect::from_center_size(center, egui::vec2(CELL - 6.0, CELL - 6.0)),
+                    4.0,
+                    egui::Stroke::new(2.0, egui::Color32::from_rgb(120, 140, 255)),
+                    egui::StrokeKind::Inside,
+                );
+            }
+
+            if let (Some(payload), Some(pos)) = (canvas.dnd_release_payload::<DragPayload>(), ui.ctx().pointer_interact_pos())
+                && let Some((column, row)) = cell_at(pos) {
+                actions.push(CanvasAction::Drop(*payload, column, row));
+            }
+
+            if canvas.clicked() {
+                actions.push(CanvasAction::Deselect);
+            }
+
+            for (index, gate) in self.placed.iter().enumerate() {
+                let x = column_x(gate.column);
+                let (lo, hi) = gate.rows();
+                let wires = gate.wires();
+                let link_color = instruction_color(&gate.instruction);
+                let is_swap = matches!(&gate.instruction, Instruction::Gate(op) if op.gate == Gate::Swap);
+                let is_barrier = matches!(gate.instruction, Instruction::Barrier(_));
+                let is_block = gate.is_block();
+
+                if is_barrier {
+                    paint_dashed(&painter, x, wire_y(lo) - CELL / 2.0 + 4.0, wire_y(hi) + CELL / 2.0 - 4.0, link_color);
+                } else if lo != hi && !is_block {
+                    painter.line_segment(
+                        [egui::pos2(x, wire_y(lo)), egui::pos2(x, wire_y(hi))],
+                        egui::Stroke::new(2.0, link_color),
+                    );
+                }
+
+                // Link handles: control dots, the second × of a SWAP, or barrier ends
+                // If/while blocks span their wires and have no handles
+                let links = if is_block { &[][..] } else { &wires[1..] };
+                for (link, &row) in links.iter().enumerate() {
+                    let center = egui::pos2(x, wire_y(row));
+                    if is_swap {
+                        paint_swap_cross(&painter, center, link_color);
+                    } else if is_barrier {
+                        painter.rect_filled(egui::Rect::from_center_size(center, egui::vec2(8.0, 8.0)), 1.0, link_color);
+                    } else {
+                        painter.circle_filled(center, 6.0, link_color);
+                    }
+                    let handle = ui.interact(
+                        egui::Rect::from_center_size(center, egui::vec2(20.0, 20.0)),
+                        canvas.id.with(("link", index, link)),
+                        egui::Sense::drag(),
+                    );
+                    if handle.hovered() {
+                        ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+                    }
+                    if handle.dragged()
+                        && let Some(pos) = ui.ctx().pointer_interact_pos() {
+                        painter.line_segment([center, pos], egui::Stroke::new(2.0, egui::Color32::from_rgb(120, 140, 255)));
+                        painter.circle_filled(pos, 6.0, link_color);
+                    }
+                    if handle.drag_stopped()
+                        && let Some(pos) = ui.ctx().pointer_interact_pos()
+                        && let Some((_, row)) = cell_at(pos) {
+                        actions.push(CanvasAction::Relink(index, link, row));
+                    }
+                }
+
+                // Gate body on the target wire
+                let half = (CELL - 12.0) / 2.0;
+                let body = if is_block {
+                    egui::Rect::from_min_max(egui::pos2(x - half, wire_y(lo) - half), egui::pos2(x + half, wire_y(hi) + half))
+                } else {
+                    egui::Rect::from_center_size(egui::pos2(x, wire_y(wires[0])), egui::vec2(CELL - 12.0, CELL - 12.0))
+                };
+                let center = body.center();
+                let selected = self.selected == Some(index);
+                match &gate.instruction {
+                    Instruction::Gate(op) if op.gate == Gate::X && !op.controls.is_empty() => {
+                        painter.circle_stroke(center, 12.0, egui::Stroke::new(2.0, link_color));
+                        painter.line_segment([center - egui::vec2(12.0, 0.0), center + egui::vec2(12.0, 0.0)], egui::Stroke::new(2.0, link_color));
+                        painter.line_segment([center - egui::vec2(0.0, 12.0), center + egui::vec2(0.0, 12.0)], egui::Stroke::new(2.0, link_color));
+                    }
+                    _ if is_swap => paint_swap_cross(&painter, center, link_color),
+                    _ if is_barrier => {
+                        painter.rect_filled(egui::Rect::from_center_size(center, egui::vec2(8.0, 8.0)), 1.0, link_color);
+                    }
+                    Instruction::If { condition, .. } | Instruction::While { condition, .. } => {
+                        let keyword = if matches!(gate.instruction, Instruction::If { .. }) { "if" } else { "while" };
+                        painter.rect_stroke(body, 4.0, egui::Stroke::new(2.0, link_color), egui::StrokeKind::Inside);
+                        painter.text(
+                            center - egui::vec2(0.0, 7.0),
+                            egui::Align2::CENTER_CENTER,
+                            keyword,
+                            egui::FontId::proportional(13.0),
+                            link_color,
+                        );
+                        painter.text(
+                            center + egui::vec2(0.0, 7.0),
+                            egui::Align2::CENTER_CENTER,
+                            format!("{}{}", condition.comparison.symbol(), condition.value),
+                            egui::FontId::monospace(10.0),
+                            egui::Color32::from_rgb(255, 200, 100),
+                        );
+                    }
+                    instruction => {
+                        let text = match instruction {
+                            Instruction::Gate(op) => op.gate.name().to_string(),
+                            Instruction::Measure { clbit, .. } => format!("M{}", clbit),
+                            other => other.label(),
+                        };
+                        painter.rect_filled(body, 4.0, link_color);
+                        painter.text(
+                            center,
+                            egui::Align2::CENTER_CENTER,
+                            text,
+                            egui::FontId::proportional(14.0),
+                            egui::Color32::BLACK,
+                        );
+                    }
+                }
+                let mut hover = gate.instruction.label();
+                if let Instruction::If { condition, .. } | Instruction::While { condition, .. } = &gate.instruction {
+                    hover = format!(
+                        "{}: {} {} {}",
+                        hover,
+                        self.circuit.condition_label(condition),
+                        condition.comparison.symbol(),
+                        condition.value
+                    );
+                }
+                if let Some((register, value)) = gate.condition {
+                    let name = self.circuit.cregs().get(register).map_or("c", |r| r.name.as_str());
+                    hover = format!("if {}=={}: {}", name, value, hover);
+                    painter.text(
+                        egui::pos2(x, body.top() - 1.0),
+                        egui::Align2::CENTER_BOTTOM,
+                        format!("{}=={}", name, value),
+                        egui::FontId::monospace(10.0),
+                        egui::Color32::from_rgb(255, 200, 100),
+                    );
+                }
+                if selected {
+                    painter.rect_stroke(body.expand(3.0), 5.0, egui::Stroke::new(2.0, egui::Color32::WHITE), egui::StrokeKind::Outside);
+                }
+
+                let response = ui.interact(body, canvas.id.with(("gate", index)), egui::Sense::click_and_drag());
+                response.dnd_set_drag_payload(DragPayload::Move(index));
+                if response.clicked() {
+                    actions.push(CanvasAction::Select(index));
+                }
+                if response.secondary_clicked() {
+                    actions.push(CanvasAction::Remove(index));
+                }
+                if response.dragged()
+                    && let Some(pos) = ui.ctx().pointer_interact_pos() {
+                    painter.rect_stroke(
+                        egui::Rect::from_center_size(pos, egui::vec2(CELL - 12.0, CELL - 12.0)),
+                        4.0,
+                        egui::Stroke::new(2.0, link_color),
+                        egui::StrokeKind::Inside,
+                    );
+                }
+                response.on_hover_text(hover);
+            }
+
+            if let Some(index) = self.selected
+                && index < self.placed.len() {
+                let gate = &self.placed[index];
+                let anchor = egui::pos2(column_x(gate.column) - CELL / 2.0, wire_y(gate.target()) + CELL / 2.0);
+                if !matches!(gate.instruction, Instruction::Barrier(_)) && !gate.is_block() {
+                    self.render_gate_editor(ui, index, anchor);
+                }
+            }
+        });
+
+        for action in actions {
+            match action {
+                CanvasAction::Drop(payload, column, row) => self.drop_gate(payload, column, row),
+                CanvasAction::Relink(index, link, row) => self.relink(index, link, row),
+                CanvasAction::Select(index) => self.selected = Some(index),
+                CanvasAction::Remove(index) => self.remove_gate(index),
+                CanvasAction::Deselect => self.selected = None,
+            }
+        }
+
+        ui.add_space(8.0);
+        ui.label(egui::RichText::new(format!(
+            "Instructions: {}    Depth: {}",
+            self.circuit.instructions().len(),
+            self.circuit.moments().len()
+        ))
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(12.0));
+    }
+
+    // Popup under the selected gate: angles, measured bit and classical condition
+    fn render_gate_editor(&mut self, ui: &mut egui::Ui, index: usize, anchor: egui::Pos2) {
+        let mut changed = false;
+        let mut close = false;
+        let (_, mut cregs) = self.registers();
+        if cregs.is_empty() {
+            cregs.push(Register::new("c", self.num_qubits));
+        }
+        let num_clbits: usize = cregs.iter().map(|r| r.size).sum();
+
+        egui::Area::new(ui.id().with("gate_editor"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(anchor)
+            .show(ui.ctx(), |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    let placed = &mut self.placed[index];
+                    ui.label(egui::RichText::new(placed.instruction.label())
+                        .color(egui::Color32::from_rgb(140, 160, 200))
+                        .strong());
+
+                    match &mut placed.instruction {
+                        Instruction::Gate(op) if !op.gate.params().is_empty() => {
+                            let gate = &mut op.gate;
+                            let names = ["θ", "φ", "λ"];
+                            for (name, value) in names.iter().zip(gate.params_mut()) {
+                                changed |= ui.add(egui::Slider::new(value, -TAU..=TAU).text(*name)).changed();
+                            }
+                            ui.horizontal(|ui| {
+                                for (label, angle) in [("π/4", FRAC_PI_4), ("π/2", FRAC_PI_2), ("π", PI), ("0", 0.0)] {
+                                    if ui.small_button(label).clicked()
+                                        && let Some(first) = gate.params_mut().into_iter().next() {
+                                        *first = angle;
+                                        changed = true;
+                                    }
+                                }
+                            });
+                        }
+                        Instruction::Measure { clbit, .. } => {
+                            ui.horizontal(|ui| {
+                                ui.label("Into bit:");
+                                changed |= ui.add(egui::DragValue::new(clbit).range(0..=num_clbits - 1)).changed();
+                            });
+                        }
+                        _ => {}
+                    }
+
+                    ui.add_space(4.0);
+                    let mut conditioned = placed.condition.is_some();
+                    if ui.checkbox(&mut conditioned, "Only if a classical register equals").changed() {
+                        placed.condition = conditioned.then_some((0, 1));
+                        changed = true;
+                    }
+                    if let Some((register, value)) = &mut placed.condition {
+                        ui.horizontal(|ui| {
+                            egui::ComboBox::from_id_salt("gate_condition_register")
+                                .selected_text(cregs[(*register).min(cregs.len() - 1)].name.clone())
+                                .show_ui(ui, |ui| {
+                                    for (i, creg) in cregs.iter().enumerate() {
+                                        changed |= ui.selectable_value(register, i, &creg.name).changed();
+                                    }
+                                });
+                            let size = cregs[(*register).min(cregs.len() - 1)].size.min(63);
+                            ui.label("==");
+                            changed |= ui.add(egui::DragValue::new(value).range(0..=(1u64 << size) - 1)).changed();
+                        });
+                    }
+
+                    if ui.small_button("✔").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if changed {
+            self.rebuild();
+        }
+        if close {
+            self.selected = None;
+        }
+    }
+
+    fn render_state(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new("📈 Output State")
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(16.0)
+            .strong());
+
+        ui.add_space(12.0);
+
+        let probabilities = match &self.density {
+            Some(density) => self.noise.model().apply_readout(&density.probabilities(), self.num_qubits),
+            None => self.state.probabilities(),
+        };
+
+        ui.horizontal(|ui| {
+            ui.vertical(|ui| {
+                ui.set_width(520.0);
+                ui.label(egui::RichText::new("Measurement probabilities")
+                    .color(egui::Color32::from_rgb(140, 160, 200))
+                    .size(13.0));
+                ProbabilityBars::new("composer_probabilities", &probabilities, self.num_qubits).show(ui);
+            });
+
+            ui.add_space(16.0);
+
+            ui.vertical(|ui| match &self.density {
+                Some(density) => self.render_density_details(ui, density),
+                None => self.render_amplitude_table(ui),
+            });
+        });
+
+        ui.add_space(16.0);
+        ui.label(egui::RichText::new("🎨 State Visualization")
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+        match &self.density {
+            Some(density) => show_city(ui, density, 320.0),
+            None => {
+                self.visual.picker(ui);
+                ui.add_space(8.0);
+                self.visual.show(ui, "composer_state_visual", &self.state, 260.0);
+            }
+        }
+
+        ui.add_space(16.0);
+        self.observable.render(ui, &self.state, self.density.as_ref());
+
+        if !self.counts.is_empty() {
+            ui.add_space(16.0);
+            if self.render_counts(ui) {
+                self.seed = self.seed.wrapping_add(1);
+                self.rebuild();
+            }
+        }
+
+        let mut sweep_requested = false;
+        if let Some(density) = &self.density
+            && !self.noise.model().is_noiseless() {
+            ui.add_space(16.0);
+            sweep_requested = self.render_noise_sweep(ui, density);
+        }
+        if sweep_requested {
+            let ideal = DensityMatrix::from_state_vector(&self.state);
+            self.noise.compute_sweep(&self.circuit, &ideal, self.seed);
+        }
+    }
+
+    // Returns true when a new shot was requested
+    fn render_counts(&self, ui: &mut egui::Ui) -> bool {
+        let mut resample = false;
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(format!("🎲 Measurement counts ({} shots)", self.counts.values().sum::<usize>()))
+                .color(egui::Color32::from_rgb(140, 160, 200))
+                .size(13.0));
+            ui.add_space(12.0);
+            ui.label(egui::RichText::new(format!("Shown shot read {}", clbit_string(&self.clbits)))
+                .color(egui::Color32::from_rgb(255, 200, 100))
+                .size(13.0)
+                .code());
+            if ui.button("New shot").on_hover_text("The state above is the one left by this shot").clicked() {
+                resample = true;
+            }
+        });
+
+        let keys: Vec<String> = self.counts.keys().cloned().collect();
+        let bars: Vec<Bar> = self.counts
+            .iter()
+            .enumerate()
+            .map(|(i, (key, &count))| Bar::new(i as f64, count as f64)
+                .name(key)
+                .width(0.7)
+                .fill(egui::Color32::from_rgb(180, 140, 255)))
+            .collect();
+
+        Plot::new("composer_counts")
+            .height(200.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .x_axis_formatter(move |mark, _| {
+                let i = mark.value.round();
+                if (mark.value - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < keys.len() {
+                    keys[i as usize].clone()
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("Counts", bars));
+            });
+        resample
+    }
+
+    // Returns true when the sweep should be (re)computed
+    fn render_noise_sweep(&self, ui: &mut egui::Ui, density: &DensityMatrix) -> bool {
+        let current = density.fidelity(&DensityMatrix::from_state_vector(&self.state));
+
+        ui.label(egui::RichText::new("📉 Fidelity vs Noise Strength")
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+        ui.label(egui::RichText::new("Each curve applies a single channel to the selected gates; the dashed line is the current model.")
+            .color(egui::Color32::from_rgb(140, 140, 160))
+            .size(11.0));
+
+        let Some(curves) = self.noise.sweep(&self.circuit, self.seed) else {
+            return ui.button("Compute sweep")
+                .on_hover_text("One density-matrix run per point; kept until the circuit, target or shot changes")
+                .clicked();
+        };
+
+        let colors = [
+            egui::Color32::from_rgb(100, 200, 255),
+            egui::Color32::from_rgb(255, 100, 150),
+            egui::Color32::from_rgb(255, 200, 100),
+            egui::Color32::from_rgb(100, 255, 150),
+            egui::Color32::from_rgb(180, 140, 255),
+        ];
+
+        Plot::new("composer_noise_sweep")
+            .height(260.0)
+            .include_y(0.0)
+            .include_y(1.0)
+            .x_axis_label("channel strength p")
+            .y_axis_label("fidelity")
+            .legend(Legend::default())
+            .show(ui, |plot_ui| {
+                for ((name, points), color) in curves.iter().zip(colors) {
+                    plot_ui.line(Line::new(*name, PlotPoints::from(points.clone()))
+                        .color(color)
+                        .width(2.0));
+                }
+                plot_ui.hline(HLine::new("Current model", current)
+                    .color(egui::Color32::WHITE)
+                    .style(egui_plot::LineStyle::dashed_loose()));
+            });
+        false
+    }
+
+    fn render_amplitude_table(&self, ui: &mut egui::Ui) {
+        let n = self.num_qubits;
+        ui.label(egui::RichText::new("Amplitudes")
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical()
+                    .id_salt("composer_amplitudes")
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("composer_amplitude_grid")
+                            .striped(true)
+                            .spacing([16.0, 4.0])
+                            .show(ui, |ui| {
+                                for header in ["State", "Amplitude", "|a|", "Phase", "P"] {
+                                    ui.label(egui::RichText::new(header).strong());
+                                }
+                                ui.end_row();
+
+                                for (i, a) in self.state.amplitudes().iter().enumerate() {
+                                    ui.label(egui::RichText::new(basis_label(i, n)).code());
+                                    ui.label(format!("{:+.4} {:+.4}i", a.re, a.im));
+                                    ui.label(format!("{:.4}", a.norm()));
+                                    let phase = if a.norm() > 1e-10 { a.arg() } else { 0.0 };
+                                    ui.label(format!("{:+.3}π", phase / PI));
+                                    ui.label(egui::RichText::new(format!("{:.4}", a.norm_sqr()))
+                                        .color(egui::Color32::from_rgb(100, 255, 150)));
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+    }
+
+    fn render_density_details(&self, ui: &mut egui::Ui, density: &DensityMatrix) {
+        let ideal = DensityMatrix::from_state_vector(&self.state);
+
+        ui.label(egui::RichText::new("Density matrix")
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                egui::Grid::new("composer_density_metrics")
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        for (name, value, tooltip) in [
+                            ("Purity Tr(ρ²)", density.purity(), "1 for a pure state, 1/2ⁿ when maximally mixed"),
+                            ("Entropy S(ρ)", density.von_neumann_entropy(), "Von Neumann entropy in bits"),
+                            ("Fidelity vs ideal", density.fidelity(&ideal), "Overlap with the noiseless state-vector result"),
+                            ("Trace distance vs ideal", density.trace_distance(&ideal), "How distinguishable the state is from the ideal one"),
+                        ] {
+                            ui.label(name).on_hover_text(tooltip);
+                            ui.label(egui::RichText::new(format!("{:.4}", value))
+                                .color(egui::Color32::from_rgb(100, 200, 255))
+                                .strong());
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new("Per-qubit reduced states")
+                    .color(egui::Color32::from_rgb(140, 160, 200))
+                    .size(12.0));
+                egui::Grid::new("composer_reduced_states")
+                    .striped(true)
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        for header in ["Qubit", "Purity", "Entropy"] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.end_row();
+                        for qubit in 0..self.num_qubits {
+                            let reduced = density.partial_trace(&[qubit]);
+                            ui.label(format!("q{}", qubit));
+                            ui.label(format!("{:.4}", reduced.purity()));
+                            ui.label(format!("{:.4}", reduced.von_neumann_entropy()));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new("|ρᵢⱼ| (colour = phase)")
+                    .color(egui::Color32::from_rgb(140, 160, 200))
+                    .size(12.0));
+                let rho = density.matrix();
+                let dim = rho.nrows();
+                let cell = (192.0 / dim as f32).max(3.0);
+                let largest = rho.iter().map(|z| z.norm()).fold(1e-12, f64::max);
+                let (rect, response) = ui.allocate_exact_size(egui::vec2(cell * dim as f32, cell * dim as f32), egui::Sense::hover());
+                let painter = ui.painter();
+                for ((i, j), z) in rho.indexed_iter() {
+                    let min = rect.min + egui::vec2(cell * j as f32, cell * i as f32);
+                    let color = phase_color(z.arg()).gamma_multiply((z.norm() / largest) as f32);
+                    painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(cell, cell)), 0.0, color);
+                }
+                if let Some(pos) = response.hover_pos() {
+                    let j = (((pos.x - rect.left()) / cell) as usize).min(dim - 1);
+                    let i = (((pos.y - rect.top()) / cell) as usize).min(dim - 1);
+                    let z = rho[[i, j]];
+                    let bits = |index: usize| basis_label(index, self.num_qubits).trim_matches(['|', '⟩']).to_string();
+                    response.on_hover_text(format!("⟨{}|ρ|{}⟩ = {:+.4} {:+.4}i", bits(i), bits(j), z.re, z.im));
+                }
+            });
+    }
+}
+
+fn paint_dashed(painter: &egui::Painter, x: f32, top: f32, bottom: f32, color: egui::Color32) {
+    let stroke = egui::Stroke::new(2.0, color);
+    let mut y = top;
+    while y < bottom {
+        painter.line_segment([egui::pos2(x, y), egui::pos2(x, (y + 6.0).min(bottom))], stroke);
+        y += 10.0;
+    }
+}
+
+fn paint_swap_cross(painter: &egui::Painter, center: egui::Pos2, color: egui::Color32) {
+    let d = 7.0;
+    let stroke = egui::Stroke::new(2.5, color);
+    painter.line_segment([center + egui::vec2(-d, -d), center + egui::vec2(d, d)], stroke);
+    painter.line_segment([center + egui::vec2(-d, d), center + egui::vec2(d, -d)], stroke);
+}
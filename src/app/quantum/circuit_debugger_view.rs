@@ -0,0 +1,343 @@
+use crate::app::widgets::bloch_sphere::BlochSphere;
+use crate::app::widgets::circuit_diagram::{CELL, LABEL_WIDTH, paint_instruction, paint_wires};
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::app::widgets::{StateVisual, show_city};
+use crate::core::quantum::backend::{Backend, BackendKind};
+use crate::core::quantum::circuit::{Circuit, Instruction};
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::executor::{clbit_string, execute_with_snapshots};
+use crate::core::quantum::state_vector::StateVector;
+use eframe::egui;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// Snapshots recorded with whichever backend is selected
+enum Recording {
+    Pure(Vec<StateVector>),
+    Mixed(Vec<DensityMatrix>),
+}
+
+pub struct CircuitDebuggerView {
+    backend: BackendKind,
+    // Copy of the circuit the snapshots were recorded for
+    circuit: Circuit,
+    moments: Vec<Vec<usize>>,
+    recording: Recording,
+    // Classical bits after each moment
+    clbits: Vec<Vec<bool>>,
+    // Seed of the measurement outcomes, so a trajectory stays put while stepping
+    seed: u64,
+    // Number of moments applied so far (0 = initial state)
+    cursor: usize,
+    visual: StateVisual,
+}
+
+impl Default for CircuitDebuggerView {
+    fn default() -> Self {
+        let mut view = Self {
+            backend: BackendKind::StateVector,
+            circuit: Circuit::new(1),
+            moments: Vec::new(),
+            recording: Recording::Pure(Vec::new()),
+            clbits: Vec::new(),
+            seed: 0,
+            cursor: 0,
+            visual: StateVisual::Amplitudes,
+        };
+        view.record();
+        view
+    }
+}
+
+impl CircuitDebuggerView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&mut self) {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        match self.backend {
+            BackendKind::StateVector => {
+                let execution = execute_with_snapshots::<StateVector, _>(&self.circuit, &mut rng);
+                self.moments = execution.moments;
+                self.clbits = execution.clbits;
+                self.recording = Recording::Pure(execution.snapshots);
+            }
+            BackendKind::DensityMatrix => {
+                let execution = execute_with_snapshots::<DensityMatrix, _>(&self.circuit, &mut rng);
+                self.moments = execution.moments;
+                self.clbits = execution.clbits;
+                self.recording = Recording::Mixed(execution.snapshots);
+            }
+        }
+        self.cursor = self.cursor.min(self.moments.len());
+    }
+
+    // Re-records the snapshots whenever the composer circuit changes
+    fn sync(&mut self, circuit: &Circuit) {
+        if &self.circuit != circuit {
+            self.circuit = circuit.clone();
+            self.record();
+        }
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui, circuit: &Circuit) {
+        self.sync(circuit);
+
+        let depth = self.moments.len();
+        ui.input(|i| {
+            if i.key_pressed(egui::Key::ArrowRight) {
+                self.cursor = (self.cursor + 1).min(depth);
+            }
+            if i.key_pressed(egui::Key::ArrowLeft) {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+        });
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🐞 Circuit Debugger")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Step through the Quantum Circuits composer one moment at a time")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_stepper(ui);
+
+            ui.add_space(12.0);
+
+            egui::ScrollArea::horizontal().id_salt("debugger_circuit_scroll").show(ui, |ui| {
+                self.render_circuit(ui);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_state(ui);
+        });
+    }
+
+    fn render_stepper(&mut self, ui: &mut egui::Ui) {
+        let depth = self.moments.len();
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("⏮").on_hover_text("Initial state").clicked() {
+                        self.cursor = 0;
+                    }
+                    if ui.button("◀").on_hover_text("Step back (←)").clicked() {
+                        self.cursor = self.cursor.saturating_sub(1);
+                    }
+                    if ui.button("▶").on_hover_text("Step forward (→)").clicked() {
+                        self.cursor = (self.cursor + 1).min(depth);
+                    }
+                    if ui.button("⏭").on_hover_text("Final state").clicked() {
+                        self.cursor = depth;
+                    }
+
+                    ui.add_space(12.0);
+                    ui.add(egui::Slider::new(&mut self.cursor, 0..=depth).text("moment"));
+
+                    ui.add_space(12.0);
+                    let before = self.backend;
+                    egui::ComboBox::from_id_salt("debugger_backend")
+                        .selected_text(self.backend.name())
+                        .show_ui(ui, |ui| {
+                            for kind in BackendKind::ALL {
+                                ui.selectable_value(&mut self.backend, kind, kind.name());
+                            }
+                        });
+                    if self.backend != before {
+                        self.record();
+                    }
+
+                    if !self.circuit.is_unitary() {
+                        ui.add_space(12.0);
+                        if ui.button("🎲 New outcomes").on_hover_text("Re-run with different measurement results").clicked() {
+                            self.seed = self.seed.wrapping_add(1);
+                            self.record();
+                        }
+                    }
+
+                    ui.add_space(12.0);
+                    let applied = if self.cursor == 0 {
+                        "initial state |0…0⟩".to_string()
+                    } else {
+                        let labels: Vec<String> = self.moments[self.cursor - 1]
+                            .iter()
+                            .map(|&i| describe(&self.circuit, &self.circuit.instructions()[i]))
+                            .collect();
+                        format!("after {}", labels.join(", "))
+                    };
+                    let applied = if self.circuit.num_clbits() > 0 {
+                        format!("{} · bits {}", applied, clbit_string(&self.clbits[self.cursor]))
+                    } else {
+                        applied
+                    };
+                    ui.label(egui::RichText::new(format!("Moment {}/{} — {}", self.cursor, depth, applied))
+                        .color(egui::Color32::from_rgb(255, 200, 100))
+                        .size(13.0));
+                });
+            });
+    }
+
+    fn render_circuit(&mut self, ui: &mut egui::Ui) {
+        let n = self.circuit.num_qubits();
+        let depth = self.moments.len();
+        let size = egui::vec2(LABEL_WIDTH + CELL * (depth as f32 + 1.0), CELL * n as f32);
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::click());
+        let rect = response.rect;
+
+        let wire_y = |row: usize| rect.top() + CELL * (row as f32 + 0.5);
+        let column_x = |column: usize| rect.left() + LABEL_WIDTH + CELL * (column as f32 + 0.5);
+
+        paint_wires(&painter, rect, n, self.cursor);
+
+        for (column, moment) in self.moments.iter().enumerate() {
+            let x = column_x(column);
+            let active = column + 1 == self.cursor;
+            let color = if active {
+                egui::Color32::from_rgb(255, 200, 100)
+            } else {
+                egui::Color32::from_rgb(100, 160, 240)
+            };
+            for &index in moment {
+                paint_instruction(&painter, &self.circuit.instructions()[index], x, &wire_y, color);
+            }
+        }
+
+        // Cursor line sits between the last applied moment and the next one
+        let cursor_x = rect.left() + LABEL_WIDTH + CELL * self.cursor as f32;
+        painter.line_segment(
+            [egui::pos2(cursor_x, rect.top()), egui::pos2(cursor_x, rect.bottom())],
+            egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 100, 150)),
+        );
+
+        if response.clicked()
+            && let Some(pos) = response.interact_pointer_pos() {
+            let column = ((pos.x - rect.left() - LABEL_WIDTH) / CELL).floor();
+            self.cursor = if column < 0.0 { 0 } else { (column as usize + 1).min(depth) };
+        }
+        response.on_hover_text("Click a moment to jump to the state after it");
+    }
+
+    fn render_state(&mut self, ui: &mut egui::Ui) {
+        match &self.recording {
+            Recording::Pure(snapshots) => {
+                let state = &snapshots[self.cursor];
+                ui.label(egui::RichText::new("📈 State")
+                    .color(egui::Color32::from_rgb(120, 140, 180))
+                    .size(16.0)
+                    .strong());
+
+                ui.add_space(8.0);
+                self.visual.picker(ui);
+                ui.add_space(8.0);
+                self.visual.show(ui, "debugger_amplitudes", state, 240.0);
+                ui.add_space(16.0);
+                render_bloch_spheres(ui, state);
+            }
+            Recording::Mixed(snapshots) => {
+                let rho = &snapshots[self.cursor];
+                ui.label(egui::RichText::new("📈 Probabilities")
+                    .color(egui::Color32::from_rgb(120, 140, 180))
+                    .size(16.0)
+                    .strong());
+
+                ui.add_space(8.0);
+                ProbabilityBars::new("debugger_probabilities", &rho.probabilities(), rho.num_qubits())
+                    .height(240.0)
+                    .show(ui);
+
+                ui.add_space(8.0);
+                let previous = &snapshots[self.cursor.saturating_sub(1)];
+                let last = &snapshots[snapshots.len() - 1];
+                ui.horizontal(|ui| {
+                    for (name, value) in [
+                        ("Purity", rho.purity()),
+                        ("Entropy (bits)", rho.von_neumann_entropy()),
+                        ("Trace distance to previous moment", rho.trace_distance(previous)),
+                        ("Fidelity with final state", rho.fidelity(last)),
+                    ] {
+                        egui::Frame::NONE
+                            .fill(egui::Color32::from_rgb(30, 30, 42))
+                            .corner_radius(6.0)
+                            .inner_margin(12.0)
+                            .show(ui, |ui| {
+                                ui.label(egui::RichText::new(name)
+                                    .color(egui::Color32::from_rgb(140, 160, 200))
+                                    .size(12.0));
+                                ui.label(egui::RichText::new(format!("{:.4}", value))
+                                    .color(egui::Color32::from_rgb(100, 200, 255))
+                                    .size(18.0)
+                                    .strong());
+                            });
+                        ui.add_space(8.0);
+                    }
+                });
+                ui.add_space(16.0);
+                ui.label(egui::RichText::new("🏙 Density Matrix")
+                    .color(egui::Color32::from_rgb(120, 140, 180))
+                    .size(16.0)
+                    .strong());
+                ui.add_space(8.0);
+                show_city(ui, rho, 300.0);
+
+                ui.add_space(16.0);
+                render_bloch_spheres(ui, rho);
+            }
+        }
+    }
+}
+
+fn render_bloch_spheres<B: Backend>(ui: &mut egui::Ui, state: &B) {
+    ui.label(egui::RichText::new("🌐 Bloch Spheres")
+        .color(egui::Color32::from_rgb(120, 140, 180))
+        .size(16.0)
+        .strong());
+
+    ui.add_space(8.0);
+    ui.horizontal_wrapped(|ui| {
+        for qubit in 0..state.num_qubits() {
+            BlochSphere::new(state.bloch_vector(qubit))
+                .label(format!("q{}", qubit))
+                .size(170.0)
+                .show(ui);
+        }
+    });
+    ui.label(egui::RichText::new("A vector shorter than the radius means the qubit is mixed or entangled with the others.")
+        .color(egui::Color32::from_rgb(140, 140, 160))
+        .size(11.0));
+}
+
+fn describe(circuit: &Circuit, instruction: &Instruction) -> String {
+    let qubits: Vec<String> = instruction.qubits().iter().map(|q| format!("q{}", q)).collect();
+    let label = match instruction {
+        Instruction::Conditional { register, value, instruction } => {
+            format!("if {}=={} {}", circuit.cregs()[*register].name, value, instruction.label())
+        }
+        Instruction::If { condition, .. } | Instruction::While { condition, .. } => format!(
+            "{} on {} {} {}",
+            instruction.label(),
+            circuit.condition_label(condition),
+            condition.comparison.symbol(),
+            condition.value
+        ),
+        _ => instruction.label(),
+    };
+    format!("{} {}", label, qubits.join(","))
+}
@@ -0,0 +1,418 @@
+use crate::app::widgets::StateVisual;
+use crate::app::widgets::probability_bars::ProbabilityBars;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::entanglement::{
+    BELL_NAMES, Basis, ChshAngles, bell_state, chsh_value, concurrence, correlation, entanglement_entropy,
+    ghz_state, joint_probabilities, negativity, sample_chsh, schmidt_decomposition, w_state, werner,
+};
+use crate::core::quantum::state_vector::{StateVector, basis_label};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::f64::consts::{PI, SQRT_2};
+
+const CHSH_ROUNDS: usize = 1000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Preset {
+    Bell(usize),
+    Ghz,
+    W,
+}
+
+impl Preset {
+    const ALL: [Preset; 6] = [Preset::Bell(0), Preset::Bell(1), Preset::Bell(2), Preset::Bell(3), Preset::Ghz, Preset::W];
+
+    fn name(self) -> &'static str {
+        match self {
+            Preset::Bell(i) => BELL_NAMES[i],
+            Preset::Ghz => "GHZ",
+            Preset::W => "W",
+        }
+    }
+}
+
+pub struct EntanglementView {
+    preset: Preset,
+    // Qubits of the GHZ and W states
+    num_qubits: usize,
+    // Werner visibility: 1 = the pure state, 0 = maximally mixed
+    visibility: f64,
+    // Qubits 0..cut on the left of the Schmidt decomposition
+    cut: usize,
+    bases: [Basis; 2],
+    angles: ChshAngles,
+    sampled: Option<f64>,
+    seed: u64,
+    visual: StateVisual,
+    // Cached from the settings above
+    state: StateVector,
+    rho: DensityMatrix,
+}
+
+impl Default for EntanglementView {
+    fn default() -> Self {
+        let state = bell_state(0);
+        Self {
+            preset: Preset::Bell(0),
+            num_qubits: 3,
+            visibility: 1.0,
+            cut: 1,
+            bases: [Basis::Z, Basis::Z],
+            angles: ChshAngles::OPTIMAL,
+            sampled: None,
+            seed: 0,
+            visual: StateVisual::QSphere,
+            rho: DensityMatrix::from_state_vector(&state),
+            state,
+        }
+    }
+}
+
+impl EntanglementView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn rebuild(&mut self) {
+        self.state = match self.preset {
+            Preset::Bell(i) => bell_state(i),
+            Preset::Ghz => ghz_state(self.num_qubits),
+            Preset::W => w_state(self.num_qubits),
+        };
+        self.rho = werner(&self.state, self.visibility);
+        self.cut = self.cut.clamp(1, self.state.num_qubits() - 1);
+        self.sampled = None;
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🔗 Entanglement")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Bell, GHZ and W states, how entangled they are, and the correlations no classical model can reproduce")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_measures(&mut columns[0]);
+                self.render_schmidt(&mut columns[1]);
+            });
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_joint_grid(&mut columns[0]);
+                self.render_chsh(&mut columns[1]);
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("State:");
+                    for preset in Preset::ALL {
+                        changed |= ui.selectable_value(&mut self.preset, preset, preset.name()).changed();
+                    }
+                    if matches!(self.preset, Preset::Ghz | Preset::W) {
+                        ui.add_space(12.0);
+                        changed |= ui.add(egui::Slider::new(&mut self.num_qubits, 3..=6).text("qubits")).changed();
+                    }
+                });
+                changed |= ui
+                    .add(egui::Slider::new(&mut self.visibility, 0.0..=1.0).text("Werner visibility p"))
+                    .on_hover_text("p|ψ⟩⟨ψ| + (1 - p) I/d: white noise washes the entanglement out")
+                    .changed();
+            });
+        if changed {
+            self.rebuild();
+        }
+
+        ui.add_space(8.0);
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.set_width(420.0);
+                ProbabilityBars::new("entanglement_probabilities", &self.rho.probabilities(), self.rho.num_qubits())
+                    .height(220.0)
+                    .show(ui);
+            });
+            ui.add_space(16.0);
+            ui.vertical(|ui| {
+                self.visual.picker(ui);
+                self.visual.show(ui, "entanglement_state_visual", &self.state, 220.0);
+            });
+        });
+    }
+
+    fn render_measures(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📏 Entanglement measures");
+        let n = self.rho.num_qubits();
+        let pair = self.rho.partial_trace(&[0, 1]);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                egui::Grid::new("entanglement_measures").num_columns(2).spacing([24.0, 6.0]).show(ui, |ui| {
+                    for k in 1..n {
+                        let left: Vec<usize> = (0..k).collect();
+                        let names: Vec<String> = left.iter().map(|q| format!("q{}", q)).collect();
+                        ui.label(format!("S({}) ", names.join(",")));
+                        ui.label(egui::RichText::new(format!("{:.4} bits", entanglement_entropy(&self.rho, &left))).monospace());
+                        ui.end_row();
+                    }
+                    let label = if n == 2 { "" } else { " of q0,q1" };
+                    ui.label(format!("Concurrence{}", label));
+                    ui.label(egui::RichText::new(format!("{:.4}", concurrence(&pair))).monospace());
+                    ui.end_row();
+                    ui.label(format!("Negativity{}", label));
+                    ui.label(egui::RichText::new(format!("{:.4}", negativity(&pair))).monospace());
+                    ui.end_row();
+                    ui.label("Purity");
+                    ui.label(egui::RichText::new(format!("{:.4}", self.rho.purity())).monospace());
+                    ui.end_row();
+                });
+
+                ui.add_space(6.0);
+                let note = if self.visibility < 1.0 {
+                    "The state is mixed, so entropies also count classical noise; concurrence and negativity do not."
+                } else if n > 2 {
+                    "GHZ loses all pairwise entanglement when a qubit is traced out; W keeps some."
+                } else {
+                    "A Bell state is maximally entangled: 1 bit of entropy, concurrence 1, negativity 0.5."
+                };
+                ui.label(egui::RichText::new(note).color(egui::Color32::from_rgb(140, 140, 160)).size(11.0));
+            });
+    }
+
+    fn render_schmidt(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "✂ Schmidt decomposition");
+        let n = self.state.num_qubits();
+        if n > 2 {
+            ui.add(egui::Slider::new(&mut self.cut, 1..=n - 1).text("qubits on the left"));
+        }
+        let left: Vec<usize> = (0..self.cut).collect();
+        let schmidt = schmidt_decomposition(&self.state, &left);
+
+        let bars: Vec<Bar> = schmidt
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| Bar::new(k as f64, c).width(0.6).fill(egui::Color32::from_rgb(180, 130, 255)))
+            .collect();
+        Plot::new("schmidt_coefficients")
+            .height(140.0)
+            .include_y(0.0)
+            .include_y(1.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| plot_ui.bar_chart(BarChart::new("λ", bars)));
+
+        ui.label(egui::RichText::new(format!("Schmidt rank {}", schmidt.rank()))
+            .color(egui::Color32::from_rgb(100, 255, 150))
+            .strong());
+        let (right_qubits, left_qubits) = (n - self.cut, self.cut);
+        for k in 0..schmidt.coefficients.len() {
+            ui.label(egui::RichText::new(format!(
+                "{:.3} · {} ⊗ {}",
+                schmidt.coefficients[k],
+                ket(&schmidt.left[k], left_qubits),
+                ket(&schmidt.right[k], right_qubits)
+            ))
+                .monospace()
+                .size(12.0));
+        }
+        if self.visibility < 1.0 {
+            ui.label(egui::RichText::new("Shown for the pure state; mixed states have no Schmidt form.")
+                .color(egui::Color32::from_rgb(140, 140, 160))
+                .size(11.0));
+        }
+    }
+
+    fn render_joint_grid(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🎯 Joint measurement of q0 and q1");
+        ui.horizontal(|ui| {
+            for (qubit, basis) in self.bases.iter_mut().enumerate() {
+                ui.label(format!("q{}:", qubit));
+                egui::ComboBox::from_id_salt(("joint_basis", qubit))
+                    .selected_text(basis.name())
+                    .width(50.0)
+                    .show_ui(ui, |ui| {
+                        for option in Basis::ALL {
+                            ui.selectable_value(basis, option, option.name());
+                        }
+                    });
+            }
+        });
+        ui.add_space(8.0);
+
+        let grid = joint_probabilities(&self.rho, [0, 1], self.bases);
+        let cell = 64.0;
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(cell * 3.0, cell * 3.0), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        let text = egui::Color32::from_rgb(160, 170, 210);
+        for i in 0..2 {
+            let header = format!("{}", i);
+            painter.text(
+                rect.left_top() + egui::vec2(cell * (i as f32 + 1.5), cell * 0.5),
+                egui::Align2::CENTER_CENTER,
+                format!("q1={}", header),
+                egui::FontId::monospace(12.0),
+                text,
+            );
+            painter.text(
+                rect.left_top() + egui::vec2(cell * 0.5, cell * (i as f32 + 1.5)),
+                egui::Align2::CENTER_CENTER,
+                format!("q0={}", header),
+                egui::FontId::monospace(12.0),
+                text,
+            );
+        }
+        for (a, row) in grid.iter().enumerate() {
+            for (b, &p) in row.iter().enumerate() {
+                let min = rect.left_top() + egui::vec2(cell * (b as f32 + 1.0), cell * (a as f32 + 1.0));
+                let square = egui::Rect::from_min_size(min, egui::vec2(cell, cell)).shrink(3.0);
+                let fill = egui::Color32::from_rgb(100, 200, 255).gamma_multiply(0.15 + 0.85 * p as f32);
+                painter.rect_filled(square, 4.0, fill);
+                painter.text(square.center(), egui::Align2::CENTER_CENTER, format!("{:.3}", p), egui::FontId::monospace(13.0), egui::Color32::WHITE);
+            }
+        }
+
+        ui.label(egui::RichText::new(format!("Correlation E = P(same) − P(different) = {:+.3}", correlation(&grid)))
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(12.0));
+    }
+
+    fn render_chsh(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🎲 CHSH experiment");
+        if self.state.num_qubits() != 2 {
+            ui.label(egui::RichText::new("Pick a Bell state: the CHSH game is played on two qubits.")
+                .color(egui::Color32::from_rgb(140, 140, 160)));
+            return;
+        }
+
+        let mut changed = false;
+        let names = [["a", "a′"], ["b", "b′"]];
+        for (side, angles) in [&mut self.angles.a, &mut self.angles.b].into_iter().enumerate() {
+            ui.horizontal(|ui| {
+                for (angle, name) in angles.iter_mut().zip(names[side]) {
+                    let mut degrees = angle.to_degrees();
+                    if ui.add(egui::Slider::new(&mut degrees, -180.0..=180.0).suffix("°").text(name)).changed() {
+                        *angle = degrees.to_radians();
+                        changed = true;
+                    }
+                }
+            });
+        }
+        ui.horizontal(|ui| {
+            if ui.button("⭐ Optimal angles").clicked() {
+                self.angles = ChshAngles::OPTIMAL;
+                changed = true;
+            }
+            if ui.button(format!("▶ Play {} rounds", CHSH_ROUNDS)).clicked() {
+                self.seed += 1;
+                let mut rng = StdRng::seed_from_u64(self.seed);
+                self.sampled = Some(sample_chsh(&self.rho, &self.angles, CHSH_ROUNDS, &mut rng));
+            }
+        });
+        if changed {
+            self.sampled = None;
+        }
+
+        let s = chsh_value(&self.rho, &self.angles);
+        let verdict = |s: f64| {
+            if s.abs() > 2.0 {
+                egui::RichText::new(format!("S = {:+.3}  > 2: no local hidden-variable model explains this", s))
+                    .color(egui::Color32::from_rgb(100, 255, 150))
+            } else {
+                egui::RichText::new(format!("S = {:+.3}  within the classical bound |S| ≤ 2", s))
+                    .color(egui::Color32::from_rgb(255, 200, 100))
+            }
+        };
+        ui.add_space(6.0);
+        ui.label(verdict(s).strong());
+        if let Some(sampled) = self.sampled {
+            ui.label(verdict(sampled).size(12.0));
+        }
+
+        // S as Bob's measurement directions rotate together
+        let curve: PlotPoints = (0..=180)
+            .map(|i| {
+                let delta = -PI + 2.0 * PI * i as f64 / 180.0;
+                let mut angles = self.angles;
+                angles.b = [angles.b[0] + delta, angles.b[1] + delta];
+                [delta.to_degrees(), chsh_value(&self.rho, &angles)]
+            })
+            .collect();
+        Plot::new("chsh_curve")
+            .height(180.0)
+            .legend(Legend::default())
+            .include_y(-3.0)
+            .include_y(3.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("S vs Bob's rotation (°)", curve).color(egui::Color32::from_rgb(100, 200, 255)));
+                plot_ui.hline(HLine::new("Classical bound", 2.0).color(egui::Color32::from_rgb(255, 200, 100)));
+                plot_ui.hline(HLine::new("", -2.0).color(egui::Color32::from_rgb(255, 200, 100)));
+                plot_ui.hline(HLine::new("Tsirelson bound 2√2", 2.0 * SQRT_2).color(egui::Color32::from_rgb(180, 130, 255)));
+            });
+    }
+}
+
+// Short ket form of a local vector, e.g. "(0.707|0⟩ + 0.707|1⟩)"
+fn ket(vector: &[num_complex::Complex64], num_qubits: usize) -> String {
+    let terms: Vec<String> = vector
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| a.norm() > 1e-6)
+        .map(|(i, a)| {
+            let label = basis_label(i, num_qubits);
+            if a.im.abs() < 1e-6 {
+                format!("{:.3}{}", a.re, label)
+            } else {
+                format!("({:.3}{:+.3}i){}", a.re, a.im, label)
+            }
+        })
+        .collect();
+    match terms.len() {
+        1 if (vector.iter().map(|a| a.norm()).fold(0.0, f64::max) - 1.0).abs() < 1e-6 => {
+            basis_label(vector.iter().position(|a| a.norm() > 1e-6).unwrap_or(0), num_qubits)
+        }
+        _ => format!("({})", terms.join(" + ")),
+    }
+}
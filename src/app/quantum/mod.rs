@@ -0,0 +1,7 @@
+pub mod algorithms_view;
+pub mod circuit_composer_view;
+pub mod circuit_debugger_view;
+pub mod entanglement_view;
+pub mod noise_panel;
+pub mod observable_panel;
+pub mod shor_view;
@@ -0,0 +1,243 @@
+use crate::core::quantum::circuit::Circuit;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::noise::{NoiseChannel, NoiseModel, NoiseTarget, ReadoutError};
+use eframe::egui;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const SWEEP_STEPS: usize = 20;
+const SWEEP_MAX: f64 = 0.5;
+
+pub type Curves = Vec<(&'static str, Vec<[f64; 2]>)>;
+
+// Fidelity curves and the circuit, target and seed they were computed for
+struct Sweep {
+    circuit: Circuit,
+    target: NoiseTarget,
+    seed: u64,
+    curves: Curves,
+}
+
+// Slider state for the composer's noise model
+pub struct NoisePanel {
+    pub enabled: bool,
+    target: NoiseTarget,
+    bit_flip: f64,
+    phase_flip: f64,
+    depolarizing: f64,
+    amplitude_damping: f64,
+    phase_damping: f64,
+    thermal: bool,
+    t1_us: f64,
+    t2_us: f64,
+    gate_time_ns: f64,
+    readout: f64,
+    // A slider is held, so expensive results can wait for the release
+    dragging: bool,
+    sweep: Option<Sweep>,
+}
+
+impl Default for NoisePanel {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target: NoiseTarget::AllGates,
+            bit_flip: 0.0,
+            phase_flip: 0.0,
+            depolarizing: 0.02,
+            amplitude_damping: 0.0,
+            phase_damping: 0.0,
+            thermal: false,
+            t1_us: 100.0,
+            t2_us: 80.0,
+            gate_time_ns: 50.0,
+            readout: 0.0,
+            dragging: false,
+            sweep: None,
+        }
+    }
+}
+
+impl NoisePanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn channels(&self) -> Vec<NoiseChannel> {
+        let mut channels = Vec::new();
+        for (p, channel) in [
+            (self.bit_flip, NoiseChannel::BitFlip(self.bit_flip)),
+            (self.phase_flip, NoiseChannel::PhaseFlip(self.phase_flip)),
+            (self.depolarizing, NoiseChannel::Depolarizing(self.depolarizing)),
+            (self.amplitude_damping, NoiseChannel::AmplitudeDamping(self.amplitude_damping)),
+            (self.phase_damping, NoiseChannel::PhaseDamping(self.phase_damping)),
+        ] {
+            if p > 0.0 {
+                channels.push(channel);
+            }
+        }
+        if self.thermal {
+            channels.push(NoiseChannel::ThermalRelaxation {
+                t1: self.t1_us,
+                t2: self.t2_us,
+                gate_time: self.gate_time_ns / 1000.0,
+            });
+        }
+        channels
+    }
+
+    pub fn model(&self) -> NoiseModel {
+        let mut model = NoiseModel::new();
+        if !self.enabled {
+            return model;
+        }
+        for channel in self.channels() {
+            model.add(self.target.clone(), channel);
+        }
+        if self.readout > 0.0 {
+            model.set_readout_error(ReadoutError {
+                p1_given_0: self.readout,
+                p0_given_1: self.readout,
+            });
+        }
+        model
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    // The computed sweep, if it still matches the circuit, target and seed
+    pub fn sweep(&self, circuit: &Circuit, seed: u64) -> Option<&Curves> {
+        self.sweep
+            .as_ref()
+            .filter(|sweep| sweep.seed == seed && sweep.target == self.target && sweep.circuit == *circuit)
+            .map(|sweep| &sweep.curves)
+    }
+
+    // Fidelity with the ideal state as each channel alone goes from 0 to SWEEP_MAX;
+    // every point reuses `seed` so measurements follow the ideal run's outcomes
+    pub fn compute_sweep(&mut self, circuit: &Circuit, ideal: &DensityMatrix, seed: u64) {
+        let makers: [fn(f64) -> NoiseChannel; 5] = [
+            NoiseChannel::BitFlip,
+            NoiseChannel::PhaseFlip,
+            NoiseChannel::Depolarizing,
+            NoiseChannel::AmplitudeDamping,
+            NoiseChannel::PhaseDamping,
+        ];
+        let curves = makers
+            .iter()
+            .map(|make| {
+                let points = (0..=SWEEP_STEPS)
+                    .map(|i| {
+                        let p = SWEEP_MAX * i as f64 / SWEEP_STEPS as f64;
+                        let mut model = NoiseModel::new();
+                        model.add(self.target.clone(), make(p));
+                        let mut rng = StdRng::seed_from_u64(seed);
+                        [p, model.run(circuit, &mut rng).state.fidelity(ideal)]
+                    })
+                    .collect();
+                (make(0.0).name(), points)
+            })
+            .collect();
+        self.sweep = Some(Sweep {
+            circuit: circuit.clone(),
+            target: self.target.clone(),
+            seed,
+            curves,
+        });
+    }
+
+    // Returns true when the model changed
+    pub fn render(&mut self, ui: &mut egui::Ui, circuit: &Circuit) -> bool {
+        let mut changed = false;
+        self.dragging = false;
+
+        ui.label(egui::RichText::new("🌫 Noise")
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+
+        ui.add_space(8.0);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                changed |= ui.checkbox(&mut self.enabled, "Enable noise (density matrix)").changed();
+                if !self.enabled {
+                    return;
+                }
+
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.label("Attach to:");
+                    let selected = match &self.target {
+                        NoiseTarget::AllGates => "Every gate".to_string(),
+                        NoiseTarget::Gate(name) => format!("{} gates", name),
+                        NoiseTarget::Qubit(q) => format!("Qubit q{}", q),
+                    };
+                    let mut names: Vec<String> = circuit
+                        .instructions()
+                        .iter()
+                        .filter_map(|instruction| instruction.operation())
+                        .map(|op| op.name())
+                        .collect();
+                    names.sort();
+                    names.dedup();
+
+                    egui::ComboBox::from_id_salt("noise_target")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            changed |= ui.selectable_value(&mut self.target, NoiseTarget::AllGates, "Every gate").changed();
+                            for name in names {
+                                let label = format!("{} gates", name);
+                                changed |= ui.selectable_value(&mut self.target, NoiseTarget::Gate(name), label).changed();
+                            }
+                            for q in 0..circuit.num_qubits() {
+                                changed |= ui.selectable_value(&mut self.target, NoiseTarget::Qubit(q), format!("Qubit q{}", q)).changed();
+                            }
+                        });
+                });
+
+                ui.add_space(4.0);
+                for (value, name) in [
+                    (&mut self.bit_flip, "Bit flip p"),
+                    (&mut self.phase_flip, "Phase flip p"),
+                    (&mut self.depolarizing, "Depolarizing p"),
+                    (&mut self.amplitude_damping, "Amplitude damping γ"),
+                    (&mut self.phase_damping, "Phase damping λ"),
+                    (&mut self.readout, "Readout error p"),
+                ] {
+                    changed |= slider(ui, egui::Slider::new(value, 0.0..=0.5).text(name), &mut self.dragging);
+                }
+
+                ui.add_space(4.0);
+                changed |= ui.checkbox(&mut self.thermal, "Thermal relaxation (T1/T2)").changed();
+                if self.thermal {
+                    changed |= slider(ui, egui::Slider::new(&mut self.t1_us, 1.0..=500.0).text("T1 (µs)"), &mut self.dragging);
+                    changed |= slider(ui, egui::Slider::new(&mut self.t2_us, 1.0..=1000.0).text("T2 (µs)"), &mut self.dragging);
+                    changed |= slider(
+                        ui,
+                        egui::Slider::new(&mut self.gate_time_ns, 10.0..=5000.0).logarithmic(true).text("Gate time (ns)"),
+                        &mut self.dragging,
+                    );
+                    if self.t2_us > 2.0 * self.t1_us {
+                        ui.label(egui::RichText::new("T2 is capped at 2·T1")
+                            .color(egui::Color32::from_rgb(255, 200, 100))
+                            .size(11.0));
+                    }
+                }
+            });
+
+        changed
+    }
+}
+
+// Adds a slider, noting whether it is being dragged; returns true when its value changed
+fn slider(ui: &mut egui::Ui, slider: egui::Slider, dragging: &mut bool) -> bool {
+    let response = ui.add(slider);
+    *dragging |= response.dragged();
+    response.changed()
+}
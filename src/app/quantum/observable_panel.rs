@@ -0,0 +1,135 @@
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::pauli::{Estimate, Hamiltonian};
+use crate::core::quantum::state_vector::StateVector;
+use eframe::egui;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// ⟨H⟩ of the composer's output state, exact and from simulated shots
+pub struct ObservablePanel {
+    source: String,
+    hamiltonian: Result<Hamiltonian, String>,
+    // Shots per measurement basis
+    shots: usize,
+    seed: u64,
+}
+
+impl Default for ObservablePanel {
+    fn default() -> Self {
+        let source = "0.5*Z0 Z1 - 1.2*X0 + 0.3*X0 X1".to_string();
+        Self {
+            hamiltonian: Hamiltonian::parse(&source),
+            source,
+            shots: 1000,
+            seed: 0,
+        }
+    }
+}
+
+impl ObservablePanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn values<B: Backend>(&self, hamiltonian: &Hamiltonian, state: &B) -> (f64, Estimate, Vec<f64>) {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let terms = hamiltonian.terms.iter().map(|(_, p)| p.expectation(state)).collect();
+        (hamiltonian.expectation(state), hamiltonian.estimate(state, self.shots, &mut rng), terms)
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui, state: &StateVector, density: Option<&DensityMatrix>) {
+        ui.label(egui::RichText::new("🧭 Observable ⟨H⟩")
+            .color(egui::Color32::from_rgb(140, 160, 200))
+            .size(13.0));
+        ui.label(egui::RichText::new("Weighted Pauli strings, e.g. 0.5*Z0 Z1 - 1.2*X0 + 0.3")
+            .color(egui::Color32::from_rgb(160, 160, 180))
+            .size(11.0));
+        ui.add_space(4.0);
+
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let edit = ui.add(egui::TextEdit::singleline(&mut self.source)
+                        .font(egui::TextStyle::Monospace)
+                        .desired_width(420.0));
+                    if edit.changed() {
+                        self.hamiltonian = Hamiltonian::parse(&self.source);
+                    }
+                    ui.add(egui::Slider::new(&mut self.shots, 10..=10_000).logarithmic(true).text("shots / basis"));
+                    if ui.button("🎲 Resample").clicked() {
+                        self.seed += 1;
+                    }
+                });
+
+                let hamiltonian = match &self.hamiltonian {
+                    Ok(hamiltonian) => hamiltonian,
+                    Err(message) => {
+                        ui.label(egui::RichText::new(message).color(egui::Color32::from_rgb(255, 120, 120)));
+                        return;
+                    }
+                };
+                ui.label(egui::RichText::new(format!("H = {}", hamiltonian.label()))
+                    .color(egui::Color32::from_rgb(160, 170, 210))
+                    .size(12.0)
+                    .code());
+                if hamiltonian.num_qubits() > state.num_qubits() {
+                    ui.label(egui::RichText::new(format!(
+                        "H acts on {} qubits but the circuit has {}",
+                        hamiltonian.num_qubits(),
+                        state.num_qubits()
+                    ))
+                    .color(egui::Color32::from_rgb(255, 200, 100)));
+                    return;
+                }
+
+                let (exact, estimate, terms) = match density {
+                    Some(rho) => self.values(hamiltonian, rho),
+                    None => self.values(hamiltonian, state),
+                };
+
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("⟨H⟩ = {:+.4}", exact))
+                        .color(egui::Color32::from_rgb(100, 200, 255))
+                        .size(16.0)
+                        .strong());
+                    ui.add_space(16.0);
+                    ui.label(egui::RichText::new(format!("estimate {:+.4} ± {:.4}", estimate.mean, estimate.standard_error))
+                        .color(egui::Color32::from_rgb(180, 140, 255))
+                        .size(16.0)
+                        .strong())
+                        .on_hover_text("Mean of the shots ± one standard error");
+                    ui.add_space(16.0);
+                    ui.label(egui::RichText::new(format!(
+                        "{} commuting group(s), {} shots in total",
+                        estimate.groups, estimate.shots
+                    ))
+                    .color(egui::Color32::from_rgb(160, 160, 180))
+                    .size(12.0));
+                });
+
+                ui.add_space(6.0);
+                let groups = hamiltonian.commuting_groups();
+                egui::Grid::new("observable_terms").striped(true).spacing([16.0, 4.0]).show(ui, |ui| {
+                    for header in ["Coefficient", "Term", "⟨P⟩", "Basis group"] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+                    for (index, ((c, p), value)) in hamiltonian.terms.iter().zip(&terms).enumerate() {
+                        ui.label(format!("{:+.4}", c));
+                        ui.label(egui::RichText::new(p.label()).code());
+                        ui.label(format!("{:+.4}", value));
+                        match groups.iter().position(|members| members.contains(&index)) {
+                            Some(group) => ui.label(format!("#{}", group + 1)),
+                            None => ui.label("exact"),
+                        };
+                        ui.end_row();
+                    }
+                });
+            });
+    }
+}
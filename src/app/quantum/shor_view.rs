@@ -0,0 +1,290 @@
+use crate::core::quantum::shor::{
+    MODULI, Recovery, classical_order, coprime_bases, gcd, mod_pow, order_finding, phase_distribution, recover,
+    work_qubits,
+};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Legend, Plot, VLine};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Readings drawn per run; a handful is usually enough to hit a good one
+const RUNS: usize = 6;
+
+pub struct ShorView {
+    modulus: u64,
+    base: u64,
+    precision: usize,
+    seed: u64,
+    selected: usize,
+    // Cached from the settings above
+    distribution: Vec<f64>,
+    num_gates: usize,
+    recoveries: Vec<Recovery>,
+}
+
+impl Default for ShorView {
+    fn default() -> Self {
+        let mut view = Self {
+            modulus: 15,
+            base: 7,
+            precision: 8,
+            seed: 0,
+            selected: 0,
+            distribution: Vec::new(),
+            num_gates: 0,
+            recoveries: Vec::new(),
+        };
+        view.rebuild();
+        view
+    }
+}
+
+impl ShorView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn rebuild(&mut self) {
+        if gcd(self.base, self.modulus) != 1 || self.base >= self.modulus {
+            self.base = coprime_bases(self.modulus)[0];
+        }
+        self.distribution = phase_distribution(self.base, self.modulus, self.precision);
+        self.num_gates = order_finding(self.base, self.modulus, self.precision).instructions().len();
+        self.sample();
+    }
+
+    // Draws RUNS readings from the exact distribution and post-processes each
+    fn sample(&mut self) {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        self.recoveries = (0..RUNS)
+            .map(|_| {
+                let mut sample: f64 = rng.random();
+                let reading = self
+                    .distribution
+                    .iter()
+                    .position(|&p| {
+                        sample -= p;
+                        sample < 0.0
+                    })
+                    .unwrap_or(self.distribution.len() - 1);
+                recover(reading as u64, self.precision, self.base, self.modulus)
+            })
+            .collect();
+        // Show the first run that worked, if any
+        self.selected = self.recoveries.iter().position(|r| r.factors.is_ok()).unwrap_or(0);
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            ui.add_space(16.0);
+
+            // Header
+            ui.heading(egui::RichText::new("🔐 Shor's Algorithm")
+                .color(egui::Color32::from_rgb(150, 170, 255))
+                .size(24.0));
+
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new("Factoring small integers: quantum order finding, then continued fractions and a gcd")
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(13.0));
+
+            ui.add_space(20.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            self.render_controls(ui);
+
+            ui.add_space(16.0);
+            self.render_distribution(ui);
+
+            ui.add_space(16.0);
+            ui.separator();
+            ui.add_space(16.0);
+
+            ui.columns(2, |columns| {
+                self.render_runs(&mut columns[0]);
+                self.render_steps(&mut columns[1]);
+            });
+        });
+    }
+
+    fn section_label(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .color(egui::Color32::from_rgb(120, 140, 180))
+            .size(14.0)
+            .strong());
+        ui.add_space(8.0);
+    }
+
+    fn render_controls(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Frame::NONE
+            .fill(egui::Color32::from_rgb(25, 25, 35))
+            .corner_radius(6.0)
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("N =");
+                    for modulus in MODULI {
+                        changed |= ui.selectable_value(&mut self.modulus, modulus, modulus.to_string()).changed();
+                    }
+                    ui.add_space(16.0);
+                    egui::ComboBox::from_label("base a")
+                        .selected_text(self.base.to_string())
+                        .show_ui(ui, |ui| {
+                            for a in coprime_bases(self.modulus) {
+                                changed |= ui.selectable_value(&mut self.base, a, a.to_string()).changed();
+                            }
+                        });
+                    ui.add_space(16.0);
+                    changed |= ui
+                        .add(egui::Slider::new(&mut self.precision, 3..=8).text("counting qubits t"))
+                        .on_hover_text("More counting qubits sharpen the peaks at s/r")
+                        .changed();
+                });
+                ui.add_space(4.0);
+                ui.label(egui::RichText::new(format!(
+                    "{} counting + {} work qubits, {} instructions. The work register starts in |1⟩ and counting qubit k \
+                     controls multiplication by a^(2^k) mod N.",
+                    self.precision,
+                    work_qubits(self.modulus),
+                    self.num_gates
+                ))
+                .color(egui::Color32::from_rgb(160, 160, 180))
+                .size(12.0));
+            });
+        if changed {
+            self.rebuild();
+        }
+    }
+
+    fn render_distribution(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "📊 Counting-register distribution");
+        let order = classical_order(self.base, self.modulus);
+        ui.label(egui::RichText::new(format!(
+            "The order of {} mod {} is r = {}, so the readings c / 2^{} cluster around s / {} (dashed).",
+            self.base, self.modulus, order, self.precision, order
+        ))
+        .color(egui::Color32::from_rgb(160, 160, 180))
+        .size(12.0));
+
+        let size = self.distribution.len() as f64;
+        let bars: Vec<Bar> = self.distribution
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| **p > 1e-6)
+            .map(|(reading, &p)| Bar::new(reading as f64 / size, p)
+                .name(format!("c = {}", reading))
+                .width(0.8 / size)
+                .fill(egui::Color32::from_rgb(180, 140, 255)))
+            .collect();
+
+        Plot::new("shor_distribution")
+            .height(220.0)
+            .legend(Legend::default())
+            .include_x(0.0)
+            .include_x(1.0)
+            .include_y(0.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("P(c / 2^t)", bars));
+                for s in 0..order {
+                    let name = if s == 0 { "s / r" } else { "" };
+                    plot_ui.vline(VLine::new(name, s as f64 / order as f64)
+                        .color(egui::Color32::from_rgb(255, 200, 100))
+                        .style(egui_plot::LineStyle::dashed_loose()));
+                }
+            });
+    }
+
+    fn render_runs(&mut self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🎲 Order-finding runs");
+        if ui.button(format!("Run {} more times", RUNS)).clicked() {
+            self.seed += 1;
+            self.sample();
+        }
+        ui.add_space(6.0);
+
+        egui::Grid::new("shor_runs").striped(true).spacing([16.0, 4.0]).show(ui, |ui| {
+            for header in ["Reading c", "c / 2^t", "r", "Outcome"] {
+                ui.label(egui::RichText::new(header).strong());
+            }
+            ui.end_row();
+            for (i, recovery) in self.recoveries.iter().enumerate() {
+                let text = format!("{:0width$b}", recovery.reading, width = self.precision);
+                if ui.selectable_label(self.selected == i, egui::RichText::new(text).code()).clicked() {
+                    self.selected = i;
+                }
+                ui.label(format!("{:.4}", recovery.phase));
+                ui.label(recovery.order.map(|r| r.to_string()).unwrap_or_else(|| "–".to_string()));
+                match &recovery.factors {
+                    Ok((p, q)) => ui.label(egui::RichText::new(format!("{} × {}", p, q)).color(egui::Color32::from_rgb(100, 255, 150))),
+                    Err(_) => ui.label(egui::RichText::new("retry").color(egui::Color32::from_rgb(255, 200, 100))),
+                };
+                ui.end_row();
+            }
+        });
+    }
+
+    fn render_steps(&self, ui: &mut egui::Ui) {
+        Self::section_label(ui, "🧩 From phase to factors");
+        let Some(recovery) = self.recoveries.get(self.selected) else {
+            return;
+        };
+        let (a, n) = (self.base, self.modulus);
+        let step = |ui: &mut egui::Ui, number: usize, text: String| {
+            ui.label(egui::RichText::new(format!("{}. {}", number, text))
+                .color(egui::Color32::from_rgb(190, 190, 210))
+                .size(13.0));
+        };
+
+        step(ui, 1, format!(
+            "Measured c = {}, so the phase is c / 2^{} = {} / {} = {:.4}",
+            recovery.reading,
+            self.precision,
+            recovery.reading,
+            1u64 << self.precision,
+            recovery.phase
+        ));
+        let terms: Vec<String> = recovery.terms.iter().map(|t| t.to_string()).collect();
+        let body = terms.get(1..).map(|rest| rest.join(", ")).unwrap_or_default();
+        step(ui, 2, format!("Continued fraction: [{}; {}]", terms.first().cloned().unwrap_or_default(), body));
+        let convergents: Vec<String> = recovery.convergents.iter().map(|(h, k)| format!("{}/{}", h, k)).collect();
+        step(ui, 3, format!("Convergents: {}", convergents.join(", ")));
+        match recovery.order {
+            Some(r) => step(ui, 4, format!("First denominator with a^r = 1 (mod N): r = {} ({}^{} mod {} = 1)", r, a, r, n)),
+            None => step(ui, 4, "No convergent denominator below N is the order".to_string()),
+        }
+        if let Some(r) = recovery.order
+            && r.is_multiple_of(2)
+        {
+            let half = mod_pow(a, r / 2, n);
+            step(ui, 5, format!(
+                "a^(r/2) = {}^{} mod {} = {}; gcd({} + 1, {}) = {}, gcd({} − 1, {}) = {}",
+                a,
+                r / 2,
+                n,
+                half,
+                half,
+                n,
+                gcd(half + 1, n),
+                half,
+                n,
+                gcd(half + n - 1, n)
+            ));
+        }
+
+        ui.add_space(8.0);
+        match &recovery.factors {
+            Ok((p, q)) => ui.label(egui::RichText::new(format!("{} = {} × {}", n, p, q))
+                .color(egui::Color32::from_rgb(100, 255, 150))
+                .size(16.0)
+                .strong()),
+            Err(reason) => ui.label(egui::RichText::new(format!("{} — run again", reason))
+                .color(egui::Color32::from_rgb(255, 200, 100))
+                .size(13.0)),
+        };
+    }
+}
@@ -2,7 +2,6 @@ use crate::core::ai::regression::linear_regression::{LinearRegression, TrainingC
 use eframe::egui;
 use egui_plot::{Line, Plot, PlotPoints, Points};
 use ndarray::Array2;
-use tracing_subscriber::registry::Data;
 
 #[derive(Clone)]
 pub struct DataPoint {
@@ -59,12 +58,8 @@ impl LinearRegressionView {
     pub fn new() -> Self {
         Self::default()
     }
-   fn train_model(&mut self) {
-        if self.data_points.len() < 2 {
-            tracing::warn!("Need at least 2 data points to train");
-            return;
-        }
-        
+    // The points as (x, y) training matrices, one row each
+    pub fn training_data(&self) -> (Array2<f64>, Array2<f64>) {
         let n = self.data_points.len();
         let mut x_train = Array2::zeros((n, 1));
         let mut y_train = Array2::zeros((n, 1));
@@ -73,6 +68,16 @@ impl LinearRegressionView {
             x_train[[i, 0]] = point.x;
             y_train[[i, 0]] = point.y;
         }
+        (x_train, y_train)
+    }
+
+   fn train_model(&mut self) {
+        if self.data_points.len() < 2 {
+            tracing::warn!("Need at least 2 data points to train");
+            return;
+        }
+        
+        let (x_train, y_train) = self.training_data();
         
         let mut model = LinearRegression::new();
         let config = TrainingConfig {
@@ -174,9 +179,9 @@ impl LinearRegressionView {
         
         ui.add_space(8.0);
         
-        egui::Frame::none()
+        egui::Frame::NONE
             .fill(egui::Color32::from_rgb(25, 25, 35))
-            .rounding(6.0)
+            .corner_radius(6.0)
             .inner_margin(12.0)
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
@@ -195,22 +200,21 @@ impl LinearRegressionView {
                 
                 ui.add_space(8.0);
                 
-                if ui.button("➕ Add Point").clicked() {
-                    if let (Ok(x), Ok(y)) = (self.input_x.parse::<f64>(), self.input_y.parse::<f64>()) {
-                        self.data_points.push(DataPoint { x, y });
-                        self.input_x.clear();
-                        self.input_y.clear();
-                        self.is_trained = false;
-                    }
+                if ui.button("➕ Add Point").clicked()
+                    && let (Ok(x), Ok(y)) = (self.input_x.parse::<f64>(), self.input_y.parse::<f64>()) {
+                    self.data_points.push(DataPoint { x, y });
+                    self.input_x.clear();
+                    self.input_y.clear();
+                    self.is_trained = false;
                 }
             });
         
         ui.add_space(12.0);
         
         // Display current data points
-        egui::Frame::none()
+        egui::Frame::NONE
             .fill(egui::Color32::from_rgb(25, 25, 35))
-            .rounding(6.0)
+            .corner_radius(6.0)
             .inner_margin(12.0)
             .show(ui, |ui| {
                 ui.label(egui::RichText::new("Current Data:")
@@ -253,16 +257,16 @@ impl LinearRegressionView {
         
         ui.add_space(8.0);
         
-        egui::Frame::none()
+        egui::Frame::NONE
             .fill(egui::Color32::from_rgb(25, 25, 35))
-            .rounding(6.0)
+            .corner_radius(6.0)
             .inner_margin(12.0)
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
                     ui.label("Learning Rate:");
                     ui.add(egui::DragValue::new(&mut self.learning_rate)
                         .speed(0.001)
-                        .clamp_range(0.0001..=1.0));
+                        .range(0.0001..=1.0));
                 });
                 
                 ui.add_space(4.0);
@@ -271,7 +275,7 @@ impl LinearRegressionView {
                     ui.label("Epochs:");
                     ui.add(egui::DragValue::new(&mut self.epochs)
                         .speed(100)
-                        .clamp_range(100..=100000));
+                        .range(100..=100000));
                 });
             });
         
@@ -299,9 +303,9 @@ impl LinearRegressionView {
             
             ui.add_space(8.0);
             
-            egui::Frame::none()
+            egui::Frame::NONE
                 .fill(egui::Color32::from_rgb(25, 25, 35))
-                .rounding(6.0)
+                .corner_radius(6.0)
                 .inner_margin(12.0)
                 .show(ui, |ui| {
                     ui.horizontal(|ui| {
@@ -347,27 +351,26 @@ impl LinearRegressionView {
         );
         
         // Plot regression line if trained
-        if let Some(model) = &self.model {
-            if !self.data_points.is_empty() {
-                let x_min = self.data_points.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
-                let x_max = self.data_points.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
-                let margin = (x_max - x_min) * 0.2;
-                
-                let line_points: PlotPoints = (0..100)
-                    .map(|i| {
-                        let x = x_min - margin + (x_max - x_min + 2.0 * margin) * i as f64 / 99.0;
-                        let x_array = Array2::from_elem((1, 1), x);
-                        let y = model.predict(x_array)[[0, 0]];
-                        [x, y]
-                    })
-                    .collect();
-                
-                plot_ui.line(
-                    Line::new("Regression Line", line_points)  // Changed: Added name as first argument
-                        .color(egui::Color32::from_rgb(255, 100, 150))
-                        .width(2.0)
-                );
-            }
+        if let Some(model) = &self.model
+            && !self.data_points.is_empty() {
+            let x_min = self.data_points.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+            let x_max = self.data_points.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
+            let margin = (x_max - x_min) * 0.2;
+            
+            let line_points: PlotPoints = (0..100)
+                .map(|i| {
+                    let x = x_min - margin + (x_max - x_min + 2.0 * margin) * i as f64 / 99.0;
+                    let x_array = Array2::from_elem((1, 1), x);
+                    let y = model.predict(x_array)[[0, 0]];
+                    [x, y]
+                })
+                .collect();
+            
+            plot_ui.line(
+                Line::new("Regression Line", line_points)  // Changed: Added name as first argument
+                    .color(egui::Color32::from_rgb(255, 100, 150))
+                    .width(2.0)
+            );
         }
     });
 }
@@ -386,9 +389,9 @@ impl LinearRegressionView {
             let weight = model.weights[[0, 0]];
             let bias = model.bias;
             
-            egui::Frame::none()
+            egui::Frame::NONE
                 .fill(egui::Color32::from_rgb(25, 25, 35))
-                .rounding(6.0)
+                .corner_radius(6.0)
                 .inner_margin(12.0)
                 .show(ui, |ui| {
                     ui.label(egui::RichText::new("Learned Equation:")
@@ -423,9 +426,9 @@ impl LinearRegressionView {
     }
     
     fn render_metric_card(&self, ui: &mut egui::Ui, name: &str, value: f64, tooltip: &str) {
-        egui::Frame::none()
+        egui::Frame::NONE
             .fill(egui::Color32::from_rgb(30, 30, 42))
-            .rounding(6.0)
+            .corner_radius(6.0)
             .inner_margin(12.0)
             .show(ui, |ui| {
                 ui.set_min_width(150.0);
@@ -453,9 +456,9 @@ impl LinearRegressionView {
     }
     
     fn render_interpretation(&self, ui: &mut egui::Ui) {
-        egui::Frame::none()
+        egui::Frame::NONE
             .fill(egui::Color32::from_rgb(25, 25, 35))
-            .rounding(6.0)
+            .corner_radius(6.0)
             .inner_margin(12.0)
             .show(ui, |ui| {
                 ui.label(egui::RichText::new("📖 Understanding the Metrics")
@@ -0,0 +1,74 @@
+use crate::app::widgets::phase_color;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::state_vector::{StateVector, basis_label};
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Plot};
+
+// Bar per basis state: height is |amplitude|, colour is its phase
+pub struct AmplitudeBars<'a> {
+    id_salt: &'a str,
+    state: &'a StateVector,
+    height: f32,
+}
+
+impl<'a> AmplitudeBars<'a> {
+    pub fn new(id_salt: &'a str, state: &'a StateVector) -> Self {
+        Self {
+            id_salt,
+            state,
+            height: 240.0,
+        }
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) {
+        let n = self.state.num_qubits();
+        let bars: Vec<Bar> = self.state
+            .amplitudes()
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let phase = if a.norm() > 1e-10 { a.arg() } else { 0.0 };
+                Bar::new(i as f64, a.norm())
+                    .name(format!("{}  phase {:+.3}π", basis_label(i, n), phase / std::f64::consts::PI))
+                    .width(0.7)
+                    .fill(phase_color(phase))
+            })
+            .collect();
+
+        Plot::new(self.id_salt)
+            .height(self.height)
+            .include_y(0.0)
+            .include_y(1.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .x_axis_formatter(move |mark, _| {
+                let i = mark.value.round();
+                if (mark.value - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < (1 << n) {
+                    basis_label(i as usize, n)
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("|amplitude|", bars));
+            });
+
+        // Phase legend
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Phase:")
+                .color(egui::Color32::from_rgb(140, 160, 200))
+                .size(11.0));
+            for (label, phase) in [("0", 0.0), ("π/2", 0.5), ("π", 1.0), ("-π/2", -0.5)] {
+                let (rect, _) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                ui.painter().rect_filled(rect, 2.0, phase_color(phase * std::f64::consts::PI));
+                ui.label(egui::RichText::new(label).size(11.0));
+            }
+        });
+    }
+}
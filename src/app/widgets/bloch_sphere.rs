@@ -0,0 +1,120 @@
+use eframe::egui;
+
+// Fixed oblique camera so that x, y and z all stay visible
+const AZIMUTH: f32 = 0.55;
+const ELEVATION: f32 = 0.35;
+
+pub struct BlochSphere {
+    vector: [f64; 3],
+    label: String,
+    size: f32,
+}
+
+impl BlochSphere {
+    pub fn new(vector: [f64; 3]) -> Self {
+        Self {
+            vector,
+            label: String::new(),
+            size: 160.0,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let label_height = if self.label.is_empty() { 0.0 } else { 20.0 };
+        let (response, painter) = ui.allocate_painter(
+            egui::vec2(self.size, self.size + label_height),
+            egui::Sense::hover(),
+        );
+        let rect = response.rect;
+        let center = egui::pos2(rect.center().x, rect.top() + self.size / 2.0);
+        let radius = self.size * 0.38;
+
+        // Returns the screen position and whether the point faces the camera
+        let project = |p: [f32; 3]| -> (egui::Pos2, bool) {
+            let (sa, ca) = AZIMUTH.sin_cos();
+            let (se, ce) = ELEVATION.sin_cos();
+            let u = -p[0] * sa + p[1] * ca;
+            let v = p[2] * ce - (p[0] * ca + p[1] * sa) * se;
+            let depth = (p[0] * ca + p[1] * sa) * ce + p[2] * se;
+            (egui::pos2(center.x + u * radius, center.y - v * radius), depth >= 0.0)
+        };
+
+        let front = egui::Color32::from_rgb(90, 90, 130);
+        let back = egui::Color32::from_rgb(45, 45, 65);
+
+        painter.circle_filled(center, radius, egui::Color32::from_rgb(24, 24, 34));
+        painter.circle_stroke(center, radius, egui::Stroke::new(1.5, front));
+
+        // Equator and the x-z meridian
+        for ring in 0..2 {
+            let points: Vec<(egui::Pos2, bool)> = (0..=64)
+                .map(|i| {
+                    let t = i as f32 / 64.0 * std::f32::consts::TAU;
+                    if ring == 0 {
+                        project([t.cos(), t.sin(), 0.0])
+                    } else {
+                        project([t.sin(), 0.0, t.cos()])
+                    }
+                })
+                .collect();
+            for pair in points.windows(2) {
+                let color = if pair[0].1 { front } else { back };
+                painter.line_segment([pair[0].0, pair[1].0], egui::Stroke::new(1.0, color));
+            }
+        }
+
+        let axis_color = egui::Color32::from_rgb(120, 130, 170);
+        for (tip, name) in [
+            ([1.0, 0.0, 0.0], "x"),
+            ([0.0, 1.0, 0.0], "y"),
+            ([0.0, 0.0, 1.0], "|0⟩"),
+            ([0.0, 0.0, -1.0], "|1⟩"),
+        ] {
+            let (end, _) = project(tip);
+            painter.line_segment([center, end], egui::Stroke::new(1.0, axis_color));
+            let (label_pos, _) = project([tip[0] * 1.22, tip[1] * 1.22, tip[2] * 1.22]);
+            painter.text(
+                label_pos,
+                egui::Align2::CENTER_CENTER,
+                name,
+                egui::FontId::proportional(11.0),
+                axis_color,
+            );
+        }
+
+        // State vector with its shadow on the equatorial plane
+        let v = [self.vector[0] as f32, self.vector[1] as f32, self.vector[2] as f32];
+        let (tip, _) = project(v);
+        let (shadow, _) = project([v[0], v[1], 0.0]);
+        painter.line_segment([center, shadow], egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 110, 140)));
+        painter.line_segment([shadow, tip], egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 110, 140)));
+        painter.line_segment([center, tip], egui::Stroke::new(2.5, egui::Color32::from_rgb(255, 100, 150)));
+        painter.circle_filled(tip, 4.0, egui::Color32::from_rgb(255, 100, 150));
+
+        if !self.label.is_empty() {
+            painter.text(
+                egui::pos2(rect.center().x, rect.bottom() - label_height / 2.0),
+                egui::Align2::CENTER_CENTER,
+                &self.label,
+                egui::FontId::proportional(13.0),
+                egui::Color32::from_rgb(180, 190, 230),
+            );
+        }
+
+        let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        response.on_hover_text(format!(
+            "x = {:+.3}\ny = {:+.3}\nz = {:+.3}\n|r| = {:.3}",
+            v[0], v[1], v[2], length
+        ))
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::quantum::circuit::{Circuit, Instruction, Operation};
+use crate::core::quantum::gates::Gate;
+use eframe::egui;
+
+pub const CELL: f32 = 52.0;
+pub const LABEL_WIDTH: f32 = 48.0;
+
+// Read-only circuit drawing, one column per moment
+pub struct CircuitDiagram<'a> {
+    circuit: &'a Circuit,
+    // Moments before this one are shaded as already applied
+    applied: usize,
+}
+
+impl<'a> CircuitDiagram<'a> {
+    pub fn new(circuit: &'a Circuit) -> Self {
+        Self { circuit, applied: 0 }
+    }
+
+    pub fn applied(mut self, applied: usize) -> Self {
+        self.applied = applied;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let n = self.circuit.num_qubits();
+        let moments = self.circuit.moments();
+        let size = egui::vec2(LABEL_WIDTH + CELL * (moments.len() as f32 + 1.0), CELL * n as f32);
+        let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+        let rect = response.rect;
+        paint_wires(&painter, rect, n, self.applied);
+
+        let wire_y = |row: usize| rect.top() + CELL * (row as f32 + 0.5);
+        for (column, moment) in moments.iter().enumerate() {
+            let x = rect.left() + LABEL_WIDTH + CELL * (column as f32 + 0.5);
+            let color = if column < self.applied {
+                egui::Color32::from_rgb(100, 160, 240).gamma_multiply(0.6)
+            } else {
+                egui::Color32::from_rgb(100, 160, 240)
+            };
+            for &index in moment {
+                paint_instruction(&painter, &self.circuit.instructions()[index], x, &wire_y, color);
+            }
+        }
+        response
+    }
+}
+
+// Background, qubit labels and wires; the first `applied` columns are shaded
+pub fn paint_wires(painter: &egui::Painter, rect: egui::Rect, num_qubits: usize, applied: usize) {
+    painter.rect_filled(rect, 6.0, egui::Color32::from_rgb(22, 22, 30));
+    if applied > 0 {
+        let shaded = egui::Rect::from_min_max(
+            egui::pos2(rect.left() + LABEL_WIDTH, rect.top()),
+            egui::pos2(rect.left() + LABEL_WIDTH + CELL * applied as f32, rect.bottom()),
+        );
+        painter.rect_filled(shaded, 0.0, egui::Color32::from_rgba_unmultiplied(100, 120, 255, 25));
+    }
+
+    for row in 0..num_qubits {
+        let y = rect.top() + CELL * (row as f32 + 0.5);
+        painter.text(
+            egui::pos2(rect.left() + 12.0, y),
+            egui::Align2::LEFT_CENTER,
+            format!("q{}", row),
+            egui::FontId::monospace(14.0),
+            egui::Color32::from_rgb(160, 170, 210),
+        );
+        painter.line_segment(
+            [egui::pos2(rect.left() + LABEL_WIDTH - 8.0, y), egui::pos2(rect.right() - 8.0, y)],
+            egui::Stroke::new(1.5, egui::Color32::from_rgb(90, 90, 120)),
+        );
+    }
+}
+
+pub fn paint_instruction(
+    painter: &egui::Painter,
+    instruction: &Instruction,
+    x: f32,
+    wire_y: &dyn Fn(usize) -> f32,
+    color: egui::Color32,
+) {
+    let boxed = |qubit: usize, text: &str| {
+        let center = egui::pos2(x, wire_y(qubit));
+        let body = egui::Rect::from_center_size(center, egui::vec2(CELL - 12.0, CELL - 12.0));
+        painter.rect_filled(body, 4.0, color);
+        painter.text(center, egui::Align2::CENTER_CENTER, text, egui::FontId::proportional(13.0), egui::Color32::BLACK);
+    };
+    match instruction {
+        Instruction::Gate(operation) => paint_operation(painter, operation, x, wire_y, color),
+        Instruction::Measure { qubit, clbit } => boxed(*qubit, &format!("M{}", clbit)),
+        Instruction::Reset(qubit) => boxed(*qubit, "|0⟩"),
+        Instruction::Barrier(qubits) => {
+            let lo = qubits.iter().copied().min().unwrap_or(0);
+            let hi = qubits.iter().copied().max().unwrap_or(0);
+            let top = wire_y(lo) - CELL / 2.0 + 4.0;
+            let bottom = wire_y(hi) + CELL / 2.0 - 4.0;
+            let stroke = egui::Stroke::new(2.0, color.gamma_multiply(0.6));
+            let mut y = top;
+            while y < bottom {
+                painter.line_segment([egui::pos2(x, y), egui::pos2(x, (y + 6.0).min(bottom))], stroke);
+                y += 10.0;
+            }
+        }
+        Instruction::Conditional { instruction, .. } => {
+            paint_instruction(painter, instruction, x, wire_y, color.gamma_multiply(0.8));
+            let top = instruction.qubits().iter().copied().min().unwrap_or(0);
+            painter.text(
+                egui::pos2(x, wire_y(top) - CELL / 2.0 + 1.0),
+                egui::Align2::CENTER_TOP,
+                "if",
+                egui::FontId::monospace(9.0),
+                egui::Color32::from_rgb(255, 200, 100),
+            );
+        }
+        // Blocks run as one step, so they are drawn as one box over their wires
+        Instruction::If { .. } | Instruction::While { .. } => {
+            let qubits = instruction.qubits();
+            let lo = qubits.iter().copied().min().unwrap_or(0);
+            let hi = qubits.iter().copied().max().unwrap_or(0);
+            let half = (CELL - 12.0) / 2.0;
+            let body = egui::Rect::from_min_max(egui::pos2(x - half, wire_y(lo) - half), egui::pos2(x + half, wire_y(hi) + half));
+            let keyword = if matches!(instruction, Instruction::If { .. }) { "if" } else { "while" };
+            painter.rect_stroke(body, 4.0, egui::Stroke::new(2.0, color), egui::StrokeKind::Inside);
+            painter.text(body.center(), egui::Align2::CENTER_CENTER, keyword, egui::FontId::proportional(13.0), color);
+        }
+    }
+}
+
+pub fn paint_operation(
+    painter: &egui::Painter,
+    operation: &Operation,
+    x: f32,
+    wire_y: &dyn Fn(usize) -> f32,
+    color: egui::Color32,
+) {
+    let qubits = operation.qubits();
+    let lo = qubits.iter().copied().min().unwrap_or(0);
+    let hi = qubits.iter().copied().max().unwrap_or(0);
+    let stroke = egui::Stroke::new(2.0, color);
+    if lo != hi {
+        painter.line_segment([egui::pos2(x, wire_y(lo)), egui::pos2(x, wire_y(hi))], stroke);
+    }
+    for &control in &operation.controls {
+        painter.circle_filled(egui::pos2(x, wire_y(control)), 6.0, color);
+    }
+    for &target in &operation.targets {
+        let center = egui::pos2(x, wire_y(target));
+        match operation.gate {
+            Gate::X if !operation.controls.is_empty() => {
+                painter.circle_stroke(center, 12.0, stroke);
+                painter.line_segment([center - egui::vec2(12.0, 0.0), center + egui::vec2(12.0, 0.0)], stroke);
+                painter.line_segment([center - egui::vec2(0.0, 12.0), center + egui::vec2(0.0, 12.0)], stroke);
+            }
+            Gate::Swap => {
+                let d = 7.0;
+                painter.line_segment([center + egui::vec2(-d, -d), center + egui::vec2(d, d)], stroke);
+                painter.line_segment([center + egui::vec2(-d, d), center + egui::vec2(d, -d)], stroke);
+            }
+            _ => {
+                let body = egui::Rect::from_center_size(center, egui::vec2(CELL - 12.0, CELL - 12.0));
+                painter.rect_filled(body, 4.0, color);
+                painter.text(
+                    center,
+                    egui::Align2::CENTER_CENTER,
+                    operation.gate.name(),
+                    egui::FontId::proportional(14.0),
+                    egui::Color32::BLACK,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::state_vector::basis_label;
+use eframe::egui;
+
+// More than 32 x 32 bars turns into noise at any sensible size
+const MAX_QUBITS: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CityPart {
+    Real,
+    Imaginary,
+}
+
+// Isometric 3-D bar per density-matrix entry (rows toward the left, columns
+// toward the right); bars below the floor are negative
+pub struct CityPlot<'a> {
+    rho: &'a DensityMatrix,
+    part: CityPart,
+    size: f32,
+}
+
+impl<'a> CityPlot<'a> {
+    pub fn new(rho: &'a DensityMatrix, part: CityPart) -> Self {
+        Self {
+            rho,
+            part,
+            size: 320.0,
+        }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let n = self.rho.num_qubits();
+        let (response, painter) = ui.allocate_painter(egui::vec2(self.size, self.size * 0.9), egui::Sense::hover());
+        let rect = response.rect;
+        if n > MAX_QUBITS {
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("City plots stop at {} qubits", MAX_QUBITS),
+                egui::FontId::proportional(13.0),
+                egui::Color32::from_rgb(140, 140, 160),
+            );
+            return response;
+        }
+
+        let d = 1 << n;
+        let matrix = self.rho.matrix();
+        let value = |i: usize, j: usize| match self.part {
+            CityPart::Real => matrix[[i, j]].re,
+            CityPart::Imaginary => matrix[[i, j]].im,
+        };
+        let largest = (0..d).flat_map(|i| (0..d).map(move |j| (i, j))).map(|(i, j)| value(i, j).abs()).fold(0.0, f64::max);
+        let scale = largest.max(0.25) as f32;
+
+        // x = column, y = row, z = value; the floor diamond fills the lower half
+        let cos30 = 3f32.sqrt() / 2.0;
+        let step = 0.9 * self.size / (2.0 * d as f32 * cos30);
+        let bar_height = rect.height() * 0.3;
+        let floor_top = rect.top() + bar_height + 14.0;
+        let project = |x: f32, y: f32, z: f32| {
+            egui::pos2(
+                rect.center().x + (x - y) * cos30 * step,
+                floor_top + (x + y) * 0.5 * step - z / scale * bar_height,
+            )
+        };
+
+        let grid = egui::Stroke::new(1.0, egui::Color32::from_rgb(45, 45, 65));
+        for k in 0..=d {
+            let k = k as f32;
+            painter.line_segment([project(k, 0.0, 0.0), project(k, d as f32, 0.0)], grid);
+            painter.line_segment([project(0.0, k, 0.0), project(d as f32, k, 0.0)], grid);
+        }
+
+        // Back to front so nearer bars cover farther ones
+        let mut cells: Vec<(usize, usize)> = (0..d).flat_map(|i| (0..d).map(move |j| (i, j))).collect();
+        cells.sort_by_key(|&(i, j)| i + j);
+        let (positive, negative) = (egui::Color32::from_rgb(100, 200, 255), egui::Color32::from_rgb(255, 150, 90));
+        for (i, j) in cells {
+            let v = value(i, j) as f32;
+            if v.abs() < 1e-6 {
+                continue;
+            }
+            let color = if v > 0.0 { positive } else { negative };
+            let (x0, x1, y0, y1) = (j as f32 + 0.15, j as f32 + 0.85, i as f32 + 0.15, i as f32 + 0.85);
+            let (top, bottom) = (v.max(0.0), v.min(0.0));
+            let outline = egui::Stroke::new(0.5, egui::Color32::from_rgb(20, 20, 28));
+
+            // Front faces facing +y (left) and +x (right), then the lid
+            painter.add(egui::Shape::convex_polygon(
+                vec![project(x0, y1, top), project(x1, y1, top), project(x1, y1, bottom), project(x0, y1, bottom)],
+                color.gamma_multiply(0.55),
+                outline,
+            ));
+            painter.add(egui::Shape::convex_polygon(
+                vec![project(x1, y0, top), project(x1, y1, top), project(x1, y1, bottom), project(x1, y0, bottom)],
+                color.gamma_multiply(0.75),
+                outline,
+            ));
+            painter.add(egui::Shape::convex_polygon(
+                vec![project(x0, y0, top), project(x1, y0, top), project(x1, y1, top), project(x0, y1, top)],
+                color,
+                outline,
+            ));
+        }
+
+        // Basis labels along the two front edges
+        if d <= 8 {
+            let label_color = egui::Color32::from_rgb(140, 150, 190);
+            for k in 0..d {
+                let label = basis_label(k, n);
+                painter.text(
+                    project(k as f32 + 0.5, d as f32 + 0.4, 0.0),
+                    egui::Align2::LEFT_TOP,
+                    &label,
+                    egui::FontId::monospace(10.0),
+                    label_color,
+                );
+                painter.text(
+                    project(d as f32 + 0.4, k as f32 + 0.5, 0.0),
+                    egui::Align2::RIGHT_TOP,
+                    &label,
+                    egui::FontId::monospace(10.0),
+                    label_color,
+                );
+            }
+        }
+
+        let title = match self.part {
+            CityPart::Real => "Re ρ",
+            CityPart::Imaginary => "Im ρ",
+        };
+        painter.text(
+            rect.left_top() + egui::vec2(4.0, 2.0),
+            egui::Align2::LEFT_TOP,
+            title,
+            egui::FontId::proportional(13.0),
+            egui::Color32::from_rgb(180, 190, 230),
+        );
+        response.on_hover_text(format!("Tallest bar {:.3}; orange bars are negative", largest))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::core::hybrid::dataset::LabeledPoint;
+use eframe::egui;
+use ndarray::Array2;
+
+// Classifier output over a square of inputs [-extent, extent]², painted
+// as a heatmap with the labelled points on top. Values are P(class 1),
+// so 0.5 is the boundary; row 0 of the grid is the top edge.
+pub struct DecisionBoundary<'a> {
+    values: &'a Array2<f64>,
+    points: &'a [LabeledPoint],
+    extent: f64,
+    predicted: Option<&'a [bool]>,
+    highlighted: &'a [usize],
+    size: f32,
+}
+
+// Blue (class 0) → dark → amber (class 1)
+pub fn class_color(p: f64) -> egui::Color32 {
+    let t = p.clamp(0.0, 1.0) as f32;
+    let (r, g, b) = if t < 0.5 {
+        let s = 2.0 * t;
+        (30.0 + 10.0 * s, 70.0 - 40.0 * s, 120.0 - 80.0 * s)
+    } else {
+        let s = 2.0 * t - 1.0;
+        (40.0 + 100.0 * s, 30.0 + 70.0 * s, 40.0)
+    };
+    egui::Color32::from_rgb(r as u8, g as u8, b as u8)
+}
+
+impl<'a> DecisionBoundary<'a> {
+    pub fn new(values: &'a Array2<f64>, points: &'a [LabeledPoint], extent: f64) -> Self {
+        Self {
+            values,
+            points,
+            extent,
+            predicted: None,
+            highlighted: &[],
+            size: 320.0,
+        }
+    }
+
+    // The cell centres, one (x, y) row each in the grid's row-major order
+    pub fn grid(resolution: usize, extent: f64) -> Array2<f64> {
+        let coordinate = |k: usize| -extent + 2.0 * extent * (k as f64 + 0.5) / resolution as f64;
+        Array2::from_shape_fn((resolution * resolution, 2), |(i, j)| {
+            if j == 0 { coordinate(i % resolution) } else { -coordinate(i / resolution) }
+        })
+    }
+
+    // Predicted labels of the points; wrong ones get a pink ring
+    pub fn predicted(mut self, predicted: &'a [bool]) -> Self {
+        self.predicted = Some(predicted);
+        self
+    }
+
+    // Points to outline in white, e.g. support vectors
+    pub fn highlighted(mut self, highlighted: &'a [usize]) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let (response, painter) = ui.allocate_painter(egui::vec2(self.size, self.size), egui::Sense::hover());
+        let rect = response.rect;
+        let (rows, cols) = self.values.dim();
+        let cell = egui::vec2(self.size / cols.max(1) as f32, self.size / rows.max(1) as f32);
+        for ((row, col), &p) in self.values.indexed_iter() {
+            let min = rect.min + egui::vec2(cell.x * col as f32, cell.y * row as f32);
+            painter.rect_filled(egui::Rect::from_min_size(min, cell + egui::vec2(0.5, 0.5)), 0.0, class_color(p));
+        }
+
+        let to_screen = |x: f64, y: f64| {
+            egui::pos2(
+                rect.left() + self.size * ((x + self.extent) / (2.0 * self.extent)) as f32,
+                rect.top() + self.size * ((self.extent - y) / (2.0 * self.extent)) as f32,
+            )
+        };
+        for (i, point) in self.points.iter().enumerate() {
+            let fill = if point.label {
+                egui::Color32::from_rgb(255, 200, 100)
+            } else {
+                egui::Color32::from_rgb(100, 200, 255)
+            };
+            let center = to_screen(point.x, point.y);
+            painter.circle_filled(center, 5.0, fill);
+            if self.highlighted.contains(&i) {
+                painter.circle_stroke(center, 5.0, egui::Stroke::new(1.5, egui::Color32::WHITE));
+            }
+            if let Some(predicted) = self.predicted
+                && predicted[i] != point.label {
+                painter.circle_stroke(center, 8.0, egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 100, 150)));
+            }
+        }
+
+        if let Some(pos) = response.hover_pos() {
+            let col = (((pos.x - rect.left()) / cell.x) as usize).min(cols.saturating_sub(1));
+            let row = (((pos.y - rect.top()) / cell.y) as usize).min(rows.saturating_sub(1));
+            if let Some(p) = self.values.get((row, col)) {
+                return response.on_hover_text(format!("P(class 1) = {:.3}", p));
+            }
+        }
+        response
+    }
+}
@@ -0,0 +1,76 @@
+pub mod amplitude_bars;
+pub mod bloch_sphere;
+pub mod circuit_diagram;
+pub mod city_plot;
+pub mod decision_boundary;
+pub mod phase_disks;
+pub mod probability_bars;
+pub mod q_sphere;
+
+use crate::app::widgets::amplitude_bars::AmplitudeBars;
+use crate::app::widgets::city_plot::{CityPart, CityPlot};
+use crate::app::widgets::phase_disks::PhaseDisks;
+use crate::app::widgets::q_sphere::QSphere;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::state_vector::StateVector;
+use eframe::egui;
+
+// Maps a complex phase in (-π, π] onto the colour wheel (0 = red)
+pub fn phase_color(phase: f64) -> egui::Color32 {
+    let hue = (phase / std::f64::consts::TAU).rem_euclid(1.0) as f32;
+    egui::ecolor::Hsva::new(hue, 0.75, 0.95, 1.0).into()
+}
+
+// The pure-state visualizations, so every view can offer the same picker
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StateVisual {
+    Amplitudes,
+    PhaseDisks,
+    QSphere,
+    City,
+}
+
+impl StateVisual {
+    pub const ALL: [StateVisual; 4] = [StateVisual::Amplitudes, StateVisual::PhaseDisks, StateVisual::QSphere, StateVisual::City];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StateVisual::Amplitudes => "Amplitudes",
+            StateVisual::PhaseDisks => "Phase disks",
+            StateVisual::QSphere => "Q-sphere",
+            StateVisual::City => "City",
+        }
+    }
+
+    // Row of toggle buttons; returns true when the choice changed
+    pub fn picker(&mut self, ui: &mut egui::Ui) -> bool {
+        let before = *self;
+        ui.horizontal(|ui| {
+            for visual in StateVisual::ALL {
+                ui.selectable_value(self, visual, visual.name());
+            }
+        });
+        *self != before
+    }
+
+    // `size` is the plot height, sphere diameter or city width
+    pub fn show(self, ui: &mut egui::Ui, id_salt: &str, state: &StateVector, size: f32) {
+        match self {
+            StateVisual::Amplitudes => AmplitudeBars::new(id_salt, state).height(size).show(ui),
+            StateVisual::PhaseDisks => PhaseDisks::new(state).show(ui),
+            StateVisual::QSphere => {
+                QSphere::new(state).size(size).show(ui);
+            }
+            StateVisual::City => show_city(ui, &DensityMatrix::from_state_vector(state), size),
+        }
+    }
+}
+
+// Real and imaginary city plots side by side
+pub fn show_city(ui: &mut egui::Ui, rho: &DensityMatrix, size: f32) {
+    ui.horizontal(|ui| {
+        CityPlot::new(rho, CityPart::Real).size(size).show(ui);
+        CityPlot::new(rho, CityPart::Imaginary).size(size).show(ui);
+    });
+}
+
@@ -0,0 +1,56 @@
+use crate::app::widgets::phase_color;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::state_vector::{StateVector, basis_label};
+use eframe::egui;
+
+const RADIUS: f32 = 24.0;
+
+// One disk per basis state, as in Quirk: the filled radius is |amplitude|
+// and the hand points along its phase (counter-clockwise from the right)
+pub struct PhaseDisks<'a> {
+    state: &'a StateVector,
+}
+
+impl<'a> PhaseDisks<'a> {
+    pub fn new(state: &'a StateVector) -> Self {
+        Self { state }
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) {
+        let n = self.state.num_qubits();
+        let r = RADIUS;
+        ui.horizontal_wrapped(|ui| {
+            for (index, amplitude) in self.state.amplitudes().iter().enumerate() {
+                let (rect, response) = ui.allocate_exact_size(egui::vec2(2.0 * r + 12.0, 2.0 * r + 28.0), egui::Sense::hover());
+                let painter = ui.painter_at(rect);
+                let center = egui::pos2(rect.center().x, rect.top() + r + 4.0);
+
+                painter.circle_filled(center, r, egui::Color32::from_rgb(24, 24, 34));
+                let magnitude = amplitude.norm() as f32;
+                if magnitude > 1e-6 {
+                    let phase = amplitude.arg();
+                    painter.circle_filled(center, r * magnitude, phase_color(phase));
+                    let hand = center + egui::vec2(phase.cos() as f32, -phase.sin() as f32) * r;
+                    painter.line_segment([center, hand], egui::Stroke::new(2.0, egui::Color32::WHITE));
+                }
+                painter.circle_stroke(center, r, egui::Stroke::new(1.0, egui::Color32::from_rgb(90, 90, 130)));
+                painter.text(
+                    egui::pos2(center.x, rect.bottom() - 10.0),
+                    egui::Align2::CENTER_CENTER,
+                    basis_label(index, n),
+                    egui::FontId::monospace(11.0),
+                    egui::Color32::from_rgb(160, 170, 210),
+                );
+
+                response.on_hover_text(format!(
+                    "{}\namplitude {:+.3}{:+.3}i\nprobability {:.3}\nphase {:+.3}π",
+                    basis_label(index, n),
+                    amplitude.re,
+                    amplitude.im,
+                    amplitude.norm_sqr(),
+                    amplitude.arg() / std::f64::consts::PI
+                ));
+            }
+        });
+    }
+}
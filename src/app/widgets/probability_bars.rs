@@ -0,0 +1,58 @@
+use crate::core::quantum::state_vector::basis_label;
+use eframe::egui;
+use egui_plot::{Bar, BarChart, Plot};
+
+// Measurement probability per computational basis state
+pub struct ProbabilityBars<'a> {
+    id_salt: &'a str,
+    probabilities: &'a [f64],
+    num_qubits: usize,
+    height: f32,
+}
+
+impl<'a> ProbabilityBars<'a> {
+    pub fn new(id_salt: &'a str, probabilities: &'a [f64], num_qubits: usize) -> Self {
+        Self {
+            id_salt,
+            probabilities,
+            num_qubits,
+            height: 260.0,
+        }
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) {
+        let n = self.num_qubits;
+        let bars: Vec<Bar> = self.probabilities
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| Bar::new(i as f64, p)
+                .name(basis_label(i, n))
+                .width(0.7)
+                .fill(egui::Color32::from_rgb(100, 200, 255)))
+            .collect();
+
+        Plot::new(self.id_salt)
+            .height(self.height)
+            .include_y(0.0)
+            .include_y(1.0)
+            .allow_drag(false)
+            .allow_zoom(false)
+            .allow_scroll(false)
+            .x_axis_formatter(move |mark, _| {
+                let i = mark.value.round();
+                if (mark.value - i).abs() < 1e-6 && i >= 0.0 && (i as usize) < (1 << n) {
+                    basis_label(i as usize, n)
+                } else {
+                    String::new()
+                }
+            })
+            .show(ui, |plot_ui| {
+                plot_ui.bar_chart(BarChart::new("Probability", bars));
+            });
+    }
+}
@@ -0,0 +1,111 @@
+use crate::app::widgets::phase_color;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::state_vector::{StateVector, basis_label};
+use eframe::egui;
+
+// Same oblique camera as the Bloch sphere
+const AZIMUTH: f32 = 0.55;
+const ELEVATION: f32 = 0.35;
+
+// Basis states on one sphere: latitude by Hamming weight (|0...0⟩ at the
+// north pole, |1...1⟩ at the south), spread evenly in longitude; node size
+// is the probability and colour the phase
+pub struct QSphere<'a> {
+    state: &'a StateVector,
+    size: f32,
+}
+
+impl<'a> QSphere<'a> {
+    pub fn new(state: &'a StateVector) -> Self {
+        Self { state, size: 280.0 }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+        let n = self.state.num_qubits();
+        let (response, painter) = ui.allocate_painter(egui::vec2(self.size, self.size), egui::Sense::hover());
+        let center = response.rect.center();
+        let radius = self.size * 0.38;
+
+        // Screen position and depth (positive faces the camera)
+        let project = |p: [f32; 3]| -> (egui::Pos2, f32) {
+            let (sa, ca) = AZIMUTH.sin_cos();
+            let (se, ce) = ELEVATION.sin_cos();
+            let u = -p[0] * sa + p[1] * ca;
+            let v = p[2] * ce - (p[0] * ca + p[1] * sa) * se;
+            let depth = (p[0] * ca + p[1] * sa) * ce + p[2] * se;
+            (egui::pos2(center.x + u * radius, center.y - v * radius), depth)
+        };
+
+        let front = egui::Color32::from_rgb(90, 90, 130);
+        let back = egui::Color32::from_rgb(45, 45, 65);
+        painter.circle_filled(center, radius, egui::Color32::from_rgb(24, 24, 34));
+        painter.circle_stroke(center, radius, egui::Stroke::new(1.5, front));
+
+        // One latitude ring per Hamming weight
+        for weight in 1..n {
+            let theta = std::f32::consts::PI * weight as f32 / n as f32;
+            let points: Vec<(egui::Pos2, f32)> = (0..=64)
+                .map(|i| {
+                    let phi = i as f32 / 64.0 * std::f32::consts::TAU;
+                    project([theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()])
+                })
+                .collect();
+            for pair in points.windows(2) {
+                let color = if pair[0].1 >= 0.0 { front } else { back };
+                painter.line_segment([pair[0].0, pair[1].0], egui::Stroke::new(1.0, color));
+            }
+        }
+
+        // Node positions, painted back to front
+        let mut by_weight: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+        for index in 0..1usize << n {
+            by_weight[index.count_ones() as usize].push(index);
+        }
+        let mut nodes: Vec<(usize, egui::Pos2, f32)> = Vec::new();
+        for (weight, states) in by_weight.iter().enumerate() {
+            let theta = std::f32::consts::PI * weight as f32 / n.max(1) as f32;
+            for (k, &index) in states.iter().enumerate() {
+                let phi = std::f32::consts::TAU * k as f32 / states.len() as f32;
+                let (pos, depth) = project([theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()]);
+                nodes.push((index, pos, depth));
+            }
+        }
+        nodes.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+        let amplitudes = self.state.amplitudes();
+        let mut hover = Vec::new();
+        for (index, pos, depth) in nodes {
+            let amplitude = amplitudes[index];
+            let probability = amplitude.norm_sqr() as f32;
+            if probability < 1e-6 {
+                painter.circle_filled(pos, 2.0, if depth >= 0.0 { front } else { back });
+                continue;
+            }
+            let mut color = phase_color(amplitude.arg());
+            if depth < 0.0 {
+                color = color.gamma_multiply(0.6);
+            }
+            painter.line_segment([center, pos], egui::Stroke::new(1.0 + 2.0 * probability, color));
+            painter.circle_filled(pos, 3.0 + 12.0 * probability.sqrt(), color);
+            painter.text(
+                pos + egui::vec2(0.0, -10.0 - 12.0 * probability.sqrt()),
+                egui::Align2::CENTER_BOTTOM,
+                basis_label(index, n),
+                egui::FontId::monospace(10.0),
+                egui::Color32::from_rgb(200, 200, 230),
+            );
+            hover.push(format!(
+                "{}  p = {:.3}, phase {:+.3}π",
+                basis_label(index, n),
+                probability,
+                amplitude.arg() / std::f64::consts::PI
+            ));
+        }
+        response.on_hover_text(hover.join("\n"))
+    }
+}
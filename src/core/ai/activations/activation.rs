@@ -0,0 +1,124 @@
+/*
+--------------------------------------------------------------------
+                        Activations
+                        -----------
+Notes
+-----
+
+- element-wise non-linearities for Dense layers
+- derivatives are taken with respect to the pre-activation z, so the
+  backward pass only needs the cached W x + b
+- LeakyReLU uses slope 0.01 below zero, ELU uses alpha = 1, SELU uses
+  the self-normalising constants from Klambauer et al. (2017)
+- GELU is the tanh approximation used by BERT and GPT-2 (there is no
+  erf in std)
+- sigmoid and softplus are written to stay finite for large |z|
+
+--------------------------------------------------------------------
+*/
+
+use std::f64::consts::PI;
+
+const LEAKY_SLOPE: f64 = 0.01;
+const ELU_ALPHA: f64 = 1.0;
+const SELU_ALPHA: f64 = 1.673_263_242_354_377_3;
+const SELU_SCALE: f64 = 1.050_700_987_355_480_5;
+const GELU_CUBIC: f64 = 0.044_715;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Activation {
+    Linear,
+    ReLU,
+    LeakyReLU,
+    Elu,
+    Selu,
+    Gelu,
+    Swish,
+    Softplus,
+    Sigmoid,
+    Tanh,
+}
+
+pub fn sigmoid(z: f64) -> f64 {
+    if z >= 0.0 {
+        1.0 / (1.0 + (-z).exp())
+    } else {
+        let e = z.exp();
+        e / (1.0 + e)
+    }
+}
+
+impl Activation {
+    pub const ALL: [Activation; 10] = [
+        Activation::Linear,
+        Activation::ReLU,
+        Activation::LeakyReLU,
+        Activation::Elu,
+        Activation::Selu,
+        Activation::Gelu,
+        Activation::Swish,
+        Activation::Softplus,
+        Activation::Sigmoid,
+        Activation::Tanh,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Activation::Linear => "Linear",
+            Activation::ReLU => "ReLU",
+            Activation::LeakyReLU => "LeakyReLU",
+            Activation::Elu => "ELU",
+            Activation::Selu => "SELU",
+            Activation::Gelu => "GELU",
+            Activation::Swish => "Swish / SiLU",
+            Activation::Softplus => "Softplus",
+            Activation::Sigmoid => "Sigmoid",
+            Activation::Tanh => "Tanh",
+        }
+    }
+
+    pub fn apply(self, z: f64) -> f64 {
+        match self {
+            Activation::Linear => z,
+            Activation::ReLU => z.max(0.0),
+            Activation::LeakyReLU => if z > 0.0 { z } else { LEAKY_SLOPE * z },
+            Activation::Elu => if z > 0.0 { z } else { ELU_ALPHA * z.exp_m1() },
+            Activation::Selu => SELU_SCALE * if z > 0.0 { z } else { SELU_ALPHA * z.exp_m1() },
+            Activation::Gelu => 0.5 * z * (1.0 + Self::gelu_inner(z).tanh()),
+            Activation::Swish => z * sigmoid(z),
+            Activation::Softplus => z.max(0.0) + (-z.abs()).exp().ln_1p(),
+            Activation::Sigmoid => sigmoid(z),
+            Activation::Tanh => z.tanh(),
+        }
+    }
+
+    pub fn derivative(self, z: f64) -> f64 {
+        match self {
+            Activation::Linear => 1.0,
+            Activation::ReLU => if z > 0.0 { 1.0 } else { 0.0 },
+            Activation::LeakyReLU => if z > 0.0 { 1.0 } else { LEAKY_SLOPE },
+            Activation::Elu => if z > 0.0 { 1.0 } else { ELU_ALPHA * z.exp() },
+            Activation::Selu => SELU_SCALE * if z > 0.0 { 1.0 } else { SELU_ALPHA * z.exp() },
+            Activation::Gelu => {
+                let t = Self::gelu_inner(z).tanh();
+                let inner_slope = (2.0 / PI).sqrt() * (1.0 + 3.0 * GELU_CUBIC * z * z);
+                0.5 * (1.0 + t) + 0.5 * z * (1.0 - t * t) * inner_slope
+            }
+            Activation::Swish => {
+                let s = sigmoid(z);
+                s + z * s * (1.0 - s)
+            }
+            Activation::Softplus => sigmoid(z),
+            Activation::Sigmoid => {
+                let s = sigmoid(z);
+                s * (1.0 - s)
+            }
+            Activation::Tanh => 1.0 - z.tanh().powi(2),
+        }
+    }
+
+    // sqrt(2/π) (z + 0.044715 z³)
+    fn gelu_inner(z: f64) -> f64 {
+        (2.0 / PI).sqrt() * (z + GELU_CUBIC * z * z * z)
+    }
+}
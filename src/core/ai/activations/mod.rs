@@ -0,0 +1,51 @@
+pub mod activation;
+pub mod softmax;
+
+#[cfg(test)]
+mod tests {
+    use super::activation::Activation;
+    use super::softmax::{softmax, softmax_jacobian};
+    use ndarray::{Array1, array};
+
+    const STEP: f64 = 1e-6;
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        for activation in Activation::ALL {
+            // Away from the kinks at 0
+            for z in [-6.0, -2.5, -0.7, 0.3, 1.1, 4.0, 40.0, -40.0] {
+                let numerical = (activation.apply(z + STEP) - activation.apply(z - STEP)) / (2.0 * STEP);
+                let analytic = activation.derivative(z);
+                assert!((numerical - analytic).abs() < 1e-5, "{} at {}: {} vs {}", activation.name(), z, analytic, numerical);
+                assert!(activation.apply(z).is_finite());
+            }
+        }
+        assert_eq!(Activation::ReLU.apply(-2.0), 0.0);
+        assert!((Activation::Selu.apply(-50.0) + 1.0507 * 1.6733).abs() < 1e-3);
+        assert!((Activation::Gelu.apply(1.0) - 0.8412).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_softmax() {
+        let logits = array![1.0, 2.0, 0.5, -1.0];
+        let s = softmax(logits.view(), 1.0);
+        assert!((s.sum() - 1.0).abs() < 1e-12);
+        assert!(s[1] > s[0] && s[0] > s[2] && s[2] > s[3]);
+        // Huge logits are fine, and temperature flattens or sharpens
+        assert!(softmax(array![1000.0, 999.0].view(), 1.0).iter().all(|p| p.is_finite()));
+        assert!(softmax(logits.view(), 10.0)[1] < s[1]);
+        assert!(softmax(logits.view(), 0.1)[1] > 0.99);
+
+        let jacobian = softmax_jacobian(&s);
+        for j in 0..logits.len() {
+            let mut plus = logits.clone();
+            plus[j] += STEP;
+            let mut minus = logits.clone();
+            minus[j] -= STEP;
+            let column: Array1<f64> = (softmax(plus.view(), 1.0) - softmax(minus.view(), 1.0)) / (2.0 * STEP);
+            for i in 0..logits.len() {
+                assert!((column[i] - jacobian[[i, j]]).abs() < 1e-6);
+            }
+        }
+    }
+}
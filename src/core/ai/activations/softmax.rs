@@ -0,0 +1,33 @@
+/*
+--------------------------------------------------------------------
+                        Softmax
+                        -------
+Notes
+-----
+
+- turns a vector of logits into probabilities, exp(z_i / T) / Σ exp(z_j / T)
+- unlike the element-wise activations every output depends on every
+  input, so its derivative is a Jacobian, diag(s) - s sᵀ (for T = 1)
+- the largest logit is subtracted first so exp never overflows
+- temperature T > 1 flattens the distribution, T < 1 sharpens it
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array1, Array2, ArrayView1};
+
+pub fn softmax(logits: ArrayView1<f64>, temperature: f64) -> Array1<f64> {
+    let largest = logits.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let exponentials = logits.mapv(|z| ((z - largest) / temperature).exp());
+    let total = exponentials.sum();
+    exponentials / total
+}
+
+// ds_i / dz_j at T = 1, given the softmax output s
+pub fn softmax_jacobian(probabilities: &Array1<f64>) -> Array2<f64> {
+    let n = probabilities.len();
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        let diagonal = if i == j { probabilities[i] } else { 0.0 };
+        diagonal - probabilities[i] * probabilities[j]
+    })
+}
@@ -0,0 +1,120 @@
+pub mod multi_head;
+pub mod positional;
+pub mod scaled_dot_product;
+
+#[cfg(test)]
+mod tests {
+    use super::multi_head::MultiHeadAttention;
+    use super::positional::sinusoidal_encoding;
+    use super::scaled_dot_product::{causal_mask, scaled_dot_product_attention};
+    use ndarray::{Array2, array, s};
+
+    fn tokens() -> Array2<f64> {
+        array![
+            [0.5, -1.0, 0.3, 0.8],
+            [1.2, 0.1, -0.4, 0.0],
+            [-0.7, 0.9, 0.6, -0.2],
+            [0.2, 0.4, -1.1, 0.7],
+        ]
+    }
+
+    #[test]
+    fn test_scaled_dot_product_attention() {
+        let x = tokens();
+        let attention = scaled_dot_product_attention(x.view(), x.view(), x.view(), None, 1.0);
+        for row in attention.weights.rows() {
+            assert!((row.sum() - 1.0).abs() < 1e-12);
+        }
+        // Every token matches itself best
+        for i in 0..4 {
+            let best = attention.weights.row(i).iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap().0;
+            assert_eq!(best, i);
+        }
+        assert!((attention.scores[[0, 1]] - x.row(0).dot(&x.row(1)) / 2.0).abs() < 1e-12);
+
+        // Identical keys give uniform weights, so the output is the mean value
+        let keys = Array2::ones((4, 4));
+        let uniform = scaled_dot_product_attention(x.view(), keys.view(), x.view(), None, 1.0);
+        let mean = x.mean_axis(ndarray::Axis(0)).unwrap();
+        for row in uniform.output.rows() {
+            assert!((&row - &mean).iter().all(|d| d.abs() < 1e-12));
+        }
+
+        // Low temperature approaches a hard lookup
+        let sharp = scaled_dot_product_attention(x.view(), x.view(), x.view(), None, 0.01);
+        assert!(sharp.weights[[1, 1]] > 0.999);
+    }
+
+    #[test]
+    fn test_causal_mask() {
+        let x = tokens();
+        let mask = causal_mask(4);
+        let attention = scaled_dot_product_attention(x.view(), x.view(), x.view(), Some(&mask), 1.0);
+        for i in 0..4 {
+            assert!((attention.weights.row(i).sum() - 1.0).abs() < 1e-12);
+            for j in i + 1..4 {
+                assert_eq!(attention.weights[[i, j]], 0.0);
+            }
+        }
+        assert_eq!(attention.weights[[0, 0]], 1.0);
+
+        // Changing the last token cannot affect the earlier outputs
+        let mut changed = x.clone();
+        changed.row_mut(3).fill(5.0);
+        let after = scaled_dot_product_attention(changed.view(), changed.view(), changed.view(), Some(&mask), 1.0);
+        assert_eq!(attention.output.slice(s![..3, ..]), after.output.slice(s![..3, ..]));
+
+        // A fully blocked query gets no weight at all
+        let blocked = Array2::from_elem((4, 4), false);
+        let none = scaled_dot_product_attention(x.view(), x.view(), x.view(), Some(&blocked), 1.0);
+        assert!(none.weights.iter().all(|w| *w == 0.0));
+    }
+
+    #[test]
+    fn test_multi_head_attention() {
+        let x = tokens();
+        let mut layer = MultiHeadAttention::new(4, 2, 7);
+        assert_eq!(layer.head_dim(), 2);
+        let forward = layer.forward(&x, None, 1.0);
+        assert_eq!(forward.heads.len(), 2);
+        assert_eq!(forward.output.dim(), (4, 4));
+
+        // Each head is plain attention over its slice of the projections
+        let columns = layer.head_columns(1);
+        let head = scaled_dot_product_attention(
+            forward.q.slice(s![.., columns.clone()]),
+            forward.k.slice(s![.., columns.clone()]),
+            forward.v.slice(s![.., columns]),
+            None,
+            1.0,
+        );
+        assert_eq!(head.weights, forward.heads[1].weights);
+
+        // One head with identity projections is scaled dot-product attention
+        layer = MultiHeadAttention::new(4, 1, 0);
+        for w in [&mut layer.w_q, &mut layer.w_k, &mut layer.w_v, &mut layer.w_o] {
+            *w = Array2::eye(4);
+        }
+        let single = layer.forward(&x, None, 1.0);
+        let direct = scaled_dot_product_attention(x.view(), x.view(), x.view(), None, 1.0);
+        assert!((&single.output - &direct.output).iter().all(|d| d.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_sinusoidal_encoding() {
+        let pe = sinusoidal_encoding(20, 8);
+        assert_eq!(pe.row(0), array![0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+        assert!((pe[[1, 0]] - 1f64.sin()).abs() < 1e-12);
+        // sin² + cos² per pair, so every row has norm √(d/2)
+        for row in pe.rows() {
+            assert!((row.dot(&row) - 4.0).abs() < 1e-12);
+        }
+        // The similarity of two positions depends only on their offset
+        for offset in 1..5 {
+            let reference = pe.row(0).dot(&pe.row(offset));
+            for p in 1..10 {
+                assert!((pe.row(p).dot(&pe.row(p + offset)) - reference).abs() < 1e-9);
+            }
+        }
+    }
+}
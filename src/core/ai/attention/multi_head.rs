@@ -0,0 +1,94 @@
+/*
+--------------------------------------------------------------------
+                        Multi-Head Attention
+                        --------------------
+Notes
+-----
+
+- Q = X W_q, K = X W_k, V = X W_v with d_model x d_model projections;
+  the columns are split into `heads` blocks of d_model / heads and each
+  block runs its own scaled dot-product attention
+- each head can learn a different pattern (previous token, same word,
+  first token, ...) at the same cost as one full-width head
+- the head outputs are concatenated back to d_model columns and mixed
+  by W_o
+- the weights are random (Xavier) here, there is no training, so the
+  patterns come from the embeddings and the projections alone
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::attention::scaled_dot_product::{Attention, scaled_dot_product_attention};
+use crate::core::ai::nn::initializer::Initializer;
+use ndarray::{Array2, Axis, s};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+pub struct MultiHeadAttention {
+    pub heads: usize,
+    pub w_q: Array2<f64>,
+    pub w_k: Array2<f64>,
+    pub w_v: Array2<f64>,
+    pub w_o: Array2<f64>,
+}
+
+// Everything a forward pass produces, for inspection
+pub struct MultiHeadOutput {
+    pub q: Array2<f64>,
+    pub k: Array2<f64>,
+    pub v: Array2<f64>,
+    pub heads: Vec<Attention>,
+    // Concatenated heads times W_o, tokens x d_model
+    pub output: Array2<f64>,
+}
+
+impl MultiHeadAttention {
+    pub fn new(d_model: usize, heads: usize, seed: u64) -> Self {
+        assert!(heads > 0 && d_model.is_multiple_of(heads), "d_model must split evenly into heads");
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut projection = || Initializer::Xavier.weights(d_model, d_model, &mut rng);
+        Self {
+            heads,
+            w_q: projection(),
+            w_k: projection(),
+            w_v: projection(),
+            w_o: projection(),
+        }
+    }
+
+    pub fn d_model(&self) -> usize {
+        self.w_q.nrows()
+    }
+
+    pub fn head_dim(&self) -> usize {
+        self.d_model() / self.heads
+    }
+
+    // Column range of head h in Q, K and V
+    pub fn head_columns(&self, head: usize) -> std::ops::Range<usize> {
+        head * self.head_dim()..(head + 1) * self.head_dim()
+    }
+
+    // x is tokens x d_model
+    pub fn forward(&self, x: &Array2<f64>, mask: Option<&Array2<bool>>, temperature: f64) -> MultiHeadOutput {
+        let q = x.dot(&self.w_q);
+        let k = x.dot(&self.w_k);
+        let v = x.dot(&self.w_v);
+        let heads: Vec<Attention> = (0..self.heads)
+            .map(|h| {
+                let columns = self.head_columns(h);
+                scaled_dot_product_attention(
+                    q.slice(s![.., columns.clone()]),
+                    k.slice(s![.., columns.clone()]),
+                    v.slice(s![.., columns]),
+                    mask,
+                    temperature,
+                )
+            })
+            .collect();
+        let views: Vec<_> = heads.iter().map(|head| head.output.view()).collect();
+        let concatenated = ndarray::concatenate(Axis(1), &views).expect("heads share the token count");
+        let output = concatenated.dot(&self.w_o);
+        MultiHeadOutput { q, k, v, heads, output }
+    }
+}
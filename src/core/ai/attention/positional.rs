@@ -0,0 +1,29 @@
+/*
+--------------------------------------------------------------------
+                        Positional Encoding
+                        -------------------
+Notes
+-----
+
+- attention itself is order-blind: permuting the tokens permutes the
+  output, so the position has to be added to the embeddings
+- sinusoidal encoding (Vaswani et al.), for position p and pair i:
+    PE[p, 2i]     = sin(p / 10000^(2i / d))
+    PE[p, 2i + 1] = cos(p / 10000^(2i / d))
+- the wavelengths grow geometrically from 2π to 10000·2π, and
+  PE[p + k] is a fixed rotation of PE[p], so PE[p] · PE[p + k] depends
+  only on the offset k
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+
+// positions x d_model
+pub fn sinusoidal_encoding(positions: usize, d_model: usize) -> Array2<f64> {
+    Array2::from_shape_fn((positions, d_model), |(p, j)| {
+        let pair = (j / 2) as f64;
+        let angle = p as f64 / 10000f64.powf(2.0 * pair / d_model as f64);
+        if j % 2 == 0 { angle.sin() } else { angle.cos() }
+    })
+}
@@ -0,0 +1,61 @@
+/*
+--------------------------------------------------------------------
+                        Scaled Dot-Product Attention
+                        ----------------------------
+Notes
+-----
+
+- Attention(Q, K, V) = softmax(Q Kᵀ / √d_k) V, one query, key and value
+  per row; row i of the weights says how much token i reads from each
+  token j
+- dividing by √d_k keeps the scores at unit variance, otherwise softmax
+  saturates as the head dimension grows
+- the mask says which keys each query may see (true = allowed); blocked
+  scores become -∞ so they get exactly zero weight
+- a causal mask only lets token i see tokens 0..=i, which is what a
+  decoder uses so it cannot peek at what it has to predict
+- temperature is applied on top of the √d_k scaling, like in the
+  softmax explorer
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::activations::softmax::softmax;
+use ndarray::{Array2, ArrayView2};
+
+pub struct Attention {
+    // Q Kᵀ / √d_k before masking, queries x keys
+    pub scores: Array2<f64>,
+    pub weights: Array2<f64>,
+    // weights V, queries x value dimension
+    pub output: Array2<f64>,
+}
+
+// Lower-triangular: query i may attend to keys 0..=i
+pub fn causal_mask(n: usize) -> Array2<bool> {
+    Array2::from_shape_fn((n, n), |(i, j)| j <= i)
+}
+
+pub fn scaled_dot_product_attention(
+    q: ArrayView2<f64>,
+    k: ArrayView2<f64>,
+    v: ArrayView2<f64>,
+    mask: Option<&Array2<bool>>,
+    temperature: f64,
+) -> Attention {
+    let scale = (q.ncols().max(1) as f64).sqrt();
+    let scores = q.dot(&k.t()) / scale;
+    let mut weights = Array2::zeros(scores.dim());
+    for (i, row) in scores.rows().into_iter().enumerate() {
+        let masked = row.indexed_iter()
+            .map(|(j, s)| if mask.is_none_or(|m| m[[i, j]]) { *s } else { f64::NEG_INFINITY })
+            .collect::<ndarray::Array1<f64>>();
+        // A query that may see nothing reads nothing
+        if masked.iter().all(|s| *s == f64::NEG_INFINITY) {
+            continue;
+        }
+        weights.row_mut(i).assign(&softmax(masked.view(), temperature));
+    }
+    let output = weights.dot(&v);
+    Attention { scores, weights, output }
+}
@@ -0,0 +1,44 @@
+/*
+--------------------------------------------------------------------
+                        Gaussian Blobs
+                        --------------
+Notes
+-----
+
+- unlabelled test data for clustering: `clusters` Gaussian blobs with
+  random centres in [-0.7, 0.7]², random sizes and random tilts, so
+  the covariances are not all round
+- the hybrid Dataset generator covers the two-class shapes (moons,
+  circles), this one any number of groups
+- seeded like the other generators
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+pub fn gaussian_blobs(n: usize, clusters: usize, seed: u64) -> Array2<f64> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // Centre, two standard deviations and a rotation per blob
+    let shapes: Vec<([f64; 2], [f64; 2], f64)> = (0..clusters)
+        .map(|_| {
+            let center = [rng.random_range(-0.7..0.7), rng.random_range(-0.7..0.7)];
+            let spread = [rng.random_range(0.04..0.2), rng.random_range(0.03..0.08)];
+            (center, spread, rng.random_range(0.0..PI))
+        })
+        .collect();
+    let mut x = Array2::zeros((n, 2));
+    for i in 0..n {
+        let (center, spread, angle) = shapes[i % clusters];
+        // Box-Muller
+        let radius = (-2.0 * (1.0 - rng.random::<f64>()).ln()).sqrt();
+        let theta = rng.random_range(0.0..2.0 * PI);
+        let (u, v) = (spread[0] * radius * theta.cos(), spread[1] * radius * theta.sin());
+        x[[i, 0]] = center[0] + u * angle.cos() - v * angle.sin();
+        x[[i, 1]] = center[1] + u * angle.sin() + v * angle.cos();
+    }
+    x
+}
@@ -0,0 +1,62 @@
+/*
+--------------------------------------------------------------------
+                        DBSCAN
+                        ------
+Notes
+-----
+
+- density-based: a point with at least `min_points` neighbours within
+  `eps` (itself included) is a core point
+- clusters grow from core points through chains of core neighbours;
+  non-core points reached this way are border points
+- anything unreachable is noise, so the number of clusters is an
+  output, not a setting, and clusters can take any shape (moons,
+  rings) where k-means can only cut the plane into convex cells
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+
+pub struct Dbscan {
+    // Cluster of each point, None for noise
+    pub labels: Vec<Option<usize>>,
+    pub core: Vec<bool>,
+    pub clusters: usize,
+}
+
+impl Dbscan {
+    pub fn fit(x: &Array2<f64>, eps: f64, min_points: usize) -> Self {
+        let n = x.nrows();
+        let neighbors: Vec<Vec<usize>> = (0..n)
+            .map(|i| (0..n).filter(|&j| (&x.row(i) - &x.row(j)).mapv(|d| d * d).sum() <= eps * eps).collect())
+            .collect();
+        let core: Vec<bool> = neighbors.iter().map(|list| list.len() >= min_points).collect();
+        let mut labels = vec![None; n];
+        let mut clusters = 0;
+        for start in 0..n {
+            if !core[start] || labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(clusters);
+            let mut frontier = vec![start];
+            while let Some(i) = frontier.pop() {
+                for &j in &neighbors[i] {
+                    if labels[j].is_none() {
+                        labels[j] = Some(clusters);
+                        // Border points join but do not expand
+                        if core[j] {
+                            frontier.push(j);
+                        }
+                    }
+                }
+            }
+            clusters += 1;
+        }
+        Self { labels, core, clusters }
+    }
+
+    pub fn noise(&self) -> usize {
+        self.labels.iter().filter(|label| label.is_none()).count()
+    }
+}
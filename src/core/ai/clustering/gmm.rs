@@ -0,0 +1,138 @@
+/*
+--------------------------------------------------------------------
+                        Gaussian Mixture Model
+                        ----------------------
+Notes
+-----
+
+- p(x) = Σ_k π_k N(x | μ_k, Σ_k), fit by expectation-maximisation:
+    E: responsibilities r_ik = π_k N(x_i | μ_k, Σ_k) / p(x_i)
+    M: N_k = Σ_i r_ik, π_k = N_k / n, μ_k = Σ_i r_ik x_i / N_k,
+       Σ_k = Σ_i r_ik (x_i - μ_k)(x_i - μ_k)ᵀ / N_k
+- a soft k-means: every point belongs a little to every component, and
+  the full covariances let clusters be elongated and tilted
+- the log-likelihood never decreases from one EM step to the next
+- densities are computed in log space through a Cholesky factor, and a
+  small ridge on each Σ_k stops a component collapsing onto one point
+- started from k-means++ means, the pooled covariance and equal weights
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::clustering::kmeans::kmeans_plus_plus;
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::f64::consts::PI;
+
+const RIDGE: f64 = 1e-6;
+
+pub struct Gmm {
+    pub weights: Array1<f64>,
+    // k x features
+    pub means: Array2<f64>,
+    pub covariances: Vec<Array2<f64>>,
+    // points x k, filled by the E step
+    pub responsibilities: Array2<f64>,
+    pub log_likelihood_history: Vec<f64>,
+}
+
+// Lower-triangular L with L Lᵀ = a, for a symmetric positive definite a
+fn cholesky(a: &Array2<f64>) -> Array2<f64> {
+    let n = a.nrows();
+    let mut l = Array2::<f64>::zeros((n, n));
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[[i, k]] * l[[j, k]]).sum();
+            if i == j {
+                l[[i, i]] = (a[[i, i]] - sum).max(RIDGE).sqrt();
+            } else {
+                l[[i, j]] = (a[[i, j]] - sum) / l[[j, j]];
+            }
+        }
+    }
+    l
+}
+
+pub fn log_normal_density(x: ArrayView1<f64>, mean: ArrayView1<f64>, covariance: &Array2<f64>) -> f64 {
+    let d = x.len();
+    let l = cholesky(covariance);
+    // Solve L z = x - μ, then the Mahalanobis distance is |z|²
+    let diff = &x - &mean;
+    let mut z = Array1::<f64>::zeros(d);
+    for i in 0..d {
+        let sum: f64 = (0..i).map(|k| l[[i, k]] * z[k]).sum();
+        z[i] = (diff[i] - sum) / l[[i, i]];
+    }
+    let log_det: f64 = 2.0 * (0..d).map(|i| l[[i, i]].ln()).sum::<f64>();
+    -0.5 * (d as f64 * (2.0 * PI).ln() + log_det + z.dot(&z))
+}
+
+impl Gmm {
+    pub fn new(x: &Array2<f64>, k: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mean = x.mean_axis(Axis(0)).expect("at least one point");
+        let centered = x - &mean;
+        let pooled = centered.t().dot(&centered) / x.nrows() as f64 + Array2::<f64>::eye(x.ncols()) * RIDGE;
+        Self {
+            weights: Array1::from_elem(k, 1.0 / k as f64),
+            means: kmeans_plus_plus(x, k, &mut rng),
+            covariances: vec![pooled; k],
+            responsibilities: Array2::from_elem((x.nrows(), k), 1.0 / k as f64),
+            log_likelihood_history: Vec::new(),
+        }
+    }
+
+    pub fn k(&self) -> usize {
+        self.weights.len()
+    }
+
+    // Fills the responsibilities and returns the log-likelihood
+    pub fn e_step(&mut self, x: &Array2<f64>) -> f64 {
+        let mut log_likelihood = 0.0;
+        for (i, p) in x.rows().into_iter().enumerate() {
+            let logs: Vec<f64> = (0..self.k())
+                .map(|c| self.weights[c].ln() + log_normal_density(p, self.means.row(c), &self.covariances[c]))
+                .collect();
+            // log-sum-exp
+            let largest = logs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let total = largest + logs.iter().map(|l| (l - largest).exp()).sum::<f64>().ln();
+            for (c, l) in logs.iter().enumerate() {
+                self.responsibilities[[i, c]] = (l - total).exp();
+            }
+            log_likelihood += total;
+        }
+        log_likelihood
+    }
+
+    pub fn m_step(&mut self, x: &Array2<f64>) {
+        let n = x.nrows() as f64;
+        for c in 0..self.k() {
+            let r = self.responsibilities.column(c);
+            let total = r.sum().max(1e-12);
+            self.weights[c] = total / n;
+            let mean = r.dot(x) / total;
+            let centered = x - &mean;
+            let weighted = &centered * &r.insert_axis(Axis(1));
+            self.covariances[c] = weighted.t().dot(&centered) / total + Array2::<f64>::eye(x.ncols()) * RIDGE;
+            self.means.row_mut(c).assign(&mean);
+        }
+    }
+
+    // One E + M round; returns the log-likelihood before the M step
+    pub fn step(&mut self, x: &Array2<f64>) -> f64 {
+        let log_likelihood = self.e_step(x);
+        self.m_step(x);
+        self.log_likelihood_history.push(log_likelihood);
+        log_likelihood
+    }
+
+    // Hard labels from the latest responsibilities
+    pub fn assignments(&self) -> Vec<usize> {
+        self.responsibilities
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).map_or(0, |(c, _)| c))
+            .collect()
+    }
+}
@@ -0,0 +1,135 @@
+/*
+--------------------------------------------------------------------
+                        K-Means
+                        -------
+Notes
+-----
+
+- Lloyd's algorithm alternates two steps until nothing moves:
+    assign: every point joins its nearest centroid
+    update: every centroid moves to the mean of its points
+- each step can only lower the inertia Σ ||x - c(x)||², so it always
+  converges, but only to a local minimum that depends on the start
+- k-means++ picks the first centroid uniformly and each next one with
+  probability ∝ D(x)², the squared distance to the closest centroid so
+  far, which spreads the starts out
+- a centroid that loses all its points stays where it is
+- the two steps are public so a view can animate them separately
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array2, ArrayView1};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub struct KMeans {
+    // k x features
+    pub centroids: Array2<f64>,
+    pub assignments: Vec<usize>,
+    pub iteration: usize,
+}
+
+// Index of the closest centroid and the squared distance to it
+pub fn nearest_centroid(centroids: &Array2<f64>, point: ArrayView1<f64>) -> (usize, f64) {
+    centroids
+        .rows()
+        .into_iter()
+        .map(|c| (&c - &point).mapv(|d| d * d).sum())
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("at least one centroid")
+}
+
+pub fn kmeans_plus_plus(x: &Array2<f64>, k: usize, rng: &mut impl Rng) -> Array2<f64> {
+    let n = x.nrows();
+    let mut centroids = Array2::zeros((k, x.ncols()));
+    centroids.row_mut(0).assign(&x.row(rng.random_range(0..n)));
+    for c in 1..k {
+        let chosen = centroids.slice(ndarray::s![..c, ..]).to_owned();
+        let weights: Vec<f64> = x.rows().into_iter().map(|p| nearest_centroid(&chosen, p).1).collect();
+        let total: f64 = weights.iter().sum();
+        // All points already sit on a centroid: any of them will do
+        let index = if total <= 0.0 {
+            rng.random_range(0..n)
+        } else {
+            let mut target = rng.random_range(0.0..total);
+            weights.iter().position(|w| {
+                target -= w;
+                target < 0.0
+            }).unwrap_or(n - 1)
+        };
+        centroids.row_mut(c).assign(&x.row(index));
+    }
+    centroids
+}
+
+// Σ ||x - c(x)||² for the given centroids and assignments
+pub fn inertia(x: &Array2<f64>, centroids: &Array2<f64>, assignments: &[usize]) -> f64 {
+    x.rows()
+        .into_iter()
+        .zip(assignments)
+        .map(|(p, &c)| (&p - &centroids.row(c)).mapv(|d| d * d).sum())
+        .sum()
+}
+
+impl KMeans {
+    // k-means++ start, nothing assigned yet
+    pub fn new(x: &Array2<f64>, k: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self {
+            centroids: kmeans_plus_plus(x, k, &mut rng),
+            assignments: vec![0; x.nrows()],
+            iteration: 0,
+        }
+    }
+
+    pub fn k(&self) -> usize {
+        self.centroids.nrows()
+    }
+
+    // Returns how many points changed cluster
+    pub fn assign(&mut self, x: &Array2<f64>) -> usize {
+        let mut changed = 0;
+        for (i, p) in x.rows().into_iter().enumerate() {
+            let (c, _) = nearest_centroid(&self.centroids, p);
+            if c != self.assignments[i] || self.iteration == 0 {
+                changed += 1;
+            }
+            self.assignments[i] = c;
+        }
+        changed
+    }
+
+    pub fn update(&mut self, x: &Array2<f64>) {
+        let mut sums = Array2::<f64>::zeros(self.centroids.dim());
+        let mut counts = vec![0usize; self.k()];
+        for (p, &c) in x.rows().into_iter().zip(&self.assignments) {
+            sums.row_mut(c).scaled_add(1.0, &p);
+            counts[c] += 1;
+        }
+        for (c, &count) in counts.iter().enumerate() {
+            if count > 0 {
+                self.centroids.row_mut(c).assign(&(&sums.row(c) / count as f64));
+            }
+        }
+        self.iteration += 1;
+    }
+
+    // One assign + update round; true once no point changes cluster
+    pub fn step(&mut self, x: &Array2<f64>) -> bool {
+        let changed = self.assign(x);
+        self.update(x);
+        changed == 0
+    }
+
+    pub fn fit(x: &Array2<f64>, k: usize, seed: u64, max_iterations: usize) -> Self {
+        let mut model = Self::new(x, k, seed);
+        while model.iteration < max_iterations && !model.step(x) {}
+        model
+    }
+
+    pub fn inertia(&self, x: &Array2<f64>) -> f64 {
+        inertia(x, &self.centroids, &self.assignments)
+    }
+}
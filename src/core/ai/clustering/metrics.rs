@@ -0,0 +1,49 @@
+/*
+--------------------------------------------------------------------
+                        Cluster Quality
+                        ---------------
+Notes
+-----
+
+- silhouette of point i: s = (b - a) / max(a, b), with a the mean
+  distance to the rest of its own cluster and b the smallest mean
+  distance to another cluster; +1 is well placed, 0 on a border,
+  negative probably in the wrong cluster
+- a point alone in its cluster scores 0 by convention
+- the mean silhouette peaks at a good k, while the inertia only ever
+  falls as k grows, hence looking for the elbow in it instead
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+
+pub fn silhouette_samples(x: &Array2<f64>, labels: &[usize]) -> Vec<f64> {
+    let n = x.nrows();
+    let k = labels.iter().max().map_or(0, |m| m + 1);
+    (0..n)
+        .map(|i| {
+            let mut sums = vec![0.0; k];
+            let mut counts = vec![0usize; k];
+            for j in (0..n).filter(|&j| j != i) {
+                sums[labels[j]] += (&x.row(i) - &x.row(j)).mapv(|d| d * d).sum().sqrt();
+                counts[labels[j]] += 1;
+            }
+            let own = labels[i];
+            if counts[own] == 0 {
+                return 0.0;
+            }
+            let a = sums[own] / counts[own] as f64;
+            let b = (0..k)
+                .filter(|&c| c != own && counts[c] > 0)
+                .map(|c| sums[c] / counts[c] as f64)
+                .fold(f64::INFINITY, f64::min);
+            if b.is_infinite() { 0.0 } else { (b - a) / a.max(b) }
+        })
+        .collect()
+}
+
+pub fn silhouette_score(x: &Array2<f64>, labels: &[usize]) -> f64 {
+    let samples = silhouette_samples(x, labels);
+    samples.iter().sum::<f64>() / samples.len().max(1) as f64
+}
@@ -0,0 +1,62 @@
+/*
+--------------------------------------------------------------------
+                        Mini-Batch K-Means
+                        ------------------
+Notes
+-----
+
+- Sculley (2010): each step draws a random batch, assigns it to the
+  nearest centroids and nudges each centroid towards its points
+- per-centroid learning rate 1 / (points seen so far), so a centroid is
+  the running mean of every sample it has ever been given
+- much cheaper per step than a full Lloyd pass, at the price of a
+  slightly higher final inertia and no exact convergence
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::clustering::kmeans::{kmeans_plus_plus, nearest_centroid};
+use ndarray::Array2;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub struct MiniBatchKMeans {
+    pub centroids: Array2<f64>,
+    pub batch_size: usize,
+    pub iteration: usize,
+    // Samples each centroid has absorbed
+    counts: Vec<usize>,
+    rng: StdRng,
+}
+
+impl MiniBatchKMeans {
+    pub fn new(x: &Array2<f64>, k: usize, batch_size: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self {
+            centroids: kmeans_plus_plus(x, k, &mut rng),
+            batch_size,
+            iteration: 0,
+            counts: vec![0; k],
+            rng,
+        }
+    }
+
+    // Returns the indices of the batch it used
+    pub fn step(&mut self, x: &Array2<f64>) -> Vec<usize> {
+        let batch: Vec<usize> = (0..self.batch_size).map(|_| self.rng.random_range(0..x.nrows())).collect();
+        // Assign the whole batch against the centroids before any moves
+        let nearest: Vec<usize> = batch.iter().map(|&i| nearest_centroid(&self.centroids, x.row(i)).0).collect();
+        for (&i, &c) in batch.iter().zip(&nearest) {
+            self.counts[c] += 1;
+            let rate = 1.0 / self.counts[c] as f64;
+            let moved = &self.centroids.row(c) * (1.0 - rate) + &x.row(i) * rate;
+            self.centroids.row_mut(c).assign(&moved);
+        }
+        self.iteration += 1;
+        batch
+    }
+
+    pub fn assignments(&self, x: &Array2<f64>) -> Vec<usize> {
+        x.rows().into_iter().map(|p| nearest_centroid(&self.centroids, p).0).collect()
+    }
+}
@@ -0,0 +1,128 @@
+pub mod blobs;
+pub mod dbscan;
+pub mod gmm;
+pub mod kmeans;
+pub mod metrics;
+pub mod mini_batch;
+
+#[cfg(test)]
+mod tests {
+    use super::dbscan::Dbscan;
+    use super::gmm::{Gmm, log_normal_density};
+    use super::kmeans::KMeans;
+    use super::metrics::{silhouette_samples, silhouette_score};
+    use super::mini_batch::MiniBatchKMeans;
+    use ndarray::{Array2, array};
+
+    // Three tight groups of four points around known centres
+    fn three_groups() -> (Array2<f64>, Array2<f64>) {
+        let centers = array![[-1.0, -1.0], [1.0, -1.0], [0.0, 1.0]];
+        let offsets = [[0.05, 0.0], [-0.05, 0.0], [0.0, 0.05], [0.0, -0.05]];
+        let x = Array2::from_shape_fn((12, 2), |(i, j)| centers[[i / 4, j]] + offsets[i % 4][j]);
+        (x, centers)
+    }
+
+    // Every true centre has a fitted centroid right on it
+    fn assert_found(centroids: &Array2<f64>, centers: &Array2<f64>, tolerance: f64) {
+        for center in centers.rows() {
+            let closest = centroids.rows().into_iter().map(|c| (&c - &center).mapv(f64::abs).sum()).fold(f64::INFINITY, f64::min);
+            assert!(closest < tolerance, "{} missed", center);
+        }
+    }
+
+    #[test]
+    fn test_kmeans() {
+        let (x, centers) = three_groups();
+        let mut model = KMeans::new(&x, 3, 0);
+        let mut previous = f64::INFINITY;
+        while !model.step(&x) {
+            // Lloyd's steps never raise the inertia
+            let inertia = model.inertia(&x);
+            assert!(inertia <= previous + 1e-12);
+            previous = inertia;
+        }
+        assert_found(&model.centroids, &centers, 1e-9);
+        assert!((model.inertia(&x) - 12.0 * 0.05 * 0.05).abs() < 1e-9);
+        // Points of one group share a label, different groups do not
+        for g in 0..3 {
+            assert!(model.assignments[4 * g..4 * g + 4].iter().all(|&c| c == model.assignments[4 * g]));
+        }
+        assert_eq!(KMeans::fit(&x, 3, 0, 100).assignments, model.assignments);
+        assert!(KMeans::fit(&x, 2, 0, 100).inertia(&x) > model.inertia(&x));
+    }
+
+    #[test]
+    fn test_mini_batch_kmeans() {
+        let (x, centers) = three_groups();
+        let mut model = MiniBatchKMeans::new(&x, 3, 4, 1);
+        for _ in 0..50 {
+            assert_eq!(model.step(&x).len(), 4);
+        }
+        assert_found(&model.centroids, &centers, 0.15);
+        let labels = model.assignments(&x);
+        assert!(silhouette_score(&x, &labels) > 0.9);
+    }
+
+    #[test]
+    fn test_dbscan() {
+        // Two rings that k-means would cut in half, plus an outlier
+        let ring = |r: f64, i: usize| [r * (i as f64 * 0.2).cos(), r * (i as f64 * 0.2).sin()];
+        let mut points: Vec<[f64; 2]> = (0..32).map(|i| ring(0.3, i)).chain((0..32).map(|i| ring(1.0, i))).collect();
+        points.push([3.0, 3.0]);
+        let x = Array2::from_shape_fn((points.len(), 2), |(i, j)| points[i][j]);
+        let model = Dbscan::fit(&x, 0.25, 3);
+        assert_eq!(model.clusters, 2);
+        assert_eq!(model.noise(), 1);
+        assert_eq!(model.labels[64], None);
+        assert!(model.labels[..32].iter().all(|l| *l == model.labels[0]));
+        assert!(model.labels[32..64].iter().all(|l| *l == model.labels[32]));
+        assert_ne!(model.labels[0], model.labels[32]);
+        assert!(model.core[0] && !model.core[64]);
+    }
+
+    #[test]
+    fn test_gmm() {
+        let covariance = array![[2.0, 0.6], [0.6, 1.0]];
+        // Closed form for a 2-D Gaussian at its mean and one step away
+        let det: f64 = 2.0 * 1.0 - 0.6 * 0.6;
+        let at_mean = -(2.0 * std::f64::consts::PI).ln() - 0.5 * det.ln();
+        let zero = array![0.0, 0.0];
+        assert!((log_normal_density(zero.view(), zero.view(), &covariance) - at_mean).abs() < 1e-12);
+        let point = array![1.0, 0.0];
+        // (x - μ)ᵀ Σ⁻¹ (x - μ) = Σ⁻¹[0, 0] = 1 / det
+        let expected = at_mean - 0.5 / det;
+        assert!((log_normal_density(point.view(), zero.view(), &covariance) - expected).abs() < 1e-12);
+
+        let (x, centers) = three_groups();
+        let mut model = Gmm::new(&x, 3, 0);
+        for _ in 0..40 {
+            model.step(&x);
+        }
+        // EM never lowers the likelihood
+        for pair in model.log_likelihood_history.windows(2) {
+            assert!(pair[1] >= pair[0] - 1e-9);
+        }
+        assert_found(&model.means, &centers, 1e-3);
+        assert!(model.weights.iter().all(|w| (w - 1.0 / 3.0).abs() < 1e-3));
+        assert!((model.covariances[0][[0, 0]] - 0.05 * 0.05 / 2.0).abs() < 1e-4);
+        assert!(model.responsibilities.rows().into_iter().all(|r| (r.sum() - 1.0).abs() < 1e-9));
+        let labels = model.assignments();
+        assert_eq!(labels[0], labels[3]);
+        assert_ne!(labels[0], labels[4]);
+    }
+
+    #[test]
+    fn test_silhouette() {
+        let (x, _) = three_groups();
+        let good: Vec<usize> = (0..12).map(|i| i / 4).collect();
+        let bad: Vec<usize> = (0..12).map(|i| i % 3).collect();
+        assert!(silhouette_score(&x, &good) > 0.9);
+        assert!(silhouette_score(&x, &bad) < 0.0);
+        let samples = silhouette_samples(&x, &good);
+        assert!(samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+        // A singleton cluster scores zero
+        let mut singleton = good.clone();
+        singleton[0] = 3;
+        assert_eq!(silhouette_samples(&x, &singleton)[0], 0.0);
+    }
+}
@@ -0,0 +1,112 @@
+/*
+--------------------------------------------------------------------
+                        Embedding Loader
+                        ----------------
+Notes
+-----
+
+- text format: one "word f1 f2 ..." per line, whitespace separated, as
+  written by GloVe and word2vec; a leading "count dimension" header
+  line is skipped
+- CSV format: "word,f1,f2,..." per line; a first line whose values are
+  not numbers is taken as a column header
+- blank lines and lines starting with '#' are ignored in both
+- every vector must have the same dimension; errors carry the 1-based
+  line they were found on
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::embeddings::EmbeddingError;
+use crate::core::ai::embeddings::space::Embeddings;
+use ndarray::Array2;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Csv,
+}
+
+impl Format {
+    // By file extension, text unless it ends in .csv
+    pub fn from_path(path: &str) -> Self {
+        if path.to_ascii_lowercase().ends_with(".csv") { Format::Csv } else { Format::Text }
+    }
+}
+
+// A small hand-built vocabulary (royalty, family, capitals, fruit, pets)
+// whose directions are consistent enough for analogies to work
+pub const SAMPLE: &str = "\
+# royal male young person country capital fruit animal france germany italy japan canine feline
+king 1.01 1.10 -0.07 1.08 -0.02 -0.02 0.15 0.01 -0.00 0.06 0.09 -0.00 0.05 -0.08
+queen 0.97 -1.04 -0.11 0.88 -0.13 -0.02 -0.01 -0.03 0.01 -0.11 -0.01 0.02 0.06 -0.07
+prince 0.97 0.84 0.96 0.82 -0.11 0.09 -0.18 0.06 0.03 -0.02 0.04 0.04 0.08 -0.02
+princess 0.95 -1.05 0.92 1.00 -0.06 0.09 -0.15 -0.09 -0.08 -0.17 0.15 -0.19 -0.02 -0.04
+man 0.13 0.84 0.09 0.94 -0.01 -0.05 0.05 -0.09 -0.01 0.03 0.15 -0.19 0.12 0.08
+woman -0.04 -0.98 -0.04 1.13 0.02 -0.02 -0.02 -0.02 -0.01 -0.07 0.16 -0.15 -0.29 -0.01
+boy -0.01 1.03 0.98 0.99 0.03 0.08 -0.04 -0.03 0.16 0.04 -0.08 0.19 0.06 -0.05
+girl -0.09 -0.98 0.93 0.92 -0.10 -0.04 0.09 -0.03 -0.12 0.05 0.01 0.07 0.10 -0.01
+father -0.01 1.00 -0.59 1.05 0.11 0.01 -0.02 -0.02 -0.06 -0.06 -0.03 -0.07 -0.03 -0.13
+mother 0.03 -1.00 -0.59 0.82 -0.00 0.09 -0.06 -0.04 -0.05 0.05 -0.07 0.08 -0.02 0.07
+france 0.00 -0.02 -0.12 -0.05 0.98 0.05 0.02 -0.06 1.03 0.08 -0.01 -0.04 -0.03 0.06
+paris 0.04 -0.07 0.03 -0.04 -0.06 1.10 0.07 -0.06 1.01 0.04 -0.05 -0.01 0.05 -0.14
+germany 0.03 0.06 0.04 -0.11 1.03 -0.07 0.05 0.05 0.02 0.94 -0.05 0.07 -0.07 0.04
+berlin 0.04 -0.02 0.19 0.01 0.17 0.84 -0.18 0.08 0.05 0.98 -0.00 -0.15 -0.05 -0.08
+italy -0.02 0.07 0.00 0.03 0.94 -0.03 0.01 -0.02 0.10 -0.07 1.15 -0.08 0.08 -0.06
+rome 0.13 0.01 0.03 0.06 -0.05 0.92 -0.16 0.10 -0.06 -0.05 1.00 0.16 -0.14 0.02
+japan -0.03 0.04 -0.14 -0.03 1.07 0.12 0.13 -0.07 0.00 -0.01 -0.11 0.88 0.06 0.02
+tokyo -0.01 0.10 -0.08 0.04 0.00 1.00 0.04 0.01 0.02 0.02 0.16 0.98 0.08 0.05
+apple -0.03 0.06 -0.07 0.09 -0.06 -0.04 1.03 0.07 0.07 0.07 -0.02 -0.08 0.04 0.02
+banana -0.08 0.08 0.02 -0.08 0.04 -0.11 0.93 0.03 -0.12 0.00 -0.11 0.06 -0.06 0.01
+cherry -0.12 -0.03 0.08 0.04 -0.15 0.07 1.07 -0.03 0.11 -0.08 -0.01 0.09 0.10 0.10
+grape -0.09 -0.14 0.03 -0.11 -0.01 -0.10 1.08 0.06 0.04 0.00 0.00 -0.02 0.03 0.02
+dog 0.04 -0.03 0.15 0.02 0.11 0.11 -0.07 0.87 0.10 -0.03 0.01 -0.02 1.01 -0.10
+puppy -0.01 -0.04 1.00 -0.19 0.07 0.03 -0.14 0.94 0.00 0.05 0.00 0.11 1.00 -0.08
+cat -0.05 0.06 -0.05 0.07 0.08 0.05 0.08 0.99 -0.00 -0.05 -0.05 -0.13 -0.04 0.91
+kitten -0.11 0.01 1.04 -0.03 0.11 0.08 0.08 0.95 -0.12 0.04 0.02 0.06 0.03 1.10
+";
+
+pub fn parse(source: &str, format: Format) -> Result<Embeddings, EmbeddingError> {
+    let mut words = Vec::new();
+    let mut values = Vec::new();
+    let mut dimension = None;
+    let mut first = true;
+    for (index, line) in source.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = match format {
+            Format::Text => line.split_whitespace().collect(),
+            Format::Csv => line.split(',').map(str::trim).collect(),
+        };
+        let numbers: Result<Vec<f64>, _> = fields[1..].iter().map(|field| field.parse::<f64>()).collect();
+        let is_first = std::mem::replace(&mut first, false);
+        let numbers = match numbers {
+            Ok(numbers) => numbers,
+            // Column header
+            Err(_) if is_first && format == Format::Csv => continue,
+            Err(error) => return Err(EmbeddingError::new(number, format!("\"{}\": {}", fields[0], error))),
+        };
+        // word2vec's "count dimension" header
+        if is_first && format == Format::Text && numbers.len() == 1 && fields[0].parse::<usize>().is_ok() {
+            continue;
+        }
+        if numbers.is_empty() {
+            return Err(EmbeddingError::new(number, format!("\"{}\" has no vector", fields[0])));
+        }
+        match dimension {
+            None => dimension = Some(numbers.len()),
+            Some(d) if d != numbers.len() => {
+                return Err(EmbeddingError::new(number, format!("\"{}\" has {} values, expected {}", fields[0], numbers.len(), d)));
+            }
+            Some(_) => {}
+        }
+        words.push(fields[0].to_string());
+        values.extend(numbers);
+    }
+    let dimension = dimension.ok_or_else(|| EmbeddingError::new(0, "no vectors found"))?;
+    let vectors = Array2::from_shape_vec((words.len(), dimension), values).expect("one row per word");
+    Ok(Embeddings::new(words, vectors))
+}
@@ -0,0 +1,120 @@
+pub mod loader;
+pub mod projection;
+pub mod space;
+pub mod tsne;
+
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmbeddingError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl EmbeddingError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::loader::{Format, SAMPLE, parse};
+    use super::projection::{classical_mds, pca};
+    use super::space::Metric;
+    use super::tsne::Tsne;
+    use ndarray::{Array2, array};
+
+    #[test]
+    fn test_parse_formats_and_errors() {
+        let text = parse("3 2\nking 1 0.5\n\n# comment\nqueen -1 0.5\ncat 0 -2\n", Format::Text).unwrap();
+        assert_eq!(text.words, ["king", "queen", "cat"]);
+        assert_eq!(text.vectors, array![[1.0, 0.5], [-1.0, 0.5], [0.0, -2.0]]);
+
+        let csv = parse("word,x,y\nking, 1, 0.5\nqueen,-1,0.5\n", Format::Csv).unwrap();
+        assert_eq!(csv.vectors, text.vectors.slice(ndarray::s![..2, ..]));
+        assert_eq!(Format::from_path("vectors.CSV"), Format::Csv);
+        assert_eq!(Format::from_path("glove.6B.50d.txt"), Format::Text);
+
+        assert_eq!(parse("a 1 2\nb 1\n", Format::Text).err().unwrap().line, 2);
+        assert_eq!(parse("a 1 2\nb 1 x\n", Format::Text).err().unwrap().line, 2);
+        assert!(parse("# nothing\n", Format::Text).is_err());
+    }
+
+    #[test]
+    fn test_neighbors_and_analogies() {
+        let embeddings = parse(SAMPLE, Format::Text).unwrap();
+        let word = |w: &str| embeddings.index(w).unwrap();
+        assert_eq!(embeddings.index("KING"), Some(word("king")));
+        for metric in Metric::ALL {
+            let neighbors = embeddings.neighbors(word("apple"), metric, 3);
+            assert_eq!(neighbors.len(), 3);
+            for (i, _) in &neighbors {
+                assert!(["banana", "cherry", "grape"].contains(&embeddings.words[*i].as_str()), "{}", embeddings.words[*i]);
+            }
+            for (a, b, c, expected) in [("man", "king", "woman", "queen"), ("france", "paris", "japan", "tokyo"), ("dog", "puppy", "cat", "kitten")] {
+                let answer = embeddings.analogy(word(a), word(b), word(c), metric, 1)[0].0;
+                assert_eq!(embeddings.words[answer], expected, "{}: {} is to {} as {} is to ?", metric.name(), a, b, c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pca_and_mds_agree() {
+        // Points on a tilted line plus a little spread across it
+        let x = array![[0.0, 0.0, 0.0], [1.0, 2.0, 0.1], [2.0, 4.0, -0.1], [3.0, 6.0, 0.0], [-1.0, -2.0, 0.05]];
+        let (scores, variances) = pca(&x, 2);
+        assert!(variances[0] > 100.0 * variances[1]);
+        // The first component runs along (1, 2, 0) / sqrt(5)
+        assert!(((scores[[1, 0]] - scores[[0, 0]]).abs() - 5.0_f64.sqrt()).abs() < 1e-2);
+
+        let n = x.nrows();
+        let distances = Array2::from_shape_fn((n, n), |(i, j)| (&x.row(i) - &x.row(j)).mapv(|d| d * d).sum().sqrt());
+        let mds = classical_mds(&distances, 2);
+        for i in 0..n {
+            for k in 0..2 {
+                assert!((mds[[i, k]].abs() - scores[[i, k]].abs()).abs() < 1e-6, "MDS {} vs PCA {}", mds[[i, k]], scores[[i, k]]);
+            }
+        }
+
+        // Wide data goes through the Gram matrix and keeps pairwise distances
+        let wide = parse(SAMPLE, Format::Text).unwrap().vectors.slice(ndarray::s![..6, ..]).to_owned();
+        let (full, _) = pca(&wide, 6);
+        let distance = |m: &Array2<f64>, i: usize, j: usize| (&m.row(i) - &m.row(j)).mapv(|d| d * d).sum().sqrt();
+        assert!((distance(&full, 0, 3) - distance(&wide, 0, 3)).abs() < 1e-6);
+
+        // These distances break the triangle inequality, so B has eigenvalues 8, 0.5, 0 and -3.25;
+        // the second axis must come from 0.5, not the larger-magnitude -3.25
+        let skewed = array![[0.0, 1.0, 1.0, 4.0], [1.0, 0.0, 1.0, 1.0], [1.0, 1.0, 0.0, 1.0], [4.0, 1.0, 1.0, 0.0]];
+        let mds = classical_mds(&skewed, 2);
+        let spread: Vec<f64> = mds.columns().into_iter().map(|c| c.dot(&c)).collect();
+        assert!((spread[0] - 8.0).abs() < 1e-9 && (spread[1] - 0.5).abs() < 1e-9, "{:?}", spread);
+    }
+
+    #[test]
+    fn test_tsne_separates_clusters() {
+        let embeddings = parse(SAMPLE, Format::Text).unwrap();
+        let mut tsne = Tsne::new(&embeddings.distance_matrix(Metric::Euclidean), 5.0, 0);
+        let first = tsne.step();
+        let mut last = first;
+        for _ in 0..500 {
+            last = tsne.step();
+        }
+        assert!(last < first, "KL {} -> {}", first, last);
+        // Fruit sit closer to each other than to the capitals
+        let word = |w: &str| embeddings.index(w).unwrap();
+        let y = &tsne.embedding;
+        let distance = |a: &str, b: &str| (&y.row(word(a)) - &y.row(word(b))).mapv(|d| d * d).sum().sqrt();
+        assert!(distance("apple", "banana") < distance("apple", "paris"));
+        assert!(distance("cherry", "grape") < distance("grape", "tokyo"));
+    }
+}
@@ -0,0 +1,130 @@
+/*
+--------------------------------------------------------------------
+                        Projections
+                        -----------
+Notes
+-----
+
+- PCA: the data projected on the top eigenvectors of its covariance;
+  when there are fewer points than dimensions the n x n Gram matrix is
+  decomposed instead, which gives the same scores more cheaply
+- classical MDS: double-centre the squared distances,
+  B = -1/2 J D² J, and scale the top eigenvectors of B by sqrt(lambda);
+  with Euclidean distances this reproduces PCA exactly
+- only the top few eigenpairs are needed, so they come from power
+  iteration with deflation (each vector kept orthogonal to the ones
+  already found), O(n²) per iteration instead of a full O(n³) solve
+- power iteration finds eigenvalues by magnitude; if a negative one
+  shows up among the top k (MDS on non-Euclidean distances), the matrix
+  is shifted by its spectral radius and the search repeated
+- t-SNE lives in its own module since it is iterative
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array1, Array2, Axis};
+
+// Near-degenerate top eigenvalues converge slowly, and any mix of them is an equally good axis
+const MAX_ITERATIONS: usize = 500;
+// Residual |M v - λ v| relative to the matrix norm
+const TOLERANCE: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Projection {
+    Pca,
+    Mds,
+    Tsne,
+}
+
+impl Projection {
+    pub const ALL: [Projection; 3] = [Projection::Pca, Projection::Mds, Projection::Tsne];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Projection::Pca => "PCA",
+            Projection::Mds => "Classical MDS",
+            Projection::Tsne => "t-SNE",
+        }
+    }
+}
+
+// The `k` largest eigenvalues of a symmetric matrix, with eigenvectors as columns
+fn top_eigen(m: &Array2<f64>, k: usize) -> (Array1<f64>, Array2<f64>) {
+    let (values, vectors) = dominant_eigen(m, k, 0.0);
+    if values.iter().all(|&v| v >= 0.0) {
+        return (values, vectors);
+    }
+    // M + ρI has the same eigenvectors and no negative eigenvalues
+    let radius = values.iter().fold(0.0_f64, |r, v| r.max(v.abs()));
+    dominant_eigen(m, k, radius)
+}
+
+// The `k` eigenpairs of M + shift I largest in magnitude, reported for M
+fn dominant_eigen(m: &Array2<f64>, k: usize, shift: f64) -> (Array1<f64>, Array2<f64>) {
+    let d = m.nrows();
+    let k = k.min(d);
+    let scale = m.mapv(|x| x * x).sum().sqrt() + shift;
+    let mut values = Array1::zeros(k);
+    let mut vectors = Array2::<f64>::zeros((d, k));
+    for i in 0..k {
+        // Deterministic start that is unlikely to be orthogonal to anything
+        let mut v = Array1::from_shape_fn(d, |j| ((j + i) as f64 * 0.618_033_988_7).fract() - 0.5);
+        deflate(&mut v, &vectors, i);
+        v /= v.dot(&v).sqrt().max(f64::MIN_POSITIVE);
+        let mut value = 0.0;
+        for _ in 0..MAX_ITERATIONS {
+            let mut w = m.dot(&v) + shift * &v;
+            deflate(&mut w, &vectors, i);
+            value = v.dot(&w);
+            let residual = (&w - &(value * &v)).mapv(|x| x * x).sum().sqrt();
+            let norm = w.dot(&w).sqrt();
+            if norm <= TOLERANCE * scale {
+                // Everything left is in the null space, so any remaining direction will do
+                value = 0.0;
+                break;
+            }
+            v = w / norm;
+            if residual <= TOLERANCE * scale {
+                break;
+            }
+        }
+        values[i] = value - shift;
+        vectors.column_mut(i).assign(&v);
+    }
+    (values, vectors)
+}
+
+// Removes the components along the first `count` columns of `found`
+fn deflate(v: &mut Array1<f64>, found: &Array2<f64>, count: usize) {
+    for column in found.columns().into_iter().take(count) {
+        let overlap = column.dot(v);
+        v.scaled_add(-overlap, &column);
+    }
+}
+
+// Scores on the top `k` principal components, and the variance each explains
+pub fn pca(x: &Array2<f64>, k: usize) -> (Array2<f64>, Array1<f64>) {
+    let (n, d) = x.dim();
+    let mean = x.mean_axis(Axis(0)).expect("at least one point");
+    let centered = x - &mean;
+    let scale = (n.max(2) - 1) as f64;
+    if d <= n {
+        let (variances, components) = top_eigen(&(centered.t().dot(&centered) / scale), k);
+        (centered.dot(&components), variances)
+    } else {
+        // Gram eigenvectors u give scores u sqrt(lambda)
+        let (values, vectors) = top_eigen(&centered.dot(&centered.t()), k);
+        let scores = &vectors * &values.mapv(|v| v.max(0.0).sqrt());
+        (scores, values / scale)
+    }
+}
+
+pub fn classical_mds(distances: &Array2<f64>, k: usize) -> Array2<f64> {
+    let n = distances.nrows();
+    let squared = distances.mapv(|d| d * d);
+    let row_means = squared.mean_axis(Axis(1)).expect("at least one point");
+    let total_mean = row_means.mean().expect("at least one point");
+    let b = Array2::from_shape_fn((n, n), |(i, j)| -0.5 * (squared[[i, j]] - row_means[i] - row_means[j] + total_mean));
+    let (values, vectors) = top_eigen(&b, k);
+    &vectors * &values.mapv(|v| v.max(0.0).sqrt())
+}
@@ -0,0 +1,118 @@
+/*
+--------------------------------------------------------------------
+                        Embedding Space
+                        ---------------
+Notes
+-----
+
+- one vector per word, stored as the rows of a matrix
+- cosine similarity compares directions only, Euclidean distance also
+  sees lengths; scores are "higher is closer" for cosine and "lower is
+  closer" for Euclidean, and results come back closest first
+- analogies use vector arithmetic: a is to b as c is to the word
+  nearest b - a + c, leaving out a, b and c themselves
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array1, Array2, ArrayView1};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    Cosine,
+    Euclidean,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 2] = [Metric::Cosine, Metric::Euclidean];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Cosine => "Cosine",
+            Metric::Euclidean => "Euclidean",
+        }
+    }
+
+    // Similarity for cosine, distance for Euclidean
+    pub fn score(self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64 {
+        match self {
+            Metric::Cosine => {
+                let norms = a.dot(&a).sqrt() * b.dot(&b).sqrt();
+                if norms > 0.0 { a.dot(&b) / norms } else { 0.0 }
+            }
+            Metric::Euclidean => (&a - &b).mapv(|d| d * d).sum().sqrt(),
+        }
+    }
+
+    // Always a distance: 1 - similarity for cosine
+    pub fn distance(self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64 {
+        match self {
+            Metric::Cosine => 1.0 - self.score(a, b),
+            Metric::Euclidean => self.score(a, b),
+        }
+    }
+
+    fn closer(self, a: f64, b: f64) -> std::cmp::Ordering {
+        match self {
+            Metric::Cosine => b.total_cmp(&a),
+            Metric::Euclidean => a.total_cmp(&b),
+        }
+    }
+}
+
+pub struct Embeddings {
+    pub words: Vec<String>,
+    pub vectors: Array2<f64>,
+}
+
+impl Embeddings {
+    pub fn new(words: Vec<String>, vectors: Array2<f64>) -> Self {
+        Self { words, vectors }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.vectors.ncols()
+    }
+
+    // Exact match first, then ignoring case
+    pub fn index(&self, word: &str) -> Option<usize> {
+        let word = word.trim();
+        self.words
+            .iter()
+            .position(|w| w == word)
+            .or_else(|| self.words.iter().position(|w| w.eq_ignore_ascii_case(word)))
+    }
+
+    // The k words closest to `query`, closest first, as (index, score)
+    pub fn nearest(&self, query: ArrayView1<f64>, metric: Metric, k: usize, exclude: &[usize]) -> Vec<(usize, f64)> {
+        let mut scored: Vec<(usize, f64)> = self.vectors
+            .rows()
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !exclude.contains(i))
+            .map(|(i, row)| (i, metric.score(query, row)))
+            .collect();
+        scored.sort_by(|a, b| metric.closer(a.1, b.1));
+        scored.truncate(k);
+        scored
+    }
+
+    pub fn neighbors(&self, word: usize, metric: Metric, k: usize) -> Vec<(usize, f64)> {
+        self.nearest(self.vectors.row(word), metric, k, &[word])
+    }
+
+    // a : b :: c : ?
+    pub fn analogy(&self, a: usize, b: usize, c: usize, metric: Metric, k: usize) -> Vec<(usize, f64)> {
+        let target: Array1<f64> = &self.vectors.row(b) - &self.vectors.row(a) + self.vectors.row(c);
+        self.nearest(target.view(), metric, k, &[a, b, c])
+    }
+
+    pub fn distance_matrix(&self, metric: Metric) -> Array2<f64> {
+        let n = self.len();
+        Array2::from_shape_fn((n, n), |(i, j)| metric.distance(self.vectors.row(i), self.vectors.row(j)))
+    }
+}
@@ -0,0 +1,127 @@
+/*
+--------------------------------------------------------------------
+                        t-SNE
+                        -----
+Notes
+-----
+
+- van der Maaten & Hinton (2008), exact O(n²) version
+- input affinities: a Gaussian around each point whose width is found
+  by bisection so that its entropy in nats matches ln(perplexity) (the
+  paper's log2 in bits gives the same width), then
+  symmetrised, p_ij = (p_j|i + p_i|j) / 2n
+- output affinities use a Student-t kernel, q_ij ∝ 1 / (1 + |y_i - y_j|²),
+  whose heavy tail lets clusters spread apart in 2-D
+- gradient descent on KL(P || Q) with momentum 0.5 then 0.8, and the
+  usual early exaggeration (P x 12 for the first 100 iterations)
+- step-wise so views can animate the layout as it settles
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array1, Array2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const EXAGGERATION: f64 = 12.0;
+const EXAGGERATION_ITERATIONS: usize = 100;
+const MOMENTUM_SWITCH: usize = 250;
+const BISECTION_STEPS: usize = 64;
+const TINY: f64 = 1e-12;
+
+pub struct Tsne {
+    p: Array2<f64>,
+    pub embedding: Array2<f64>,
+    velocity: Array2<f64>,
+    pub learning_rate: f64,
+    pub iteration: usize,
+}
+
+// Conditional affinities p_j|i for one row of squared distances
+fn conditional_row(distances: &Array1<f64>, i: usize, perplexity: f64) -> Array1<f64> {
+    let target = perplexity.ln();
+    let (mut low, mut high, mut beta) = (0.0, f64::INFINITY, 1.0);
+    let mut row = Array1::zeros(distances.len());
+    for _ in 0..BISECTION_STEPS {
+        let nearest = distances.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, d)| *d).fold(f64::INFINITY, f64::min);
+        for (j, d) in distances.iter().enumerate() {
+            row[j] = if j == i { 0.0 } else { (-beta * (d - nearest)).exp() };
+        }
+        let total: f64 = row.sum();
+        row /= total;
+        // Shannon entropy in nats
+        let entropy: f64 = -row.iter().filter(|p| **p > TINY).map(|p| p * p.ln()).sum::<f64>();
+        if (entropy - target).abs() < 1e-5 {
+            break;
+        }
+        if entropy > target {
+            low = beta;
+            beta = if high.is_finite() { (beta + high) / 2.0 } else { beta * 2.0 };
+        } else {
+            high = beta;
+            beta = (beta + low) / 2.0;
+        }
+    }
+    row
+}
+
+impl Tsne {
+    // `distances` between the original points; perplexity is roughly the
+    // number of neighbours each point pays attention to
+    pub fn new(distances: &Array2<f64>, perplexity: f64, seed: u64) -> Self {
+        let n = distances.nrows();
+        let perplexity = perplexity.min((n.max(2) - 1) as f64);
+        let mut conditional = Array2::zeros((n, n));
+        for i in 0..n {
+            let squared = distances.row(i).mapv(|d| d * d);
+            conditional.row_mut(i).assign(&conditional_row(&squared, i, perplexity));
+        }
+        let p = (&conditional + &conditional.t()) / (2.0 * n as f64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self {
+            p: p.mapv(|p: f64| p.max(TINY)),
+            embedding: Array2::from_shape_fn((n, 2), |_| rng.random_range(-1e-2..1e-2)),
+            velocity: Array2::zeros((n, 2)),
+            learning_rate: (n as f64 / EXAGGERATION).max(50.0),
+            iteration: 0,
+        }
+    }
+
+    // One gradient step; returns KL(P || Q) before the step
+    pub fn step(&mut self) -> f64 {
+        let n = self.embedding.nrows();
+        let y = &self.embedding;
+        let kernel = Array2::from_shape_fn((n, n), |(i, j)| {
+            if i == j {
+                0.0
+            } else {
+                let (dx, dy) = (y[[i, 0]] - y[[j, 0]], y[[i, 1]] - y[[j, 1]]);
+                1.0 / (1.0 + dx * dx + dy * dy)
+            }
+        });
+        let total = kernel.sum().max(TINY);
+        let exaggeration = if self.iteration < EXAGGERATION_ITERATIONS { EXAGGERATION } else { 1.0 };
+
+        let mut gradient = Array2::<f64>::zeros((n, 2));
+        let mut divergence = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let q = (kernel[[i, j]] / total).max(TINY);
+                let p = self.p[[i, j]];
+                divergence += p * (p / q).ln();
+                let force = 4.0 * (exaggeration * p - q) * kernel[[i, j]];
+                gradient[[i, 0]] += force * (y[[i, 0]] - y[[j, 0]]);
+                gradient[[i, 1]] += force * (y[[i, 1]] - y[[j, 1]]);
+            }
+        }
+
+        let momentum = if self.iteration < MOMENTUM_SWITCH { 0.5 } else { 0.8 };
+        self.velocity = &self.velocity * momentum - &gradient * self.learning_rate;
+        self.embedding += &self.velocity;
+        self.iteration += 1;
+        divergence
+    }
+}
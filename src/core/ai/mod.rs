@@ -1 +1,8 @@
-pub mod regression;
\ No newline at end of file
+pub mod activations;
+pub mod attention;
+pub mod clustering;
+pub mod embeddings;
+pub mod nn;
+pub mod optim;
+pub mod regression;
+pub mod svm;
\ No newline at end of file
@@ -0,0 +1,68 @@
+/*
+--------------------------------------------------------------------
+                        Dense Layer
+                        -----------
+Notes
+-----
+
+- a = act(X W + b) on a batch, one sample per row, the same layout as
+  LinearRegression (weights are inputs x outputs)
+- backward takes dL/da for the batch and returns dL/dX to hand to the
+  previous layer together with dL/dW and dL/db
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::nn::initializer::Initializer;
+use ndarray::{Array1, Array2, Axis};
+use rand::Rng;
+
+pub struct Dense {
+    pub weights: Array2<f64>,
+    pub bias: Array1<f64>,
+    pub activation: Activation,
+}
+
+// What one layer contributes to the loss gradient
+pub struct DenseGradients {
+    pub input: Array2<f64>,
+    pub weights: Array2<f64>,
+    pub bias: Array1<f64>,
+}
+
+impl Dense {
+    pub fn new(inputs: usize, outputs: usize, activation: Activation, initializer: Initializer, rng: &mut impl Rng) -> Self {
+        Self {
+            weights: initializer.weights(inputs, outputs, rng),
+            bias: Array1::zeros(outputs),
+            activation,
+        }
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.weights.nrows()
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.weights.ncols()
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.weights.len() + self.bias.len()
+    }
+
+    // z = X W + b
+    pub fn pre_activation(&self, input: &Array2<f64>) -> Array2<f64> {
+        input.dot(&self.weights) + &self.bias
+    }
+
+    pub fn backward(&self, input: &Array2<f64>, pre_activation: &Array2<f64>, grad_output: &Array2<f64>) -> DenseGradients {
+        let grad_z = grad_output * &pre_activation.mapv(|z| self.activation.derivative(z));
+        DenseGradients {
+            input: grad_z.dot(&self.weights.t()),
+            weights: input.t().dot(&grad_z),
+            bias: grad_z.sum_axis(Axis(0)),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+--------------------------------------------------------------------
+                        Initializers
+                        ------------
+Notes
+-----
+
+- uniform weight initializations, biases always start at zero
+- Xavier/Glorot keeps the variance steady for tanh and sigmoid layers,
+  limit sqrt(6 / (inputs + outputs))
+- Kaiming/He doubles it for ReLU layers, limit sqrt(6 / inputs)
+- Zeros is kept to show why it fails: every neuron in a layer gets the
+  same gradient, so they never stop being identical
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Initializer {
+    Zeros,
+    Xavier,
+    He,
+}
+
+impl Initializer {
+    pub const ALL: [Initializer; 3] = [Initializer::Zeros, Initializer::Xavier, Initializer::He];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Initializer::Zeros => "Zeros",
+            Initializer::Xavier => "Xavier",
+            Initializer::He => "He",
+        }
+    }
+
+    // inputs x outputs weight matrix
+    pub fn weights(self, inputs: usize, outputs: usize, rng: &mut impl Rng) -> Array2<f64> {
+        let limit = match self {
+            Initializer::Zeros => return Array2::zeros((inputs, outputs)),
+            Initializer::Xavier => (6.0 / (inputs + outputs) as f64).sqrt(),
+            Initializer::He => (6.0 / inputs as f64).sqrt(),
+        };
+        Array2::from_shape_fn((inputs, outputs), |_| rng.random_range(-limit..limit))
+    }
+}
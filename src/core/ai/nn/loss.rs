@@ -0,0 +1,63 @@
+/*
+--------------------------------------------------------------------
+                        Losses
+                        ------
+Notes
+-----
+
+- both are means over every entry of the batch, so the gradient
+  already carries the 1/m
+- MSE is halved like in LinearRegression, which makes its gradient
+  the plain error (prediction - target) / m
+- cross-entropy is binary, per output: predictions are probabilities,
+  so the output layer should be a sigmoid; they are clamped away from
+  0 and 1 to keep the logarithms finite
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array2, Zip};
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Loss {
+    Mse,
+    CrossEntropy,
+}
+
+impl Loss {
+    pub const ALL: [Loss; 2] = [Loss::Mse, Loss::CrossEntropy];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Loss::Mse => "MSE",
+            Loss::CrossEntropy => "Cross-entropy",
+        }
+    }
+
+    pub fn value(self, predictions: &Array2<f64>, targets: &Array2<f64>) -> f64 {
+        let total: f64 = Zip::from(predictions).and(targets).fold(0.0, |total, &p, &y| {
+            total + match self {
+                Loss::Mse => 0.5 * (p - y).powi(2),
+                Loss::CrossEntropy => {
+                    let p = p.clamp(EPSILON, 1.0 - EPSILON);
+                    -(y * p.ln() + (1.0 - y) * (1.0 - p).ln())
+                }
+            }
+        });
+        total / predictions.len() as f64
+    }
+
+    // dL/d(predictions)
+    pub fn gradient(self, predictions: &Array2<f64>, targets: &Array2<f64>) -> Array2<f64> {
+        let m = predictions.len() as f64;
+        Zip::from(predictions).and(targets).map_collect(|&p, &y| match self {
+            Loss::Mse => (p - y) / m,
+            Loss::CrossEntropy => {
+                let p = p.clamp(EPSILON, 1.0 - EPSILON);
+                (p - y) / (p * (1.0 - p) * m)
+            }
+        })
+    }
+}
@@ -0,0 +1,67 @@
+pub mod dense;
+pub mod initializer;
+pub mod loss;
+pub mod network;
+
+#[cfg(test)]
+mod tests {
+    use super::initializer::Initializer;
+    use super::loss::Loss;
+    use super::network::NeuralNetwork;
+    use crate::core::ai::activations::activation::Activation;
+    use crate::core::ai::optim::optimizer::finite_difference;
+    use crate::core::ai::regression::linear_regression::TrainingConfig;
+    use ndarray::{Array2, array};
+
+    fn xor() -> (Array2<f64>, Array2<f64>) {
+        (array![[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]], array![[0.0], [1.0], [1.0], [0.0]])
+    }
+
+    #[test]
+    fn test_backprop_matches_finite_differences() {
+        let (x, y) = xor();
+        for (activation, loss) in [(Activation::Tanh, Loss::CrossEntropy), (Activation::Sigmoid, Loss::Mse)] {
+            let output = if loss == Loss::Mse { Activation::Linear } else { Activation::Sigmoid };
+            let mut network = NeuralNetwork::new(&[2, 3, 2, 1], activation, output, Initializer::Xavier, loss, 7);
+            let params = network.params();
+            assert_eq!(params.len(), network.num_params());
+            let analytic = network.backward(&network.forward(&x), &y);
+            let numerical = finite_difference(|p| {
+                network.set_params(p);
+                network.loss.value(network.forward(&x).output(), &y)
+            }, &params);
+            for (a, n) in analytic.iter().zip(numerical.iter()) {
+                assert!((a - n).abs() < 1e-6, "{:?}: backprop {} vs numerical {}", activation, a, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_network_learns_xor() {
+        let (x, y) = xor();
+        let mut network = NeuralNetwork::new(&[2, 4, 1], Activation::Tanh, Activation::Sigmoid, Initializer::Xavier, Loss::CrossEntropy, 1);
+        let config = TrainingConfig {
+            learning_rate: 1.0,
+            epochs: 2000,
+            verbose: false,
+        };
+        network.train(x.clone(), y.clone(), config);
+        let predictions = network.predict(x);
+        for (p, target) in predictions.iter().zip(y.iter()) {
+            assert!((p - target).abs() < 0.2, "predicted {} for {}", p, target);
+        }
+        assert!(network.loss_history.last().unwrap() < network.loss_history.first().unwrap());
+    }
+
+    #[test]
+    fn test_zero_initialization_stays_symmetric() {
+        let (x, y) = xor();
+        let mut network = NeuralNetwork::new(&[2, 4, 1], Activation::Tanh, Activation::Sigmoid, Initializer::Zeros, Loss::CrossEntropy, 1);
+        network.train(x, y, TrainingConfig::default());
+        // Every hidden neuron received the same updates, so they are all alike
+        let hidden = &network.layers[0].weights;
+        for column in hidden.columns() {
+            assert_eq!(column, hidden.column(0));
+        }
+    }
+}
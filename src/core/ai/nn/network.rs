@@ -0,0 +1,171 @@
+/*
+--------------------------------------------------------------------
+                        Neural Network
+                        --------------
+Notes
+-----
+
+- a stack of Dense layers: the hidden ones share one activation, the
+  output layer has its own (Sigmoid for cross-entropy, Linear for MSE)
+- forward keeps every layer's pre-activation and activation so that
+  backprop can reuse them, and views can show what each neuron did
+- backprop walks the layers in reverse, each turning dL/da into
+  dL/d(input) for the layer before it
+- parameters are flattened layer by layer, [W..., b...], so training
+  goes through the same Objective / Optimizer interface as
+  LinearRegression and takes the same TrainingConfig
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::nn::dense::Dense;
+use crate::core::ai::nn::initializer::Initializer;
+use crate::core::ai::nn::loss::Loss;
+use crate::core::ai::optim::gradient_descent::GradientDescent;
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use crate::core::ai::regression::linear_regression::{TrainingConfig, vprint};
+use ndarray::{Array1, Array2, s};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+pub struct NeuralNetwork {
+    pub layers: Vec<Dense>,
+    pub loss: Loss,
+    // Loss after every epoch
+    pub loss_history: Vec<f64>,
+}
+
+// Everything a forward pass computed; activations[0] is the input
+pub struct Forward {
+    pub activations: Vec<Array2<f64>>,
+    pub pre_activations: Vec<Array2<f64>>,
+}
+
+impl Forward {
+    pub fn output(&self) -> &Array2<f64> {
+        self.activations.last().expect("starts with the input")
+    }
+}
+
+impl NeuralNetwork {
+    // `sizes` runs from the input width to the output width
+    pub fn new(sizes: &[usize], hidden: Activation, output: Activation, initializer: Initializer, loss: Loss, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let layers = sizes
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let activation = if i + 2 == sizes.len() { output } else { hidden };
+                Dense::new(pair[0], pair[1], activation, initializer, &mut rng)
+            })
+            .collect();
+        Self {
+            layers,
+            loss,
+            loss_history: Vec::new(),
+        }
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.layers.iter().map(Dense::num_params).sum()
+    }
+
+    pub fn params(&self) -> Array1<f64> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.weights.iter().chain(layer.bias.iter()).copied().collect::<Vec<_>>())
+            .collect()
+    }
+
+    pub fn set_params(&mut self, params: &Array1<f64>) {
+        let mut offset = 0;
+        for layer in &mut self.layers {
+            let shape = layer.weights.dim();
+            let count = layer.weights.len();
+            layer.weights.assign(&params.slice(s![offset..offset + count]).to_shape(shape).expect("weight block"));
+            offset += count;
+            let count = layer.bias.len();
+            layer.bias.assign(&params.slice(s![offset..offset + count]));
+            offset += count;
+        }
+    }
+
+    pub fn forward(&self, x: &Array2<f64>) -> Forward {
+        let mut forward = Forward {
+            activations: vec![x.clone()],
+            pre_activations: Vec::with_capacity(self.layers.len()),
+        };
+        for layer in &self.layers {
+            let z = layer.pre_activation(forward.output());
+            forward.activations.push(z.mapv(|z| layer.activation.apply(z)));
+            forward.pre_activations.push(z);
+        }
+        forward
+    }
+
+    // dL/d(params) for the batch the forward pass ran on, flattened like params()
+    pub fn backward(&self, forward: &Forward, y: &Array2<f64>) -> Array1<f64> {
+        let mut grad = self.loss.gradient(forward.output(), y);
+        let mut blocks = Vec::with_capacity(self.layers.len());
+        for (i, layer) in self.layers.iter().enumerate().rev() {
+            let gradients = layer.backward(&forward.activations[i], &forward.pre_activations[i], &grad);
+            blocks.push(gradients.weights.iter().chain(gradients.bias.iter()).copied().collect::<Vec<_>>());
+            grad = gradients.input;
+        }
+        blocks.into_iter().rev().flatten().collect()
+    }
+
+    pub fn train(&mut self, x_train: Array2<f64>, y_train: Array2<f64>, config: TrainingConfig) {
+        let mut optimizer = GradientDescent::new(config.learning_rate);
+        self.train_with(&mut optimizer, &x_train, &y_train, config.epochs, config.verbose);
+    }
+
+    // Continues from the current weights with any optimizer, one step per epoch
+    pub fn train_with(&mut self, optimizer: &mut dyn Optimizer, x_train: &Array2<f64>, y_train: &Array2<f64>, epochs: usize, verbose: bool) {
+        let (input, output) = (self.layers[0].inputs(), self.layers[self.layers.len() - 1].outputs());
+        if x_train.ncols() != input || y_train.shape() != [x_train.nrows(), output] {
+            tracing::error!("Shape mismatched!");
+            return;
+        }
+        let mut params = self.params();
+        let mut objective = NetworkObjective {
+            network: self,
+            x: x_train,
+            y: y_train,
+        };
+        let mut losses = Vec::with_capacity(epochs);
+        for epoch in 0..epochs {
+            vprint("Epoch: ".to_string() + &epoch.to_string(), &verbose);
+            let loss = optimizer.step(&mut objective, &mut params);
+            vprint("Loss: ".to_string() + &loss.to_string(), &verbose);
+            losses.push(loss);
+        }
+        self.set_params(&params);
+        self.loss_history.extend(losses);
+    }
+
+    pub fn predict(&self, x_test: Array2<f64>) -> Array2<f64> {
+        self.forward(&x_test).activations.pop().expect("output")
+    }
+}
+
+struct NetworkObjective<'a> {
+    network: &'a mut NeuralNetwork,
+    x: &'a Array2<f64>,
+    y: &'a Array2<f64>,
+}
+
+impl Objective for NetworkObjective<'_> {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        self.network.set_params(params);
+        let forward = self.network.forward(self.x);
+        self.network.loss.value(forward.output(), self.y)
+    }
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        self.network.set_params(params);
+        let forward = self.network.forward(self.x);
+        self.network.backward(&forward, self.y)
+    }
+}
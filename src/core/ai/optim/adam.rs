@@ -0,0 +1,63 @@
+/*
+--------------------------------------------------------------------
+                        Adam
+                        ----
+Notes
+-----
+
+- Kingma & Ba (2015): running means of the gradient (beta1 = 0.9) and
+  of its square (beta2 = 0.999), both bias-corrected for the first steps
+- each parameter moves by about learning_rate per step whatever the
+  gradient's scale, so the learning rate is a distance, not a multiplier
+- moments are sized on the first step, like Momentum's velocity
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::{Array1, Zip};
+
+const BETA1: f64 = 0.9;
+const BETA2: f64 = 0.999;
+const EPSILON: f64 = 1e-8;
+
+pub struct Adam {
+    pub learning_rate: f64,
+    first: Array1<f64>,
+    second: Array1<f64>,
+    iteration: i32,
+}
+
+impl Adam {
+    pub fn new(learning_rate: f64) -> Self {
+        Self {
+            learning_rate,
+            first: Array1::zeros(0),
+            second: Array1::zeros(0),
+            iteration: 0,
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        if self.first.len() != params.len() {
+            self.first = Array1::zeros(params.len());
+            self.second = Array1::zeros(params.len());
+        }
+        let gradient = objective.gradient(params);
+        self.iteration += 1;
+        let first_correction = 1.0 - BETA1.powi(self.iteration);
+        let second_correction = 1.0 - BETA2.powi(self.iteration);
+        Zip::from(&mut *params)
+            .and(&mut self.first)
+            .and(&mut self.second)
+            .and(&gradient)
+            .for_each(|p, m, v, &g| {
+                *m = BETA1 * *m + (1.0 - BETA1) * g;
+                *v = BETA2 * *v + (1.0 - BETA2) * g * g;
+                *p -= self.learning_rate * (*m / first_correction) / ((*v / second_correction).sqrt() + EPSILON);
+            });
+        objective.value(params)
+    }
+}
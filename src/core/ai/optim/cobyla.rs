@@ -0,0 +1,255 @@
+/*
+--------------------------------------------------------------------
+                        COBYLA
+                        ------
+Notes
+-----
+
+- Powell's Constrained Optimization BY Linear Approximations, without
+  constraints (the objectives here have none, so the merit function is
+  just f): a linear model is interpolated on a simplex of n + 1 points
+  around the best one (the pole) and minimised inside a trust region
+- two radii as in Powell's later methods and the PRIMA reference code:
+  Δ is the trust-region radius, grown or shrunk by the ratio of actual
+  to predicted reduction, and ρ is the resolution, a floor on Δ that only
+  ever decreases (ρ_beg → ρ_end in steps of 0.1, √(ρ ρ_end), ρ_end)
+- a trial point replaces the vertex that keeps the simplex best shaped;
+  when a poor step meets a degenerate simplex (an edge longer than 2.1 Δ
+  or a vertex closer than 0.25 Δ to its opposite face) a geometry step
+  moves that vertex 0.5 Δ off the face instead
+- ρ is only reduced once steps fail at Δ = ρ on an acceptable simplex;
+  after ρ_end a converged run just idles
+- one objective evaluation per step after the n + 1 initial ones
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::{Array1, Array2};
+
+const RHO_END: f64 = 1e-8;
+// Powell's thresholds on edge length and vertex-to-face distance, and the geometry step length, in units of Δ
+const MAX_EDGE: f64 = 2.1;
+const MIN_DISTANCE: f64 = 0.25;
+const GEOMETRY_STEP: f64 = 0.5;
+
+pub struct Cobyla {
+    // Resolution, never increases
+    pub rho: f64,
+    // Trust-region radius, at least rho
+    pub delta: f64,
+    pole: (Array1<f64>, f64),
+    // The other n interpolation points
+    vertices: Vec<(Array1<f64>, f64)>,
+    poor_step: bool,
+    converged: bool,
+}
+
+// What the current simplex says about the objective
+struct Model {
+    gradient: Array1<f64>,
+    // Column j is the normal of the face opposite vertex j, scaled so its dot with vertex j's offset is 1
+    inverse: Array2<f64>,
+    edges: Vec<f64>,
+    distances: Vec<f64>,
+}
+
+impl Cobyla {
+    pub fn new(initial_radius: f64) -> Self {
+        Self {
+            rho: initial_radius,
+            delta: initial_radius,
+            pole: (Array1::zeros(0), f64::INFINITY),
+            vertices: Vec::new(),
+            poor_step: false,
+            converged: false,
+        }
+    }
+
+    // Coordinate steps of rho; a better vertex becomes the pole as soon as it is found
+    fn initialize(&mut self, objective: &mut dyn Objective, params: &Array1<f64>) {
+        self.pole = (params.clone(), objective.value(params));
+        self.vertices.clear();
+        for i in 0..params.len() {
+            let mut point = self.pole.0.clone();
+            point[i] += self.rho;
+            let value = objective.value(&point);
+            self.vertices.push((point, value));
+            self.promote(i);
+        }
+    }
+
+    // Swaps vertex j with the pole if it is better
+    fn promote(&mut self, j: usize) {
+        if self.vertices[j].1 < self.pole.1 {
+            std::mem::swap(&mut self.vertices[j], &mut self.pole);
+        }
+    }
+
+    fn model(&self) -> Option<Model> {
+        let n = self.vertices.len();
+        let offsets = Array2::from_shape_fn((n, n), |(j, i)| self.vertices[j].0[i] - self.pole.0[i]);
+        let inverse = invert(offsets.clone())?;
+        let rises = Array1::from_shape_fn(n, |j| self.vertices[j].1 - self.pole.1);
+        Some(Model {
+            gradient: inverse.dot(&rises),
+            edges: offsets.rows().into_iter().map(|row| row.dot(&row).sqrt()).collect(),
+            distances: inverse.columns().into_iter().map(|column| 1.0 / column.dot(&column).sqrt()).collect(),
+            inverse,
+        })
+    }
+
+    fn acceptable(&self, model: &Model) -> bool {
+        model.edges.iter().all(|&e| e <= MAX_EDGE * self.delta) && model.distances.iter().all(|&d| d >= MIN_DISTANCE * self.delta)
+    }
+
+    // Trust-region step straight down the linear model, replacing the vertex that keeps the simplex well shaped
+    fn trust_region_step(&mut self, objective: &mut dyn Objective, model: &Model, slope: f64) {
+        let step = &model.gradient * (-self.delta / slope);
+        let point = &self.pole.0 + &step;
+        let value = objective.value(&point);
+        let ratio = (self.pole.1 - value) / (self.delta * slope);
+
+        let improved = value < self.pole.1;
+        let best = if improved { &point } else { &self.pole.0 };
+        let (drop, score) = (0..self.vertices.len())
+            .map(|j| {
+                let offset = &self.vertices[j].0 - best;
+                let weight = (offset.dot(&offset).sqrt() / self.delta).max(1.0).powi(2);
+                (j, model.inverse.column(j).dot(&step).abs() * weight)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((0, 0.0));
+        // A worse point is only kept if it improves the geometry
+        if improved || score > 1.0 {
+            self.vertices[drop] = (point, value);
+            self.promote(drop);
+        }
+
+        self.delta = if ratio <= 0.1 {
+            0.5 * self.delta
+        } else if ratio <= 0.7 {
+            self.delta
+        } else {
+            2.0 * self.delta
+        };
+        if self.delta <= 1.5 * self.rho {
+            self.delta = self.rho;
+        }
+        self.poor_step = ratio <= 0.1;
+    }
+
+    // Moves the worst-placed vertex GEOMETRY_STEP Δ off its opposite face, downhill on the model
+    fn geometry_step(&mut self, objective: &mut dyn Objective, model: &Model) {
+        let too_long = (0..model.edges.len()).max_by(|&a, &b| model.edges[a].total_cmp(&model.edges[b]));
+        let drop = match too_long {
+            Some(j) if model.edges[j] > MAX_EDGE * self.delta => j,
+            _ => (0..model.distances.len()).min_by(|&a, &b| model.distances[a].total_cmp(&model.distances[b])).unwrap_or(0),
+        };
+        let normal = model.inverse.column(drop);
+        let mut step = &normal * (GEOMETRY_STEP * self.delta / normal.dot(&normal).sqrt());
+        if step.dot(&model.gradient) > 0.0 {
+            step = -step;
+        }
+        let point = &self.pole.0 + &step;
+        let value = objective.value(&point);
+        self.vertices[drop] = (point, value);
+        self.promote(drop);
+        self.poor_step = false;
+    }
+
+    fn reduce_rho(&mut self) {
+        if self.rho <= RHO_END {
+            self.converged = true;
+            return;
+        }
+        let rho = if self.rho > 250.0 * RHO_END {
+            0.1 * self.rho
+        } else if self.rho > 16.0 * RHO_END {
+            (self.rho * RHO_END).sqrt()
+        } else {
+            RHO_END
+        };
+        self.delta = (0.5 * self.delta).max(rho);
+        self.rho = rho;
+        self.poor_step = false;
+    }
+
+    // Restarts the simplex around the pole when it has collapsed numerically
+    fn rebuild(&mut self, objective: &mut dyn Objective) {
+        let pole = self.pole.0.clone();
+        for (i, (point, value)) in self.vertices.iter_mut().enumerate() {
+            point.assign(&pole);
+            point[i] += self.delta;
+            *value = objective.value(point);
+        }
+        for j in 0..self.vertices.len() {
+            self.promote(j);
+        }
+    }
+}
+
+// Gauss-Jordan elimination with partial pivoting, None if singular
+fn invert(mut matrix: Array2<f64>) -> Option<Array2<f64>> {
+    let n = matrix.nrows();
+    let mut inverse = Array2::eye(n);
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| matrix[[a, col]].abs().total_cmp(&matrix[[b, col]].abs()))?;
+        if matrix[[pivot, col]].abs() < 1e-14 {
+            return None;
+        }
+        for k in 0..n {
+            matrix.swap([col, k], [pivot, k]);
+            inverse.swap([col, k], [pivot, k]);
+        }
+        let scale = matrix[[col, col]];
+        for k in 0..n {
+            matrix[[col, k]] /= scale;
+            inverse[[col, k]] /= scale;
+        }
+        for row in 0..n {
+            let factor = matrix[[row, col]];
+            if row != col && factor != 0.0 {
+                for k in 0..n {
+                    matrix[[row, k]] -= factor * matrix[[col, k]];
+                    inverse[[row, k]] -= factor * inverse[[col, k]];
+                }
+            }
+        }
+    }
+    Some(inverse)
+}
+
+impl Optimizer for Cobyla {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        if self.pole.0.len() != params.len() {
+            self.initialize(objective, params);
+        } else {
+            while !self.converged {
+                let Some(model) = self.model() else {
+                    self.rebuild(objective);
+                    break;
+                };
+                let acceptable = self.acceptable(&model);
+                let slope = model.gradient.dot(&model.gradient).sqrt();
+                if self.poor_step && !acceptable {
+                    self.geometry_step(objective, &model);
+                    break;
+                }
+                if self.poor_step && self.delta <= self.rho {
+                    self.reduce_rho();
+                    continue;
+                }
+                if slope.is_finite() && slope > 1e-14 {
+                    self.trust_region_step(objective, &model, slope);
+                    break;
+                }
+                // A flat model gives no step: shrink Δ and treat it as a failure
+                self.delta = if 0.1 * self.delta <= 1.5 * self.rho { self.rho } else { 0.1 * self.delta };
+                self.poor_step = true;
+            }
+        }
+        params.assign(&self.pole.0);
+        self.pole.1
+    }
+}
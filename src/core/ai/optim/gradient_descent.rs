@@ -0,0 +1,34 @@
+/*
+--------------------------------------------------------------------
+                        Gradient Descent
+                        ----------------
+Notes
+-----
+
+- plain full-batch descent, params -= learning_rate * gradient
+- the gradient comes from the objective, so analytic or shifted
+  gradients are used when the objective provides them
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::Array1;
+
+pub struct GradientDescent {
+    pub learning_rate: f64,
+}
+
+impl GradientDescent {
+    pub fn new(learning_rate: f64) -> Self {
+        Self { learning_rate }
+    }
+}
+
+impl Optimizer for GradientDescent {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        let gradient = objective.gradient(params);
+        params.scaled_add(-self.learning_rate, &gradient);
+        objective.value(params)
+    }
+}
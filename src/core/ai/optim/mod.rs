@@ -0,0 +1,103 @@
+pub mod adam;
+pub mod cobyla;
+pub mod gradient_descent;
+pub mod momentum;
+pub mod nelder_mead;
+pub mod optimizer;
+pub mod spsa;
+pub mod test_functions;
+
+#[cfg(test)]
+mod tests {
+    use super::optimizer::{Objective, OptimizerKind, finite_difference};
+    use super::test_functions::TestFunction;
+    use ndarray::{Array1, array};
+
+    // Tilted quadratic bowl with its minimum 1.5 at (1, -2)
+    struct Bowl {
+        evaluations: usize,
+    }
+
+    impl Objective for Bowl {
+        fn value(&mut self, params: &Array1<f64>) -> f64 {
+            self.evaluations += 1;
+            let (x, y) = (params[0] - 1.0, params[1] + 2.0);
+            x * x + 3.0 * y * y + x * y + 1.5
+        }
+    }
+
+    #[test]
+    fn test_optimizers_find_the_minimum() {
+        for (kind, step_size, steps) in [
+            (OptimizerKind::Cobyla, 0.5, 200),
+            (OptimizerKind::NelderMead, 0.5, 200),
+            (OptimizerKind::Spsa, 0.5, 2000),
+            (OptimizerKind::GradientDescent, 0.1, 500),
+            (OptimizerKind::Momentum, 0.05, 500),
+            (OptimizerKind::Adam, 0.05, 1000),
+        ] {
+            let mut objective = Bowl { evaluations: 0 };
+            let mut optimizer = kind.build(step_size, 0);
+            let mut params = array![0.0, 0.0];
+            let mut value = f64::INFINITY;
+            for _ in 0..steps {
+                value = optimizer.step(&mut objective, &mut params);
+            }
+            assert!((value - 1.5).abs() < 1e-4, "{} stopped at {}", kind.name(), value);
+            assert!((params[0] - 1.0).abs() < 1e-2 && (params[1] + 2.0).abs() < 1e-2, "{}: {}", kind.name(), params);
+            assert!(objective.evaluations > 0);
+        }
+    }
+
+    // Badly scaled 4-D quadratic: COBYLA has to keep its simplex in shape and walk rho down to resolve it
+    #[test]
+    fn test_cobyla_on_a_narrow_valley() {
+        struct Valley;
+        impl Objective for Valley {
+            fn value(&mut self, p: &Array1<f64>) -> f64 {
+                (p[0] - 1.0).powi(2) + 100.0 * (p[1] - p[0]).powi(2) + 10.0 * (p[2] + 0.5).powi(2) + (p[3] - p[2]).powi(2)
+            }
+        }
+        let mut optimizer = OptimizerKind::Cobyla.build(0.5, 0);
+        let mut params = Array1::zeros(4);
+        let mut value = f64::INFINITY;
+        for _ in 0..4000 {
+            value = optimizer.step(&mut Valley, &mut params);
+        }
+        assert!(value < 1e-8, "stopped at {} ({})", value, params);
+    }
+
+    #[test]
+    fn test_finite_difference_gradient() {
+        let mut objective = Bowl { evaluations: 0 };
+        let gradient = objective.gradient(&array![0.0, 0.0]);
+        // d/dx = 2x + y, d/dy = 6y + x at (x, y) = (-1, 2)
+        assert!((gradient[0] - 0.0).abs() < 1e-6 && (gradient[1] - 11.0).abs() < 1e-6, "{}", gradient);
+    }
+
+    #[test]
+    fn test_functions_have_their_minima() {
+        for function in TestFunction::ALL {
+            let mut objective = function;
+            for [x, y] in function.minima() {
+                assert!(function.value(x, y).abs() < 1e-9, "{} at ({}, {})", function.name(), x, y);
+                let [dx, dy] = function.gradient(x, y);
+                assert!(dx.abs() < 1e-4 && dy.abs() < 1e-4, "{} gradient ({}, {})", function.name(), dx, dy);
+            }
+            let start = array![function.start()[0], function.start()[1]];
+            let numerical = finite_difference(|p| Objective::value(&mut objective, p), &start);
+            let analytic = Objective::gradient(&mut objective, &start);
+            assert!((&numerical - &analytic).iter().all(|d| d.abs() < 1e-4), "{}: {} vs {}", function.name(), analytic, numerical);
+        }
+
+        // Descent at the suggested rate settles into Himmelblau's nearest bowl
+        let mut function = TestFunction::Himmelblau;
+        let mut optimizer = OptimizerKind::GradientDescent.build(function.learning_rate(), 0);
+        let mut params = array![function.start()[0], function.start()[1]];
+        for _ in 0..1000 {
+            optimizer.step(&mut function, &mut params);
+        }
+        assert!(function.minima().iter().any(|m| (params[0] - m[0]).abs() < 1e-3 && (params[1] - m[1]).abs() < 1e-3), "{}", params);
+    }
+}
+
@@ -0,0 +1,48 @@
+/*
+--------------------------------------------------------------------
+                        Momentum
+                        --------
+Notes
+-----
+
+- heavy-ball gradient descent: velocity = beta * velocity - learning_rate
+  * gradient, params += velocity
+- beta = 0.9 keeps ~10 steps of history, which damps the zig-zag across
+  narrow valleys and speeds up travel along them
+- the velocity is sized on the first step, so one instance serves a
+  single parameter vector
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::Array1;
+
+const BETA: f64 = 0.9;
+
+pub struct Momentum {
+    pub learning_rate: f64,
+    velocity: Array1<f64>,
+}
+
+impl Momentum {
+    pub fn new(learning_rate: f64) -> Self {
+        Self {
+            learning_rate,
+            velocity: Array1::zeros(0),
+        }
+    }
+}
+
+impl Optimizer for Momentum {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        if self.velocity.len() != params.len() {
+            self.velocity = Array1::zeros(params.len());
+        }
+        let gradient = objective.gradient(params);
+        self.velocity *= BETA;
+        self.velocity.scaled_add(-self.learning_rate, &gradient);
+        *params += &self.velocity;
+        objective.value(params)
+    }
+}
@@ -0,0 +1,84 @@
+/*
+--------------------------------------------------------------------
+                        Nelder-Mead
+                        -----------
+Notes
+-----
+
+- downhill simplex with the standard coefficients: reflection 1,
+  expansion 2, contraction 0.5, shrink 0.5
+- the simplex is built around the starting point on the first step,
+  one vertex per axis at `initial_step`
+- one step is one reflect / expand / contract / shrink decision
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::Array1;
+
+pub struct NelderMead {
+    pub initial_step: f64,
+    // (vertex, value), best first after every step
+    simplex: Vec<(Array1<f64>, f64)>,
+}
+
+impl NelderMead {
+    pub fn new(initial_step: f64) -> Self {
+        Self {
+            initial_step,
+            simplex: Vec::new(),
+        }
+    }
+}
+
+impl Optimizer for NelderMead {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        let n = params.len();
+        if self.simplex.is_empty() {
+            self.simplex.push((params.clone(), objective.value(params)));
+            for i in 0..n {
+                let mut vertex = params.clone();
+                vertex[i] += self.initial_step;
+                let value = objective.value(&vertex);
+                self.simplex.push((vertex, value));
+            }
+        }
+        self.simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let centroid = self.simplex[..n].iter().fold(Array1::zeros(n), |sum, (v, _)| sum + v) / n as f64;
+        let (worst, worst_value) = self.simplex[n].clone();
+        let toward = |t: f64| &centroid + &((&centroid - &worst) * t);
+
+        let reflected = toward(1.0);
+        let reflected_value = objective.value(&reflected);
+        if reflected_value < self.simplex[0].1 {
+            let expanded = toward(2.0);
+            let expanded_value = objective.value(&expanded);
+            self.simplex[n] = if expanded_value < reflected_value {
+                (expanded, expanded_value)
+            } else {
+                (reflected, reflected_value)
+            };
+        } else if reflected_value < self.simplex[n - 1].1 {
+            self.simplex[n] = (reflected, reflected_value);
+        } else {
+            // Contract towards whichever of the reflection and the worst vertex is better
+            let contracted = if reflected_value < worst_value { toward(0.5) } else { toward(-0.5) };
+            let contracted_value = objective.value(&contracted);
+            if contracted_value < worst_value.min(reflected_value) {
+                self.simplex[n] = (contracted, contracted_value);
+            } else {
+                let best = self.simplex[0].0.clone();
+                for (vertex, value) in self.simplex.iter_mut().skip(1) {
+                    *vertex = &best + &((&*vertex - &best) * 0.5);
+                    *value = objective.value(vertex);
+                }
+            }
+        }
+
+        self.simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+        params.assign(&self.simplex[0].0);
+        self.simplex[0].1
+    }
+}
@@ -0,0 +1,113 @@
+/*
+--------------------------------------------------------------------
+                        Optimizer
+                        ---------
+Notes
+-----
+
+- one interface for every training loop: an Objective to minimise and
+  an Optimizer that advances the parameters one iteration per step
+- step-wise so views can animate training and stop whenever they like
+- gradient-free methods only call value; the default gradient is a
+  central finite difference, which objectives override when they know
+  better (analytic, parameter shift, ...)
+- a single "step size" knob maps onto each method's natural scale
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::adam::Adam;
+use crate::core::ai::optim::cobyla::Cobyla;
+use crate::core::ai::optim::gradient_descent::GradientDescent;
+use crate::core::ai::optim::momentum::Momentum;
+use crate::core::ai::optim::nelder_mead::NelderMead;
+use crate::core::ai::optim::spsa::Spsa;
+use ndarray::Array1;
+
+const FINITE_DIFFERENCE_STEP: f64 = 1e-5;
+
+pub trait Objective {
+    fn value(&mut self, params: &Array1<f64>) -> f64;
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        finite_difference(|p| self.value(p), params)
+    }
+}
+
+// Central differences, two evaluations per parameter
+pub fn finite_difference(mut value: impl FnMut(&Array1<f64>) -> f64, params: &Array1<f64>) -> Array1<f64> {
+    let mut shifted = params.clone();
+    Array1::from_shape_fn(params.len(), |i| {
+        shifted[i] = params[i] + FINITE_DIFFERENCE_STEP;
+        let plus = value(&shifted);
+        shifted[i] = params[i] - FINITE_DIFFERENCE_STEP;
+        let minus = value(&shifted);
+        shifted[i] = params[i];
+        (plus - minus) / (2.0 * FINITE_DIFFERENCE_STEP)
+    })
+}
+
+pub trait Optimizer {
+    // One iteration; moves `params` to the new (best known) point and returns its value
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptimizerKind {
+    Cobyla,
+    NelderMead,
+    Spsa,
+    GradientDescent,
+    Momentum,
+    Adam,
+}
+
+impl OptimizerKind {
+    pub const ALL: [OptimizerKind; 6] = [
+        OptimizerKind::Cobyla,
+        OptimizerKind::NelderMead,
+        OptimizerKind::Spsa,
+        OptimizerKind::GradientDescent,
+        OptimizerKind::Momentum,
+        OptimizerKind::Adam,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            OptimizerKind::Cobyla => "COBYLA",
+            OptimizerKind::NelderMead => "Nelder–Mead",
+            OptimizerKind::Spsa => "SPSA",
+            OptimizerKind::GradientDescent => "Gradient descent",
+            OptimizerKind::Momentum => "Momentum",
+            OptimizerKind::Adam => "Adam",
+        }
+    }
+
+    // One line for tooltips
+    pub fn description(self) -> &'static str {
+        match self {
+            OptimizerKind::Cobyla => "Powell's linear-approximation trust region on a simplex, derivative-free",
+            OptimizerKind::NelderMead => "Derivative-free simplex reflections and contractions",
+            OptimizerKind::Spsa => "Two evaluations per step along a random direction, robust to shot noise",
+            OptimizerKind::GradientDescent => "Steps along the negative gradient",
+            OptimizerKind::Momentum => "Gradient descent with a velocity term (β = 0.9)",
+            OptimizerKind::Adam => "Per-parameter step sizes from running gradient moments",
+        }
+    }
+
+    pub fn uses_gradient(self) -> bool {
+        matches!(self, OptimizerKind::GradientDescent | OptimizerKind::Momentum | OptimizerKind::Adam)
+    }
+
+    // Trust-region radius, simplex size, SPSA gain or learning rate
+    pub fn build(self, step_size: f64, seed: u64) -> Box<dyn Optimizer> {
+        match self {
+            OptimizerKind::Cobyla => Box::new(Cobyla::new(step_size)),
+            OptimizerKind::NelderMead => Box::new(NelderMead::new(step_size)),
+            OptimizerKind::Spsa => Box::new(Spsa::new(step_size, 0.1, seed)),
+            OptimizerKind::GradientDescent => Box::new(GradientDescent::new(step_size)),
+            OptimizerKind::Momentum => Box::new(Momentum::new(step_size)),
+            OptimizerKind::Adam => Box::new(Adam::new(step_size)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/*
+--------------------------------------------------------------------
+                        SPSA
+                        ----
+Notes
+-----
+
+- simultaneous perturbation: two evaluations per step estimate the
+  whole gradient along a random ±1 direction, whatever the dimension
+- gains a_k = a / (k + 1 + A)^0.602 and c_k = c / (k + 1)^0.101 are
+  Spall's recommended decay rates, with stability constant A = 10
+- tolerant of noisy objectives such as shot-based expectations
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ALPHA: f64 = 0.602;
+const GAMMA: f64 = 0.101;
+const STABILITY: f64 = 10.0;
+
+pub struct Spsa {
+    pub a: f64,
+    pub c: f64,
+    iteration: usize,
+    rng: StdRng,
+}
+
+impl Spsa {
+    pub fn new(a: f64, c: f64, seed: u64) -> Self {
+        Self {
+            a,
+            c,
+            iteration: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Optimizer for Spsa {
+    fn step(&mut self, objective: &mut dyn Objective, params: &mut Array1<f64>) -> f64 {
+        let k = self.iteration as f64;
+        let a_k = self.a / (k + 1.0 + STABILITY).powf(ALPHA);
+        let c_k = self.c / (k + 1.0).powf(GAMMA);
+        self.iteration += 1;
+
+        let delta = Array1::from_shape_fn(params.len(), |_| if self.rng.random::<bool>() { 1.0 } else { -1.0 });
+        let plus = objective.value(&(&*params + &(&delta * c_k)));
+        let minus = objective.value(&(&*params - &(&delta * c_k)));
+        // 1 / delta_i = delta_i for ±1 entries
+        let gradient = delta * ((plus - minus) / (2.0 * c_k));
+        params.scaled_add(-a_k, &gradient);
+        objective.value(params)
+    }
+}
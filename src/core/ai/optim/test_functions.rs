@@ -0,0 +1,163 @@
+/*
+--------------------------------------------------------------------
+                        Test Functions
+                        --------------
+Notes
+-----
+
+- classic 2-D optimisation benchmarks, each an Objective with its
+  analytic gradient
+- Rosenbrock: a curved, flat-bottomed valley; Himmelblau: four equal
+  minima; Beale: sharp ridges and plateaus; Rastrigin: a bowl covered
+  in local minima; Saddle: x² - y², unbounded below; Ill-conditioned:
+  an elongated bowl (condition number 25) that makes descent zig-zag
+- bounds, a start point and a stable learning rate are suggestions for
+  plotting and racing optimizers
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::Objective;
+use ndarray::{Array1, array};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TestFunction {
+    Rosenbrock,
+    Himmelblau,
+    Beale,
+    Rastrigin,
+    Saddle,
+    IllConditioned,
+}
+
+impl TestFunction {
+    pub const ALL: [TestFunction; 6] = [
+        TestFunction::Rosenbrock,
+        TestFunction::Himmelblau,
+        TestFunction::Beale,
+        TestFunction::Rastrigin,
+        TestFunction::Saddle,
+        TestFunction::IllConditioned,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TestFunction::Rosenbrock => "Rosenbrock",
+            TestFunction::Himmelblau => "Himmelblau",
+            TestFunction::Beale => "Beale",
+            TestFunction::Rastrigin => "Rastrigin",
+            TestFunction::Saddle => "Saddle",
+            TestFunction::IllConditioned => "Ill-conditioned quadratic",
+        }
+    }
+
+    pub fn formula(self) -> &'static str {
+        match self {
+            TestFunction::Rosenbrock => "(1 - x)² + 100 (y - x²)²",
+            TestFunction::Himmelblau => "(x² + y - 11)² + (x + y² - 7)²",
+            TestFunction::Beale => "(1.5 - x + xy)² + (2.25 - x + xy²)² + (2.625 - x + xy³)²",
+            TestFunction::Rastrigin => "20 + x² - 10 cos 2πx + y² - 10 cos 2πy",
+            TestFunction::Saddle => "x² - y²",
+            TestFunction::IllConditioned => "x² + 25 y²",
+        }
+    }
+
+    pub fn value(self, x: f64, y: f64) -> f64 {
+        match self {
+            TestFunction::Rosenbrock => (1.0 - x).powi(2) + 100.0 * (y - x * x).powi(2),
+            TestFunction::Himmelblau => (x * x + y - 11.0).powi(2) + (x + y * y - 7.0).powi(2),
+            TestFunction::Beale => {
+                let [a, b, c] = Self::beale_terms(x, y);
+                a * a + b * b + c * c
+            }
+            TestFunction::Rastrigin => 20.0 + x * x - 10.0 * (2.0 * PI * x).cos() + y * y - 10.0 * (2.0 * PI * y).cos(),
+            TestFunction::Saddle => x * x - y * y,
+            TestFunction::IllConditioned => x * x + 25.0 * y * y,
+        }
+    }
+
+    pub fn gradient(self, x: f64, y: f64) -> [f64; 2] {
+        match self {
+            TestFunction::Rosenbrock => [-2.0 * (1.0 - x) - 400.0 * x * (y - x * x), 200.0 * (y - x * x)],
+            TestFunction::Himmelblau => {
+                let (a, b) = (x * x + y - 11.0, x + y * y - 7.0);
+                [4.0 * x * a + 2.0 * b, 2.0 * a + 4.0 * y * b]
+            }
+            TestFunction::Beale => {
+                let [a, b, c] = Self::beale_terms(x, y);
+                [
+                    2.0 * a * (y - 1.0) + 2.0 * b * (y * y - 1.0) + 2.0 * c * (y * y * y - 1.0),
+                    2.0 * a * x + 4.0 * b * x * y + 6.0 * c * x * y * y,
+                ]
+            }
+            TestFunction::Rastrigin => [
+                2.0 * x + 20.0 * PI * (2.0 * PI * x).sin(),
+                2.0 * y + 20.0 * PI * (2.0 * PI * y).sin(),
+            ],
+            TestFunction::Saddle => [2.0 * x, -2.0 * y],
+            TestFunction::IllConditioned => [2.0 * x, 50.0 * y],
+        }
+    }
+
+    fn beale_terms(x: f64, y: f64) -> [f64; 3] {
+        [1.5 - x + x * y, 2.25 - x + x * y * y, 2.625 - x + x * y * y * y]
+    }
+
+    // [x_min, x_max, y_min, y_max]
+    pub fn bounds(self) -> [f64; 4] {
+        match self {
+            TestFunction::Rosenbrock => [-2.0, 2.0, -1.0, 3.0],
+            TestFunction::Himmelblau => [-5.0, 5.0, -5.0, 5.0],
+            TestFunction::Beale => [-4.5, 4.5, -4.5, 4.5],
+            TestFunction::Rastrigin => [-5.12, 5.12, -5.12, 5.12],
+            TestFunction::Saddle => [-2.0, 2.0, -2.0, 2.0],
+            TestFunction::IllConditioned => [-5.0, 5.0, -5.0, 5.0],
+        }
+    }
+
+    // Global minima; the saddle has none
+    pub fn minima(self) -> Vec<[f64; 2]> {
+        match self {
+            TestFunction::Rosenbrock => vec![[1.0, 1.0]],
+            TestFunction::Himmelblau => vec![[3.0, 2.0], [-2.805118, 3.131312], [-3.779310, -3.283186], [3.584428, -1.848126]],
+            TestFunction::Beale => vec![[3.0, 0.5]],
+            TestFunction::Rastrigin | TestFunction::IllConditioned => vec![[0.0, 0.0]],
+            TestFunction::Saddle => Vec::new(),
+        }
+    }
+
+    pub fn start(self) -> [f64; 2] {
+        match self {
+            TestFunction::Rosenbrock => [-1.5, 2.0],
+            TestFunction::Himmelblau => [-0.5, -0.5],
+            TestFunction::Beale => [1.0, 1.5],
+            TestFunction::Rastrigin => [3.3, -2.7],
+            TestFunction::Saddle => [1.8, 0.01],
+            TestFunction::IllConditioned => [-4.5, 3.0],
+        }
+    }
+
+    // Below 2 / (largest curvature along the way), so plain descent converges
+    pub fn learning_rate(self) -> f64 {
+        match self {
+            TestFunction::Rosenbrock => 0.001,
+            TestFunction::Himmelblau => 0.01,
+            TestFunction::Beale => 0.01,
+            TestFunction::Rastrigin => 0.002,
+            TestFunction::Saddle => 0.05,
+            TestFunction::IllConditioned => 0.035,
+        }
+    }
+}
+
+impl Objective for TestFunction {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        TestFunction::value(*self, params[0], params[1])
+    }
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        let [dx, dy] = TestFunction::gradient(*self, params[0], params[1]);
+        array![dx, dy]
+    }
+}
@@ -7,11 +7,15 @@ Notes
 
 - 0 weights are fine for linear regression but not NN
 - uses Kaiming/He uniform initialization for small random number initialization
+- training is gradient descent through the shared Optimizer interface, on
+  [weights..., bias] with the analytic MSE gradient
 
 --------------------------------------------------------------------
 */
 
-use ndarray::Array2;
+use crate::core::ai::optim::gradient_descent::GradientDescent;
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use ndarray::{Array1, Array2, s};
 use ndarray_rand::RandomExt;
 use rand::distr::Uniform;
 
@@ -37,11 +41,45 @@ impl Default for TrainingConfig {
 }
 
 pub fn vprint(text: String, verbose: &bool) {
-    if *verbose == true {
+    if *verbose {
         tracing::debug!(text);
     }
 }
 
+// Half the mean squared error over [weights..., bias]; the 1/2 makes the
+// gradient the classic 1/m * X(transpose) * error
+pub struct MseObjective<'a> {
+    pub x: &'a Array2<f64>,
+    pub y: &'a Array2<f64>,
+}
+
+impl MseObjective<'_> {
+    fn residuals(&self, params: &Array1<f64>) -> Array2<f64> {
+        let n = self.x.ncols();
+        let weights = params.slice(s![..n]).to_owned().insert_axis(ndarray::Axis(1));
+        self.x.dot(&weights) + params[n] - self.y
+    }
+}
+
+impl Objective for MseObjective<'_> {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        let residuals = self.residuals(params);
+        (&residuals * &residuals).sum() / (2.0 * residuals.nrows() as f64)
+    }
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        // partial derivative on weights = 1/m * X(transpose) * error
+        // partial derivative on bias = 1/m * sum(error)
+        let residuals = self.residuals(params);
+        let rows = residuals.nrows() as f64;
+        let grad_w = self.x.t().dot(&residuals) / rows;
+        let mut gradient = Array1::zeros(params.len());
+        gradient.slice_mut(s![..self.x.ncols()]).assign(&grad_w.column(0));
+        gradient[self.x.ncols()] = residuals.sum() / rows;
+        gradient
+    }
+}
+
 impl LinearRegression {
     pub fn new() -> Self {
         Self {
@@ -55,31 +93,26 @@ impl LinearRegression {
         let limit = 1.0 / (n as f64).sqrt();
         let dist = Uniform::new(-limit, limit).unwrap(); // Kaiming/He uniform initialization
         self.weights = Array2::random((n, 1), dist);
+        if y_train.shape() != [x_train.nrows(), 1] {
+            tracing::error!("Shape mismatched!");
+            return;
+        };
+
+        let mut params = Array1::zeros(n + 1);
+        params.slice_mut(s![..n]).assign(&self.weights.column(0));
+        params[n] = self.bias;
+        let mut objective = MseObjective { x: &x_train, y: &y_train };
+        let mut optimizer = GradientDescent::new(config.learning_rate);
 
         // Training loop
         for epoch in 0..config.epochs {
             vprint("Epoch: ".to_string() + &epoch.to_string(), &config.verbose);
-            // Forward pass
-            let y_hat = x_train.dot(&self.weights) + self.bias;
-            // Loss calculation [MSE]
-            if y_hat.shape() != y_train.shape() {
-                tracing::error!("Shape mismatched!");
-                return;
-            };
-            let rows = y_train.nrows();
-            let residuals = &y_hat - &y_train;
-            let squared_residuals = &residuals * &residuals;
-            let mse = squared_residuals.sum() / (rows as f64);
-            vprint("MSE: ".to_string() + &mse.to_string(), &config.verbose);
-            // Gradient computation
-            // partial derivative on weights = 1/m * X(transpose) * error
-            // partial derivative on bias = 1/m * sum(error)
-            let grad_w = x_train.t().dot(&residuals) / rows as f64;
-            let grad_b = residuals.sum() / rows as f64;
-            // update parameters
-            self.weights = &self.weights - &(config.learning_rate * grad_w);
-            self.bias = self.bias - config.learning_rate * grad_b;
+            let loss = optimizer.step(&mut objective, &mut params);
+            vprint("MSE: ".to_string() + &(2.0 * loss).to_string(), &config.verbose);
         }
+
+        self.weights.column_mut(0).assign(&params.slice(s![..n]));
+        self.bias = params[n];
     }
 
     pub fn predict(&self, x_test: Array2<f64>) -> Array2<f64> {
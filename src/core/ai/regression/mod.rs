@@ -1,9 +1,8 @@
 pub mod linear_regression;
 
-use linear_regression::{LinearRegression, TrainingConfig};
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::linear_regression::{LinearRegression, TrainingConfig};
     use ndarray::array;
 
     #[test]
@@ -0,0 +1,54 @@
+/*
+--------------------------------------------------------------------
+                        Kernels
+                        -------
+Notes
+-----
+
+- a kernel is an inner product in some feature space, evaluated on
+  raw inputs (rows of an Array2)
+- matrix builds K[i, j] = k(a_i, b_j); kernels with an expensive
+  feature map override it to map every row only once
+- RBF: exp(-γ |a - b|²); linear: a · b
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array2, ArrayView1};
+
+pub trait Kernel {
+    fn name(&self) -> String;
+
+    fn eval(&self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64;
+
+    fn matrix(&self, a: &Array2<f64>, b: &Array2<f64>) -> Array2<f64> {
+        Array2::from_shape_fn((a.nrows(), b.nrows()), |(i, j)| self.eval(a.row(i), b.row(j)))
+    }
+}
+
+pub struct LinearKernel;
+
+impl Kernel for LinearKernel {
+    fn name(&self) -> String {
+        "Linear".to_string()
+    }
+
+    fn eval(&self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64 {
+        a.dot(&b)
+    }
+}
+
+pub struct RbfKernel {
+    pub gamma: f64,
+}
+
+impl Kernel for RbfKernel {
+    fn name(&self) -> String {
+        format!("RBF (γ = {:.2})", self.gamma)
+    }
+
+    fn eval(&self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64 {
+        let distance: f64 = a.iter().zip(b.iter()).map(|(u, v)| (u - v).powi(2)).sum();
+        (-self.gamma * distance).exp()
+    }
+}
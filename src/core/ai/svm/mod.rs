@@ -0,0 +1,58 @@
+pub mod kernel;
+pub mod support_vector_machine;
+
+#[cfg(test)]
+mod tests {
+    use super::kernel::{Kernel, LinearKernel, RbfKernel};
+    use super::support_vector_machine::{Svm, SvmConfig};
+    use ndarray::{Array2, array};
+
+    fn accuracy(svm: &Svm, x: &Array2<f64>, y: &Array2<f64>) -> f64 {
+        let predictions = svm.predict(x.clone());
+        predictions.iter().zip(y.iter()).filter(|(p, y)| p == y).count() as f64 / y.nrows() as f64
+    }
+
+    // Points on two rings: inner ring is class 1
+    fn rings() -> (Array2<f64>, Array2<f64>) {
+        let n = 24;
+        let x = Array2::from_shape_fn((n, 2), |(i, j)| {
+            let angle = i as f64 * 0.9;
+            let radius = if i % 2 == 1 { 0.3 } else { 1.0 };
+            if j == 0 { radius * angle.cos() } else { radius * angle.sin() }
+        });
+        let y = Array2::from_shape_fn((n, 1), |(i, _)| (i % 2) as f64);
+        (x, y)
+    }
+
+    #[test]
+    fn test_kernels() {
+        let a = array![[1.0, 2.0], [0.0, -1.0]];
+        let linear = LinearKernel.matrix(&a, &a);
+        assert_eq!(linear, array![[5.0, -2.0], [-2.0, 1.0]]);
+        let rbf = RbfKernel { gamma: 0.5 }.matrix(&a, &a);
+        assert!((rbf[[0, 0]] - 1.0).abs() < 1e-12 && (rbf[[0, 1]] - (-0.5f64 * 10.0).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_svm_margins() {
+        // Separable along x + y = 0: the two closest points are the support vectors
+        let x = array![[1.0, 1.0], [2.0, 2.5], [3.0, 1.0], [-1.0, -1.0], [-2.0, -1.5], [-1.0, -3.0]];
+        let y = array![[1.0], [1.0], [1.0], [0.0], [0.0], [0.0]];
+        let mut svm = Svm::new(Box::new(LinearKernel));
+        svm.train(x.clone(), y.clone(), SvmConfig { c: 100.0, ..SvmConfig::default() });
+        assert_eq!(accuracy(&svm, &x, &y), 1.0);
+        assert_eq!(svm.support, vec![0, 3]);
+        // Hard margin: support vectors sit on f = ±1
+        let f = svm.decision_function(&x);
+        assert!((f[0] - 1.0).abs() < 1e-2 && (f[3] + 1.0).abs() < 1e-2, "{}", f);
+
+        // Rings need a non-linear kernel
+        let (x, y) = rings();
+        let mut linear = Svm::new(Box::new(LinearKernel));
+        linear.train(x.clone(), y.clone(), SvmConfig::default());
+        let mut rbf = Svm::new(Box::new(RbfKernel { gamma: 2.0 }));
+        rbf.train(x.clone(), y.clone(), SvmConfig { c: 10.0, ..SvmConfig::default() });
+        assert!(accuracy(&linear, &x, &y) < 0.8);
+        assert_eq!(accuracy(&rbf, &x, &y), 1.0);
+    }
+}
@@ -0,0 +1,170 @@
+/*
+--------------------------------------------------------------------
+                        Support Vector Machine
+                        ----------------------
+Notes
+-----
+
+- soft-margin binary SVM in the dual: f(x) = Σ α_i y_i k(x_i, x) + b,
+  0 <= α_i <= C, with labels 0/1 mapped to -1/+1
+- trained by SMO: repeatedly pick a KKT-violating α_i and a partner
+  α_j (largest |E_i - E_j| first, where E = f - y), solve the two-variable
+  problem in closed form and clip it to the box
+- the Gram matrix is computed once up front, so any Kernel works,
+  including ones that run circuits
+- stops after `max_passes` sweeps in a row without a change
+- only support vectors (α > 0) are kept for prediction
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::svm::kernel::Kernel;
+use ndarray::{Array1, Array2, Axis};
+
+// α below this counts as zero
+const ALPHA_EPSILON: f64 = 1e-8;
+
+pub struct SvmConfig {
+    // Margin violation penalty; larger fits the training data harder
+    pub c: f64,
+    pub tolerance: f64,
+    pub max_passes: usize,
+    pub max_sweeps: usize,
+}
+
+impl Default for SvmConfig {
+    fn default() -> Self {
+        Self {
+            c: 1.0,
+            tolerance: 1e-3,
+            max_passes: 5,
+            max_sweeps: 1000,
+        }
+    }
+}
+
+pub struct Svm {
+    kernel: Box<dyn Kernel>,
+    pub support_vectors: Array2<f64>,
+    // α_i y_i of each support vector
+    pub coefficients: Array1<f64>,
+    pub bias: f64,
+    // Rows of the training data that became support vectors
+    pub support: Vec<usize>,
+}
+
+impl Svm {
+    pub fn new(kernel: Box<dyn Kernel>) -> Self {
+        Self {
+            kernel,
+            support_vectors: Array2::zeros((0, 0)),
+            coefficients: Array1::zeros(0),
+            bias: 0.0,
+            support: Vec::new(),
+        }
+    }
+
+    pub fn kernel(&self) -> &dyn Kernel {
+        self.kernel.as_ref()
+    }
+
+    pub fn train(&mut self, x_train: Array2<f64>, y_train: Array2<f64>, config: SvmConfig) {
+        let n = x_train.nrows();
+        if y_train.shape() != [n, 1] {
+            tracing::error!("Shape mismatched!");
+            return;
+        }
+        let y: Array1<f64> = y_train.column(0).mapv(|v| if v > 0.5 { 1.0 } else { -1.0 });
+        let k = self.kernel.matrix(&x_train, &x_train);
+        let mut alpha = Array1::<f64>::zeros(n);
+        let mut b = 0.0;
+        let errors = |alpha: &Array1<f64>, b: f64| -> Array1<f64> { k.dot(&(alpha * &y)) + b - &y };
+
+        let (mut passes, mut sweeps) = (0, 0);
+        while passes < config.max_passes && sweeps < config.max_sweeps {
+            sweeps += 1;
+            let mut changed = false;
+            for i in 0..n {
+                let e = errors(&alpha, b);
+                let violates = (y[i] * e[i] < -config.tolerance && alpha[i] < config.c)
+                    || (y[i] * e[i] > config.tolerance && alpha[i] > 0.0);
+                if !violates {
+                    continue;
+                }
+                let mut partners: Vec<usize> = (0..n).filter(|&j| j != i).collect();
+                partners.sort_by(|&p, &q| (e[i] - e[q]).abs().total_cmp(&(e[i] - e[p]).abs()));
+                for j in partners {
+                    if let Some((ai, aj, new_b)) = take_step(i, j, &alpha, b, &y, &e, &k, config.c) {
+                        alpha[i] = ai;
+                        alpha[j] = aj;
+                        b = new_b;
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+            passes = if changed { 0 } else { passes + 1 };
+        }
+
+        self.support = (0..n).filter(|&i| alpha[i] > ALPHA_EPSILON).collect();
+        self.support_vectors = x_train.select(Axis(0), &self.support);
+        self.coefficients = self.support.iter().map(|&i| alpha[i] * y[i]).collect();
+        self.bias = b;
+    }
+
+    pub fn decision_function(&self, x: &Array2<f64>) -> Array1<f64> {
+        if self.support.is_empty() {
+            return Array1::from_elem(x.nrows(), self.bias);
+        }
+        self.kernel.matrix(x, &self.support_vectors).dot(&self.coefficients) + self.bias
+    }
+
+    // Labels 0/1, one row per sample
+    pub fn predict(&self, x_test: Array2<f64>) -> Array2<f64> {
+        self.decision_function(&x_test).mapv(|f| if f > 0.0 { 1.0 } else { 0.0 }).insert_axis(Axis(1))
+    }
+}
+
+// Joint optimum of (α_i, α_j) on their constraint line, clipped to [0, C]
+#[allow(clippy::too_many_arguments)]
+fn take_step(
+    i: usize,
+    j: usize,
+    alpha: &Array1<f64>,
+    b: f64,
+    y: &Array1<f64>,
+    e: &Array1<f64>,
+    k: &Array2<f64>,
+    c: f64,
+) -> Option<(f64, f64, f64)> {
+    let (low, high) = if y[i] != y[j] {
+        ((alpha[j] - alpha[i]).max(0.0), (c + alpha[j] - alpha[i]).min(c))
+    } else {
+        ((alpha[i] + alpha[j] - c).max(0.0), (alpha[i] + alpha[j]).min(c))
+    };
+    if high - low < 1e-12 {
+        return None;
+    }
+    // Curvature along the line; <= 0 only for degenerate pairs
+    let eta = 2.0 * k[[i, j]] - k[[i, i]] - k[[j, j]];
+    if eta >= -1e-12 {
+        return None;
+    }
+    let aj = (alpha[j] - y[j] * (e[i] - e[j]) / eta).clamp(low, high);
+    if (aj - alpha[j]).abs() < 1e-7 {
+        return None;
+    }
+    let ai = alpha[i] + y[i] * y[j] * (alpha[j] - aj);
+
+    let (di, dj) = (ai - alpha[i], aj - alpha[j]);
+    let b1 = b - e[i] - y[i] * di * k[[i, i]] - y[j] * dj * k[[i, j]];
+    let b2 = b - e[j] - y[i] * di * k[[i, j]] - y[j] * dj * k[[j, j]];
+    let new_b = if ai > 0.0 && ai < c {
+        b1
+    } else if aj > 0.0 && aj < c {
+        b2
+    } else {
+        (b1 + b2) / 2.0
+    };
+    Some((ai, aj, new_b))
+}
@@ -0,0 +1,94 @@
+/*
+--------------------------------------------------------------------
+                        Toy Datasets
+                        ------------
+Notes
+-----
+
+- two-feature points like the regression view's DataPoint, plus a
+  binary class label
+- every generator lands roughly in [-1, 1] x [-1, 1], so features can
+  be used as rotation angles after a single scale factor
+- seeded, so a view can regenerate the same points
+
+--------------------------------------------------------------------
+*/
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabeledPoint {
+    pub x: f64,
+    pub y: f64,
+    pub label: bool,
+}
+
+impl LabeledPoint {
+    pub fn features(&self) -> [f64; 2] {
+        [self.x, self.y]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dataset {
+    Blobs,
+    Moons,
+    Circles,
+    Xor,
+}
+
+impl Dataset {
+    pub const ALL: [Dataset; 4] = [Dataset::Blobs, Dataset::Moons, Dataset::Circles, Dataset::Xor];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Dataset::Blobs => "Blobs",
+            Dataset::Moons => "Moons",
+            Dataset::Circles => "Circles",
+            Dataset::Xor => "XOR",
+        }
+    }
+
+    // Alternating labels, so any prefix is balanced
+    pub fn generate(self, n: usize, noise: f64, seed: u64) -> Vec<LabeledPoint> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|i| {
+                let label = i % 2 == 1;
+                let (x, y) = match self {
+                    Dataset::Blobs => {
+                        let c = if label { 0.5 } else { -0.5 };
+                        (c + rng.random_range(-0.3..0.3), c + rng.random_range(-0.3..0.3))
+                    }
+                    Dataset::Moons => {
+                        let t = rng.random_range(0.0..PI);
+                        if label {
+                            (0.5 - 0.8 * t.cos(), 0.25 - 0.8 * t.sin())
+                        } else {
+                            (-0.5 + 0.8 * t.cos(), -0.15 + 0.8 * t.sin())
+                        }
+                    }
+                    Dataset::Circles => {
+                        let t = rng.random_range(0.0..2.0 * PI);
+                        let r = if label { 0.35 } else { 0.85 };
+                        (r * t.cos(), r * t.sin())
+                    }
+                    Dataset::Xor => {
+                        let x: f64 = rng.random_range(0.15..0.9);
+                        let y: f64 = rng.random_range(0.15..0.9);
+                        // Same-sign quadrants are class 0
+                        let flip = if rng.random::<bool>() { -1.0 } else { 1.0 };
+                        if label { (flip * x, -flip * y) } else { (flip * x, flip * y) }
+                    }
+                };
+                LabeledPoint {
+                    x: x + noise * rng.random_range(-1.0..1.0),
+                    y: y + noise * rng.random_range(-1.0..1.0),
+                    label,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,200 @@
+/*
+--------------------------------------------------------------------
+                        Feature Maps
+                        ------------
+Notes
+-----
+
+- a feature map turns a classical vector x into a circuit U(x); the
+  encoded state is U(x)|0...0⟩
+- angle: Ry(x_i) on qubit i, one qubit per feature
+- amplitude: the (zero-padded, normalized) vector becomes the real
+  amplitudes of ceil(log2 d) qubits, loaded by a tree of uniformly
+  controlled Ry rotations; the norm is lost
+- basis: qubit i is |1⟩ when x_i > 0
+- IQP: (H, Rz(2 x_i), ZZ(2 x_i x_j)) repeated, Havlíček-style "ZZ":
+  (H, P(2 x_i), ZZ(2 (π - x_i)(π - x_j))) repeated
+- data re-uploading: the features are fed in again in every layer,
+  separated by CZ entanglers, so deeper circuits see higher frequencies
+- the kernel k(x, x') = |⟨φ(x)|φ(x')⟩|² is the fidelity of the states
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::Array2;
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FeatureMap {
+    Angle,
+    Amplitude,
+    Basis,
+    Iqp { reps: usize },
+    ZZ { reps: usize },
+    ReUploading { layers: usize },
+}
+
+impl FeatureMap {
+    pub const ALL: [FeatureMap; 6] = [
+        FeatureMap::Angle,
+        FeatureMap::Amplitude,
+        FeatureMap::Basis,
+        FeatureMap::Iqp { reps: 2 },
+        FeatureMap::ZZ { reps: 2 },
+        FeatureMap::ReUploading { layers: 2 },
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FeatureMap::Angle => "Angle",
+            FeatureMap::Amplitude => "Amplitude",
+            FeatureMap::Basis => "Basis",
+            FeatureMap::Iqp { .. } => "IQP",
+            FeatureMap::ZZ { .. } => "ZZ",
+            FeatureMap::ReUploading { .. } => "Re-uploading",
+        }
+    }
+
+    // Repetitions or layers, for the maps that have them
+    pub fn depth(self) -> Option<usize> {
+        match self {
+            FeatureMap::Iqp { reps } | FeatureMap::ZZ { reps } => Some(reps),
+            FeatureMap::ReUploading { layers } => Some(layers),
+            _ => None,
+        }
+    }
+
+    pub fn with_depth(self, depth: usize) -> Self {
+        match self {
+            FeatureMap::Iqp { .. } => FeatureMap::Iqp { reps: depth },
+            FeatureMap::ZZ { .. } => FeatureMap::ZZ { reps: depth },
+            FeatureMap::ReUploading { .. } => FeatureMap::ReUploading { layers: depth },
+            other => other,
+        }
+    }
+
+    pub fn num_qubits(self, num_features: usize) -> usize {
+        match self {
+            FeatureMap::Amplitude => num_features.next_power_of_two().trailing_zeros().max(1) as usize,
+            _ => num_features,
+        }
+    }
+
+    pub fn circuit(self, x: &[f64]) -> Circuit {
+        let n = self.num_qubits(x.len());
+        let mut circuit = Circuit::new(n);
+        match self {
+            FeatureMap::Angle => {
+                for (q, &v) in x.iter().enumerate() {
+                    circuit.push(Operation::new(Gate::Ry(v), vec![q]));
+                }
+            }
+            FeatureMap::Amplitude => {
+                let mut amplitudes = x.to_vec();
+                amplitudes.resize(1 << n, 0.0);
+                append_amplitude_loader(&mut circuit, &amplitudes);
+            }
+            FeatureMap::Basis => {
+                for (q, &v) in x.iter().enumerate() {
+                    if v > 0.0 {
+                        circuit.push(Operation::new(Gate::X, vec![q]));
+                    }
+                }
+            }
+            FeatureMap::Iqp { reps } => {
+                for _ in 0..reps {
+                    append_diagonal_layer(&mut circuit, x, |v| 2.0 * v, |a, b| 2.0 * a * b);
+                }
+            }
+            FeatureMap::ZZ { reps } => {
+                for _ in 0..reps {
+                    append_diagonal_layer(&mut circuit, x, |v| 2.0 * v, |a, b| 2.0 * (PI - a) * (PI - b));
+                }
+            }
+            FeatureMap::ReUploading { layers } => {
+                for layer in 0..layers {
+                    for (q, &v) in x.iter().enumerate() {
+                        circuit.push(Operation::new(Gate::Ry(v), vec![q]));
+                        circuit.push(Operation::new(Gate::Rz(x[(q + 1) % x.len()]), vec![q]));
+                    }
+                    if layer + 1 < layers {
+                        for q in 0..n.saturating_sub(1) {
+                            circuit.push(Operation::controlled(Gate::Z, vec![q], vec![q + 1]));
+                        }
+                    }
+                }
+            }
+        }
+        circuit
+    }
+
+    pub fn state(self, x: &[f64]) -> StateVector {
+        run_unitary(&self.circuit(x))
+    }
+
+    pub fn gram_matrix(self, points: &[Vec<f64>]) -> Array2<f64> {
+        let states: Vec<StateVector> = points.iter().map(|x| self.state(x)).collect();
+        Array2::from_shape_fn((points.len(), points.len()), |(i, j)| states[i].inner(&states[j]).norm_sqr())
+    }
+}
+
+// H on every qubit, single-qubit phases, then pairwise ZZ phases via CX-Rz-CX
+fn append_diagonal_layer(
+    circuit: &mut Circuit,
+    x: &[f64],
+    single: impl Fn(f64) -> f64,
+    pair: impl Fn(f64, f64) -> f64,
+) {
+    for (q, &v) in x.iter().enumerate() {
+        circuit.push(Operation::new(Gate::H, vec![q]));
+        circuit.push(Operation::new(Gate::Phase(single(v)), vec![q]));
+    }
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            circuit.push(Operation::controlled(Gate::X, vec![i], vec![j]));
+            circuit.push(Operation::new(Gate::Rz(pair(x[i], x[j])), vec![j]));
+            circuit.push(Operation::controlled(Gate::X, vec![i], vec![j]));
+        }
+    }
+}
+
+// Top qubit first: each level splits the remaining weight with an Ry
+// controlled on the already-fixed higher qubits (X-conjugated for 0s)
+fn append_amplitude_loader(circuit: &mut Circuit, amplitudes: &[f64]) {
+    let n = circuit.num_qubits();
+    if amplitudes.iter().all(|a| a.abs() < 1e-12) {
+        return;
+    }
+    for level in 0..n {
+        let target = n - 1 - level;
+        let block = 1 << (target + 1);
+        let controls: Vec<usize> = (target + 1..n).collect();
+        for prefix in 0..1usize << level {
+            let start = prefix * block;
+            let half = block / 2;
+            let (low, high) = (&amplitudes[start..start + half], &amplitudes[start + half..start + block]);
+            let angle = if half == 1 {
+                // Leaves keep their signs
+                2.0 * high[0].atan2(low[0])
+            } else {
+                let norm = |v: &[f64]| v.iter().map(|a| a * a).sum::<f64>().sqrt();
+                2.0 * norm(high).atan2(norm(low))
+            };
+            if angle.abs() < 1e-12 {
+                continue;
+            }
+            let zeros: Vec<usize> = controls.iter().copied().filter(|&c| prefix >> (c - target - 1) & 1 == 0).collect();
+            for &c in &zeros {
+                circuit.push(Operation::new(Gate::X, vec![c]));
+            }
+            circuit.push(Operation::controlled(Gate::Ry(angle), controls.clone(), vec![target]));
+            for &c in &zeros {
+                circuit.push(Operation::new(Gate::X, vec![c]));
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+pub mod dataset;
+pub mod feature_maps;
+pub mod pipeline;
+pub mod qnn;
+pub mod quantum_kernel;
+
+#[cfg(test)]
+mod tests {
+    use super::dataset::Dataset;
+    use super::feature_maps::FeatureMap;
+    use super::pipeline::{Pipeline, QuantumLayer, Scaler, StageSpec};
+    use super::qnn::Qnn;
+    use super::quantum_kernel::QuantumKernel;
+    use crate::core::ai::activations::activation::Activation;
+    use crate::core::ai::nn::dense::Dense;
+    use crate::core::ai::nn::initializer::Initializer;
+    use crate::core::ai::svm::kernel::Kernel;
+    use crate::core::ai::svm::support_vector_machine::{Svm, SvmConfig};
+    use crate::core::ai::optim::optimizer::finite_difference;
+    use crate::core::ai::regression::linear_regression::TrainingConfig;
+    use crate::core::quantum::gradients::GradientMethod;
+    use crate::core::quantum::backend::Backend;
+    use ndarray::Array2;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_datasets_are_balanced_and_bounded() {
+        for dataset in Dataset::ALL {
+            let points = dataset.generate(40, 0.05, 7);
+            assert_eq!(points.iter().filter(|p| p.label).count(), 20, "{}", dataset.name());
+            assert!(points.iter().all(|p| p.x.abs() < 1.5 && p.y.abs() < 1.5), "{}", dataset.name());
+            assert_eq!(points, dataset.generate(40, 0.05, 7));
+        }
+    }
+
+    #[test]
+    fn test_simple_encodings() {
+        // Angle: Ry(x) tilts qubit 0 by x from +z towards +x
+        let state = FeatureMap::Angle.state(&[PI / 2.0, PI]);
+        let [x, _, z] = state.bloch_vector(0);
+        assert!((x - 1.0).abs() < 1e-9 && z.abs() < 1e-9);
+        assert!((state.bloch_vector(1)[2] + 1.0).abs() < 1e-9);
+
+        // Basis: signs pick the bits
+        let probabilities = FeatureMap::Basis.state(&[0.3, -0.2, 0.9]).probabilities();
+        assert!((probabilities[0b101] - 1.0).abs() < 1e-9);
+
+        // Amplitude: real, signed, zero-padded and normalized
+        for x in [vec![0.6, -0.8], vec![1.0, 2.0, -2.0], vec![0.0, 0.0, 0.0, 3.0, -1.0, 0.5, 0.2]] {
+            let state = FeatureMap::Amplitude.state(&x);
+            let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+            assert_eq!(state.num_qubits(), FeatureMap::Amplitude.num_qubits(x.len()));
+            for (i, a) in state.amplitudes().iter().enumerate() {
+                let expected = x.get(i).copied().unwrap_or(0.0) / norm;
+                assert!((a.re - expected).abs() < 1e-9 && a.im.abs() < 1e-9, "{:?}: {} vs {}", x, a, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kernels_are_fidelities() {
+        let points: Vec<Vec<f64>> = Dataset::Moons.generate(8, 0.1, 1).iter().map(|p| p.features().to_vec()).collect();
+        for map in FeatureMap::ALL {
+            let gram = map.gram_matrix(&points);
+            for i in 0..points.len() {
+                assert!((gram[[i, i]] - 1.0).abs() < 1e-9, "{}", map.name());
+                for j in 0..points.len() {
+                    assert!((gram[[i, j]] - gram[[j, i]]).abs() < 1e-9 && (-1e-9..=1.0 + 1e-9).contains(&gram[[i, j]]));
+                }
+            }
+        }
+
+        // Angle encoding factorizes: Π cos²((x_i - x'_i) / 2)
+        let (a, b) = ([0.3, -1.1], [1.2, 0.4]);
+        let expected: f64 = a.iter().zip(&b).map(|(u, v)| ((u - v) / 2.0f64).cos().powi(2)).product();
+        let kernel = FeatureMap::Angle.gram_matrix(&[a.to_vec(), b.to_vec()])[[0, 1]];
+        assert!((kernel - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_qnn_learns_circles() {
+        let points = Dataset::Circles.generate(40, 0.05, 3);
+        let x = Array2::from_shape_fn((40, 2), |(i, j)| points[i].features()[j]);
+        let y = Array2::from_shape_fn((40, 1), |(i, _)| if points[i].label { 1.0 } else { 0.0 });
+
+        let mut model = Qnn::new(FeatureMap::ReUploading { layers: 2 }, 1.5, 2, 2, 0);
+        assert_eq!(model.weights.len(), 8);
+        for _ in 0..3 {
+            let config = TrainingConfig { learning_rate: 0.5, epochs: 20, verbose: false };
+            model.train(x.clone(), y.clone(), config);
+        }
+        assert_eq!(model.loss_history.len(), 60);
+        assert!(model.loss_history[59] < 0.5 * model.loss_history[0], "{:?}", model.loss_history);
+
+        let predictions = model.predict(x);
+        assert_eq!(predictions.shape(), [40, 1]);
+        assert!(predictions.iter().all(|p| (0.0..=1.0).contains(p)));
+        let correct = predictions.iter().zip(y.iter()).filter(|(p, y)| (**p > 0.5) == (**y > 0.5)).count();
+        assert!(correct >= 36, "{}/40", correct);
+    }
+
+    #[test]
+    fn test_quantum_kernel_svm() {
+        let kernel = QuantumKernel { feature_map: FeatureMap::ZZ { reps: 1 }, scale: 1.5 };
+        let train = Dataset::Circles.generate(40, 0.05, 1);
+        let test = Dataset::Circles.generate(40, 0.05, 2);
+        let features = |points: &[super::dataset::LabeledPoint]| Array2::from_shape_fn((points.len(), 2), |(i, j)| points[i].features()[j]);
+        let labels = |points: &[super::dataset::LabeledPoint]| Array2::from_shape_fn((points.len(), 1), |(i, _)| if points[i].label { 1.0 } else { 0.0 });
+
+        // Same entries as the feature map's own Gram matrix, scaled inputs
+        let x = features(&train);
+        let gram = kernel.matrix(&x, &x);
+        let scaled: Vec<Vec<f64>> = train.iter().map(|p| p.features().iter().map(|v| 1.5 * v).collect()).collect();
+        assert!((&gram - &FeatureMap::ZZ { reps: 1 }.gram_matrix(&scaled)).iter().all(|d| d.abs() < 1e-12));
+        assert!((kernel.eval(x.row(0), x.row(1)) - gram[[0, 1]]).abs() < 1e-12);
+
+        let mut svm = Svm::new(Box::new(kernel));
+        svm.train(x, labels(&train), SvmConfig { c: 10.0, ..SvmConfig::default() });
+        let predictions = svm.predict(features(&test));
+        let correct = predictions.iter().zip(labels(&test).iter()).filter(|(p, y)| p == y).count();
+        assert!(correct >= 36, "{}/40 on held-out points", correct);
+        assert!(!svm.support.is_empty() && svm.support.len() < 40);
+    }
+
+    fn hybrid_pipeline(x: &Array2<f64>, method: GradientMethod) -> Pipeline {
+        Pipeline::new(vec![
+            Box::new(Scaler::fit(x)),
+            Box::new(Dense::new(2, 2, Activation::Tanh, Initializer::Xavier, &mut StdRng::seed_from_u64(1))),
+            Box::new(QuantumLayer::new(2, 2, method, 2)),
+            Box::new(Dense::new(2, 1, Activation::Sigmoid, Initializer::Xavier, &mut StdRng::seed_from_u64(3))),
+        ])
+    }
+
+    #[test]
+    fn test_pipeline_gradients_flow_through_every_stage() {
+        let points = Dataset::Moons.generate(8, 0.05, 4);
+        let x = Array2::from_shape_fn((8, 2), |(i, j)| points[i].features()[j]);
+        let sample = x.row(3).to_owned();
+        let label = if points[3].label { 1.0 } else { 0.0 };
+
+        for method in GradientMethod::ALL {
+            let mut pipeline = hybrid_pipeline(&x, method);
+            let params = pipeline.params();
+            assert_eq!(params.len(), 6 + 8 + 3);
+            let tensors = pipeline.trace(&sample);
+            assert_eq!(tensors.iter().map(|t| t.len()).collect::<Vec<_>>(), vec![2, 2, 2, 2, 1]);
+            let output = tensors[4][0];
+            let grads = pipeline.backward(&tensors, &ndarray::array![Pipeline::loss(output, label).1]);
+            let analytic: Vec<f64> = grads.iter().flatten().copied().collect();
+
+            let numerical = finite_difference(|p| {
+                pipeline.set_params(p);
+                Pipeline::loss(pipeline.trace(&sample)[4][0], label).0
+            }, &params);
+            for (i, (a, n)) in analytic.iter().zip(&numerical).enumerate() {
+                assert!((a - n).abs() < 1e-5, "{} parameter {}: {} vs {}", method.name(), i, a, n);
+            }
+            assert!(grads[2].iter().any(|g| g.abs() > 1e-6), "no gradient reached the quantum layer");
+        }
+
+        // The quantum layer reports its state, classical stages do not
+        let pipeline = hybrid_pipeline(&x, GradientMethod::Adjoint);
+        let tensors = pipeline.trace(&sample);
+        let state = pipeline.stages[2].state(&tensors[2]).unwrap();
+        assert_eq!(state.num_qubits(), 2);
+        assert!((state.bloch_vector(0)[2] - tensors[3][0]).abs() < 1e-12);
+        assert!(pipeline.stages[1].state(&tensors[1]).is_none());
+    }
+
+    #[test]
+    fn test_pipeline_specs_are_checked_before_building() {
+        let x = Array2::from_shape_fn((4, 2), |(i, j)| (i * 2 + j) as f64);
+        let dense = |outputs, activation| StageSpec::Dense { outputs, activation };
+        let readout = dense(1, Activation::Sigmoid);
+        let build = |specs: &[StageSpec]| Pipeline::build(specs, &x, GradientMethod::Adjoint, 0);
+
+        let pipeline = build(&[StageSpec::Scaler, dense(3, Activation::ReLU), StageSpec::Quantum { qubits: 3, layers: 1 }, readout]).unwrap();
+        assert_eq!(pipeline.trace(&x.row(1).to_owned()).iter().map(|t| t.len()).collect::<Vec<_>>(), vec![2, 2, 3, 3, 1]);
+        assert_eq!(pipeline.params().len(), 9 + 6 + 4);
+
+        // A quantum layer encodes one value per qubit
+        let error = build(&[dense(3, Activation::Tanh), StageSpec::Quantum { qubits: 2, layers: 1 }, readout]).err().unwrap();
+        assert_eq!(error.stage, 1);
+        let error = build(&[dense(2, Activation::Tanh), StageSpec::Scaler, readout]).err().unwrap();
+        assert_eq!(error.stage, 1);
+        let error = build(&[StageSpec::Quantum { qubits: 2, layers: 1 }]).err().unwrap();
+        assert_eq!(error.stage, 0);
+        assert!(error.message.contains("sigmoid"));
+        assert!(build(&[]).is_err());
+        assert!(build(&[StageSpec::Quantum { qubits: 2, layers: 1 }, readout]).is_ok());
+    }
+
+    #[test]
+    fn test_pipeline_learns_moons() {
+        let points = Dataset::Moons.generate(40, 0.05, 5);
+        let x = Array2::from_shape_fn((40, 2), |(i, j)| points[i].features()[j]);
+        let y = Array2::from_shape_fn((40, 1), |(i, _)| if points[i].label { 1.0 } else { 0.0 });
+        let mut pipeline = hybrid_pipeline(&x, GradientMethod::Adjoint);
+        pipeline.train(x.clone(), y.clone(), TrainingConfig { learning_rate: 0.5, epochs: 150, verbose: false });
+        assert!(pipeline.loss_history[149] < 0.5 * pipeline.loss_history[0], "{:?}", pipeline.loss_history);
+        let correct = pipeline.predict(x).iter().zip(y.iter()).filter(|(p, y)| (**p > 0.5) == (**y > 0.5)).count();
+        assert!(correct >= 36, "{}/40", correct);
+    }
+}
@@ -0,0 +1,392 @@
+/*
+--------------------------------------------------------------------
+                        Hybrid Pipeline
+                        ---------------
+Notes
+-----
+
+- a chain of stages, each a differentiable map from one vector to the
+  next: classical preprocessing, a quantum layer, classical readout
+- every stage provides forward and a vector-Jacobian product: given
+  dL/d(output) it returns dL/d(input) and dL/d(its parameters), so the
+  loss gradient flows backwards through the whole chain
+- classical layers are nn::Dense (and its Activation) run on one sample
+  at a time, so the hybrid and classical networks share one layer type
+- the quantum layer angle-encodes its inputs, applies trainable
+  Ry-Rz-CZ layers and outputs ⟨Z_k⟩ per qubit; its VJP is the gradient
+  of ⟨Σ g_k Z_k⟩ with respect to inputs and weights together, one
+  gradient evaluation whatever the number of outputs
+- binary classification: the last stage should end in a sigmoid, and
+  the loss is the mean cross-entropy
+- parameters are concatenated stage by stage for the Optimizer
+- StageSpec lists describe a pipeline before it has weights; building
+  checks the widths stage by stage (a quantum layer encodes one value
+  per qubit) and that the chain ends in one sigmoid output
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::activations::activation::Activation;
+use crate::core::ai::nn::dense::Dense;
+use crate::core::ai::nn::initializer::Initializer;
+use crate::core::ai::optim::gradient_descent::GradientDescent;
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use crate::core::ai::regression::linear_regression::{TrainingConfig, vprint};
+use crate::core::quantum::circuit::Operation;
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::gradients::{GradientMethod, gradient};
+use crate::core::quantum::parameters::{Axis, ParameterizedCircuit};
+use crate::core::quantum::pauli::{Hamiltonian, Pauli, PauliString};
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::{Array1, Array2, ArrayView1, Axis as ArrayAxis, concatenate, s};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+
+// Keeps the cross-entropy finite
+const EPSILON: f64 = 1e-9;
+
+pub trait Stage {
+    fn name(&self) -> String;
+
+    fn params(&self) -> Array1<f64>;
+
+    fn set_params(&mut self, params: ArrayView1<f64>);
+
+    fn forward(&self, input: &Array1<f64>) -> Array1<f64>;
+
+    // (dL/d input, dL/d params)
+    fn backward(&self, input: &Array1<f64>, grad_output: &Array1<f64>) -> (Array1<f64>, Array1<f64>);
+
+    // The quantum state a stage prepares, if it has one
+    fn state(&self, _input: &Array1<f64>) -> Option<StateVector> {
+        None
+    }
+}
+
+// Standardizes each feature with the training data's mean and spread
+pub struct Scaler {
+    pub mean: Array1<f64>,
+    pub std: Array1<f64>,
+}
+
+impl Scaler {
+    pub fn fit(x: &Array2<f64>) -> Self {
+        let mean = x.mean_axis(ArrayAxis(0)).expect("at least one row");
+        let std = x.std_axis(ArrayAxis(0), 0.0).mapv(|s| if s > 1e-12 { s } else { 1.0 });
+        Self { mean, std }
+    }
+}
+
+impl Stage for Scaler {
+    fn name(&self) -> String {
+        "Scaler".to_string()
+    }
+
+    fn params(&self) -> Array1<f64> {
+        Array1::zeros(0)
+    }
+
+    fn set_params(&mut self, _params: ArrayView1<f64>) {}
+
+    fn forward(&self, input: &Array1<f64>) -> Array1<f64> {
+        (input - &self.mean) / &self.std
+    }
+
+    fn backward(&self, _input: &Array1<f64>, grad_output: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
+        (grad_output / &self.std, Array1::zeros(0))
+    }
+}
+
+// nn::Dense applied to one sample as a one-row batch
+impl Stage for Dense {
+    fn name(&self) -> String {
+        format!("Dense {}→{} ({})", self.inputs(), self.outputs(), self.activation.name())
+    }
+
+    fn params(&self) -> Array1<f64> {
+        concatenate![ArrayAxis(0), self.weights.flatten(), self.bias]
+    }
+
+    fn set_params(&mut self, params: ArrayView1<f64>) {
+        let (inputs, outputs) = self.weights.dim();
+        self.weights.assign(&params.slice(s![..inputs * outputs]).to_shape((inputs, outputs)).expect("weight block"));
+        self.bias.assign(&params.slice(s![inputs * outputs..]));
+    }
+
+    fn forward(&self, input: &Array1<f64>) -> Array1<f64> {
+        let z = self.pre_activation(&input.view().insert_axis(ArrayAxis(0)).to_owned());
+        z.row(0).mapv(|z| self.activation.apply(z))
+    }
+
+    fn backward(&self, input: &Array1<f64>, grad_output: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
+        let batch = input.view().insert_axis(ArrayAxis(0)).to_owned();
+        let z = self.pre_activation(&batch);
+        let grads = Dense::backward(self, &batch, &z, &grad_output.view().insert_axis(ArrayAxis(0)).to_owned());
+        (grads.input.row(0).to_owned(), concatenate![ArrayAxis(0), grads.weights.flatten(), grads.bias])
+    }
+}
+
+// Ry(x_k) encoding, trainable layers, ⟨Z_k⟩ readout on every qubit
+pub struct QuantumLayer {
+    pub num_qubits: usize,
+    pub layers: usize,
+    pub weights: Array1<f64>,
+    pub method: GradientMethod,
+}
+
+impl QuantumLayer {
+    pub fn new(num_qubits: usize, layers: usize, method: GradientMethod, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self {
+            num_qubits,
+            layers,
+            weights: Array1::from_shape_fn(2 * num_qubits * layers, |_| rng.random_range(-1.0..1.0)),
+            method,
+        }
+    }
+
+    // Inputs are the first parameters ("x0", ...), weights follow
+    pub fn template(&self) -> ParameterizedCircuit {
+        let n = self.num_qubits;
+        let mut circuit = ParameterizedCircuit::new(n);
+        for q in 0..n {
+            circuit.rotation(Axis::Y, &format!("x{}", q), 1.0, vec![], q);
+        }
+        for layer in 0..self.layers {
+            for q in 0..n {
+                circuit.rotation(Axis::Y, &format!("w{}", 2 * (layer * n + q)), 1.0, vec![], q);
+                circuit.rotation(Axis::Z, &format!("w{}", 2 * (layer * n + q) + 1), 1.0, vec![], q);
+            }
+            for q in 0..n.saturating_sub(1) {
+                circuit.push(Operation::controlled(Gate::Z, vec![q], vec![q + 1]));
+            }
+        }
+        circuit
+    }
+
+    fn values(&self, input: &Array1<f64>) -> Array1<f64> {
+        concatenate![ArrayAxis(0), input.view(), self.weights]
+    }
+
+    // Σ coefficient_k Z_k
+    fn observable(&self, coefficients: &Array1<f64>) -> Hamiltonian {
+        Hamiltonian::new(coefficients.iter().enumerate().map(|(k, &c)| (c, PauliString::new([(k, Pauli::Z)]))).collect())
+    }
+}
+
+impl Stage for QuantumLayer {
+    fn name(&self) -> String {
+        format!("Quantum {}q × {} layers", self.num_qubits, self.layers)
+    }
+
+    fn params(&self) -> Array1<f64> {
+        self.weights.clone()
+    }
+
+    fn set_params(&mut self, params: ArrayView1<f64>) {
+        self.weights.assign(&params);
+    }
+
+    fn forward(&self, input: &Array1<f64>) -> Array1<f64> {
+        let state = self.state(input).expect("quantum layers prepare a state");
+        Array1::from_shape_fn(self.num_qubits, |k| PauliString::new([(k, Pauli::Z)]).expectation(&state))
+    }
+
+    fn backward(&self, input: &Array1<f64>, grad_output: &Array1<f64>) -> (Array1<f64>, Array1<f64>) {
+        let (grad, _) = gradient(self.method, &self.template(), &self.observable(grad_output), &self.values(input));
+        (grad.slice(s![..self.num_qubits]).to_owned(), grad.slice(s![self.num_qubits..]).to_owned())
+    }
+
+    fn state(&self, input: &Array1<f64>) -> Option<StateVector> {
+        Some(run_unitary(&self.template().bind(&self.values(input))))
+    }
+}
+
+// A stage before it has weights, as the pipeline builder edits it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StageSpec {
+    Scaler,
+    Dense { outputs: usize, activation: Activation },
+    Quantum { qubits: usize, layers: usize },
+}
+
+impl StageSpec {
+    pub fn name(self) -> String {
+        match self {
+            StageSpec::Scaler => "Scaler".to_string(),
+            StageSpec::Dense { outputs, activation } => format!("Dense →{} ({})", outputs, activation.name()),
+            StageSpec::Quantum { qubits, layers } => format!("Quantum {}q × {} layers", qubits, layers),
+        }
+    }
+
+    // Width of the output for `width` inputs, None if the stage cannot take them
+    pub fn output_width(self, width: usize) -> Option<usize> {
+        match self {
+            StageSpec::Scaler => Some(width),
+            StageSpec::Dense { outputs, .. } => Some(outputs),
+            StageSpec::Quantum { qubits, .. } => (qubits == width).then_some(qubits),
+        }
+    }
+}
+
+// Why a stage list cannot be built, at the offending stage
+#[derive(Clone, Debug, PartialEq)]
+pub struct PipelineError {
+    pub stage: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stage {}: {}", self.stage + 1, self.message)
+    }
+}
+
+pub struct Pipeline {
+    pub stages: Vec<Box<dyn Stage>>,
+    // Mean cross-entropy after every epoch
+    pub loss_history: Vec<f64>,
+}
+
+impl Pipeline {
+    pub fn new(stages: Vec<Box<dyn Stage>>) -> Self {
+        Self {
+            stages,
+            loss_history: Vec::new(),
+        }
+    }
+
+    // Checks `specs` for `x_train`'s features, then builds them with fresh
+    // weights; the scaler is fitted on `x_train`
+    pub fn build(specs: &[StageSpec], x_train: &Array2<f64>, method: GradientMethod, seed: u64) -> Result<Self, PipelineError> {
+        let error = |stage: usize, message: String| PipelineError { stage, message };
+        let Some(last) = specs.len().checked_sub(1) else {
+            return Err(error(0, "a pipeline needs at least one stage".to_string()));
+        };
+        let mut width = x_train.ncols();
+        let mut stages: Vec<Box<dyn Stage>> = Vec::with_capacity(specs.len());
+        for (i, &spec) in specs.iter().enumerate() {
+            let stage_seed = seed + i as u64;
+            let output = spec
+                .output_width(width)
+                .ok_or_else(|| error(i, format!("{} encodes one value per qubit but receives {}", spec.name(), width)))?;
+            stages.push(match spec {
+                StageSpec::Scaler if i > 0 => return Err(error(i, "the scaler is fitted on the raw features, so it must come first".to_string())),
+                StageSpec::Scaler => Box::new(Scaler::fit(x_train)),
+                StageSpec::Dense { outputs, activation } => {
+                    Box::new(Dense::new(width, outputs, activation, Initializer::Xavier, &mut StdRng::seed_from_u64(stage_seed)))
+                }
+                StageSpec::Quantum { qubits, layers } => Box::new(QuantumLayer::new(qubits, layers, method, stage_seed)),
+            });
+            width = output;
+        }
+        if !matches!(specs[last], StageSpec::Dense { outputs: 1, activation: Activation::Sigmoid }) {
+            return Err(error(last, "the last stage must be a sigmoid Dense layer with one output, the class probability".to_string()));
+        }
+        Ok(Self::new(stages))
+    }
+
+    // The input followed by every stage's output
+    pub fn trace(&self, input: &Array1<f64>) -> Vec<Array1<f64>> {
+        let mut tensors = vec![input.clone()];
+        for stage in &self.stages {
+            let next = stage.forward(tensors.last().expect("starts with the input"));
+            tensors.push(next);
+        }
+        tensors
+    }
+
+    pub fn params(&self) -> Array1<f64> {
+        self.stages.iter().flat_map(|stage| stage.params()).collect()
+    }
+
+    pub fn set_params(&mut self, params: &Array1<f64>) {
+        let mut offset = 0;
+        for stage in &mut self.stages {
+            let count = stage.params().len();
+            stage.set_params(params.slice(s![offset..offset + count]));
+            offset += count;
+        }
+    }
+
+    // dL/d(output) pushed back through every stage: dL/d(params) per stage
+    pub fn backward(&self, tensors: &[Array1<f64>], grad_output: &Array1<f64>) -> Vec<Array1<f64>> {
+        let mut grad = grad_output.clone();
+        let mut grads = Vec::with_capacity(self.stages.len());
+        for (stage, input) in self.stages.iter().zip(tensors).rev() {
+            let (grad_input, grad_params) = stage.backward(input, &grad);
+            grads.push(grad_params);
+            grad = grad_input;
+        }
+        grads.reverse();
+        grads
+    }
+
+    // Cross-entropy of one sample and its gradient with respect to the output
+    pub fn loss(output: f64, label: f64) -> (f64, f64) {
+        let p = output.clamp(EPSILON, 1.0 - EPSILON);
+        (-(label * p.ln() + (1.0 - label) * (1.0 - p).ln()), (p - label) / (p * (1.0 - p)))
+    }
+
+    pub fn train(&mut self, x_train: Array2<f64>, y_train: Array2<f64>, config: TrainingConfig) {
+        if y_train.shape() != [x_train.nrows(), 1] {
+            tracing::error!("Shape mismatched!");
+            return;
+        }
+        let mut params = self.params();
+        let mut optimizer = GradientDescent::new(config.learning_rate);
+        let mut objective = CrossEntropy {
+            pipeline: self,
+            x: &x_train,
+            y: &y_train,
+        };
+        let mut losses = Vec::with_capacity(config.epochs);
+        for epoch in 0..config.epochs {
+            vprint("Epoch: ".to_string() + &epoch.to_string(), &config.verbose);
+            let loss = optimizer.step(&mut objective, &mut params);
+            vprint("Loss: ".to_string() + &loss.to_string(), &config.verbose);
+            losses.push(loss);
+        }
+        self.set_params(&params);
+        self.loss_history.extend(losses);
+    }
+
+    // The final stage's first output for every row
+    pub fn predict(&self, x_test: Array2<f64>) -> Array2<f64> {
+        let outputs = x_test.rows().into_iter().map(|x| self.trace(&x.to_owned()).last().expect("output")[0]);
+        Array2::from_shape_vec((x_test.nrows(), 1), outputs.collect()).expect("one prediction per row")
+    }
+}
+
+struct CrossEntropy<'a> {
+    pipeline: &'a mut Pipeline,
+    x: &'a Array2<f64>,
+    y: &'a Array2<f64>,
+}
+
+impl Objective for CrossEntropy<'_> {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        self.pipeline.set_params(params);
+        let total: f64 = self.x.rows().into_iter().zip(self.y.column(0)).map(|(x, &y)| {
+            let output = self.pipeline.trace(&x.to_owned()).last().expect("output")[0];
+            Pipeline::loss(output, y).0
+        }).sum();
+        total / self.x.nrows() as f64
+    }
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        self.pipeline.set_params(params);
+        let mut total = Array1::zeros(params.len());
+        for (x, &y) in self.x.rows().into_iter().zip(self.y.column(0)) {
+            let tensors = self.pipeline.trace(&x.to_owned());
+            let output = tensors.last().expect("output");
+            let mut grad_output = Array1::zeros(output.len());
+            grad_output[0] = Pipeline::loss(output[0], y).1;
+            let grads = self.pipeline.backward(&tensors, &grad_output);
+            total += &concatenate(ArrayAxis(0), &grads.iter().map(|g| g.view()).collect::<Vec<_>>()).expect("flat gradient");
+        }
+        total / self.x.nrows() as f64
+    }
+}
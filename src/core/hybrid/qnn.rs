@@ -0,0 +1,161 @@
+/*
+--------------------------------------------------------------------
+                        Quantum Neural Network
+                        ----------------------
+Notes
+-----
+
+- a binary classifier: feature map U(x), then a trainable ansatz of
+  Ry-Rz on every qubit and a CZ chain per layer, then read ⟨Z0⟩
+- P(class 1) = (1 - ⟨Z0⟩) / 2, the probability of measuring qubit 0 as 1
+- loss is the mean binary cross-entropy; its gradient chains
+  dL/dp through dp/dw = -1/2 d⟨Z0⟩/dw, with d⟨Z0⟩/dw from the parameter
+  shift rule, i.e. the same shifted runs a device would need
+- train/predict mirror LinearRegression (rows are samples, labels are
+  0/1 in one column), except that training continues from the current
+  weights, so a view can run it an epoch at a time
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::gradient_descent::GradientDescent;
+use crate::core::ai::optim::optimizer::{Objective, Optimizer};
+use crate::core::ai::regression::linear_regression::{TrainingConfig, vprint};
+use crate::core::hybrid::feature_maps::FeatureMap;
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::gradients::{expectation, parameter_shift};
+use crate::core::quantum::parameters::{Axis, ParameterizedCircuit};
+use crate::core::quantum::pauli::{Hamiltonian, Pauli, PauliString};
+use ndarray::{Array1, Array2, ArrayView1};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Keeps the cross-entropy finite
+const EPSILON: f64 = 1e-9;
+
+pub struct Qnn {
+    pub feature_map: FeatureMap,
+    // Features are multiplied by this before encoding
+    pub scale: f64,
+    pub layers: usize,
+    pub num_features: usize,
+    pub weights: Array1<f64>,
+    // Mean cross-entropy after every epoch
+    pub loss_history: Vec<f64>,
+}
+
+impl Qnn {
+    pub fn new(feature_map: FeatureMap, scale: f64, layers: usize, num_features: usize, seed: u64) -> Self {
+        let mut model = Self {
+            feature_map,
+            scale,
+            layers,
+            num_features,
+            weights: Array1::zeros(0),
+            loss_history: Vec::new(),
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        let count = model.template(&vec![0.0; num_features]).num_parameters();
+        model.weights = Array1::from_shape_fn(count, |_| rng.random_range(-1.0..1.0));
+        model
+    }
+
+    // Feature map gates for this input, then the trainable ansatz
+    pub fn template(&self, x: &[f64]) -> ParameterizedCircuit {
+        let scaled: Vec<f64> = x.iter().map(|v| self.scale * v).collect();
+        let encoding = self.feature_map.circuit(&scaled);
+        let n = encoding.num_qubits();
+        let mut circuit = ParameterizedCircuit::new(n);
+        for operation in encoding.instructions().iter().filter_map(|i| i.operation()) {
+            circuit.push(operation.clone());
+        }
+        for layer in 0..self.layers {
+            for q in 0..n {
+                circuit.rotation(Axis::Y, &format!("w{}", 2 * (layer * n + q)), 1.0, vec![], q);
+                circuit.rotation(Axis::Z, &format!("w{}", 2 * (layer * n + q) + 1), 1.0, vec![], q);
+            }
+            for q in 0..n.saturating_sub(1) {
+                circuit.push(Operation::controlled(Gate::Z, vec![q], vec![q + 1]));
+            }
+        }
+        circuit
+    }
+
+    pub fn circuit(&self, x: &[f64]) -> Circuit {
+        self.template(x).bind(&self.weights)
+    }
+
+    pub fn train(&mut self, x_train: Array2<f64>, y_train: Array2<f64>, config: TrainingConfig) {
+        if x_train.ncols() != self.num_features || y_train.shape() != [x_train.nrows(), 1] {
+            tracing::error!("Shape mismatched!");
+            return;
+        }
+        let mut objective = CrossEntropy {
+            model: self,
+            x: &x_train,
+            y: &y_train,
+        };
+        let mut weights = objective.model.weights.clone();
+        let mut optimizer = GradientDescent::new(config.learning_rate);
+        let mut losses = Vec::with_capacity(config.epochs);
+        for epoch in 0..config.epochs {
+            vprint("Epoch: ".to_string() + &epoch.to_string(), &config.verbose);
+            let loss = optimizer.step(&mut objective, &mut weights);
+            vprint("Loss: ".to_string() + &loss.to_string(), &config.verbose);
+            losses.push(loss);
+        }
+        self.weights = weights;
+        self.loss_history.extend(losses);
+    }
+
+    // P(class 1) for every row
+    pub fn predict(&self, x_test: Array2<f64>) -> Array2<f64> {
+        let readout = readout();
+        let probabilities = x_test.rows().into_iter().map(|x| probability(&self.template(&row(x)), &readout, &self.weights));
+        Array2::from_shape_vec((x_test.nrows(), 1), probabilities.collect()).expect("one prediction per row")
+    }
+}
+
+fn row(x: ArrayView1<f64>) -> Vec<f64> {
+    x.iter().copied().collect()
+}
+
+// Z on qubit 0
+fn readout() -> Hamiltonian {
+    Hamiltonian::new(vec![(1.0, PauliString::new([(0, Pauli::Z)]))])
+}
+
+fn probability(circuit: &ParameterizedCircuit, readout: &Hamiltonian, weights: &Array1<f64>) -> f64 {
+    (1.0 - expectation(circuit, readout, weights)) / 2.0
+}
+
+struct CrossEntropy<'a> {
+    model: &'a Qnn,
+    x: &'a Array2<f64>,
+    y: &'a Array2<f64>,
+}
+
+impl Objective for CrossEntropy<'_> {
+    fn value(&mut self, weights: &Array1<f64>) -> f64 {
+        let readout = readout();
+        let total: f64 = self.x.rows().into_iter().zip(self.y.column(0)).map(|(x, &y)| {
+            let p = probability(&self.model.template(&row(x)), &readout, weights).clamp(EPSILON, 1.0 - EPSILON);
+            -(y * p.ln() + (1.0 - y) * (1.0 - p).ln())
+        }).sum();
+        total / self.x.nrows() as f64
+    }
+
+    fn gradient(&mut self, weights: &Array1<f64>) -> Array1<f64> {
+        let readout = readout();
+        let mut gradient = Array1::zeros(weights.len());
+        for (x, &y) in self.x.rows().into_iter().zip(self.y.column(0)) {
+            let template = self.model.template(&row(x));
+            let p = probability(&template, &readout, weights).clamp(EPSILON, 1.0 - EPSILON);
+            let (d_expectation, _) = parameter_shift(&template, &readout, weights);
+            // dL/dp * dp/dw
+            gradient.scaled_add((p - y) / (p * (1.0 - p)) * -0.5, &d_expectation);
+        }
+        gradient / self.x.nrows() as f64
+    }
+}
@@ -0,0 +1,49 @@
+/*
+--------------------------------------------------------------------
+                        Quantum Kernel
+                        --------------
+Notes
+-----
+
+- k(x, x') = |⟨φ(x)|φ(x')⟩|², the fidelity of the feature-map states,
+  so any classical kernel method (here the SVM) can use it unchanged
+- on hardware each entry is estimated from U(x')† U(x) runs; the
+  state vector gives it exactly
+- matrix simulates every row once and then only takes overlaps
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::svm::kernel::Kernel;
+use crate::core::hybrid::feature_maps::FeatureMap;
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::{Array2, ArrayView1};
+
+pub struct QuantumKernel {
+    pub feature_map: FeatureMap,
+    // Features are multiplied by this before encoding
+    pub scale: f64,
+}
+
+impl QuantumKernel {
+    fn state(&self, x: ArrayView1<f64>) -> StateVector {
+        let scaled: Vec<f64> = x.iter().map(|v| self.scale * v).collect();
+        self.feature_map.state(&scaled)
+    }
+}
+
+impl Kernel for QuantumKernel {
+    fn name(&self) -> String {
+        format!("Quantum ({})", self.feature_map.name())
+    }
+
+    fn eval(&self, a: ArrayView1<f64>, b: ArrayView1<f64>) -> f64 {
+        self.state(a).inner(&self.state(b)).norm_sqr()
+    }
+
+    fn matrix(&self, a: &Array2<f64>, b: &Array2<f64>) -> Array2<f64> {
+        let left: Vec<StateVector> = a.rows().into_iter().map(|x| self.state(x)).collect();
+        let right: Vec<StateVector> = b.rows().into_iter().map(|x| self.state(x)).collect();
+        Array2::from_shape_fn((left.len(), right.len()), |(i, j)| left[i].inner(&right[j]).norm_sqr())
+    }
+}
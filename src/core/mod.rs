@@ -1,2 +1,3 @@
 pub mod ai;
+pub mod hybrid;
 pub mod quantum;
\ No newline at end of file
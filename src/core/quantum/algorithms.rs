@@ -0,0 +1,263 @@
+/*
+--------------------------------------------------------------------
+                        Textbook Algorithms
+                        -------------------
+Notes
+-----
+
+- every constructor returns a ready-to-run Circuit whose answer lands in
+  classical register c, read as an integer with c[0] the lowest bit
+- oracles are built from X/CX/multi-controlled gates so they show up in
+  the composer and debugger like any other gate
+- Deutsch-Jozsa and Bernstein-Vazirani share the phase-kickback oracle
+  f(x) = s.x (mod 2), plus a constant offset
+- Simon's oracle is 2-to-1 with f(x) = f(x ^ s); the secret comes from
+  classical linear algebra over GF(2) on the sampled y (s.y = 0)
+- QFT acts on qubits[0] as the least significant bit, swaps included
+- phase estimation uses U = P(2 pi phi) on its eigenstate |1>
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Comparison, Condition, Instruction, Operation, Register};
+use crate::core::quantum::gates::Gate;
+use std::f64::consts::PI;
+
+fn with_output(num_qubits: usize, num_clbits: usize) -> Circuit {
+    Circuit::with_registers(vec![Register::new("q", num_qubits)], vec![Register::new("c", num_clbits)])
+}
+
+fn hadamards(circuit: &mut Circuit, qubits: impl IntoIterator<Item = usize>) {
+    for q in qubits {
+        circuit.push(Operation::new(Gate::H, vec![q]));
+    }
+}
+
+fn measure_into_c(circuit: &mut Circuit, qubits: impl IntoIterator<Item = usize>) {
+    for (clbit, qubit) in qubits.into_iter().enumerate() {
+        circuit.push(Instruction::Measure { qubit, clbit });
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DjOracle {
+    Constant(bool),
+    // f(x) = mask.x (mod 2), balanced for any non-zero mask
+    Balanced(u64),
+}
+
+// f(x) = mask.x (+ offset) written into the ancilla
+fn parity_oracle(circuit: &mut Circuit, num_inputs: usize, mask: u64, offset: bool) {
+    for q in (0..num_inputs).filter(|q| mask >> q & 1 == 1) {
+        circuit.push(Operation::controlled(Gate::X, vec![q], vec![num_inputs]));
+    }
+    if offset {
+        circuit.push(Operation::new(Gate::X, vec![num_inputs]));
+    }
+}
+
+// Inputs q0..q(n-1), ancilla qn in |-⟩; reads all zeros iff f is constant
+pub fn deutsch_jozsa(num_inputs: usize, oracle: DjOracle) -> Circuit {
+    let mut circuit = with_output(num_inputs + 1, num_inputs);
+    circuit.push(Operation::new(Gate::X, vec![num_inputs]));
+    hadamards(&mut circuit, 0..=num_inputs);
+    match oracle {
+        DjOracle::Constant(value) => parity_oracle(&mut circuit, num_inputs, 0, value),
+        DjOracle::Balanced(mask) => parity_oracle(&mut circuit, num_inputs, mask, false),
+    }
+    hadamards(&mut circuit, 0..num_inputs);
+    measure_into_c(&mut circuit, 0..num_inputs);
+    circuit
+}
+
+// Reads the secret string s of f(x) = s.x in a single query
+pub fn bernstein_vazirani(num_inputs: usize, secret: u64) -> Circuit {
+    let mut circuit = with_output(num_inputs + 1, num_inputs);
+    circuit.push(Operation::new(Gate::X, vec![num_inputs]));
+    hadamards(&mut circuit, 0..=num_inputs);
+    parity_oracle(&mut circuit, num_inputs, secret, false);
+    hadamards(&mut circuit, 0..num_inputs);
+    measure_into_c(&mut circuit, 0..num_inputs);
+    circuit
+}
+
+// Inputs q0..q(n-1), outputs qn..q(2n-1); every reading y has s.y = 0
+pub fn simon(num_inputs: usize, secret: u64) -> Circuit {
+    let n = num_inputs;
+    let mut circuit = with_output(2 * n, n);
+    hadamards(&mut circuit, 0..n);
+    for q in 0..n {
+        circuit.push(Operation::controlled(Gate::X, vec![q], vec![n + q]));
+    }
+    // XOR s into the output whenever x has s's lowest set bit, so f(x) = f(x ^ s)
+    if secret != 0 {
+        let pivot = secret.trailing_zeros() as usize;
+        for k in (0..n).filter(|k| secret >> k & 1 == 1) {
+            circuit.push(Operation::controlled(Gate::X, vec![pivot], vec![n + k]));
+        }
+    }
+    hadamards(&mut circuit, 0..n);
+    measure_into_c(&mut circuit, 0..n);
+    circuit
+}
+
+// The unique non-zero s with s.y = 0 for every sample, if the samples pin it down
+pub fn simon_secret(samples: &[u64], num_inputs: usize) -> Option<u64> {
+    let mut candidates = (1..1u64 << num_inputs).filter(|s| samples.iter().all(|y| (s & y).count_ones() % 2 == 0));
+    let first = candidates.next()?;
+    candidates.next().is_none().then_some(first)
+}
+
+// Z on |1...1⟩ of the given qubits
+fn multi_controlled_z(circuit: &mut Circuit, qubits: &[usize]) {
+    let (target, controls) = qubits.split_last().expect("at least one qubit");
+    circuit.push(Operation::controlled(Gate::Z, controls.to_vec(), vec![*target]));
+}
+
+// Flips the sign of every marked basis state
+pub fn grover_oracle(circuit: &mut Circuit, num_qubits: usize, marked: &[usize]) {
+    let qubits: Vec<usize> = (0..num_qubits).collect();
+    for &m in marked {
+        let zeros: Vec<usize> = qubits.iter().copied().filter(|q| m >> q & 1 == 0).collect();
+        for &q in &zeros {
+            circuit.push(Operation::new(Gate::X, vec![q]));
+        }
+        multi_controlled_z(circuit, &qubits);
+        for &q in &zeros {
+            circuit.push(Operation::new(Gate::X, vec![q]));
+        }
+    }
+}
+
+// Inversion about the mean, 2|s⟩⟨s| - I (up to a global phase)
+pub fn grover_diffuser(circuit: &mut Circuit, num_qubits: usize) {
+    let qubits: Vec<usize> = (0..num_qubits).collect();
+    hadamards(circuit, 0..num_qubits);
+    for &q in &qubits {
+        circuit.push(Operation::new(Gate::X, vec![q]));
+    }
+    multi_controlled_z(circuit, &qubits);
+    for &q in &qubits {
+        circuit.push(Operation::new(Gate::X, vec![q]));
+    }
+    hadamards(circuit, 0..num_qubits);
+}
+
+pub fn grover(num_qubits: usize, marked: &[usize], iterations: usize) -> Circuit {
+    let mut circuit = with_output(num_qubits, num_qubits);
+    hadamards(&mut circuit, 0..num_qubits);
+    for _ in 0..iterations {
+        grover_oracle(&mut circuit, num_qubits, marked);
+        grover_diffuser(&mut circuit, num_qubits);
+    }
+    measure_into_c(&mut circuit, 0..num_qubits);
+    circuit
+}
+
+// sin^2((2k + 1) theta) with sin theta = sqrt(M/N)
+pub fn grover_success_probability(num_qubits: usize, num_marked: usize, iterations: usize) -> f64 {
+    let theta = (num_marked as f64 / (1u64 << num_qubits) as f64).sqrt().asin();
+    ((2 * iterations + 1) as f64 * theta).sin().powi(2)
+}
+
+// floor(pi/4 sqrt(N/M)), the iteration count closest to a full rotation
+pub fn optimal_grover_iterations(num_qubits: usize, num_marked: usize) -> usize {
+    if num_marked == 0 {
+        return 0;
+    }
+    (PI / 4.0 * ((1u64 << num_qubits) as f64 / num_marked as f64).sqrt()).floor() as usize
+}
+
+fn qft_operations(qubits: &[usize]) -> Vec<Operation> {
+    let n = qubits.len();
+    let mut ops = Vec::new();
+    for j in (0..n).rev() {
+        ops.push(Operation::new(Gate::H, vec![qubits[j]]));
+        for k in (0..j).rev() {
+            let angle = PI / (1u64 << (j - k)) as f64;
+            ops.push(Operation::controlled(Gate::Phase(angle), vec![qubits[k]], vec![qubits[j]]));
+        }
+    }
+    for i in 0..n / 2 {
+        ops.push(Operation::new(Gate::Swap, vec![qubits[i], qubits[n - 1 - i]]));
+    }
+    ops
+}
+
+// |x⟩ -> sum_y e^(2 pi i x y / 2^n) |y⟩ / sqrt(2^n)
+pub fn append_qft(circuit: &mut Circuit, qubits: &[usize]) {
+    for op in qft_operations(qubits) {
+        circuit.push(op);
+    }
+}
+
+pub fn append_inverse_qft(circuit: &mut Circuit, qubits: &[usize]) {
+    for op in qft_operations(qubits).into_iter().rev() {
+        circuit.push(Operation::controlled(op.gate.inverse(), op.controls, op.targets));
+    }
+}
+
+// QFT of the basis state |input⟩
+pub fn qft(num_qubits: usize, input: usize) -> Circuit {
+    let mut circuit = Circuit::new(num_qubits);
+    for q in (0..num_qubits).filter(|q| input >> q & 1 == 1) {
+        circuit.push(Operation::new(Gate::X, vec![q]));
+    }
+    let qubits: Vec<usize> = (0..num_qubits).collect();
+    append_qft(&mut circuit, &qubits);
+    circuit
+}
+
+// Uniform superposition with phase 2 pi k y / 2^n, undone back to |k⟩ by the inverse QFT
+pub fn inverse_qft(num_qubits: usize, frequency: usize) -> Circuit {
+    let mut circuit = with_output(num_qubits, num_qubits);
+    hadamards(&mut circuit, 0..num_qubits);
+    for q in 0..num_qubits {
+        let angle = 2.0 * PI * frequency as f64 * (1u64 << q) as f64 / (1u64 << num_qubits) as f64;
+        circuit.push(Operation::new(Gate::Phase(angle), vec![q]));
+    }
+    let qubits: Vec<usize> = (0..num_qubits).collect();
+    append_inverse_qft(&mut circuit, &qubits);
+    measure_into_c(&mut circuit, 0..num_qubits);
+    circuit
+}
+
+// Counting qubits q0..q(t-1), eigenstate |1⟩ on qt; c / 2^t estimates phi
+pub fn phase_estimation(precision: usize, phase: f64) -> Circuit {
+    let t = precision;
+    let mut circuit = with_output(t + 1, t);
+    circuit.push(Operation::new(Gate::X, vec![t]));
+    hadamards(&mut circuit, 0..t);
+    for k in 0..t {
+        // Controlled U^(2^k)
+        let angle = 2.0 * PI * phase * (1u64 << k) as f64;
+        circuit.push(Operation::controlled(Gate::Phase(angle), vec![k], vec![t]));
+    }
+    let counting: Vec<usize> = (0..t).collect();
+    append_inverse_qft(&mut circuit, &counting);
+    measure_into_c(&mut circuit, 0..t);
+    circuit
+}
+
+// Sends U(theta, phi, 0)|0⟩ from q0 to q2 using a Bell pair and two classical bits
+pub fn teleportation(theta: f64, phi: f64) -> Circuit {
+    let mut circuit = with_output(3, 2);
+    circuit
+        .push(Operation::new(Gate::U(theta, phi, 0.0), vec![0]))
+        .push(Instruction::Barrier(vec![0, 1, 2]))
+        .push(Operation::new(Gate::H, vec![1]))
+        .push(Operation::controlled(Gate::X, vec![1], vec![2]))
+        .push(Instruction::Barrier(vec![0, 1, 2]))
+        .push(Operation::controlled(Gate::X, vec![0], vec![1]))
+        .push(Operation::new(Gate::H, vec![0]))
+        .push(Instruction::Measure { qubit: 0, clbit: 0 })
+        .push(Instruction::Measure { qubit: 1, clbit: 1 });
+    for (clbit, gate) in [(1, Gate::X), (0, Gate::Z)] {
+        circuit.push(Instruction::If {
+            condition: Condition { clbits: vec![clbit], comparison: Comparison::Ne, value: 0 },
+            then: vec![Instruction::Gate(Operation::new(gate, vec![2]))],
+            otherwise: Vec::new(),
+        });
+    }
+    circuit
+}
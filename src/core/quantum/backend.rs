@@ -0,0 +1,107 @@
+/*
+--------------------------------------------------------------------
+                        Simulation Backend
+                        ------------------
+Notes
+-----
+
+- common circuit-execution interface shared by every state type
+- a backend only has to know how to apply a (controlled) unitary
+- apply is provided on top of apply_matrix; whole circuits run shot by
+  shot through the executor
+- measurement takes a uniform sample in [0, 1) so callers own the rng
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::Operation;
+use crate::core::quantum::gates::Gate;
+use ndarray::{Array2, ArrayViewMut1};
+use num_complex::Complex64;
+
+pub trait Backend: Clone {
+    // |0...0> on the given number of qubits
+    fn new(num_qubits: usize) -> Self;
+
+    fn num_qubits(&self) -> usize;
+
+    // Applies a 2^k x 2^k unitary to k target qubits, only on the subspace
+    // where every control qubit is |1>
+    fn apply_matrix(&mut self, matrix: &Array2<Complex64>, targets: &[usize], controls: &[usize]);
+
+    // Probability of each computational basis state
+    fn probabilities(&self) -> Vec<f64>;
+
+    // (x, y, z) of the reduced single-qubit state; length < 1 when mixed
+    fn bloch_vector(&self, qubit: usize) -> [f64; 3];
+
+    // Projective Z measurement; reads 1 when sample < P(1), then collapses
+    fn measure(&mut self, qubit: usize, sample: f64) -> bool;
+
+    fn reset(&mut self, qubit: usize, sample: f64) {
+        if self.measure(qubit, sample) {
+            self.apply(&Operation::new(Gate::X, vec![qubit]));
+        }
+    }
+
+    fn apply(&mut self, operation: &Operation) {
+        self.apply_matrix(&operation.gate.matrix(), &operation.targets, &operation.controls);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackendKind {
+    StateVector,
+    DensityMatrix,
+}
+
+impl BackendKind {
+    pub const ALL: [BackendKind; 2] = [BackendKind::StateVector, BackendKind::DensityMatrix];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BackendKind::StateVector => "State vector",
+            BackendKind::DensityMatrix => "Density matrix",
+        }
+    }
+}
+
+// Shared kernel: applies the unitary to one vector of 2^n entries. The
+// density matrix reuses it on every column (U) and every row (conj U).
+pub fn apply_unitary(
+    mut data: ArrayViewMut1<Complex64>,
+    matrix: &Array2<Complex64>,
+    targets: &[usize],
+    controls: &[usize],
+) {
+    let dim = 1 << targets.len();
+    assert_eq!(matrix.nrows(), dim, "matrix does not match the number of targets");
+
+    let target_mask: usize = targets.iter().map(|&t| 1 << t).sum();
+    let control_mask: usize = controls.iter().map(|&c| 1 << c).sum();
+
+    // Offsets of each local basis state |j> inside the full index
+    let offsets: Vec<usize> = (0..dim)
+        .map(|j| {
+            targets
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| j >> bit & 1 == 1)
+                .map(|(_, &t)| 1 << t)
+                .sum()
+        })
+        .collect();
+
+    let mut local = vec![Complex64::new(0.0, 0.0); dim];
+    for base in 0..data.len() {
+        if base & target_mask != 0 || base & control_mask != control_mask {
+            continue;
+        }
+        for (j, offset) in offsets.iter().enumerate() {
+            local[j] = data[base | offset];
+        }
+        for (row, offset) in offsets.iter().enumerate() {
+            data[base | offset] = (0..dim).map(|col| matrix[[row, col]] * local[col]).sum();
+        }
+    }
+}
@@ -0,0 +1,395 @@
+/*
+--------------------------------------------------------------------
+                        Quantum Circuit
+                        ---------------
+Notes
+-----
+
+- qubit 0 is the least significant bit of a basis index (|q1 q0>)
+- instructions are stored in execution order
+- moments group instructions that act on disjoint qubits and bits
+- qubits/bits of all registers are numbered in declaration order
+- If/While blocks hold nested instructions that are decided per shot
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::gates::Gate;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Operation {
+    pub gate: Gate,
+    pub targets: Vec<usize>,
+    pub controls: Vec<usize>,
+}
+
+impl Operation {
+    pub fn new(gate: Gate, targets: Vec<usize>) -> Self {
+        Self::controlled(gate, Vec::new(), targets)
+    }
+
+    pub fn controlled(gate: Gate, controls: Vec<usize>, targets: Vec<usize>) -> Self {
+        assert_eq!(
+            gate.num_targets(),
+            targets.len(),
+            "{} expects {} target(s)",
+            gate.name(),
+            gate.num_targets()
+        );
+        Self { gate, targets, controls }
+    }
+
+    pub fn qubits(&self) -> Vec<usize> {
+        self.controls.iter().chain(self.targets.iter()).copied().collect()
+    }
+
+    // Gate type without parameters, e.g. "CX", "CCX" or "Rx"
+    pub fn name(&self) -> String {
+        "C".repeat(self.controls.len()) + self.gate.name()
+    }
+
+    // Display label such as "CX", "CCX" or "Rx(1.571)"
+    pub fn label(&self) -> String {
+        let mut label = self.name();
+        let params = self.gate.params();
+        if !params.is_empty() {
+            let args: Vec<String> = params.iter().map(|p| format!("{:.3}", p)).collect();
+            label += &format!("({})", args.join(", "));
+        }
+        label
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Eq => "==",
+            Comparison::Ne => "!=",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+        }
+    }
+}
+
+// Compares the integer read from `clbits` (first bit = LSB) with `value`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    pub clbits: Vec<usize>,
+    pub comparison: Comparison,
+    pub value: u64,
+}
+
+impl Condition {
+    pub fn holds(&self, bits: &[bool]) -> bool {
+        let read: u64 = self
+            .clbits
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| bits[**b])
+            .map(|(i, _)| 1 << i)
+            .sum();
+        match self.comparison {
+            Comparison::Eq => read == self.value,
+            Comparison::Ne => read != self.value,
+            Comparison::Lt => read < self.value,
+            Comparison::Le => read <= self.value,
+            Comparison::Gt => read > self.value,
+            Comparison::Ge => read >= self.value,
+        }
+    }
+}
+
+// Non-unitary steps (measure, reset, barrier, classical control) sit
+// alongside gates
+#[derive(Clone, Debug, PartialEq)]
+pub enum Instruction {
+    Gate(Operation),
+    Measure { qubit: usize, clbit: usize },
+    Reset(usize),
+    Barrier(Vec<usize>),
+    // Runs `instruction` only when classical register `register` reads `value`
+    Conditional {
+        register: usize,
+        value: u64,
+        instruction: Box<Instruction>,
+    },
+    // Structured control flow, decided per shot from measured bits
+    If {
+        condition: Condition,
+        then: Vec<Instruction>,
+        otherwise: Vec<Instruction>,
+    },
+    While {
+        condition: Condition,
+        body: Vec<Instruction>,
+    },
+}
+
+impl Instruction {
+    pub fn qubits(&self) -> Vec<usize> {
+        match self {
+            Instruction::Gate(operation) => operation.qubits(),
+            Instruction::Measure { qubit, .. } | Instruction::Reset(qubit) => vec![*qubit],
+            Instruction::Barrier(qubits) => qubits.clone(),
+            Instruction::Conditional { instruction, .. } => instruction.qubits(),
+            Instruction::If { then, otherwise, .. } => block_qubits(then.iter().chain(otherwise)),
+            Instruction::While { body, .. } => block_qubits(body.iter()),
+        }
+    }
+
+    // Renames every qubit, e.g. to shift an instruction to other wires
+    pub fn map_qubits(&mut self, map: &dyn Fn(usize) -> usize) {
+        match self {
+            Instruction::Gate(operation) => {
+                for q in operation.targets.iter_mut().chain(operation.controls.iter_mut()) {
+                    *q = map(*q);
+                }
+            }
+            Instruction::Measure { qubit, .. } | Instruction::Reset(qubit) => *qubit = map(*qubit),
+            Instruction::Barrier(qubits) => qubits.iter_mut().for_each(|q| *q = map(*q)),
+            Instruction::Conditional { instruction, .. } => instruction.map_qubits(map),
+            Instruction::If { then, otherwise, .. } => {
+                then.iter_mut().chain(otherwise.iter_mut()).for_each(|i| i.map_qubits(map));
+            }
+            Instruction::While { body, .. } => body.iter_mut().for_each(|i| i.map_qubits(map)),
+        }
+    }
+
+    pub fn operation(&self) -> Option<&Operation> {
+        match self {
+            Instruction::Gate(operation) => Some(operation),
+            Instruction::Conditional { instruction, .. } => instruction.operation(),
+            _ => None,
+        }
+    }
+
+    pub fn is_unitary(&self) -> bool {
+        matches!(self, Instruction::Gate(_) | Instruction::Barrier(_))
+    }
+
+    // Short display label, e.g. "CX", "M→c0" or "if c==1 X"
+    pub fn label(&self) -> String {
+        match self {
+            Instruction::Gate(operation) => operation.label(),
+            Instruction::Measure { clbit, .. } => format!("M→c{}", clbit),
+            Instruction::Reset(_) => "|0⟩".to_string(),
+            Instruction::Barrier(_) => "Barrier".to_string(),
+            Instruction::Conditional { register, value, instruction } => {
+                format!("if r{}=={} {}", register, value, instruction.label())
+            }
+            Instruction::If { then, otherwise, .. } => format!("if ({} + {} ops)", then.len(), otherwise.len()),
+            Instruction::While { body, .. } => format!("while ({} ops)", body.len()),
+        }
+    }
+}
+
+fn block_qubits<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Vec<usize> {
+    let mut qubits: Vec<usize> = instructions.flat_map(|i| i.qubits()).collect();
+    qubits.sort_unstable();
+    qubits.dedup();
+    qubits
+}
+
+impl From<Operation> for Instruction {
+    fn from(operation: Operation) -> Self {
+        Instruction::Gate(operation)
+    }
+}
+
+// A named slice of qubits or classical bits, e.g. `qreg q[3]`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Register {
+    pub name: String,
+    pub size: usize,
+}
+
+impl Register {
+    pub fn new(name: &str, size: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            size,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circuit {
+    qregs: Vec<Register>,
+    cregs: Vec<Register>,
+    instructions: Vec<Instruction>,
+}
+
+impl Circuit {
+    // One quantum register `q` and no classical bits
+    pub fn new(num_qubits: usize) -> Self {
+        Self::with_registers(vec![Register::new("q", num_qubits)], Vec::new())
+    }
+
+    pub fn with_registers(qregs: Vec<Register>, cregs: Vec<Register>) -> Self {
+        Self {
+            qregs,
+            cregs,
+            instructions: Vec::new(),
+        }
+    }
+
+    pub fn num_qubits(&self) -> usize {
+        self.qregs.iter().map(|r| r.size).sum()
+    }
+
+    pub fn num_clbits(&self) -> usize {
+        self.cregs.iter().map(|r| r.size).sum()
+    }
+
+    pub fn qregs(&self) -> &[Register] {
+        &self.qregs
+    }
+
+    pub fn cregs(&self) -> &[Register] {
+        &self.cregs
+    }
+
+    // Index of the first classical bit of a register
+    pub fn creg_offset(&self, register: usize) -> usize {
+        self.cregs[..register].iter().map(|r| r.size).sum()
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn is_unitary(&self) -> bool {
+        self.instructions.iter().all(Instruction::is_unitary)
+    }
+
+    pub fn push(&mut self, instruction: impl Into<Instruction>) -> &mut Self {
+        let instruction = instruction.into();
+        self.validate(&instruction);
+        self.instructions.push(instruction);
+        self
+    }
+
+    fn validate(&self, instruction: &Instruction) {
+        let qubits = instruction.qubits();
+        let num_qubits = self.num_qubits();
+        for (i, &q) in qubits.iter().enumerate() {
+            assert!(q < num_qubits, "qubit {} out of range for {} qubits", q, num_qubits);
+            assert!(!qubits[i + 1..].contains(&q), "qubit {} used twice in {}", q, instruction.label());
+        }
+        match instruction {
+            Instruction::Measure { clbit, .. } => {
+                assert!(*clbit < self.num_clbits(), "bit {} out of range for {} bits", clbit, self.num_clbits());
+            }
+            Instruction::Conditional { register, instruction, .. } => {
+                assert!(*register < self.cregs.len(), "no classical register {}", register);
+                assert!(
+                    matches!(**instruction, Instruction::Gate(_) | Instruction::Measure { .. } | Instruction::Reset(_)),
+                    "only gates, measurements and resets can be conditioned"
+                );
+                self.validate(instruction);
+            }
+            Instruction::If { condition, then, otherwise } => {
+                self.validate_condition(condition);
+                then.iter().chain(otherwise).for_each(|i| self.validate(i));
+            }
+            Instruction::While { condition, body } => {
+                self.validate_condition(condition);
+                body.iter().for_each(|i| self.validate(i));
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_condition(&self, condition: &Condition) {
+        for &bit in &condition.clbits {
+            assert!(bit < self.num_clbits(), "bit {} out of range for {} bits", bit, self.num_clbits());
+        }
+    }
+
+    // Register name for a condition's bits: "c" for a whole register, "c[1]" for one bit
+    pub fn condition_label(&self, condition: &Condition) -> String {
+        let mut offset = 0;
+        for register in &self.cregs {
+            let bits: Vec<usize> = (offset..offset + register.size).collect();
+            if condition.clbits == bits {
+                return register.name.clone();
+            }
+            if let [bit] = condition.clbits[..]
+                && bits.contains(&bit) {
+                return format!("{}[{}]", register.name, bit - offset);
+            }
+            offset += register.size;
+        }
+        let bits: Vec<String> = condition.clbits.iter().map(|b| b.to_string()).collect();
+        format!("bits[{}]", bits.join(", "))
+    }
+
+    // Classical bits an instruction reads or writes
+    pub fn clbits(&self, instruction: &Instruction) -> Vec<usize> {
+        match instruction {
+            Instruction::Measure { clbit, .. } => vec![*clbit],
+            Instruction::Conditional { register, instruction, .. } => {
+                let offset = self.creg_offset(*register);
+                let mut bits: Vec<usize> = (offset..offset + self.cregs[*register].size).collect();
+                bits.extend(self.clbits(instruction));
+                bits
+            }
+            Instruction::If { condition, then, otherwise } => {
+                let mut bits = condition.clbits.clone();
+                bits.extend(then.iter().chain(otherwise).flat_map(|i| self.clbits(i)));
+                bits.sort_unstable();
+                bits.dedup();
+                bits
+            }
+            Instruction::While { condition, body } => {
+                let mut bits = condition.clbits.clone();
+                bits.extend(body.iter().flat_map(|i| self.clbits(i)));
+                bits.sort_unstable();
+                bits.dedup();
+                bits
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    // Greedy ASAP layering: each instruction lands in the first moment after
+    // the last one touching any of its qubits or classical bits
+    pub fn moments(&self) -> Vec<Vec<usize>> {
+        let mut next_free = vec![0; self.num_qubits()];
+        let mut next_free_bit = vec![0; self.num_clbits()];
+        let mut moments: Vec<Vec<usize>> = Vec::new();
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            let qubits = instruction.qubits();
+            let bits = self.clbits(instruction);
+            let layer = qubits
+                .iter()
+                .map(|&q| next_free[q])
+                .chain(bits.iter().map(|&b| next_free_bit[b]))
+                .max()
+                .unwrap_or(0);
+            if layer == moments.len() {
+                moments.push(Vec::new());
+            }
+            moments[layer].push(index);
+            for q in qubits {
+                next_free[q] = layer + 1;
+            }
+            for b in bits {
+                next_free_bit[b] = layer + 1;
+            }
+        }
+        moments
+    }
+}
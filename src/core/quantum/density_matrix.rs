@@ -0,0 +1,173 @@
+/*
+--------------------------------------------------------------------
+                        Density Matrix
+                        --------------
+Notes
+-----
+
+- rho is a 2^n x 2^n Hermitian, positive, unit-trace matrix
+- gates act as rho -> U rho U^dagger (columns with U, rows with conj U)
+- entropies are in bits (log base 2)
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::backend::{Backend, apply_unitary};
+use crate::core::quantum::linalg::{hermitian_eigen, psd_sqrt};
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::Array2;
+use num_complex::Complex64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityMatrix {
+    num_qubits: usize,
+    rho: Array2<Complex64>,
+}
+
+impl DensityMatrix {
+    // |psi><psi|
+    pub fn from_state_vector(state: &StateVector) -> Self {
+        let a = state.amplitudes();
+        let rho = Array2::from_shape_fn((a.len(), a.len()), |(i, j)| a[i] * a[j].conj());
+        Self {
+            num_qubits: state.num_qubits(),
+            rho,
+        }
+    }
+
+    // Takes rho as given; it should already be Hermitian, positive and unit trace
+    pub fn from_matrix(rho: Array2<Complex64>) -> Self {
+        assert!(rho.is_square() && rho.nrows().is_power_of_two(), "rho must be 2^n x 2^n");
+        Self {
+            num_qubits: rho.nrows().trailing_zeros() as usize,
+            rho,
+        }
+    }
+
+    pub fn matrix(&self) -> &Array2<Complex64> {
+        &self.rho
+    }
+
+    // Reduced state of the kept qubits; keep[0] becomes qubit 0 of the result
+    pub fn partial_trace(&self, keep: &[usize]) -> DensityMatrix {
+        let kept_mask: usize = keep.iter().map(|&q| 1 << q).sum();
+        let expand = |local: usize| -> usize {
+            keep.iter()
+                .enumerate()
+                .filter(|(bit, _)| local >> bit & 1 == 1)
+                .map(|(_, &q)| 1 << q)
+                .sum()
+        };
+        let compress = |full: usize| -> usize {
+            keep.iter()
+                .enumerate()
+                .filter(|(_, q)| full >> **q & 1 == 1)
+                .map(|(bit, _)| 1 << bit)
+                .sum()
+        };
+
+        let dim = 1 << keep.len();
+        let mut reduced = Array2::zeros((dim, dim));
+        for a in 0..self.rho.nrows() {
+            let traced = a & !kept_mask;
+            let i = compress(a);
+            for j in 0..dim {
+                reduced[[i, j]] += self.rho[[a, traced | expand(j)]];
+            }
+        }
+        DensityMatrix {
+            num_qubits: keep.len(),
+            rho: reduced,
+        }
+    }
+
+    // rho -> sum_k K rho K^dagger for single-qubit Kraus operators K
+    pub fn apply_kraus(&mut self, kraus: &[Array2<Complex64>], qubit: usize) {
+        let mut result = Array2::zeros(self.rho.dim());
+        for k in kraus {
+            let mut term = self.clone();
+            term.apply_matrix(k, &[qubit], &[]);
+            result += &term.rho;
+        }
+        self.rho = result;
+    }
+
+    // Tr(rho^2): 1 for pure states, 1/2^n for the maximally mixed state
+    pub fn purity(&self) -> f64 {
+        self.rho.iter().map(|z| z.norm_sqr()).sum()
+    }
+
+    // S(rho) = -Tr(rho log2 rho)
+    pub fn von_neumann_entropy(&self) -> f64 {
+        let (values, _) = hermitian_eigen(&self.rho);
+        values
+            .iter()
+            .filter(|&&l| l > 1e-12)
+            .map(|&l| -l * l.log2())
+            .sum()
+    }
+
+    // Uhlmann fidelity F = (Tr sqrt(sqrt(rho) sigma sqrt(rho)))^2
+    pub fn fidelity(&self, other: &DensityMatrix) -> f64 {
+        let root = psd_sqrt(&self.rho);
+        let product = root.dot(&other.rho).dot(&root);
+        let (values, _) = hermitian_eigen(&product);
+        let trace: f64 = values.iter().map(|&l| l.max(0.0).sqrt()).sum();
+        (trace * trace).min(1.0)
+    }
+
+    // D = 1/2 Tr|rho - sigma|
+    pub fn trace_distance(&self, other: &DensityMatrix) -> f64 {
+        let difference = &self.rho - &other.rho;
+        let (values, _) = hermitian_eigen(&difference);
+        0.5 * values.iter().map(|l| l.abs()).sum::<f64>()
+    }
+}
+
+impl Backend for DensityMatrix {
+    fn new(num_qubits: usize) -> Self {
+        let dim = 1 << num_qubits;
+        let mut rho = Array2::zeros((dim, dim));
+        rho[[0, 0]] = Complex64::new(1.0, 0.0);
+        Self { num_qubits, rho }
+    }
+
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_matrix(&mut self, matrix: &Array2<Complex64>, targets: &[usize], controls: &[usize]) {
+        for column in self.rho.columns_mut() {
+            apply_unitary(column, matrix, targets, controls);
+        }
+        let conjugate = matrix.mapv(|z| z.conj());
+        for row in self.rho.rows_mut() {
+            apply_unitary(row, &conjugate, targets, controls);
+        }
+    }
+
+    fn probabilities(&self) -> Vec<f64> {
+        self.rho.diag().iter().map(|z| z.re).collect()
+    }
+
+    fn bloch_vector(&self, qubit: usize) -> [f64; 3] {
+        let reduced = self.partial_trace(&[qubit]).rho;
+        let rho_10 = reduced[[1, 0]];
+        [2.0 * rho_10.re, 2.0 * rho_10.im, reduced[[0, 0]].re - reduced[[1, 1]].re]
+    }
+
+    fn measure(&mut self, qubit: usize, sample: f64) -> bool {
+        let bit = 1 << qubit;
+        let p1: f64 = (0..self.rho.nrows()).filter(|i| i & bit != 0).map(|i| self.rho[[i, i]].re).sum();
+        let outcome = sample < p1;
+        let p = if outcome { p1 } else { 1.0 - p1 };
+        for ((i, j), z) in self.rho.indexed_iter_mut() {
+            if (i & bit != 0) == outcome && (j & bit != 0) == outcome {
+                *z /= p;
+            } else {
+                *z = Complex64::new(0.0, 0.0);
+            }
+        }
+        outcome
+    }
+}
@@ -0,0 +1,272 @@
+/*
+--------------------------------------------------------------------
+                        Entanglement
+                        ------------
+Notes
+-----
+
+- entanglement entropy of a bipartition is S(rho_A) in bits, which only
+  measures entanglement when the whole state is pure
+- concurrence (Wootters) and negativity are two-qubit measures that
+  also work for mixed states; both are 1 / 0.5 for a Bell state
+- Schmidt decomposition comes from the eigenvectors of rho_A, so no SVD
+- measurement directions for CHSH lie in the X-Z plane, at angle theta
+  from +Z towards +X; rotating by Ry(-theta) turns them into Z
+- Werner states p|psi><psi| + (1 - p) I/d lose all entanglement at
+  p = 1/3 and stop violating CHSH at p = 1/sqrt(2)
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::Operation;
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::linalg::{hermitian_eigen, psd_sqrt};
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::Array2;
+use num_complex::Complex64;
+use rand::Rng;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4};
+
+// |Φ+⟩, |Φ-⟩, |Ψ+⟩, |Ψ-⟩
+pub const BELL_NAMES: [&str; 4] = ["|Φ+⟩", "|Φ-⟩", "|Ψ+⟩", "|Ψ-⟩"];
+
+pub fn bell_state(index: usize) -> StateVector {
+    let (a, b) = (Complex64::new(FRAC_1_SQRT_2, 0.0), Complex64::new(0.0, 0.0));
+    let amplitudes = match index {
+        0 => vec![a, b, b, a],
+        1 => vec![a, b, b, -a],
+        2 => vec![b, a, a, b],
+        _ => vec![b, a, -a, b],
+    };
+    StateVector::from_amplitudes(amplitudes)
+}
+
+// (|0...0⟩ + |1...1⟩)/√2
+pub fn ghz_state(num_qubits: usize) -> StateVector {
+    let mut amplitudes = vec![Complex64::new(0.0, 0.0); 1 << num_qubits];
+    amplitudes[0] = Complex64::new(1.0, 0.0);
+    amplitudes[(1 << num_qubits) - 1] = Complex64::new(1.0, 0.0);
+    StateVector::from_amplitudes(amplitudes)
+}
+
+// Equal superposition of every state with exactly one 1
+pub fn w_state(num_qubits: usize) -> StateVector {
+    let mut amplitudes = vec![Complex64::new(0.0, 0.0); 1 << num_qubits];
+    for q in 0..num_qubits {
+        amplitudes[1 << q] = Complex64::new(1.0, 0.0);
+    }
+    StateVector::from_amplitudes(amplitudes)
+}
+
+// p|psi><psi| + (1 - p) I/d
+pub fn werner(state: &StateVector, p: f64) -> DensityMatrix {
+    let pure = DensityMatrix::from_state_vector(state);
+    let d = pure.matrix().nrows();
+    let identity = Array2::from_diag_elem(d, Complex64::new((1.0 - p) / d as f64, 0.0));
+    DensityMatrix::from_matrix(pure.matrix() * Complex64::new(p, 0.0) + identity)
+}
+
+// S(rho_A) of the `subsystem` qubits, in bits
+pub fn entanglement_entropy(rho: &DensityMatrix, subsystem: &[usize]) -> f64 {
+    rho.partial_trace(subsystem).von_neumann_entropy()
+}
+
+// Wootters concurrence of a two-qubit state
+pub fn concurrence(rho: &DensityMatrix) -> f64 {
+    assert_eq!(rho.num_qubits(), 2, "concurrence is defined for two qubits");
+    let m = rho.matrix();
+    // rho~ = (Y ⊗ Y) rho* (Y ⊗ Y); Y ⊗ Y only flips every bit, with sign (-1)^(popcount)
+    let sign = |i: usize| if i.count_ones().is_multiple_of(2) { 1.0 } else { -1.0 };
+    let flipped = Array2::from_shape_fn((4, 4), |(i, j)| m[[3 - i, 3 - j]].conj() * sign(i) * sign(j));
+    let root = psd_sqrt(m);
+    let (values, _) = hermitian_eigen(&root.dot(&flipped).dot(&root));
+    let mut lambdas: Vec<f64> = values.iter().map(|&l| l.max(0.0).sqrt()).collect();
+    lambdas.sort_by(|a, b| b.total_cmp(a));
+    (lambdas[0] - lambdas[1] - lambdas[2] - lambdas[3]).max(0.0)
+}
+
+// Negativity (||rho^T_B||_1 - 1)/2, transposing the second qubit
+pub fn negativity(rho: &DensityMatrix) -> f64 {
+    assert_eq!(rho.num_qubits(), 2, "negativity is defined for two qubits");
+    let m = rho.matrix();
+    // Swap qubit 1 between row and column index
+    let transposed = Array2::from_shape_fn((4, 4), |(i, j)| m[[(i & 1) | (j & 2), (j & 1) | (i & 2)]]);
+    let (values, _) = hermitian_eigen(&transposed);
+    values.iter().filter(|&&l| l < 0.0).map(|l| -l).sum()
+}
+
+// psi = sum_k coefficients[k] |left[k]⟩ ⊗ |right[k]⟩, largest coefficient first
+#[derive(Clone, Debug)]
+pub struct SchmidtDecomposition {
+    pub coefficients: Vec<f64>,
+    pub left: Vec<Vec<Complex64>>,
+    pub right: Vec<Vec<Complex64>>,
+}
+
+impl SchmidtDecomposition {
+    // Number of non-zero coefficients; 1 means a product state
+    pub fn rank(&self) -> usize {
+        self.coefficients.iter().filter(|&&c| c > 1e-9).count()
+    }
+}
+
+// Splits a pure state into `subsystem` (left, subsystem[0] = bit 0) and the rest
+pub fn schmidt_decomposition(state: &StateVector, subsystem: &[usize]) -> SchmidtDecomposition {
+    let n = state.num_qubits();
+    let rest: Vec<usize> = (0..n).filter(|q| !subsystem.contains(q)).collect();
+    let local = |index: usize, qubits: &[usize]| -> usize {
+        qubits.iter().enumerate().filter(|(_, q)| index >> **q & 1 == 1).map(|(bit, _)| 1 << bit).sum()
+    };
+
+    // psi as a d_A x d_B matrix
+    let mut m = Array2::zeros((1 << subsystem.len(), 1 << rest.len()));
+    for (index, amplitude) in state.amplitudes().iter().enumerate() {
+        m[[local(index, subsystem), local(index, &rest)]] = *amplitude;
+    }
+
+    let rho_a = m.dot(&m.t().mapv(|z: Complex64| z.conj()));
+    let (values, vectors) = hermitian_eigen(&rho_a);
+    let mut decomposition = SchmidtDecomposition {
+        coefficients: Vec::new(),
+        left: Vec::new(),
+        right: Vec::new(),
+    };
+    for k in (0..values.len()).rev() {
+        let coefficient = values[k].max(0.0).sqrt();
+        if coefficient < 1e-9 {
+            continue;
+        }
+        let u: Vec<Complex64> = vectors.column(k).to_vec();
+        // v_k = M^T u_k* / s_k
+        let v = (0..m.ncols())
+            .map(|b| (0..m.nrows()).map(|a| m[[a, b]] * u[a].conj()).sum::<Complex64>() / coefficient)
+            .collect();
+        decomposition.coefficients.push(coefficient);
+        decomposition.left.push(u);
+        decomposition.right.push(v);
+    }
+    decomposition
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Basis {
+    Z,
+    X,
+    Y,
+    // Direction in the X-Z plane, theta from +Z towards +X
+    Angle(f64),
+}
+
+impl Basis {
+    pub const ALL: [Basis; 3] = [Basis::Z, Basis::X, Basis::Y];
+
+    pub fn name(self) -> String {
+        match self {
+            Basis::Z => "Z".to_string(),
+            Basis::X => "X".to_string(),
+            Basis::Y => "Y".to_string(),
+            Basis::Angle(theta) => format!("{:.0}°", theta.to_degrees()),
+        }
+    }
+
+    // Gates that turn this basis into the computational one
+    fn rotation(self, qubit: usize) -> Vec<Operation> {
+        match self {
+            Basis::Z => Vec::new(),
+            Basis::X => vec![Operation::new(Gate::H, vec![qubit])],
+            Basis::Y => vec![Operation::new(Gate::Sdg, vec![qubit]), Operation::new(Gate::H, vec![qubit])],
+            Basis::Angle(theta) => vec![Operation::new(Gate::Ry(-theta), vec![qubit])],
+        }
+    }
+}
+
+// P(a, b) of measuring qubits[0] in bases[0] and qubits[1] in bases[1]; indexed [a][b]
+pub fn joint_probabilities(rho: &DensityMatrix, qubits: [usize; 2], bases: [Basis; 2]) -> [[f64; 2]; 2] {
+    let mut rotated = rho.clone();
+    for (qubit, basis) in qubits.iter().zip(bases) {
+        for op in basis.rotation(*qubit) {
+            rotated.apply(&op);
+        }
+    }
+    let p = rotated.partial_trace(&qubits).probabilities();
+    [[p[0], p[2]], [p[1], p[3]]]
+}
+
+// E = P(same) - P(different), in [-1, 1]
+pub fn correlation(grid: &[[f64; 2]; 2]) -> f64 {
+    grid[0][0] + grid[1][1] - grid[0][1] - grid[1][0]
+}
+
+// Alice's angles a, a' and Bob's b, b' for the CHSH game
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChshAngles {
+    pub a: [f64; 2],
+    pub b: [f64; 2],
+}
+
+impl ChshAngles {
+    // Reach Tsirelson's bound 2√2 on |Φ+⟩
+    pub const OPTIMAL: ChshAngles = ChshAngles {
+        a: [0.0, FRAC_PI_2],
+        b: [FRAC_PI_4, 3.0 * FRAC_PI_4],
+    };
+
+    fn sign(i: usize, j: usize) -> f64 {
+        if i == 0 && j == 1 { -1.0 } else { 1.0 }
+    }
+}
+
+// S = E(a,b) - E(a,b') + E(a',b) + E(a',b') on qubits 0 and 1; |S| <= 2 classically
+pub fn chsh_value(rho: &DensityMatrix, angles: &ChshAngles) -> f64 {
+    let mut s = 0.0;
+    for i in 0..2 {
+        for j in 0..2 {
+            let grid = joint_probabilities(rho, [0, 1], [Basis::Angle(angles.a[i]), Basis::Angle(angles.b[j])]);
+            s += ChshAngles::sign(i, j) * correlation(&grid);
+        }
+    }
+    s
+}
+
+// S estimated from `shots` rounds, each with randomly chosen settings
+pub fn sample_chsh<R: Rng>(rho: &DensityMatrix, angles: &ChshAngles, shots: usize, rng: &mut R) -> f64 {
+    let mut grids = [[[[0.0; 2]; 2]; 2]; 2];
+    for (i, row) in grids.iter_mut().enumerate() {
+        for (j, grid) in row.iter_mut().enumerate() {
+            *grid = joint_probabilities(rho, [0, 1], [Basis::Angle(angles.a[i]), Basis::Angle(angles.b[j])]);
+        }
+    }
+
+    // (sum of ±1 products, rounds) per setting pair
+    let mut tallies = [[(0.0, 0usize); 2]; 2];
+    for _ in 0..shots {
+        let (i, j) = (rng.random_range(0..2), rng.random_range(0..2));
+        let grid = &grids[i][j];
+        let mut sample: f64 = rng.random();
+        let mut outcome = (1, 1);
+        'pick: for (a, row) in grid.iter().enumerate() {
+            for (b, &p) in row.iter().enumerate() {
+                if sample < p {
+                    outcome = (a, b);
+                    break 'pick;
+                }
+                sample -= p;
+            }
+        }
+        let tally = &mut tallies[i][j];
+        tally.0 += if outcome.0 == outcome.1 { 1.0 } else { -1.0 };
+        tally.1 += 1;
+    }
+
+    let mut s = 0.0;
+    for (i, row) in tallies.iter().enumerate() {
+        for (j, &(sum, rounds)) in row.iter().enumerate() {
+            if rounds > 0 {
+                s += ChshAngles::sign(i, j) * sum / rounds as f64;
+            }
+        }
+    }
+    s
+}
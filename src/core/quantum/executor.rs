@@ -0,0 +1,160 @@
+/*
+--------------------------------------------------------------------
+                        Circuit Executor
+                        ----------------
+Notes
+-----
+
+- runs a circuit moment by moment and keeps the state after each one
+- snapshots[0] is the initial |0...0> state, snapshots[k] follows moment k
+- memory grows with depth * state size, fine for the small circuits we debug
+- measurements collapse the state, so every run is one shot; pass a seeded
+  rng for repeatable trajectories
+- a classical register reads as an integer with its first bit as the LSB
+- while loops stop after MAX_LOOP_ITERATIONS so a bad condition cannot
+  hang the UI
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::{Circuit, Instruction, Operation};
+use rand::Rng;
+use std::collections::BTreeMap;
+
+pub const MAX_LOOP_ITERATIONS: usize = 1000;
+
+pub struct Execution<B: Backend> {
+    pub moments: Vec<Vec<usize>>,
+    pub snapshots: Vec<B>,
+    // Classical bits after each snapshot
+    pub clbits: Vec<Vec<bool>>,
+}
+
+// Final state and classical bits of a single run
+pub struct Shot<B: Backend> {
+    pub state: B,
+    pub clbits: Vec<bool>,
+}
+
+pub fn register_value(circuit: &Circuit, clbits: &[bool], register: usize) -> u64 {
+    let offset = circuit.creg_offset(register);
+    (0..circuit.cregs()[register].size)
+        .filter(|&bit| clbits[offset + bit])
+        .map(|bit| 1 << bit)
+        .sum()
+}
+
+// Classical bits as a string with the last bit on the left, like basis labels
+pub fn clbit_string(clbits: &[bool]) -> String {
+    clbits.iter().rev().map(|&b| if b { '1' } else { '0' }).collect()
+}
+
+// Applies one instruction; `after_gate` runs after every executed gate and
+// `read` turns each measured outcome into the recorded bit (the noise model
+// hooks in at both)
+pub fn apply_instruction<B: Backend, R: Rng>(
+    circuit: &Circuit,
+    instruction: &Instruction,
+    state: &mut B,
+    clbits: &mut [bool],
+    rng: &mut R,
+    after_gate: &mut impl FnMut(&mut B, &Operation),
+    read: &mut impl FnMut(bool, &mut R) -> bool,
+) {
+    match instruction {
+        Instruction::Gate(operation) => {
+            state.apply(operation);
+            after_gate(state, operation);
+        }
+        Instruction::Measure { qubit, clbit } => {
+            let outcome = state.measure(*qubit, rng.random());
+            clbits[*clbit] = read(outcome, rng);
+        }
+        Instruction::Reset(qubit) => state.reset(*qubit, rng.random()),
+        Instruction::Barrier(_) => {}
+        Instruction::Conditional { register, value, instruction } => {
+            if register_value(circuit, clbits, *register) == *value {
+                apply_instruction(circuit, instruction, state, clbits, rng, after_gate, read);
+            }
+        }
+        Instruction::If { condition, then, otherwise } => {
+            let branch = if condition.holds(clbits) { then } else { otherwise };
+            for instruction in branch {
+                apply_instruction(circuit, instruction, state, clbits, rng, after_gate, read);
+            }
+        }
+        Instruction::While { condition, body } => {
+            let mut iterations = 0;
+            while condition.holds(clbits) {
+                if iterations == MAX_LOOP_ITERATIONS {
+                    tracing::warn!("while loop stopped after {} iterations", MAX_LOOP_ITERATIONS);
+                    break;
+                }
+                for instruction in body {
+                    apply_instruction(circuit, instruction, state, clbits, rng, after_gate, read);
+                }
+                iterations += 1;
+            }
+        }
+    }
+}
+
+pub fn run_shot_with<B: Backend, R: Rng>(
+    circuit: &Circuit,
+    rng: &mut R,
+    mut after_gate: impl FnMut(&mut B, &Operation),
+    mut read: impl FnMut(bool, &mut R) -> bool,
+) -> Shot<B> {
+    let mut state = B::new(circuit.num_qubits());
+    let mut clbits = vec![false; circuit.num_clbits()];
+    for instruction in circuit.instructions() {
+        apply_instruction(circuit, instruction, &mut state, &mut clbits, rng, &mut after_gate, &mut read);
+    }
+    Shot { state, clbits }
+}
+
+pub fn run_shot<B: Backend, R: Rng>(circuit: &Circuit, rng: &mut R) -> Shot<B> {
+    run_shot_with(circuit, rng, |_, _| {}, |bit, _| bit)
+}
+
+// Final state of the unconditional gates alone, for circuits with no classical part
+pub fn run_unitary<B: Backend>(circuit: &Circuit) -> B {
+    let mut state = B::new(circuit.num_qubits());
+    for instruction in circuit.instructions() {
+        if let Instruction::Gate(op) = instruction {
+            state.apply(op);
+        }
+    }
+    state
+}
+
+// Histogram of the classical bits over many shots, keyed by clbit_string
+pub fn sample_counts<B: Backend, R: Rng>(circuit: &Circuit, shots: usize, rng: &mut R) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for _ in 0..shots {
+        let shot = run_shot::<B, R>(circuit, rng);
+        *counts.entry(clbit_string(&shot.clbits)).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn execute_with_snapshots<B: Backend, R: Rng>(circuit: &Circuit, rng: &mut R) -> Execution<B> {
+    let moments = circuit.moments();
+    let mut state = B::new(circuit.num_qubits());
+    let mut bits = vec![false; circuit.num_clbits()];
+    let mut snapshots = Vec::with_capacity(moments.len() + 1);
+    let mut clbits = Vec::with_capacity(moments.len() + 1);
+    snapshots.push(state.clone());
+    clbits.push(bits.clone());
+
+    for moment in &moments {
+        for &index in moment {
+            apply_instruction(circuit, &circuit.instructions()[index], &mut state, &mut bits, rng, &mut |_, _| {}, &mut |bit, _| bit);
+        }
+        snapshots.push(state.clone());
+        clbits.push(bits.clone());
+    }
+
+    Execution { moments, snapshots, clbits }
+}
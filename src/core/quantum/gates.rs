@@ -0,0 +1,152 @@
+/*
+--------------------------------------------------------------------
+                        Quantum Gates
+                        -------------
+Notes
+-----
+
+- a Gate is the base unitary acting on its target qubits only
+- controls are attached by the Operation, so CX = X + 1 control
+- multi-target matrices use targets[0] as the least significant bit
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::{Array2, array};
+use num_complex::Complex64;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Gate {
+    H,
+    X,
+    Y,
+    Z,
+    S,
+    Sdg,
+    T,
+    Tdg,
+    SX,
+    SXdg,
+    Rx(f64),
+    Ry(f64),
+    Rz(f64),
+    Phase(f64),
+    U(f64, f64, f64),
+    Swap,
+}
+
+fn c(re: f64, im: f64) -> Complex64 {
+    Complex64::new(re, im)
+}
+
+impl Gate {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gate::H => "H",
+            Gate::X => "X",
+            Gate::Y => "Y",
+            Gate::Z => "Z",
+            Gate::S => "S",
+            Gate::Sdg => "S†",
+            Gate::T => "T",
+            Gate::Tdg => "T†",
+            Gate::SX => "√X",
+            Gate::SXdg => "√X†",
+            Gate::Rx(_) => "Rx",
+            Gate::Ry(_) => "Ry",
+            Gate::Rz(_) => "Rz",
+            Gate::Phase(_) => "P",
+            Gate::U(..) => "U",
+            Gate::Swap => "SWAP",
+        }
+    }
+
+    pub fn num_targets(&self) -> usize {
+        match self {
+            Gate::Swap => 2,
+            _ => 1,
+        }
+    }
+
+    // Angles of the gate, in the order they appear in the constructor
+    pub fn params(&self) -> Vec<f64> {
+        match self {
+            Gate::Rx(t) | Gate::Ry(t) | Gate::Rz(t) | Gate::Phase(t) => vec![*t],
+            Gate::U(theta, phi, lambda) => vec![*theta, *phi, *lambda],
+            _ => Vec::new(),
+        }
+    }
+
+    // U^dagger as another gate of the same family
+    pub fn inverse(&self) -> Gate {
+        match *self {
+            Gate::S => Gate::Sdg,
+            Gate::Sdg => Gate::S,
+            Gate::T => Gate::Tdg,
+            Gate::Tdg => Gate::T,
+            Gate::SX => Gate::SXdg,
+            Gate::SXdg => Gate::SX,
+            Gate::Rx(t) => Gate::Rx(-t),
+            Gate::Ry(t) => Gate::Ry(-t),
+            Gate::Rz(t) => Gate::Rz(-t),
+            Gate::Phase(t) => Gate::Phase(-t),
+            Gate::U(theta, phi, lambda) => Gate::U(-theta, -lambda, -phi),
+            ref gate => gate.clone(),
+        }
+    }
+
+    pub fn params_mut(&mut self) -> Vec<&mut f64> {
+        match self {
+            Gate::Rx(t) | Gate::Ry(t) | Gate::Rz(t) | Gate::Phase(t) => vec![t],
+            Gate::U(theta, phi, lambda) => vec![theta, phi, lambda],
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn matrix(&self) -> Array2<Complex64> {
+        let zero = c(0.0, 0.0);
+        let one = c(1.0, 0.0);
+        match self {
+            Gate::H => array![
+                [c(FRAC_1_SQRT_2, 0.0), c(FRAC_1_SQRT_2, 0.0)],
+                [c(FRAC_1_SQRT_2, 0.0), c(-FRAC_1_SQRT_2, 0.0)]
+            ],
+            Gate::X => array![[zero, one], [one, zero]],
+            Gate::Y => array![[zero, c(0.0, -1.0)], [c(0.0, 1.0), zero]],
+            Gate::Z => array![[one, zero], [zero, -one]],
+            Gate::S => array![[one, zero], [zero, c(0.0, 1.0)]],
+            Gate::Sdg => array![[one, zero], [zero, c(0.0, -1.0)]],
+            Gate::T => array![[one, zero], [zero, Complex64::from_polar(1.0, std::f64::consts::FRAC_PI_4)]],
+            Gate::Tdg => array![[one, zero], [zero, Complex64::from_polar(1.0, -std::f64::consts::FRAC_PI_4)]],
+            Gate::SX => array![[c(0.5, 0.5), c(0.5, -0.5)], [c(0.5, -0.5), c(0.5, 0.5)]],
+            Gate::SXdg => array![[c(0.5, -0.5), c(0.5, 0.5)], [c(0.5, 0.5), c(0.5, -0.5)]],
+            Gate::Rx(theta) => {
+                let (s, co) = (theta / 2.0).sin_cos();
+                array![[c(co, 0.0), c(0.0, -s)], [c(0.0, -s), c(co, 0.0)]]
+            }
+            Gate::Ry(theta) => {
+                let (s, co) = (theta / 2.0).sin_cos();
+                array![[c(co, 0.0), c(-s, 0.0)], [c(s, 0.0), c(co, 0.0)]]
+            }
+            Gate::Rz(theta) => array![
+                [Complex64::from_polar(1.0, -theta / 2.0), zero],
+                [zero, Complex64::from_polar(1.0, theta / 2.0)]
+            ],
+            Gate::Phase(lambda) => array![[one, zero], [zero, Complex64::from_polar(1.0, *lambda)]],
+            Gate::U(theta, phi, lambda) => {
+                let (s, co) = (theta / 2.0).sin_cos();
+                array![
+                    [c(co, 0.0), -Complex64::from_polar(s, *lambda)],
+                    [Complex64::from_polar(s, *phi), Complex64::from_polar(co, phi + lambda)]
+                ]
+            }
+            Gate::Swap => array![
+                [one, zero, zero, zero],
+                [zero, zero, one, zero],
+                [zero, one, zero, zero],
+                [zero, zero, zero, one]
+            ],
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/*
+--------------------------------------------------------------------
+                        Circuit Gradients
+                        -----------------
+Notes
+-----
+
+- f(θ) = ⟨0|U(θ)† H U(θ)|0⟩ for a parameterized circuit and a Pauli
+  Hamiltonian, differentiated three ways:
+- parameter shift: exact from shifted circuit runs, as on hardware.
+  Gates whose generator has eigenvalues ±1/2 (rotations, and Phase,
+  a shifted Rz) use (f(+π/2) - f(-π/2)) / 2; controlled rotations
+  (eigenvalues 0, ±1/2) need the four-term rule with shifts π/2, 3π/2
+- finite differences: central, two runs per parameter, with truncation
+  and round-off error
+- adjoint: one forward pass, then walk the gates backwards carrying
+  |ψ⟩ and H|ψ⟩, adding 2 Re⟨Hψ|dU ψ⟩ at each rotation; state vector
+  only, and the whole gradient costs about three circuit executions
+- evaluations count circuit executions; the adjoint method counts one
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, finite_difference};
+use crate::core::quantum::backend::apply_unitary;
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::linalg::dagger;
+use crate::core::quantum::parameters::{Axis, ParameterizedCircuit, Step};
+use crate::core::quantum::pauli::Hamiltonian;
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::{Array1, Array2, ArrayViewMut1};
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_PI_2, SQRT_2};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientMethod {
+    ParameterShift,
+    FiniteDifference,
+    Adjoint,
+}
+
+impl GradientMethod {
+    pub const ALL: [GradientMethod; 3] = [
+        GradientMethod::ParameterShift,
+        GradientMethod::FiniteDifference,
+        GradientMethod::Adjoint,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GradientMethod::ParameterShift => "Parameter shift",
+            GradientMethod::FiniteDifference => "Finite difference",
+            GradientMethod::Adjoint => "Adjoint",
+        }
+    }
+}
+
+pub fn expectation(circuit: &ParameterizedCircuit, hamiltonian: &Hamiltonian, params: &Array1<f64>) -> f64 {
+    hamiltonian.expectation(&run_unitary::<StateVector>(&circuit.bind(params)))
+}
+
+pub fn parameter_shift(circuit: &ParameterizedCircuit, hamiltonian: &Hamiltonian, params: &Array1<f64>) -> (Array1<f64>, usize) {
+    let mut gradient = Array1::zeros(params.len());
+    let mut evaluations = 0;
+    let mut shifted = |index: usize, delta: f64| {
+        evaluations += 1;
+        hamiltonian.expectation(&run_unitary::<StateVector>(&circuit.bind_shifted(params, Some((index, delta)))))
+    };
+    for (index, step) in circuit.steps().iter().enumerate() {
+        if let Step::Rotation { axis, controls, parameter, scale, .. } = step {
+            let two_term = (shifted(index, FRAC_PI_2) - shifted(index, -FRAC_PI_2)) / 2.0;
+            let derivative = if controls.is_empty() || *axis == Axis::Phase {
+                two_term
+            } else {
+                let (near, far) = ((SQRT_2 + 1.0) / (4.0 * SQRT_2), (SQRT_2 - 1.0) / (4.0 * SQRT_2));
+                let wide = shifted(index, 3.0 * FRAC_PI_2) - shifted(index, -3.0 * FRAC_PI_2);
+                near * 2.0 * two_term - far * wide
+            };
+            gradient[*parameter] += scale * derivative;
+        }
+    }
+    (gradient, evaluations)
+}
+
+pub fn adjoint(circuit: &ParameterizedCircuit, hamiltonian: &Hamiltonian, params: &Array1<f64>) -> Array1<f64> {
+    let n = circuit.num_qubits();
+    let bound = circuit.bind(params);
+    let mut psi = run_unitary::<StateVector>(&bound).amplitudes().to_vec();
+    let h: Array2<Complex64> = hamiltonian.matrix(n);
+    let mut lambda = h.dot(&Array1::from(psi.clone())).to_vec();
+
+    let mut gradient = Array1::zeros(params.len());
+    let operations: Vec<_> = bound.instructions().iter().filter_map(|i| i.operation()).collect();
+    for (step, operation) in circuit.steps().iter().zip(operations).rev() {
+        let inverse = dagger(&operation.gate.matrix());
+        let undo = |v: &mut Vec<Complex64>| apply_unitary(ArrayViewMut1::from(&mut v[..]), &inverse, &operation.targets, &operation.controls);
+        undo(&mut psi);
+        if let Step::Rotation { axis, controls, parameter, scale, .. } = step {
+            // dU acts only where every control is |1⟩ and annihilates the rest
+            let control_mask: usize = controls.iter().map(|&c| 1 << c).sum();
+            let mut mu: Vec<Complex64> = psi
+                .iter()
+                .enumerate()
+                .map(|(i, a)| if i & control_mask == control_mask { *a } else { Complex64::new(0.0, 0.0) })
+                .collect();
+            let angle = operation.gate.params()[0];
+            apply_unitary(ArrayViewMut1::from(&mut mu[..]), &axis.derivative(angle), &operation.targets, controls);
+            let overlap: Complex64 = lambda.iter().zip(&mu).map(|(l, m)| l.conj() * m).sum();
+            gradient[*parameter] += scale * 2.0 * overlap.re;
+        }
+        undo(&mut lambda);
+    }
+    gradient
+}
+
+// The gradient by any method, with the circuit executions it took
+pub fn gradient(method: GradientMethod, circuit: &ParameterizedCircuit, hamiltonian: &Hamiltonian, params: &Array1<f64>) -> (Array1<f64>, usize) {
+    match method {
+        GradientMethod::ParameterShift => parameter_shift(circuit, hamiltonian, params),
+        GradientMethod::FiniteDifference => {
+            let mut evaluations = 0;
+            let gradient = finite_difference(|p| {
+                evaluations += 1;
+                expectation(circuit, hamiltonian, p)
+            }, params);
+            (gradient, evaluations)
+        }
+        GradientMethod::Adjoint => (adjoint(circuit, hamiltonian, params), 1),
+    }
+}
+
+// ⟨H⟩ of a parameterized circuit as an optimizer objective
+pub struct ExpectationObjective<'a> {
+    pub circuit: &'a ParameterizedCircuit,
+    pub hamiltonian: &'a Hamiltonian,
+    pub method: GradientMethod,
+    pub evaluations: usize,
+}
+
+impl<'a> ExpectationObjective<'a> {
+    pub fn new(circuit: &'a ParameterizedCircuit, hamiltonian: &'a Hamiltonian, method: GradientMethod) -> Self {
+        Self { circuit, hamiltonian, method, evaluations: 0 }
+    }
+}
+
+impl Objective for ExpectationObjective<'_> {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        self.evaluations += 1;
+        expectation(self.circuit, self.hamiltonian, params)
+    }
+
+    fn gradient(&mut self, params: &Array1<f64>) -> Array1<f64> {
+        let (gradient, evaluations) = gradient(self.method, self.circuit, self.hamiltonian, params);
+        self.evaluations += evaluations;
+        gradient
+    }
+}
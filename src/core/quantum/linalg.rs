@@ -0,0 +1,89 @@
+/*
+--------------------------------------------------------------------
+                        Complex Linear Algebra
+                        ----------------------
+Notes
+-----
+
+- just enough dense linear algebra for density matrices, no LAPACK
+- eigen decomposition uses cyclic complex Jacobi rotations
+- Jacobi is O(d^3) per sweep, fine for d = 2^n with n <= ~7
+
+--------------------------------------------------------------------
+*/
+
+use ndarray::Array2;
+use num_complex::Complex64;
+
+const MAX_SWEEPS: usize = 100;
+const TOLERANCE: f64 = 1e-14;
+
+// Conjugate transpose
+pub fn dagger(m: &Array2<Complex64>) -> Array2<Complex64> {
+    m.t().mapv(|z| z.conj())
+}
+
+// Eigenvalues (ascending) and eigenvectors (as columns) of a Hermitian matrix
+pub fn hermitian_eigen(m: &Array2<Complex64>) -> (Vec<f64>, Array2<Complex64>) {
+    let d = m.nrows();
+    let mut a = m.clone();
+    let mut v = Array2::from_diag_elem(d, Complex64::new(1.0, 0.0));
+
+    for _ in 0..MAX_SWEEPS {
+        let off: f64 = (0..d)
+            .flat_map(|i| (0..d).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[[i, j]].norm_sqr())
+            .sum();
+        if off < TOLERANCE * TOLERANCE {
+            break;
+        }
+
+        for p in 0..d {
+            for q in p + 1..d {
+                let apq = a[[p, q]];
+                let r = apq.norm();
+                if r < TOLERANCE {
+                    continue;
+                }
+                // Phase that makes a[p][q] real, followed by a real Jacobi rotation
+                let phase = apq / r;
+                let theta = 0.5 * (2.0 * r).atan2(a[[q, q]].re - a[[p, p]].re);
+                let (s, c) = theta.sin_cos();
+                let u = [
+                    [Complex64::new(c, 0.0), Complex64::new(s, 0.0)],
+                    [-s * phase.conj(), c * phase.conj()],
+                ];
+
+                // a <- a u, v <- v u (columns p and q)
+                for i in 0..d {
+                    let (aip, aiq) = (a[[i, p]], a[[i, q]]);
+                    a[[i, p]] = aip * u[0][0] + aiq * u[1][0];
+                    a[[i, q]] = aip * u[0][1] + aiq * u[1][1];
+                    let (vip, viq) = (v[[i, p]], v[[i, q]]);
+                    v[[i, p]] = vip * u[0][0] + viq * u[1][0];
+                    v[[i, q]] = vip * u[0][1] + viq * u[1][1];
+                }
+                // a <- u^dagger a (rows p and q)
+                for j in 0..d {
+                    let (apj, aqj) = (a[[p, j]], a[[q, j]]);
+                    a[[p, j]] = u[0][0].conj() * apj + u[1][0].conj() * aqj;
+                    a[[q, j]] = u[0][1].conj() * apj + u[1][1].conj() * aqj;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..d).collect();
+    order.sort_by(|&i, &j| a[[i, i]].re.total_cmp(&a[[j, j]].re));
+    let values = order.iter().map(|&i| a[[i, i]].re).collect();
+    let vectors = Array2::from_shape_fn((d, d), |(i, k)| v[[i, order[k]]]);
+    (values, vectors)
+}
+
+// Square root of a positive semi-definite Hermitian matrix
+pub fn psd_sqrt(m: &Array2<Complex64>) -> Array2<Complex64> {
+    let (values, vectors) = hermitian_eigen(m);
+    let roots: Vec<Complex64> = values.iter().map(|&l| Complex64::new(l.max(0.0).sqrt(), 0.0)).collect();
+    let scaled = Array2::from_shape_fn(vectors.dim(), |(i, k)| vectors[[i, k]] * roots[k]);
+    scaled.dot(&dagger(&vectors))
+}
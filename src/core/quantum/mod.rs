@@ -0,0 +1,614 @@
+pub mod algorithms;
+pub mod backend;
+pub mod circuit;
+pub mod density_matrix;
+pub mod entanglement;
+pub mod executor;
+pub mod gates;
+pub mod gradients;
+pub mod linalg;
+pub mod noise;
+pub mod parameters;
+pub mod pauli;
+pub mod qaoa;
+pub mod qasm;
+pub mod shor;
+pub mod state_vector;
+pub mod vqe;
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Array2;
+    use num_complex::Complex64;
+    use super::algorithms::{
+        DjOracle, bernstein_vazirani, deutsch_jozsa, grover, grover_success_probability, inverse_qft,
+        optimal_grover_iterations, phase_estimation, qft, simon, simon_secret, teleportation,
+    };
+    use super::backend::Backend;
+    use super::circuit::{Circuit, Instruction, Operation, Register};
+    use super::density_matrix::DensityMatrix;
+    use super::entanglement::{
+        Basis, ChshAngles, bell_state, chsh_value, concurrence, correlation, entanglement_entropy, ghz_state,
+        joint_probabilities, negativity, sample_chsh, schmidt_decomposition, w_state, werner,
+    };
+    use super::executor::{execute_with_snapshots, register_value, run_shot, sample_counts};
+    use super::gates::Gate;
+    use super::gradients::{ExpectationObjective, GradientMethod, adjoint, expectation, parameter_shift};
+    use super::linalg::dagger;
+    use super::noise::{NoiseChannel, NoiseModel, NoiseTarget, ReadoutError};
+    use super::parameters::{Axis, ParameterizedCircuit};
+    use super::pauli::{Hamiltonian, Pauli, PauliString};
+    use super::qaoa::{Graph, Qaoa, expected_cut, landscape, state};
+    use super::shor::{
+        append_modular_multiplier, classical_order, continued_fraction, convergents, order_finding, phase_distribution,
+        recover, work_qubits,
+    };
+    use super::state_vector::StateVector;
+    use super::vqe::{Ansatz, Molecule, Vqe};
+    use crate::core::ai::optim::optimizer::{Objective, OptimizerKind, finite_difference};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(0)
+    }
+
+    fn run<B: Backend>(circuit: &Circuit) -> B {
+        run_shot(circuit, &mut rng()).state
+    }
+
+    #[test]
+    fn test_bell_state_amplitudes() {
+        let mut circuit = Circuit::new(2);
+        circuit
+            .push(Operation::new(Gate::H, vec![0]))
+            .push(Operation::controlled(Gate::X, vec![0], vec![1]));
+
+        let state: StateVector = run(&circuit);
+
+        let probabilities = state.probabilities();
+        let expected = [0.5, 0.0, 0.0, 0.5];
+        for (p, e) in probabilities.iter().zip(expected.iter()) {
+            assert!((p - e).abs() < 1e-12, "Probability {} differs from {}", p, e);
+        }
+    }
+
+    #[test]
+    fn test_swap_and_moments() {
+        // X on q0 then SWAP(q0, q2) moves the excitation to q2: |100>
+        let mut circuit = Circuit::new(3);
+        circuit
+            .push(Operation::new(Gate::X, vec![0]))
+            .push(Operation::new(Gate::H, vec![1]))
+            .push(Operation::new(Gate::Swap, vec![0, 2]))
+            .push(Operation::new(Gate::H, vec![1]));
+
+        let state: StateVector = run(&circuit);
+        assert!((state.probabilities()[0b100] - 1.0).abs() < 1e-12);
+
+        assert_eq!(circuit.moments(), vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_snapshots_and_bloch_vectors() {
+        let mut circuit = Circuit::new(2);
+        circuit
+            .push(Operation::new(Gate::H, vec![0]))
+            .push(Operation::controlled(Gate::X, vec![0], vec![1]));
+
+        let execution = execute_with_snapshots::<StateVector, _>(&circuit, &mut rng());
+        assert_eq!(execution.snapshots.len(), circuit.moments().len() + 1);
+
+        // After the first moment q0 is |+>, pointing along +x
+        let [x, y, z] = execution.snapshots[1].bloch_vector(0);
+        assert!((x - 1.0).abs() < 1e-12 && y.abs() < 1e-12 && z.abs() < 1e-12);
+
+        // After the CNOT both qubits are maximally mixed
+        let state: StateVector = run(&circuit);
+        let last = execution.snapshots.last().unwrap();
+        assert_eq!(last, &state);
+        for qubit in 0..2 {
+            let length: f64 = last.bloch_vector(qubit).iter().map(|c| c * c).sum::<f64>().sqrt();
+            assert!(length < 1e-12, "Bloch vector of q{} has length {}", qubit, length);
+        }
+    }
+
+    #[test]
+    fn test_density_matrix_matches_state_vector() {
+        let mut circuit = Circuit::new(3);
+        circuit
+            .push(Operation::new(Gate::H, vec![0]))
+            .push(Operation::new(Gate::Ry(0.7), vec![2]))
+            .push(Operation::controlled(Gate::X, vec![0], vec![1]))
+            .push(Operation::controlled(Gate::Rz(1.3), vec![1], vec![2]));
+
+        let state: StateVector = run(&circuit);
+        let rho: DensityMatrix = run(&circuit);
+
+        let expected = DensityMatrix::from_state_vector(&state);
+        assert!(rho.trace_distance(&expected) < 1e-9);
+        assert!((rho.fidelity(&expected) - 1.0).abs() < 1e-9);
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+        for qubit in 0..3 {
+            let (a, b) = (rho.bloch_vector(qubit), state.bloch_vector(qubit));
+            for axis in 0..3 {
+                assert!((a[axis] - b[axis]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bell_state_reduced_entropy() {
+        let mut circuit = Circuit::new(2);
+        circuit
+            .push(Operation::new(Gate::H, vec![0]))
+            .push(Operation::controlled(Gate::X, vec![0], vec![1]));
+        let rho: DensityMatrix = run(&circuit);
+
+        assert!(rho.von_neumann_entropy().abs() < 1e-9);
+        let reduced = rho.partial_trace(&[1]);
+        assert!((reduced.purity() - 0.5).abs() < 1e-12);
+        assert!((reduced.von_neumann_entropy() - 1.0).abs() < 1e-9);
+
+        // |0> vs |+>: F = 1/2, D = 1/sqrt(2)
+        let zero = DensityMatrix::new(1);
+        let mut plus = DensityMatrix::new(1);
+        plus.apply(&Operation::new(Gate::H, vec![0]));
+        assert!((zero.fidelity(&plus) - 0.5).abs() < 1e-9);
+        assert!((zero.trace_distance(&plus) - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_noise_channels_are_trace_preserving() {
+        let channels = [
+            NoiseChannel::BitFlip(0.1),
+            NoiseChannel::PhaseFlip(0.2),
+            NoiseChannel::Depolarizing(0.3),
+            NoiseChannel::AmplitudeDamping(0.4),
+            NoiseChannel::PhaseDamping(0.5),
+            NoiseChannel::ThermalRelaxation { t1: 50.0, t2: 70.0, gate_time: 5.0 },
+        ];
+        for channel in channels {
+            let sum = channel
+                .kraus()
+                .iter()
+                .map(|k| dagger(k).dot(k))
+                .fold(Array2::<Complex64>::zeros((2, 2)), |acc, m| acc + m);
+            for ((i, j), z) in sum.indexed_iter() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((z.re - expected).abs() < 1e-12 && z.im.abs() < 1e-12, "{} is not trace preserving", channel.name());
+            }
+        }
+
+        // Full amplitude damping relaxes |1> to |0>
+        let mut rho = DensityMatrix::new(1);
+        rho.apply(&Operation::new(Gate::X, vec![0]));
+        rho.apply_kraus(&NoiseChannel::AmplitudeDamping(1.0).kraus(), 0);
+        assert!((rho.probabilities()[0] - 1.0).abs() < 1e-12);
+
+        // Full depolarizing leaves the maximally mixed state
+        let mut rho = DensityMatrix::new(1);
+        rho.apply_kraus(&NoiseChannel::Depolarizing(1.0).kraus(), 0);
+        assert!((rho.purity() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bell_fidelity_decays_with_noise() {
+        let mut circuit = Circuit::new(2);
+        circuit
+            .push(Operation::new(Gate::H, vec![0]))
+            .push(Operation::controlled(Gate::X, vec![0], vec![1]));
+        let ideal: StateVector = run(&circuit);
+        let ideal = DensityMatrix::from_state_vector(&ideal);
+
+        let mut last = 1.0 + 1e-12;
+        for step in 0..=5 {
+            let mut model = NoiseModel::new();
+            model.add(NoiseTarget::AllGates, NoiseChannel::Depolarizing(step as f64 * 0.1));
+            let fidelity = model.run(&circuit, &mut rng()).state.fidelity(&ideal);
+            assert!(fidelity < last, "fidelity {} did not decrease", fidelity);
+            last = fidelity;
+        }
+
+        // Noise attached to a gate type only fires on that gate
+        let mut model = NoiseModel::new();
+        model.add(NoiseTarget::Gate("CZ".to_string()), NoiseChannel::Depolarizing(0.5));
+        assert!((model.run(&circuit, &mut rng()).state.fidelity(&ideal) - 1.0).abs() < 1e-9);
+
+        let mut model = NoiseModel::new();
+        model.set_readout_error(ReadoutError { p1_given_0: 0.1, p0_given_1: 0.0 });
+        let probabilities = model.apply_readout(&[1.0, 0.0], 1);
+        assert!((probabilities[0] - 0.9).abs() < 1e-12 && (probabilities[1] - 0.1).abs() < 1e-12);
+
+        // Sampled shots see the same confusion on the recorded bits
+        let mut measured = Circuit::with_registers(vec![Register::new("q", 2)], vec![Register::new("c", 2)]);
+        measured.push(Instruction::Gate(Operation::new(Gate::X, vec![1])));
+        measured.push(Instruction::Measure { qubit: 0, clbit: 0 });
+        measured.push(Instruction::Measure { qubit: 1, clbit: 1 });
+        let mut model = NoiseModel::new();
+        model.set_readout_error(ReadoutError { p1_given_0: 0.2, p0_given_1: 0.1 });
+        let shots = 4000;
+        let counts = model.sample_counts(&measured, shots, &mut rng());
+        let expected = [("10", 0.8 * 0.9), ("11", 0.2 * 0.9), ("00", 0.8 * 0.1), ("01", 0.2 * 0.1)];
+        for (bits, p) in expected {
+            let observed = counts.get(bits).copied().unwrap_or(0) as f64 / shots as f64;
+            assert!((observed - p).abs() < 0.03, "{}: {} vs {}", bits, observed, p);
+        }
+        // The state itself is untouched: a perfect readout reads 10 every time
+        assert_eq!(NoiseModel::new().sample_counts(&measured, 50, &mut rng()).get("10"), Some(&50));
+    }
+    #[test]
+    fn test_measurement_collapse_and_reset() {
+        // |+> reads 1 when the sample falls below P(1) = 1/2
+        let mut state = StateVector::new(1);
+        state.apply(&Operation::new(Gate::H, vec![0]));
+        let mut rho = DensityMatrix::from_state_vector(&state);
+        assert!(state.measure(0, 0.3));
+        assert!(!rho.measure(0, 0.7));
+        assert!((state.probabilities()[1] - 1.0).abs() < 1e-12);
+        assert!((rho.probabilities()[0] - 1.0).abs() < 1e-12);
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+
+        state.reset(0, 0.0);
+        assert!((state.probabilities()[0] - 1.0).abs() < 1e-12);
+
+        // A condition waits for the measurement that writes its register
+        let mut circuit = Circuit::with_registers(vec![Register::new("q", 2)], vec![Register::new("c", 1)]);
+        circuit
+            .push(Operation::new(Gate::X, vec![0]))
+            .push(Instruction::Measure { qubit: 0, clbit: 0 })
+            .push(Instruction::Conditional {
+                register: 0,
+                value: 1,
+                instruction: Box::new(Operation::new(Gate::X, vec![1]).into()),
+            });
+        assert_eq!(circuit.moments(), vec![vec![0], vec![1], vec![2]]);
+        let rho: DensityMatrix = run(&circuit);
+        assert!((rho.probabilities()[0b11] - 1.0).abs() < 1e-12);
+    }
+    #[test]
+    fn test_entanglement_measures() {
+        let bell = DensityMatrix::from_state_vector(&bell_state(3));
+        assert!((entanglement_entropy(&bell, &[0]) - 1.0).abs() < 1e-9);
+        assert!((concurrence(&bell) - 1.0).abs() < 1e-9);
+        assert!((negativity(&bell) - 0.5).abs() < 1e-9);
+
+        let mut circuit = Circuit::new(2);
+        circuit.push(Operation::new(Gate::H, vec![0])).push(Operation::new(Gate::X, vec![1]));
+        let product: DensityMatrix = run(&circuit);
+        assert!(entanglement_entropy(&product, &[0]).abs() < 1e-9);
+        assert!(concurrence(&product).abs() < 1e-9 && negativity(&product).abs() < 1e-9);
+
+        // Werner states are separable up to p = 1/3
+        assert!(concurrence(&werner(&bell_state(0), 0.3)) < 1e-9);
+        assert!((concurrence(&werner(&bell_state(0), 0.6)) - 0.4).abs() < 1e-9);
+
+        // GHZ pairs are classically correlated only; W pairs stay entangled
+        let ghz = DensityMatrix::from_state_vector(&ghz_state(3)).partial_trace(&[0, 1]);
+        let w = DensityMatrix::from_state_vector(&w_state(3)).partial_trace(&[0, 1]);
+        assert!(concurrence(&ghz).abs() < 1e-9);
+        assert!((concurrence(&w) - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_schmidt_decomposition_and_chsh() {
+        let schmidt = schmidt_decomposition(&ghz_state(3), &[0]);
+        assert_eq!(schmidt.rank(), 2);
+        for c in &schmidt.coefficients {
+            assert!((c - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+        }
+        assert_eq!(schmidt_decomposition(&w_state(2), &[0]).rank(), 2);
+
+        let bell = DensityMatrix::from_state_vector(&bell_state(0));
+        let s = chsh_value(&bell, &ChshAngles::OPTIMAL);
+        assert!((s - 2.0 * std::f64::consts::SQRT_2).abs() < 1e-9);
+        let sampled = sample_chsh(&bell, &ChshAngles::OPTIMAL, 4000, &mut rng());
+        assert!(sampled > 2.5, "sampled S = {}", sampled);
+
+        let grid = joint_probabilities(&bell, [0, 1], [Basis::X, Basis::X]);
+        assert!((correlation(&grid) - 1.0).abs() < 1e-9);
+        assert!(chsh_value(&werner(&bell_state(0), 0.6), &ChshAngles::OPTIMAL) < 2.0);
+    }
+
+    #[test]
+    fn test_oracle_algorithms() {
+        let mut rng = rng();
+        let reading = |circuit: &Circuit, rng: &mut StdRng| {
+            let shot = run_shot::<StateVector, _>(circuit, rng);
+            register_value(circuit, &shot.clbits, 0)
+        };
+
+        // One query tells constant (all zeros) from balanced
+        for oracle in [DjOracle::Constant(false), DjOracle::Constant(true)] {
+            assert_eq!(reading(&deutsch_jozsa(4, oracle), &mut rng), 0);
+        }
+        assert_ne!(reading(&deutsch_jozsa(4, DjOracle::Balanced(0b0110)), &mut rng), 0);
+
+        assert_eq!(reading(&bernstein_vazirani(5, 0b10110), &mut rng), 0b10110);
+
+        // Every Simon reading is orthogonal to s, and enough of them pin s down
+        let circuit = simon(3, 0b110);
+        let samples: Vec<u64> = (0..16).map(|_| reading(&circuit, &mut rng)).collect();
+        assert!(samples.iter().all(|y| (y & 0b110).count_ones() % 2 == 0));
+        assert_eq!(simon_secret(&samples, 3), Some(0b110));
+        assert_eq!(simon_secret(&[0], 3), None);
+    }
+
+    #[test]
+    fn test_grover_amplification() {
+        let iterations = optimal_grover_iterations(4, 1);
+        assert_eq!(iterations, 3);
+        let counts = sample_counts::<StateVector, _>(&grover(4, &[0b1011], iterations), 200, &mut rng());
+        let hits = counts.get("1011").copied().unwrap_or(0);
+        assert!(hits > 180, "{} hits", hits);
+        assert!(grover_success_probability(4, 1, iterations) > 0.95);
+        assert!(grover_success_probability(4, 1, 6) < 0.1);
+
+        // Two marked states out of eight: a single iteration finds one of them for sure
+        assert_eq!(optimal_grover_iterations(3, 2), 1);
+        assert!((grover_success_probability(3, 2, 1) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_qft_and_phase_estimation() {
+        // QFT|x⟩ has amplitude e^(2 pi i x y / N) / sqrt(N) on |y⟩
+        let (n, x) = (3, 5);
+        let state: StateVector = run(&qft(n, x));
+        let size = 1 << n;
+        for (y, amplitude) in state.amplitudes().iter().enumerate() {
+            let angle = 2.0 * std::f64::consts::PI * (x * y) as f64 / size as f64;
+            let expected = Complex64::from_polar(1.0 / (size as f64).sqrt(), angle);
+            assert!((amplitude - expected).norm() < 1e-9, "y = {}: {} vs {}", y, amplitude, expected);
+        }
+
+        let circuit = inverse_qft(4, 11);
+        let shot = run_shot::<StateVector, _>(&circuit, &mut rng());
+        assert_eq!(register_value(&circuit, &shot.clbits, 0), 11);
+
+        // phi = 5/16 is exact with four counting qubits
+        let circuit = phase_estimation(4, 5.0 / 16.0);
+        let shot = run_shot::<StateVector, _>(&circuit, &mut rng());
+        assert_eq!(register_value(&circuit, &shot.clbits, 0), 5);
+    }
+
+    #[test]
+    fn test_teleportation() {
+        let (theta, phi): (f64, f64) = (1.1, 0.7);
+        let expected = [theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()];
+        let circuit = teleportation(theta, phi);
+        let mut rng = rng();
+        for _ in 0..8 {
+            let shot = run_shot::<StateVector, _>(&circuit, &mut rng);
+            let bloch = shot.state.bloch_vector(2);
+            for (b, e) in bloch.iter().zip(expected.iter()) {
+                assert!((b - e).abs() < 1e-9, "{:?} vs {:?}", bloch, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_modular_multiplier_permutes_residues() {
+        for (a, modulus) in [(7, 15), (2, 21), (4, 35)] {
+            let n = work_qubits(modulus);
+            let work: Vec<usize> = (1..=n).collect();
+            for y in 0..1u64 << n {
+                // Control q0 set, work register loaded with y
+                let mut circuit = Circuit::new(n + 1);
+                circuit.push(Operation::new(Gate::X, vec![0]));
+                for bit in (0..n).filter(|bit| y >> bit & 1 == 1) {
+                    circuit.push(Operation::new(Gate::X, vec![work[bit]]));
+                }
+                append_modular_multiplier(&mut circuit, a, modulus, &work, 0);
+                let state: StateVector = run(&circuit);
+                let expected = if y < modulus { a * y % modulus } else { y };
+                assert!((state.probabilities()[(expected << 1 | 1) as usize] - 1.0).abs() < 1e-9, "{} * {} mod {}", a, y, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shor_recovers_factors() {
+        assert_eq!(continued_fraction(13, 64), vec![0, 4, 1, 12]);
+        assert_eq!(convergents(&[0, 4, 1, 12]), vec![(0, 1), (1, 4), (1, 5), (13, 64)]);
+
+        // a = 7 has order 4 mod 15: peaks at multiples of 256 / 4
+        let distribution = phase_distribution(7, 15, 8);
+        for (reading, p) in distribution.iter().enumerate() {
+            let expected = if reading % 64 == 0 { 0.25 } else { 0.0 };
+            assert!((p - expected).abs() < 1e-9, "reading {}: {}", reading, p);
+        }
+        let recovery = recover(192, 8, 7, 15);
+        assert_eq!(recovery.order, Some(4));
+        assert_eq!(recovery.factors, Ok((3, 5)));
+        assert!(recover(0, 8, 7, 15).factors.is_err());
+
+        // A sampled run on N = 21, a = 2 (order 6)
+        assert_eq!(classical_order(2, 21), 6);
+        let circuit = order_finding(2, 21, 6);
+        let mut rng = rng();
+        let found = (0..20).any(|_| {
+            let shot = run_shot::<StateVector, _>(&circuit, &mut rng);
+            recover(register_value(&circuit, &shot.clbits, 0), 6, 2, 21).factors == Ok((3, 7))
+        });
+        assert!(found);
+    }
+
+    #[test]
+    fn test_hamiltonian_parsing() {
+        let h = Hamiltonian::parse("0.5*Z0 Z1 - 1.2*X0 + 0.3 + y2*X1 -2e-1 I").unwrap();
+        assert_eq!(h.terms.len(), 5);
+        assert_eq!(h.terms[0], (0.5, PauliString::new([(0, Pauli::Z), (1, Pauli::Z)])));
+        assert_eq!(h.terms[1], (-1.2, PauliString::new([(0, Pauli::X)])));
+        assert!(h.terms[2].1.is_identity() && h.terms[4].1.is_identity());
+        assert_eq!(h.terms[3].1.label(), "X1 Y2");
+        assert_eq!(h.num_qubits(), 3);
+        assert_eq!(Hamiltonian::parse(&h.label()).unwrap(), h);
+
+        for bad in ["", "Z", "Z0 Z0", "0.5*Z0 X1 X", "Z0 Q1", "Z0 Z1 2"] {
+            assert!(Hamiltonian::parse(bad).is_err(), "{:?} should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_pauli_expectations_and_estimates() {
+        let bell = bell_state(0);
+        let h = Hamiltonian::parse("Z0 Z1 + X0 X1 - Y0 Y1 + 0.5*Z0 + 0.25").unwrap();
+        assert!((h.expectation(&bell) - 3.25).abs() < 1e-9);
+        let rho = werner(&bell, 0.5);
+        assert!((h.expectation(&rho) - 1.75).abs() < 1e-9);
+
+        // ZZ and Z0 share a basis; XX and YY each need their own
+        assert_eq!(h.commuting_groups(), vec![vec![0, 3], vec![1], vec![2]]);
+
+        let estimate = h.estimate(&rho, 2000, &mut rng());
+        assert_eq!(estimate.groups, 3);
+        assert_eq!(estimate.shots, 6000);
+        assert!(estimate.standard_error > 0.0 && estimate.standard_error < 0.1);
+        assert!((estimate.mean - 1.75).abs() < 4.0 * estimate.standard_error, "{:?}", estimate);
+
+        // Eigenstates give exact, zero-variance estimates
+        let exact = Hamiltonian::parse("Z0 Z1").unwrap().estimate(&bell, 100, &mut rng());
+        assert_eq!((exact.mean, exact.standard_error), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_molecular_hamiltonians() {
+        let h2 = Molecule::H2.hamiltonian();
+        assert_eq!(h2.num_qubits(), 4);
+        assert_eq!(h2.terms.len(), 15);
+        // Full CI and Hartree-Fock energies of H2 in STO-3G at 0.7414 Å
+        assert!((h2.ground_energy() - -1.137_270_175).abs() < 1e-8, "{}", h2.ground_energy());
+        let hartree_fock = Ansatz::DoubleExcitation.state(&ndarray::array![0.0]);
+        assert!((h2.expectation(&hartree_fock) - -1.116_684_387).abs() < 1e-8);
+
+        // The decomposition reproduces the matrix it came from
+        let rebuilt = Hamiltonian::from_matrix(&h2.matrix(4));
+        assert!((rebuilt.matrix(4) - h2.matrix(4)).iter().all(|z| z.norm() < 1e-12));
+
+        // LiH in STO-3G at 1.6 Å: the Hartree-Fock determinant carries the
+        // full RHF energy, and the active space recovers a little of the
+        // correlation but stays above the all-electron FCI
+        let lih = Molecule::LiH.hamiltonian();
+        assert!((lih.expectation(&hartree_fock) - -7.861_864_770).abs() < 1e-8, "{}", lih.expectation(&hartree_fock));
+        assert!((lih.ground_energy() - -7.862_128_833).abs() < 1e-8, "{}", lih.ground_energy());
+        assert!(lih.ground_energy() > -7.882_324_379);
+    }
+
+    #[test]
+    fn test_vqe_reaches_ground_energy() {
+        for molecule in Molecule::ALL {
+            let exact = molecule.hamiltonian().ground_energy();
+            for optimizer in OptimizerKind::ALL {
+                let mut vqe = Vqe::new(molecule, Ansatz::DoubleExcitation, optimizer, 0.5, 0);
+                for _ in 0..200 {
+                    vqe.step();
+                }
+                assert!((vqe.energy() - exact).abs() < 1e-4, "{} with {}: {} vs {}", molecule.name(), optimizer.name(), vqe.energy(), exact);
+            }
+        }
+
+        // Gradient descent on adjoint gradients
+        let exact = Molecule::H2.hamiltonian().ground_energy();
+        let mut vqe = Vqe::new(Molecule::H2, Ansatz::DoubleExcitation, OptimizerKind::GradientDescent, 0.5, 0);
+        vqe.gradient = GradientMethod::Adjoint;
+        for _ in 0..200 {
+            vqe.step();
+        }
+        assert!((vqe.energy() - exact).abs() < 1e-4, "{} vs {}", vqe.energy(), exact);
+
+        // Chemical accuracy (1.6 mHa) with the hardware-efficient ansatz
+        let mut vqe = Vqe::new(Molecule::H2, Ansatz::HardwareEfficient { layers: 1 }, OptimizerKind::Cobyla, 0.5, 0);
+        for _ in 0..400 {
+            vqe.step();
+        }
+        let exact = Molecule::H2.hamiltonian().ground_energy();
+        assert!(vqe.energy() - exact < 1.6e-3, "{} vs {} after {} evaluations", vqe.energy(), exact, vqe.evaluations);
+    }
+
+    #[test]
+    fn test_circuit_gradients_match_numerical() {
+        let mut circuit = ParameterizedCircuit::new(3);
+        circuit.push(Operation::new(Gate::H, vec![0]));
+        circuit.push(Operation::new(Gate::H, vec![2]));
+        circuit.rotation(Axis::X, "a", 1.0, vec![], 0);
+        circuit.rotation(Axis::Y, "b", 2.0, vec![], 1);
+        circuit.push(Operation::controlled(Gate::X, vec![0], vec![1]));
+        circuit.rotation(Axis::Z, "c", 1.0, vec![1], 2);
+        // Shared and scaled parameters, controlled rotations of each kind
+        circuit.rotation(Axis::Y, "a", -0.5, vec![2], 0);
+        circuit.rotation(Axis::Phase, "d", 1.0, vec![], 1);
+        circuit.rotation(Axis::Phase, "b", 1.5, vec![0], 2);
+        circuit.rotation(Axis::X, "d", 1.0, vec![0, 1], 2);
+        circuit.push(Operation::new(Gate::S, vec![0]));
+        assert_eq!(circuit.parameter("c"), 2);
+        assert_eq!(circuit.parameter_names(), ["a", "b", "c", "d"]);
+
+        let h = Hamiltonian::parse("0.5*Z0 Z1 + 0.3*X0 Y2 - 0.7*X1 Z2 + 0.2*Y0 Y1 Y2 + 0.4*Z2").unwrap();
+        let params = ndarray::array![0.4, -1.1, 0.8, 2.3];
+        let numerical = finite_difference(|p| expectation(&circuit, &h, p), &params);
+
+        let (shifted, evaluations) = parameter_shift(&circuit, &h, &params);
+        // Two runs per rotation, four for controlled non-phase rotations
+        assert_eq!(evaluations, 2 * 7 + 2 * 3);
+        let exact = adjoint(&circuit, &h, &params);
+        for i in 0..params.len() {
+            assert!((shifted[i] - numerical[i]).abs() < 1e-7, "shift {}: {} vs {}", i, shifted[i], numerical[i]);
+            assert!((exact[i] - shifted[i]).abs() < 1e-10, "adjoint {}: {} vs {}", i, exact[i], shifted[i]);
+        }
+
+        // Every method through the optimizer objective
+        for method in GradientMethod::ALL {
+            let mut objective = ExpectationObjective::new(&circuit, &h, method);
+            let gradient = objective.gradient(&params);
+            assert!((&gradient - &exact).iter().all(|d| d.abs() < 1e-7), "{}: {}", method.name(), gradient);
+            assert!(objective.evaluations > 0);
+        }
+    }
+
+    #[test]
+    fn test_graph_editing_and_max_cut() {
+        let square = Graph::ring(4);
+        assert_eq!(square.max_cut(), (4.0, vec![0b0101, 0b1010]));
+        assert_eq!(square.cut_value(0b0011), 2.0);
+
+        // Triangle with a heavy edge: the heavy edge is always cut, plus one light one
+        let mut triangle = Graph::complete(3);
+        triangle.set_edge(1, 0, 3.0);
+        assert_eq!(triangle.edges.len(), 3);
+        assert_eq!(triangle.edge(0, 1), Some(3.0));
+        assert_eq!(triangle.max_cut().0, 4.0);
+
+        let mut graph = Graph::ring(5);
+        graph.remove_node(2);
+        assert_eq!(graph.num_nodes, 4);
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(graph.edge(2, 3), Some(1.0));
+        assert_eq!(graph.edge(1, 2), None);
+    }
+
+    #[test]
+    fn test_qaoa_improves_on_random_cuts() {
+        let graph = Graph::ring(4);
+        // γ = β = 0 leaves the uniform superposition: a random cut
+        let uniform = state(&graph, &ndarray::array![0.0, 0.0]);
+        assert!((expected_cut(&graph, &uniform.probabilities()) - 2.0).abs() < 1e-9);
+
+        // p = 1 on the 4-ring peaks at 3 (a 0.75 approximation ratio)
+        let mut qaoa = Qaoa::new(graph.clone(), 1, OptimizerKind::Cobyla, 0.5, 0);
+        for _ in 0..100 {
+            qaoa.step();
+        }
+        assert!((qaoa.expected_cut() - 3.0).abs() < 1e-3, "{}", qaoa.expected_cut());
+        let grid = landscape(&graph, 41);
+        let peak = grid.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        assert!(peak <= qaoa.expected_cut() + 1e-6 && peak > 2.9, "{}", peak);
+        assert!(graph.max_cut().1.contains(&qaoa.most_likely()));
+
+        // A second layer does strictly better
+        let mut deeper = Qaoa::new(graph, 2, OptimizerKind::Cobyla, 0.5, 0);
+        for _ in 0..300 {
+            deeper.step();
+        }
+        assert!(deeper.expected_cut() > 3.2, "{}", deeper.expected_cut());
+    }
+}
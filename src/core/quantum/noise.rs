@@ -0,0 +1,213 @@
+/*
+--------------------------------------------------------------------
+                        Noise Channels
+                        --------------
+Notes
+-----
+
+- every channel is a set of single-qubit Kraus operators, sum K^dagger K = I
+- noise is applied after a gate, on the qubits the rule selects
+- readout error is classical: a noisy shot flips each recorded bit with
+  p(1|0) or p(0|1), so counts and classical control both see it;
+  apply_readout gives the same confusion on exact probabilities
+- thermal relaxation = amplitude damping (T1) + extra dephasing (T2),
+  which needs T2 <= 2 T1
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::density_matrix::DensityMatrix;
+use crate::core::quantum::executor::{Shot, clbit_string, run_shot_with};
+use crate::core::quantum::gates::Gate;
+use ndarray::{Array2, array};
+use num_complex::Complex64;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NoiseChannel {
+    BitFlip(f64),
+    PhaseFlip(f64),
+    // rho -> (1 - p) rho + p I/2
+    Depolarizing(f64),
+    AmplitudeDamping(f64),
+    PhaseDamping(f64),
+    ThermalRelaxation { t1: f64, t2: f64, gate_time: f64 },
+}
+
+fn real(m: Array2<f64>) -> Array2<Complex64> {
+    m.mapv(|x| Complex64::new(x, 0.0))
+}
+
+impl NoiseChannel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NoiseChannel::BitFlip(_) => "Bit flip",
+            NoiseChannel::PhaseFlip(_) => "Phase flip",
+            NoiseChannel::Depolarizing(_) => "Depolarizing",
+            NoiseChannel::AmplitudeDamping(_) => "Amplitude damping",
+            NoiseChannel::PhaseDamping(_) => "Phase damping",
+            NoiseChannel::ThermalRelaxation { .. } => "Thermal relaxation",
+        }
+    }
+
+    pub fn kraus(&self) -> Vec<Array2<Complex64>> {
+        let identity = real(array![[1.0, 0.0], [0.0, 1.0]]);
+        match *self {
+            NoiseChannel::BitFlip(p) => vec![
+                identity * Complex64::new((1.0 - p).sqrt(), 0.0),
+                Gate::X.matrix() * Complex64::new(p.sqrt(), 0.0),
+            ],
+            NoiseChannel::PhaseFlip(p) => vec![
+                identity * Complex64::new((1.0 - p).sqrt(), 0.0),
+                Gate::Z.matrix() * Complex64::new(p.sqrt(), 0.0),
+            ],
+            NoiseChannel::Depolarizing(p) => {
+                let pauli = Complex64::new((p / 4.0).sqrt(), 0.0);
+                vec![
+                    identity * Complex64::new((1.0 - 3.0 * p / 4.0).sqrt(), 0.0),
+                    Gate::X.matrix() * pauli,
+                    Gate::Y.matrix() * pauli,
+                    Gate::Z.matrix() * pauli,
+                ]
+            }
+            NoiseChannel::AmplitudeDamping(gamma) => vec![
+                real(array![[1.0, 0.0], [0.0, (1.0 - gamma).sqrt()]]),
+                real(array![[0.0, gamma.sqrt()], [0.0, 0.0]]),
+            ],
+            NoiseChannel::PhaseDamping(lambda) => vec![
+                real(array![[1.0, 0.0], [0.0, (1.0 - lambda).sqrt()]]),
+                real(array![[0.0, 0.0], [0.0, lambda.sqrt()]]),
+            ],
+            NoiseChannel::ThermalRelaxation { t1, t2, gate_time } => {
+                // Amplitude damping alone decays coherences as exp(-t / 2T1);
+                // the remaining exp(-t / T_phi) comes from pure dephasing
+                let t2 = t2.min(2.0 * t1);
+                let gamma = 1.0 - (-gate_time / t1).exp();
+                let dephasing_rate = (1.0 / t2 - 1.0 / (2.0 * t1)).max(0.0);
+                let lambda = 1.0 - (-2.0 * gate_time * dephasing_rate).exp();
+
+                let damping = NoiseChannel::AmplitudeDamping(gamma).kraus();
+                let dephasing = NoiseChannel::PhaseDamping(lambda).kraus();
+                dephasing
+                    .iter()
+                    .flat_map(|d| damping.iter().map(move |a| d.dot(a)))
+                    .collect()
+            }
+        }
+    }
+}
+
+// Classical confusion of each measured bit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReadoutError {
+    // P(read 1 | qubit is 0)
+    pub p1_given_0: f64,
+    // P(read 0 | qubit is 1)
+    pub p0_given_1: f64,
+}
+
+impl ReadoutError {
+    // The bit recorded for a measured outcome
+    pub fn read<R: Rng>(&self, outcome: bool, rng: &mut R) -> bool {
+        let flip = if outcome { self.p0_given_1 } else { self.p1_given_0 };
+        outcome != (rng.random::<f64>() < flip)
+    }
+}
+
+// Which gate applications a channel is attached to
+#[derive(Clone, Debug, PartialEq)]
+pub enum NoiseTarget {
+    // After every gate, on each qubit the gate touches
+    AllGates,
+    // After gates of one type (by Operation::name, e.g. "CX"), on each qubit they touch
+    Gate(String),
+    // After any gate touching this qubit, on that qubit only
+    Qubit(usize),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NoiseModel {
+    rules: Vec<(NoiseTarget, NoiseChannel)>,
+    readout: Option<ReadoutError>,
+}
+
+impl NoiseModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, target: NoiseTarget, channel: NoiseChannel) -> &mut Self {
+        self.rules.push((target, channel));
+        self
+    }
+
+    pub fn set_readout_error(&mut self, readout: ReadoutError) -> &mut Self {
+        self.readout = Some(readout);
+        self
+    }
+
+    pub fn is_noiseless(&self) -> bool {
+        self.rules.is_empty() && self.readout.is_none()
+    }
+
+    // Applies every matching channel after `operation` has been applied
+    pub fn apply_after(&self, rho: &mut DensityMatrix, operation: &Operation) {
+        let touched = operation.qubits();
+        for (target, channel) in &self.rules {
+            let qubits: Vec<usize> = match target {
+                NoiseTarget::AllGates => touched.clone(),
+                NoiseTarget::Gate(name) if *name == operation.name() => touched.clone(),
+                NoiseTarget::Qubit(q) if touched.contains(q) => vec![*q],
+                _ => Vec::new(),
+            };
+            if qubits.is_empty() {
+                continue;
+            }
+            let kraus = channel.kraus();
+            for qubit in qubits {
+                rho.apply_kraus(&kraus, qubit);
+            }
+        }
+    }
+
+    // Probabilities as seen through the readout error of every qubit
+    pub fn apply_readout(&self, probabilities: &[f64], num_qubits: usize) -> Vec<f64> {
+        let Some(readout) = self.readout else {
+            return probabilities.to_vec();
+        };
+        let mut result = probabilities.to_vec();
+        for qubit in 0..num_qubits {
+            let bit = 1 << qubit;
+            let mut next = vec![0.0; result.len()];
+            for (index, &p) in result.iter().enumerate() {
+                let flip = if index & bit == 0 { readout.p1_given_0 } else { readout.p0_given_1 };
+                next[index] += p * (1.0 - flip);
+                next[index ^ bit] += p * flip;
+            }
+            result = next;
+        }
+        result
+    }
+
+    // Gate-level noisy simulation of one shot on the density-matrix backend
+    pub fn run<R: Rng>(&self, circuit: &Circuit, rng: &mut R) -> Shot<DensityMatrix> {
+        run_shot_with(
+            circuit,
+            rng,
+            |rho, operation| self.apply_after(rho, operation),
+            |outcome, rng| self.readout.map_or(outcome, |readout| readout.read(outcome, rng)),
+        )
+    }
+
+    // Histogram of noisy shots, keyed by clbit_string like executor::sample_counts
+    pub fn sample_counts<R: Rng>(&self, circuit: &Circuit, shots: usize, rng: &mut R) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for _ in 0..shots {
+            let shot = self.run(circuit, rng);
+            *counts.entry(clbit_string(&shot.clbits)).or_insert(0) += 1;
+        }
+        counts
+    }
+}
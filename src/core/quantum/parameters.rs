@@ -0,0 +1,148 @@
+/*
+--------------------------------------------------------------------
+                        Parameterized Circuits
+                        ----------------------
+Notes
+-----
+
+- a template of fixed operations and rotations whose angles are
+  scale * θ for a named parameter θ; binding a value vector (in
+  declaration order) yields an ordinary Circuit
+- the same parameter may drive several rotations (with different
+  scales); gradients sum over every occurrence
+- rotations are Rx, Ry, Rz or Phase, optionally controlled, which is
+  what the shift rules and the adjoint method know how to differentiate
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::gates::Gate;
+use ndarray::{Array1, Array2};
+use num_complex::Complex64;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+    // diag(1, e^{iθ}): Rz up to a global phase, which matters once controlled
+    Phase,
+}
+
+impl Axis {
+    pub fn gate(self, angle: f64) -> Gate {
+        match self {
+            Axis::X => Gate::Rx(angle),
+            Axis::Y => Gate::Ry(angle),
+            Axis::Z => Gate::Rz(angle),
+            Axis::Phase => Gate::Phase(angle),
+        }
+    }
+
+    // dU/dθ; for the rotations e^{-iθP/2} this is U(θ + π) / 2
+    pub fn derivative(self, angle: f64) -> Array2<Complex64> {
+        match self {
+            Axis::Phase => {
+                let mut m = Array2::zeros((2, 2));
+                m[[1, 1]] = Complex64::new(0.0, 1.0) * Complex64::from_polar(1.0, angle);
+                m
+            }
+            _ => self.gate(angle + std::f64::consts::PI).matrix() * Complex64::new(0.5, 0.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+    Fixed(Operation),
+    Rotation {
+        axis: Axis,
+        controls: Vec<usize>,
+        target: usize,
+        parameter: usize,
+        scale: f64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParameterizedCircuit {
+    num_qubits: usize,
+    names: Vec<String>,
+    steps: Vec<Step>,
+}
+
+impl ParameterizedCircuit {
+    pub fn new(num_qubits: usize) -> Self {
+        Self {
+            num_qubits,
+            names: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    pub fn num_parameters(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn parameter_names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    // Index of the named parameter, declaring it on first use
+    pub fn parameter(&mut self, name: &str) -> usize {
+        match self.names.iter().position(|n| n == name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.to_string());
+                self.names.len() - 1
+            }
+        }
+    }
+
+    pub fn push(&mut self, operation: Operation) -> &mut Self {
+        self.steps.push(Step::Fixed(operation));
+        self
+    }
+
+    // Rotation by `scale` times the named parameter
+    pub fn rotation(&mut self, axis: Axis, name: &str, scale: f64, controls: Vec<usize>, target: usize) -> &mut Self {
+        let parameter = self.parameter(name);
+        self.steps.push(Step::Rotation { axis, controls, target, parameter, scale });
+        self
+    }
+
+    pub fn bind(&self, values: &Array1<f64>) -> Circuit {
+        self.bind_shifted(values, None)
+    }
+
+    // Binds with one step's angle moved by `shift`, as the shift rules need
+    pub fn bind_shifted(&self, values: &Array1<f64>, shift: Option<(usize, f64)>) -> Circuit {
+        assert_eq!(values.len(), self.names.len(), "expected {} parameter values", self.names.len());
+        let mut circuit = Circuit::new(self.num_qubits);
+        for (index, step) in self.steps.iter().enumerate() {
+            match step {
+                Step::Fixed(operation) => {
+                    circuit.push(operation.clone());
+                }
+                Step::Rotation { axis, controls, target, parameter, scale } => {
+                    let delta = match shift {
+                        Some((at, delta)) if at == index => delta,
+                        _ => 0.0,
+                    };
+                    let gate = axis.gate(scale * values[*parameter] + delta);
+                    circuit.push(Operation::controlled(gate, controls.clone(), vec![*target]));
+                }
+            }
+        }
+        circuit
+    }
+}
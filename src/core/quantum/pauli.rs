@@ -0,0 +1,389 @@
+/*
+--------------------------------------------------------------------
+                        Pauli Observables
+                        -----------------
+Notes
+-----
+
+- a PauliString keeps only its non-identity factors, keyed by qubit
+- a Hamiltonian is a real-weighted sum of Pauli strings, parsed from
+  text such as "0.5*Z0 Z1 - 1.2*X0 + 0.3" (a bare number is a multiple
+  of the identity)
+- expectations rotate a copy of the state so every factor becomes Z
+  (X: H, Y: Sdg then H) and read the parity of the measured bits, so
+  they work on any backend
+- shot estimates measure one basis per group of qubit-wise commuting
+  terms; the standard error comes from the per-shot spread of the
+  group's weighted sum, so covariances inside a group are included
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::Operation;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::linalg::hermitian_eigen;
+use ndarray::Array2;
+use num_complex::Complex64;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Pauli {
+    I,
+    X,
+    Y,
+    Z,
+}
+
+impl Pauli {
+    pub fn symbol(self) -> char {
+        match self {
+            Pauli::I => 'I',
+            Pauli::X => 'X',
+            Pauli::Y => 'Y',
+            Pauli::Z => 'Z',
+        }
+    }
+
+    fn from_symbol(symbol: char) -> Option<Pauli> {
+        match symbol.to_ascii_uppercase() {
+            'I' => Some(Pauli::I),
+            'X' => Some(Pauli::X),
+            'Y' => Some(Pauli::Y),
+            'Z' => Some(Pauli::Z),
+            _ => None,
+        }
+    }
+
+    // Gates that turn this Pauli's eigenbasis into the computational one
+    pub fn rotation(self, qubit: usize) -> Vec<Operation> {
+        match self {
+            Pauli::I | Pauli::Z => Vec::new(),
+            Pauli::X => vec![Operation::new(Gate::H, vec![qubit])],
+            Pauli::Y => vec![Operation::new(Gate::Sdg, vec![qubit]), Operation::new(Gate::H, vec![qubit])],
+        }
+    }
+}
+
+// Tensor product of single-qubit Paulis; identity on every qubit not listed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PauliString {
+    factors: BTreeMap<usize, Pauli>,
+}
+
+impl PauliString {
+    pub fn new(factors: impl IntoIterator<Item = (usize, Pauli)>) -> Self {
+        Self {
+            factors: factors.into_iter().filter(|(_, p)| *p != Pauli::I).collect(),
+        }
+    }
+
+    pub fn get(&self, qubit: usize) -> Pauli {
+        self.factors.get(&qubit).copied().unwrap_or(Pauli::I)
+    }
+
+    pub fn factors(&self) -> impl Iterator<Item = (usize, Pauli)> + '_ {
+        self.factors.iter().map(|(&q, &p)| (q, p))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.factors.is_empty()
+    }
+
+    // Qubits the string needs, i.e. one past its highest factor
+    pub fn num_qubits(&self) -> usize {
+        self.factors.keys().next_back().map_or(0, |q| q + 1)
+    }
+
+    // Bits whose parity gives the eigenvalue once every factor is rotated to Z
+    fn mask(&self) -> usize {
+        self.factors.keys().map(|q| 1 << q).sum()
+    }
+
+    // Every shared qubit carries the same Pauli, so one basis measures both
+    pub fn qubit_wise_commutes(&self, other: &PauliString) -> bool {
+        self.factors().all(|(q, p)| matches!(other.get(q), Pauli::I) || other.get(q) == p)
+    }
+
+    // Dense 2^n x 2^n matrix; entry (j ^ flips, j) is the only non-zero one in column j
+    pub fn matrix(&self, num_qubits: usize) -> Array2<Complex64> {
+        let mut m = Array2::zeros((1 << num_qubits, 1 << num_qubits));
+        let flips: usize = self.factors().filter(|(_, p)| matches!(p, Pauli::X | Pauli::Y)).map(|(q, _)| 1 << q).sum();
+        for j in 0..1usize << num_qubits {
+            let mut entry = Complex64::new(1.0, 0.0);
+            for (q, p) in self.factors() {
+                let sign = if j >> q & 1 == 1 { -1.0 } else { 1.0 };
+                entry *= match p {
+                    Pauli::I | Pauli::X => Complex64::new(1.0, 0.0),
+                    Pauli::Y => Complex64::new(0.0, sign),
+                    Pauli::Z => Complex64::new(sign, 0.0),
+                };
+            }
+            m[[j ^ flips, j]] = entry;
+        }
+        m
+    }
+
+    pub fn label(&self) -> String {
+        if self.is_identity() {
+            return "I".to_string();
+        }
+        self.factors().map(|(q, p)| format!("{}{}", p.symbol(), q)).collect::<Vec<_>>().join(" ")
+    }
+
+    // Basis-state probabilities after rotating every factor to Z
+    fn rotated_probabilities<B: Backend>(&self, state: &B) -> Vec<f64> {
+        let mut rotated = state.clone();
+        for (qubit, pauli) in self.factors() {
+            for op in pauli.rotation(qubit) {
+                rotated.apply(&op);
+            }
+        }
+        rotated.probabilities()
+    }
+
+    pub fn expectation<B: Backend>(&self, state: &B) -> f64 {
+        if self.is_identity() {
+            return 1.0;
+        }
+        let mask = self.mask();
+        self.rotated_probabilities(state)
+            .iter()
+            .enumerate()
+            .map(|(index, p)| parity_sign(index & mask) * p)
+            .sum()
+    }
+}
+
+fn parity_sign(bits: usize) -> f64 {
+    if bits.count_ones().is_multiple_of(2) { 1.0 } else { -1.0 }
+}
+
+// Shot-based estimate of an expectation value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Estimate {
+    pub mean: f64,
+    pub standard_error: f64,
+    // Measurement bases used, one per commuting group
+    pub groups: usize,
+    pub shots: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hamiltonian {
+    pub terms: Vec<(f64, PauliString)>,
+}
+
+impl Hamiltonian {
+    pub fn new(terms: Vec<(f64, PauliString)>) -> Self {
+        Self { terms }
+    }
+
+    // Terms like "0.5*Z0 Z1", "-1.2 X0", "X0*Y1" or a bare constant, joined by + and -
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut pos = 0;
+        let skip_spaces = |pos: &mut usize| {
+            while *pos < chars.len() && chars[*pos].is_whitespace() {
+                *pos += 1;
+            }
+        };
+
+        let mut terms = Vec::new();
+        loop {
+            skip_spaces(&mut pos);
+            if pos == chars.len() {
+                if terms.is_empty() {
+                    return Err("Empty Hamiltonian".to_string());
+                }
+                break;
+            }
+
+            let mut sign = 1.0;
+            match chars[pos] {
+                '+' => pos += 1,
+                '-' => {
+                    sign = -1.0;
+                    pos += 1;
+                }
+                _ if !terms.is_empty() => return Err(format!("Expected + or - at column {}", pos + 1)),
+                _ => {}
+            }
+            skip_spaces(&mut pos);
+
+            // Optional coefficient, then an optional '*'
+            let start = pos;
+            while pos < chars.len()
+                && (chars[pos].is_ascii_digit()
+                    || chars[pos] == '.'
+                    || matches!(chars[pos], 'e' | 'E')
+                    || (matches!(chars[pos], '+' | '-') && pos > start && matches!(chars[pos - 1], 'e' | 'E')))
+            {
+                pos += 1;
+            }
+            let coefficient = if pos > start {
+                let number: String = chars[start..pos].iter().collect();
+                number.parse::<f64>().map_err(|_| format!("Invalid coefficient '{}'", number))?
+            } else {
+                1.0
+            };
+            skip_spaces(&mut pos);
+            if pos < chars.len() && chars[pos] == '*' {
+                pos += 1;
+            }
+
+            // Factors such as X0, Z12 or a lone I
+            let mut factors = BTreeMap::new();
+            loop {
+                skip_spaces(&mut pos);
+                let Some(pauli) = chars.get(pos).and_then(|&c| Pauli::from_symbol(c)) else {
+                    break;
+                };
+                pos += 1;
+                let digits = pos;
+                while pos < chars.len() && chars[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                if digits == pos {
+                    if pauli != Pauli::I {
+                        return Err(format!("{} at column {} needs a qubit index, e.g. {}0", pauli.symbol(), digits, pauli.symbol()));
+                    }
+                } else {
+                    let qubit: usize = chars[digits..pos].iter().collect::<String>().parse().map_err(|_| "Qubit index too large".to_string())?;
+                    if factors.insert(qubit, pauli).is_some() {
+                        return Err(format!("Qubit {} appears twice in one term", qubit));
+                    }
+                }
+                skip_spaces(&mut pos);
+                if pos < chars.len() && chars[pos] == '*' {
+                    pos += 1;
+                }
+            }
+
+            if start == pos {
+                return Err(format!("Expected a term at column {}", pos + 1));
+            }
+            terms.push((sign * coefficient, PauliString::new(factors)));
+        }
+        Ok(Self::new(terms))
+    }
+
+    // Pauli decomposition c_P = Tr(P H) / 2^n of a Hermitian matrix, dropping zero terms
+    pub fn from_matrix(m: &Array2<Complex64>) -> Self {
+        let n = m.nrows().trailing_zeros() as usize;
+        let mut terms = Vec::new();
+        for code in 0..1usize << (2 * n) {
+            // Two bits per qubit: 0 = I, 1 = X, 2 = Y, 3 = Z
+            let string = PauliString::new((0..n).map(|q| {
+                let pauli = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z][code >> (2 * q) & 3];
+                (q, pauli)
+            }));
+            let p = string.matrix(n);
+            let trace: Complex64 = p.iter().zip(m.t().iter()).map(|(a, b)| a * b).sum();
+            let coefficient = trace.re / (1 << n) as f64;
+            if coefficient.abs() > 1e-10 {
+                terms.push((coefficient, string));
+            }
+        }
+        Self::new(terms)
+    }
+
+    pub fn matrix(&self, num_qubits: usize) -> Array2<Complex64> {
+        self.terms
+            .iter()
+            .fold(Array2::zeros((1 << num_qubits, 1 << num_qubits)), |sum, (c, p)| sum + p.matrix(num_qubits) * Complex64::new(*c, 0.0))
+    }
+
+    // Smallest eigenvalue, by exact diagonalisation
+    pub fn ground_energy(&self) -> f64 {
+        let (values, _) = hermitian_eigen(&self.matrix(self.num_qubits().max(1)));
+        values.iter().copied().fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn num_qubits(&self) -> usize {
+        self.terms.iter().map(|(_, p)| p.num_qubits()).max().unwrap_or(0)
+    }
+
+    pub fn label(&self) -> String {
+        let mut text = String::new();
+        for (i, (c, p)) in self.terms.iter().enumerate() {
+            let sign = if *c < 0.0 { "-" } else if i > 0 { "+" } else { "" };
+            let spacing = if i > 0 { " " } else { "" };
+            text += &format!("{}{}{}{}", spacing, sign, spacing, c.abs());
+            if !p.is_identity() {
+                text += &format!("*{}", p.label());
+            }
+        }
+        text
+    }
+
+    pub fn expectation<B: Backend>(&self, state: &B) -> f64 {
+        self.terms.iter().map(|(c, p)| c * p.expectation(state)).sum()
+    }
+
+    // Greedy partition of the non-identity terms into qubit-wise commuting groups
+    pub fn commuting_groups(&self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<(PauliString, Vec<usize>)> = Vec::new();
+        for (index, (_, term)) in self.terms.iter().enumerate() {
+            if term.is_identity() {
+                continue;
+            }
+            match groups.iter_mut().find(|(basis, _)| basis.qubit_wise_commutes(term)) {
+                Some((basis, members)) => {
+                    basis.factors.extend(term.factors());
+                    members.push(index);
+                }
+                None => groups.push((term.clone(), vec![index])),
+            }
+        }
+        groups.into_iter().map(|(_, members)| members).collect()
+    }
+
+    // `shots` measurements in each group's basis; identity terms are exact
+    pub fn estimate<B: Backend, R: Rng>(&self, state: &B, shots: usize, rng: &mut R) -> Estimate {
+        let groups = self.commuting_groups();
+        let mut mean: f64 = self.terms.iter().filter(|(_, p)| p.is_identity()).map(|(c, _)| c).sum();
+        let mut variance = 0.0;
+
+        for members in &groups {
+            let basis = PauliString::new(members.iter().flat_map(|&i| self.terms[i].1.factors()));
+            let probabilities = basis.rotated_probabilities(state);
+            let weighted: Vec<(f64, usize)> = members.iter().map(|&i| (self.terms[i].0, self.terms[i].1.mask())).collect();
+
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..shots {
+                let index = sample_index(&probabilities, rng.random());
+                let value: f64 = weighted.iter().map(|(c, mask)| c * parity_sign(index & mask)).sum();
+                sum += value;
+                sum_sq += value * value;
+            }
+            if shots > 0 {
+                let group_mean = sum / shots as f64;
+                mean += group_mean;
+                if shots > 1 {
+                    let sample_variance = (sum_sq - shots as f64 * group_mean * group_mean).max(0.0) / (shots - 1) as f64;
+                    variance += sample_variance / shots as f64;
+                }
+            }
+        }
+
+        Estimate {
+            mean,
+            standard_error: variance.sqrt(),
+            groups: groups.len(),
+            shots: shots * groups.len(),
+        }
+    }
+}
+
+// Basis state drawn from `probabilities` with the uniform sample u in [0, 1)
+fn sample_index(probabilities: &[f64], mut u: f64) -> usize {
+    for (index, p) in probabilities.iter().enumerate() {
+        if u < *p {
+            return index;
+        }
+        u -= p;
+    }
+    probabilities.len() - 1
+}
@@ -0,0 +1,254 @@
+/*
+--------------------------------------------------------------------
+                        QAOA for MaxCut
+                        ---------------
+Notes
+-----
+
+- one qubit per node; bit q of a basis index puts node q on side 1
+- cost C = Σ w (1 - Z_i Z_j) / 2 over the edges, so C(x) is the weight
+  of the cut x; each layer applies e^{-iγC} (CX, Rz, CX per edge, up
+  to a global phase) and then the mixer e^{-iβ Σ X} (Rx(2β) per node)
+- parameters are laid out [γ1, β1, γ2, β2, ...]
+- the optimizers minimise, so the objective is -⟨C⟩
+- C is diagonal: ⟨C⟩ is read straight off the probabilities, and the
+  brute-force optimum simply scans all 2^n cuts
+- layers start on a linear ramp (a discretized anneal), which avoids
+  the flat region around γ = β = 0
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Objective, Optimizer, OptimizerKind};
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::parameters::{Axis, ParameterizedCircuit};
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::{Array1, Array2};
+use std::f64::consts::PI;
+
+pub const MAX_NODES: usize = 10;
+// p = 1 landscape: γ over one period of integer weights, β over the mixer's period
+pub const GAMMA_RANGE: f64 = PI;
+pub const BETA_RANGE: f64 = PI / 2.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Graph {
+    pub num_nodes: usize,
+    // (i, j, weight) with i < j
+    pub edges: Vec<(usize, usize, f64)>,
+}
+
+impl Graph {
+    pub fn new(num_nodes: usize) -> Self {
+        Self { num_nodes, edges: Vec::new() }
+    }
+
+    pub fn ring(num_nodes: usize) -> Self {
+        let mut graph = Self::new(num_nodes);
+        for i in 0..num_nodes {
+            graph.set_edge(i, (i + 1) % num_nodes, 1.0);
+        }
+        graph
+    }
+
+    pub fn complete(num_nodes: usize) -> Self {
+        let mut graph = Self::new(num_nodes);
+        for i in 0..num_nodes {
+            for j in i + 1..num_nodes {
+                graph.set_edge(i, j, 1.0);
+            }
+        }
+        graph
+    }
+
+    pub fn edge(&self, a: usize, b: usize) -> Option<f64> {
+        let (i, j) = (a.min(b), a.max(b));
+        self.edges.iter().find(|e| e.0 == i && e.1 == j).map(|e| e.2)
+    }
+
+    // Adds the edge or replaces its weight
+    pub fn set_edge(&mut self, a: usize, b: usize, weight: f64) {
+        assert!(a != b && a.max(b) < self.num_nodes, "edge ({}, {}) out of range", a, b);
+        let (i, j) = (a.min(b), a.max(b));
+        self.remove_edge(i, j);
+        self.edges.push((i, j, weight));
+    }
+
+    pub fn remove_edge(&mut self, a: usize, b: usize) {
+        let (i, j) = (a.min(b), a.max(b));
+        self.edges.retain(|e| !(e.0 == i && e.1 == j));
+    }
+
+    pub fn add_node(&mut self) -> usize {
+        self.num_nodes += 1;
+        self.num_nodes - 1
+    }
+
+    // Later nodes shift down by one
+    pub fn remove_node(&mut self, node: usize) {
+        self.edges.retain(|e| e.0 != node && e.1 != node);
+        for edge in &mut self.edges {
+            if edge.0 > node {
+                edge.0 -= 1;
+            }
+            if edge.1 > node {
+                edge.1 -= 1;
+            }
+        }
+        self.num_nodes -= 1;
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.edges.iter().map(|e| e.2).sum()
+    }
+
+    pub fn cut_value(&self, bits: usize) -> f64 {
+        self.edges
+            .iter()
+            .filter(|(i, j, _)| (bits >> i & 1) != (bits >> j & 1))
+            .map(|e| e.2)
+            .sum()
+    }
+
+    // Every cut with the largest value; x and its complement are both listed
+    pub fn max_cut(&self) -> (f64, Vec<usize>) {
+        let mut best = f64::NEG_INFINITY;
+        let mut cuts = Vec::new();
+        for bits in 0..1usize << self.num_nodes {
+            let value = self.cut_value(bits);
+            if value > best + 1e-9 {
+                best = value;
+                cuts.clear();
+            }
+            if value > best - 1e-9 {
+                cuts.push(bits);
+            }
+        }
+        (best, cuts)
+    }
+}
+
+pub fn num_parameters(layers: usize) -> usize {
+    2 * layers
+}
+
+pub fn initial_parameters(layers: usize) -> Array1<f64> {
+    Array1::from_shape_fn(num_parameters(layers), |i| {
+        let t = (i / 2) as f64 + 0.5;
+        let fraction = t / layers as f64;
+        if i.is_multiple_of(2) { 0.8 * fraction } else { 0.8 * (1.0 - fraction) }
+    })
+}
+
+pub fn template(graph: &Graph, layers: usize) -> ParameterizedCircuit {
+    let mut circuit = ParameterizedCircuit::new(graph.num_nodes);
+    for q in 0..graph.num_nodes {
+        circuit.push(Operation::new(Gate::H, vec![q]));
+    }
+    for layer in 1..=layers {
+        let (gamma, beta) = (format!("γ{}", layer), format!("β{}", layer));
+        circuit.parameter(&gamma);
+        circuit.parameter(&beta);
+        for &(i, j, w) in &graph.edges {
+            // e^{+iγw Z_i Z_j / 2} is what remains of e^{-iγw (1 - Z_i Z_j) / 2}
+            circuit.push(Operation::controlled(Gate::X, vec![i], vec![j]));
+            circuit.rotation(Axis::Z, &gamma, -w, vec![], j);
+            circuit.push(Operation::controlled(Gate::X, vec![i], vec![j]));
+        }
+        for q in 0..graph.num_nodes {
+            circuit.rotation(Axis::X, &beta, 2.0, vec![], q);
+        }
+    }
+    circuit
+}
+
+pub fn circuit(graph: &Graph, params: &Array1<f64>) -> Circuit {
+    template(graph, params.len() / 2).bind(params)
+}
+
+pub fn state(graph: &Graph, params: &Array1<f64>) -> StateVector {
+    run_unitary(&circuit(graph, params))
+}
+
+pub fn expected_cut(graph: &Graph, probabilities: &[f64]) -> f64 {
+    probabilities.iter().enumerate().map(|(bits, p)| p * graph.cut_value(bits)).sum()
+}
+
+// ⟨C⟩ over a resolution x resolution grid; rows are β, columns γ
+pub fn landscape(graph: &Graph, resolution: usize) -> Array2<f64> {
+    let step = |range: f64, k: usize| range * k as f64 / (resolution - 1) as f64;
+    Array2::from_shape_fn((resolution, resolution), |(row, col)| {
+        let params = Array1::from(vec![step(GAMMA_RANGE, col), step(BETA_RANGE, row)]);
+        expected_cut(graph, &state(graph, &params).probabilities())
+    })
+}
+
+// -⟨C⟩, counting evaluations
+struct CutObjective<'a> {
+    graph: &'a Graph,
+    evaluations: usize,
+}
+
+impl Objective for CutObjective<'_> {
+    fn value(&mut self, params: &Array1<f64>) -> f64 {
+        self.evaluations += 1;
+        -expected_cut(self.graph, &state(self.graph, params).probabilities())
+    }
+}
+
+pub struct Qaoa {
+    pub graph: Graph,
+    pub layers: usize,
+    optimizer: Box<dyn Optimizer>,
+    pub params: Array1<f64>,
+    // ⟨C⟩ after every optimizer step, starting with the initial parameters
+    pub history: Vec<f64>,
+    pub evaluations: usize,
+}
+
+impl Qaoa {
+    pub fn new(graph: Graph, layers: usize, optimizer: OptimizerKind, step_size: f64, seed: u64) -> Self {
+        let params = initial_parameters(layers);
+        let initial = expected_cut(&graph, &state(&graph, &params).probabilities());
+        Self {
+            graph,
+            layers,
+            optimizer: optimizer.build(step_size, seed),
+            params,
+            history: vec![initial],
+            evaluations: 1,
+        }
+    }
+
+    pub fn expected_cut(&self) -> f64 {
+        *self.history.last().expect("history starts with the initial value")
+    }
+
+    pub fn step(&mut self) -> f64 {
+        let mut objective = CutObjective {
+            graph: &self.graph,
+            evaluations: 0,
+        };
+        let value = -self.optimizer.step(&mut objective, &mut self.params);
+        self.evaluations += objective.evaluations;
+        self.history.push(value);
+        value
+    }
+
+    pub fn probabilities(&self) -> Vec<f64> {
+        state(&self.graph, &self.params).probabilities()
+    }
+
+    // The cut a measurement most often returns
+    pub fn most_likely(&self) -> usize {
+        self.probabilities()
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(bits, _)| bits)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,153 @@
+/*
+--------------------------------------------------------------------
+                        QASM Lexer
+                        ----------
+Notes
+-----
+
+- splits source into tokens tagged with their 1-based line and column
+- skips whitespace, // line comments and /* block comments */
+- integers and reals are kept apart: register sizes must be integers
+- one token set serves both OpenQASM 2.0 and 3
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::qasm::QasmError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenKind {
+    Ident(String),
+    Int(u64),
+    Real(f64),
+    Str(String),
+    // Punctuation and operators, e.g. ";", "->", "=="
+    Symbol(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Token {
+    pub fn error(&self, message: impl Into<String>) -> QasmError {
+        QasmError::new(self.line, self.column, message)
+    }
+
+    pub fn describe(&self) -> String {
+        match &self.kind {
+            TokenKind::Ident(name) => format!("'{}'", name),
+            TokenKind::Int(value) => format!("'{}'", value),
+            TokenKind::Real(value) => format!("'{}'", value),
+            TokenKind::Str(text) => format!("\"{}\"", text),
+            TokenKind::Symbol(symbol) => format!("'{}'", symbol),
+            TokenKind::Eof => "end of file".to_string(),
+        }
+    }
+}
+
+// Longest symbols first so "->" wins over "-"
+const SYMBOLS: [&str; 24] = [
+    "->", "==", "!=", "<=", ">=", ";", ":", ",", "(", ")", "[", "]", "{", "}", "+", "-", "*", "/", "^",
+    "@", "=", "<", ">", "!",
+];
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, QasmError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line, mut column) = (0, 1, 1);
+
+    // Moves past n characters, keeping line/column in step
+    let advance = |i: &mut usize, line: &mut usize, column: &mut usize, n: usize| {
+        for _ in 0..n {
+            if chars[*i] == '\n' {
+                *line += 1;
+                *column = 1;
+            } else {
+                *column += 1;
+            }
+            *i += 1;
+        }
+    };
+
+    while i < chars.len() {
+        let ch = chars[i];
+        let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+        let (start_line, start_column) = (line, column);
+
+        if ch.is_whitespace() {
+            advance(&mut i, &mut line, &mut column, 1);
+        } else if rest == "//" {
+            while i < chars.len() && chars[i] != '\n' {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+        } else if rest == "/*" {
+            advance(&mut i, &mut line, &mut column, 2);
+            loop {
+                if i + 1 >= chars.len() {
+                    return Err(QasmError::new(start_line, start_column, "unterminated block comment"));
+                }
+                if chars[i] == '*' && chars[i + 1] == '/' {
+                    advance(&mut i, &mut line, &mut column, 2);
+                    break;
+                }
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+        } else if ch.is_ascii_alphabetic() || ch == '_' {
+            let mut name = String::new();
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                name.push(chars[i]);
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            tokens.push(Token { kind: TokenKind::Ident(name), line: start_line, column: start_column });
+        } else if ch.is_ascii_digit() || (ch == '.' && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())) {
+            let mut text = String::new();
+            let mut is_real = false;
+            while i < chars.len() {
+                let c = chars[i];
+                let exponent_sign = (c == '+' || c == '-') && text.ends_with(['e', 'E']);
+                if c.is_ascii_digit() || exponent_sign {
+                    text.push(c);
+                } else if c == '.' || c == 'e' || c == 'E' {
+                    is_real = true;
+                    text.push(c);
+                } else {
+                    break;
+                }
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            let kind = if is_real {
+                text.parse().map(TokenKind::Real)
+                    .map_err(|_| QasmError::new(start_line, start_column, format!("invalid number '{}'", text)))?
+            } else {
+                text.parse().map(TokenKind::Int)
+                    .map_err(|_| QasmError::new(start_line, start_column, format!("integer '{}' is too large", text)))?
+            };
+            tokens.push(Token { kind, line: start_line, column: start_column });
+        } else if ch == '"' {
+            advance(&mut i, &mut line, &mut column, 1);
+            let mut text = String::new();
+            while i < chars.len() && chars[i] != '"' && chars[i] != '\n' {
+                text.push(chars[i]);
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            if i >= chars.len() || chars[i] != '"' {
+                return Err(QasmError::new(start_line, start_column, "unterminated string"));
+            }
+            advance(&mut i, &mut line, &mut column, 1);
+            tokens.push(Token { kind: TokenKind::Str(text), line: start_line, column: start_column });
+        } else if let Some(symbol) = SYMBOLS.iter().find(|s| rest.starts_with(**s)) {
+            advance(&mut i, &mut line, &mut column, symbol.len());
+            tokens.push(Token { kind: TokenKind::Symbol(symbol), line: start_line, column: start_column });
+        } else {
+            return Err(QasmError::new(start_line, start_column, format!("unexpected character '{}'", ch)));
+        }
+    }
+
+    tokens.push(Token { kind: TokenKind::Eof, line, column });
+    Ok(tokens)
+}
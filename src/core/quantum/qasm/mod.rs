@@ -0,0 +1,285 @@
+pub mod lexer;
+pub mod parser;
+pub mod writer;
+
+use crate::core::quantum::circuit::Circuit;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Version {
+    V2,
+    V3,
+}
+
+impl Version {
+    pub const ALL: [Version; 2] = [Version::V2, Version::V3];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Version::V2 => "OpenQASM 2.0",
+            Version::V3 => "OpenQASM 3",
+        }
+    }
+}
+
+// A parsed program with the `input` parameters it declared and the values used
+#[derive(Clone, Debug)]
+pub struct Program {
+    pub version: Version,
+    pub circuit: Circuit,
+    pub inputs: Vec<(String, f64)>,
+}
+
+// Parse failure at a 1-based line and column of the source
+#[derive(Clone, Debug, PartialEq)]
+pub struct QasmError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl QasmError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Version, parser, writer};
+    use crate::core::quantum::backend::Backend;
+    use crate::core::quantum::circuit::{Circuit, Instruction, Operation};
+    use crate::core::quantum::executor::{run_shot, sample_counts};
+    use crate::core::quantum::gates::Gate;
+    use crate::core::quantum::state_vector::StateVector;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::collections::HashMap;
+
+    fn parse(source: &str) -> Result<Circuit, super::QasmError> {
+        parser::parse(source, &HashMap::new()).map(|program| program.circuit)
+    }
+
+    const BELL: &str = r#"
+        OPENQASM 2.0;
+        include "qelib1.inc";
+        qreg q[2];
+        creg c[2];
+        h q[0];
+        cx q[0], q[1];
+        barrier q;
+        measure q -> c;
+    "#;
+
+    #[test]
+    fn test_parse_bell_with_broadcast_measure() {
+        let circuit = parse(BELL).unwrap();
+        assert_eq!(circuit.num_qubits(), 2);
+        assert_eq!(circuit.num_clbits(), 2);
+        assert_eq!(circuit.instructions()[1], Instruction::Gate(Operation::controlled(Gate::X, vec![0], vec![1])));
+        assert_eq!(circuit.instructions()[2], Instruction::Barrier(vec![0, 1]));
+        assert_eq!(circuit.instructions()[4], Instruction::Measure { qubit: 1, clbit: 1 });
+
+        // Bell pairs only ever read 00 or 11
+        let mut rng = StdRng::seed_from_u64(7);
+        let counts = sample_counts::<StateVector, _>(&circuit, 200, &mut rng);
+        assert_eq!(counts.keys().cloned().collect::<Vec<_>>(), vec!["00", "11"]);
+    }
+
+    #[test]
+    fn test_custom_gates_and_conditionals() {
+        let source = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            gate flip(theta) a, b { rx(theta) a; cx a, b; }
+            qreg q[2];
+            creg c[1];
+            flip(pi) q[0], q[1];
+            measure q[1] -> c[0];
+            if(c==1) x q[0];
+            if(c==0) x q[1];
+        "#;
+        let circuit = parse(source).unwrap();
+        assert_eq!(circuit.instructions().len(), 5);
+
+        // rx(pi) flips q0, so the measurement reads 1 and q0 is flipped back
+        let mut rng = StdRng::seed_from_u64(1);
+        let shot = run_shot::<StateVector, _>(&circuit, &mut rng);
+        assert_eq!(shot.clbits, vec![true]);
+        assert!((shot.state.probabilities()[2] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors_carry_line_and_column() {
+        let source = "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[2];\nh q[2];\n";
+        let error = parse(source).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 3));
+
+        let error = parse("OPENQASM 2.0;\nqreg q[1];\nh q[0];").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert!(error.message.contains("unknown gate"));
+
+        let error = parse("OPENQASM 2.0;\nqreg q[1]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+
+        // if(c==n) reads the whole creg as a u64
+        let error = parse("OPENQASM 2.0;\ncreg c[65];").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert!(error.message.contains("64 bits"));
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        let circuit = parse(BELL).unwrap();
+        let source = writer::write(&circuit, Version::V2).unwrap();
+        assert!(source.contains("cx q[0], q[1];"));
+        assert_eq!(parse(&source).unwrap(), circuit);
+
+        let rotated = parse("OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[1];\nrx(pi/2) q[0];\nu1(0.3) q[0];").unwrap();
+        let source = writer::write(&rotated, Version::V2).unwrap();
+        assert!(source.contains("rx(pi/2) q[0];"));
+        assert_eq!(parse(&source).unwrap(), rotated);
+    }
+    #[test]
+    fn test_qasm3_modifiers() {
+        let source = r#"
+            OPENQASM 3;
+            include "stdgates.inc";
+            qubit[3] q;
+            ctrl @ x q[0], q[1];
+            ctrl(2) @ x q[0], q[1], q[2];
+            inv @ s q[0];
+            pow(2) @ t q[1];
+            pow(0.5) @ rz(pi) q[2];
+            negctrl @ z q[0], q[1];
+        "#;
+        let circuit = parse(source).unwrap();
+        let gates: Vec<&Operation> = circuit.instructions().iter().filter_map(|i| i.operation()).collect();
+        assert_eq!(*gates[0], Operation::controlled(Gate::X, vec![0], vec![1]));
+        assert_eq!(*gates[1], Operation::controlled(Gate::X, vec![0, 1], vec![2]));
+        assert_eq!(gates[2].gate, Gate::Sdg);
+        assert_eq!((gates[3].gate.clone(), gates[4].gate.clone()), (Gate::T, Gate::T));
+        assert_eq!(gates[5].gate, Gate::Rz(std::f64::consts::PI / 2.0));
+        // negctrl flips the control around a controlled gate
+        assert_eq!(*gates[6], Operation::new(Gate::X, vec![0]));
+        assert_eq!(*gates[7], Operation::controlled(Gate::Z, vec![0], vec![1]));
+        assert_eq!(*gates[8], Operation::new(Gate::X, vec![0]));
+
+        // A controlled global phase becomes a phase on the control
+        let source = "OPENQASM 3;\nqubit[2] q;\ngate g a { gphase(pi); }\nctrl @ g q[0], q[1];";
+        let circuit = parse(source).unwrap();
+        assert_eq!(circuit.instructions(), &[Instruction::Gate(Operation::new(Gate::Phase(std::f64::consts::PI), vec![0]))]);
+
+        // Huge or non-finite powers are rejected instead of unrolled
+        let error = parse("OPENQASM 3;\nqubit[1] q;\npow(1e12) @ x q[0];").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 13));
+        assert!(error.message.contains("limited to"));
+        let error = parse("OPENQASM 3;\nqubit[1] q;\npow(1e400) @ x q[0];").unwrap_err();
+        assert!(error.message.contains("finite"));
+    }
+
+    #[test]
+    fn test_qasm3_for_loops_and_inputs() {
+        let source = r#"
+            OPENQASM 3.0;
+            include "stdgates.inc";
+            input float theta;
+            const int n = 4;
+            qubit[n] q;
+            for int i in [0:n - 2] { cx q[i], q[i + 1]; }
+            for i in {3, 1} { rx(theta * i) q[i]; }
+        "#;
+        let mut inputs = HashMap::new();
+        inputs.insert("theta".to_string(), 0.5);
+        let program = parser::parse(source, &inputs).unwrap();
+        assert_eq!(program.version, Version::V3);
+        assert_eq!(program.inputs, vec![("theta".to_string(), 0.5)]);
+        let instructions = program.circuit.instructions();
+        assert_eq!(instructions.len(), 5);
+        assert_eq!(instructions[2], Instruction::Gate(Operation::controlled(Gate::X, vec![2], vec![3])));
+        assert_eq!(instructions[3], Instruction::Gate(Operation::new(Gate::Rx(1.5), vec![3])));
+
+        // Inputs that are not given default to zero
+        let program = parser::parse(source, &HashMap::new()).unwrap();
+        assert_eq!(program.inputs, vec![("theta".to_string(), 0.0)]);
+
+        let error = parse("OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[2] q;\nfor i in [0:2] { x q[i]; }").unwrap_err();
+        assert!(error.message.contains("out of range"));
+
+        // Nesting shares one unroll budget instead of multiplying per-construct limits
+        let error = parse("OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[1] q;\nfor i in [0:2999] {\n  for j in [0:2999] { x q; }\n}").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert!(error.message.contains("limited to"), "{}", error.message);
+        let error = parse("OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[1] q;\nfor i in [0:999] { pow(1000) @ x q; }").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 32));
+        let error = parse("OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[1] q;\ngate g a { pow(1000) @ x a; }\npow(1000) @ g q;").unwrap_err();
+        assert_eq!(error.line, 5);
+        let program = parse("OPENQASM 3;\ninclude \"stdgates.inc\";\nqubit[1] q;\nfor i in [0:99] { for j in [0:99] { x q; } }").unwrap();
+        assert_eq!(program.instructions().len(), 10_000);
+    }
+
+    #[test]
+    fn test_qasm3_repeat_until_success() {
+        // Keeps re-preparing |+> until it measures 1, so every shot ends in |1>
+        let source = r#"
+            OPENQASM 3;
+            include "stdgates.inc";
+            qubit q;
+            bit c;
+            h q;
+            c = measure q;
+            while (c == 0) {
+                reset q;
+                h q;
+                c = measure q;
+            }
+            if (c) { x q; } else { z q; }
+        "#;
+        let circuit = parse(source).unwrap();
+        assert_eq!(circuit.instructions().len(), 4);
+        let mut rng = StdRng::seed_from_u64(3);
+        let counts = sample_counts::<StateVector, _>(&circuit, 100, &mut rng);
+        assert_eq!(counts.get("1"), Some(&100));
+        for _ in 0..10 {
+            let shot = run_shot::<StateVector, _>(&circuit, &mut rng);
+            assert!((shot.state.probabilities()[0] - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_qasm3_write_round_trip() {
+        let source = r#"
+            OPENQASM 3;
+            include "stdgates.inc";
+            qubit[3] q;
+            bit[2] c;
+            h q[0];
+            ctrl(2) @ s q[0], q[1], q[2];
+            c[0] = measure q[0];
+            if (c[0]) { x q[1]; } else { sxdg q[2]; }
+            while (c >= 1) { c[0] = measure q[1]; }
+        "#;
+        let circuit = parse(source).unwrap();
+        let written = writer::write(&circuit, Version::V3).unwrap();
+        assert!(written.contains("ctrl(2) @ s q[0], q[1], q[2];"));
+        assert!(written.contains("if (c[0]) {"));
+        assert_eq!(parse(&written).unwrap(), circuit);
+        assert!(writer::write(&circuit, Version::V2).is_err());
+
+        // Conditions read registers as u64, so wider ones are refused
+        assert!(parse("OPENQASM 3;\nbit[64] c;").is_ok());
+        let error = parse("OPENQASM 3;\nbit[70] c;").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert!(error.message.contains("64 bits"));
+    }
+}
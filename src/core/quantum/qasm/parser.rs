@@ -0,0 +1,1108 @@
+/*
+--------------------------------------------------------------------
+                        OpenQASM Parser
+                        ---------------
+Notes
+-----
+
+- recursive-descent reader straight into a Circuit, no separate AST
+- the `OPENQASM 2.0;` / `OPENQASM 3;` header picks the dialect
+- only U (and CX) exist until `include "qelib1.inc";` (2.0) or
+  `include "stdgates.inc";` (3) brings in the standard library (built in,
+  the file itself is never read)
+- custom `gate` definitions are expanded inline at every call
+- whole-register arguments broadcast, e.g. `h q;` or `cx a, b;`
+- id/u0 are identities and produce no instruction
+- OpenQASM 3 subset: qubit/bit declarations, ctrl/negctrl/inv/pow
+  modifiers, `c = measure q;`, if/else and while on measured bits,
+  `for` loops (unrolled here, so ranges must be constant), const values
+  and `input float` parameters
+- loops, pow and custom gates are unrolled, with one running count for
+  the whole program so nesting cannot multiply past the limit
+- global phases only survive where they matter: under a `ctrl @`
+- u2/u3 follow the qelib1 convention, without stdgates' global phase
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Comparison, Condition, Instruction, Operation, Register};
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::qasm::lexer::{Token, TokenKind, tokenize};
+use crate::core::quantum::qasm::{Program, QasmError, Version};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::f64::consts::{PI, TAU};
+
+// Loops, pow and gate calls are all unrolled; this caps what a whole program may expand into
+const MAX_UNROLLED_INSTRUCTIONS: usize = 100_000;
+// Conditions read a classical register as a u64
+const MAX_CLASSICAL_BITS: usize = 64;
+
+// Parameter expression; gate parameters are referenced by position
+#[derive(Clone, Debug)]
+enum Expr {
+    Number(f64),
+    Param(usize),
+    Neg(Box<Expr>),
+    Binary(&'static str, Box<Expr>, Box<Expr>),
+    Call(fn(f64) -> f64, Box<Expr>),
+}
+
+impl Expr {
+    fn eval(&self, params: &[f64]) -> f64 {
+        match self {
+            Expr::Number(x) => *x,
+            Expr::Param(i) => params[*i],
+            Expr::Neg(e) => -e.eval(params),
+            Expr::Call(f, e) => f(e.eval(params)),
+            Expr::Binary(op, a, b) => {
+                let (a, b) = (a.eval(params), b.eval(params));
+                match *op {
+                    "+" => a + b,
+                    "-" => a - b,
+                    "*" => a * b,
+                    "/" => a / b,
+                    _ => a.powf(b),
+                }
+            }
+        }
+    }
+}
+
+fn function(name: &str) -> Option<fn(f64) -> f64> {
+    match name {
+        "sin" => Some(f64::sin),
+        "cos" => Some(f64::cos),
+        "tan" => Some(f64::tan),
+        "exp" => Some(f64::exp),
+        "ln" => Some(f64::ln),
+        "sqrt" => Some(f64::sqrt),
+        _ => None,
+    }
+}
+
+// (parameters, qubits) of the built-in gates
+fn builtin_arity(name: &str, library: bool) -> Option<(usize, usize)> {
+    let arity = match name {
+        "U" => (3, 1),
+        "CX" => (0, 2),
+        _ if !library => return None,
+        "u3" | "u" => (3, 1),
+        "u2" => (2, 1),
+        "u1" | "p" | "phase" | "u0" | "rx" | "ry" | "rz" => (1, 1),
+        "id" | "x" | "y" | "z" | "h" | "s" | "sdg" | "t" | "tdg" | "sx" | "sxdg" => (0, 1),
+        "cx" | "cy" | "cz" | "ch" | "csx" | "swap" => (0, 2),
+        "crx" | "cry" | "crz" | "cu1" | "cp" | "cphase" | "rxx" | "rzz" => (1, 2),
+        "cu3" => (3, 2),
+        "cu" => (4, 2),
+        "ccx" | "cswap" => (0, 3),
+        _ => return None,
+    };
+    Some(arity)
+}
+
+// Operations of a built-in gate whose arity has already been checked
+fn builtin_operations(name: &str, p: &[f64], q: &[usize]) -> Vec<Operation> {
+    let single = |gate| vec![Operation::new(gate, vec![q[0]])];
+    let controlled = |gate| vec![Operation::controlled(gate, vec![q[0]], vec![q[1]])];
+    match name {
+        "U" | "u3" | "u" => single(Gate::U(p[0], p[1], p[2])),
+        "u2" => single(Gate::U(PI / 2.0, p[0], p[1])),
+        "u1" | "p" | "phase" => single(Gate::Phase(p[0])),
+        "rx" => single(Gate::Rx(p[0])),
+        "ry" => single(Gate::Ry(p[0])),
+        "rz" => single(Gate::Rz(p[0])),
+        "x" => single(Gate::X),
+        "y" => single(Gate::Y),
+        "z" => single(Gate::Z),
+        "h" => single(Gate::H),
+        "s" => single(Gate::S),
+        "sdg" => single(Gate::Sdg),
+        "t" => single(Gate::T),
+        "tdg" => single(Gate::Tdg),
+        "sx" => single(Gate::SX),
+        "sxdg" => single(Gate::SXdg),
+        "CX" | "cx" => controlled(Gate::X),
+        "cy" => controlled(Gate::Y),
+        "cz" => controlled(Gate::Z),
+        "ch" => controlled(Gate::H),
+        "csx" => controlled(Gate::SX),
+        "crx" => controlled(Gate::Rx(p[0])),
+        "cry" => controlled(Gate::Ry(p[0])),
+        "crz" => controlled(Gate::Rz(p[0])),
+        "cu1" | "cp" | "cphase" => controlled(Gate::Phase(p[0])),
+        "cu3" => controlled(Gate::U(p[0], p[1], p[2])),
+        "cu" => vec![
+            Operation::new(Gate::Phase(p[3]), vec![q[0]]),
+            Operation::controlled(Gate::U(p[0], p[1], p[2]), vec![q[0]], vec![q[1]]),
+        ],
+        "swap" => vec![Operation::new(Gate::Swap, vec![q[0], q[1]])],
+        "rzz" => vec![
+            Operation::controlled(Gate::X, vec![q[0]], vec![q[1]]),
+            Operation::new(Gate::Phase(p[0]), vec![q[1]]),
+            Operation::controlled(Gate::X, vec![q[0]], vec![q[1]]),
+        ],
+        "rxx" => {
+            let hadamards = vec![Operation::new(Gate::H, vec![q[0]]), Operation::new(Gate::H, vec![q[1]])];
+            let mut ops = hadamards.clone();
+            ops.extend(builtin_operations("rzz", p, q));
+            ops.extend(hadamards);
+            ops
+        }
+        "ccx" => vec![Operation::controlled(Gate::X, vec![q[0], q[1]], vec![q[2]])],
+        "cswap" => vec![Operation::controlled(Gate::Swap, vec![q[0]], vec![q[1], q[2]])],
+        // id, u0
+        _ => Vec::new(),
+    }
+}
+
+// Expanded gate before it becomes instructions
+#[derive(Clone, Debug)]
+enum Piece {
+    Op(Operation),
+    GlobalPhase(f64),
+    Barrier(Vec<usize>),
+}
+
+impl Piece {
+    fn into_instruction(self) -> Option<Instruction> {
+        match self {
+            Piece::Op(op) => Some(Instruction::Gate(op)),
+            Piece::Barrier(qubits) => Some(Instruction::Barrier(qubits)),
+            Piece::GlobalPhase(_) => None,
+        }
+    }
+}
+
+// Gate modifiers, applied right to left: `ctrl @ inv @ x` inverts first
+#[derive(Clone, Debug)]
+enum Modifier {
+    Ctrl(usize),
+    NegCtrl(usize),
+    Inv,
+    Pow(Expr),
+}
+
+// One call inside a gate body, with qubits as indices into the gate's arguments
+enum BodyStatement {
+    Call {
+        name: Token,
+        modifiers: Vec<Modifier>,
+        args: Vec<Expr>,
+        qubits: Vec<usize>,
+    },
+    Barrier(Vec<usize>),
+}
+
+struct GateDef {
+    num_params: usize,
+    num_qubits: usize,
+    body: Vec<BodyStatement>,
+}
+
+// A resolved `name` or `name[i]` argument
+struct Argument {
+    token: Token,
+    bits: Vec<usize>,
+    indexed: bool,
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    version: Version,
+    library: bool,
+    gates: HashMap<String, GateDef>,
+    qregs: Vec<Register>,
+    cregs: Vec<Register>,
+    // Compile-time values: consts, inputs and for-loop variables
+    values: HashMap<String, f64>,
+    given_inputs: &'a HashMap<String, f64>,
+    inputs: Vec<(String, f64)>,
+    // Nesting depth of blocks; declarations are only allowed at depth 0
+    depth: usize,
+    // Instructions and loop passes unrolled so far, across nesting
+    unrolled: Cell<usize>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    // Counts `count` more unrolled instructions against the program-wide limit
+    fn unroll(&self, token: &Token, count: usize) -> Result<(), QasmError> {
+        let total = self.unrolled.get().saturating_add(count);
+        self.unrolled.set(total);
+        if total > MAX_UNROLLED_INSTRUCTIONS {
+            return Err(token.error(format!("unrolling is limited to {} instructions per program", MAX_UNROLLED_INSTRUCTIONS)));
+        }
+        Ok(())
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek().kind, TokenKind::Symbol(s) if s == symbol)
+    }
+
+    fn is_ident(&self, name: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Ident(s) if s == name)
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<Token, QasmError> {
+        if self.is_symbol(symbol) {
+            Ok(self.next())
+        } else {
+            let token = self.peek();
+            Err(token.error(format!("expected '{}', found {}", symbol, token.describe())))
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<(String, Token), QasmError> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Ident(name) => Ok((name.clone(), token)),
+            _ => Err(token.error(format!("expected an identifier, found {}", token.describe()))),
+        }
+    }
+
+    // Constant non-negative integer expression, e.g. a size or `i + 1`
+    fn expect_index(&mut self) -> Result<usize, QasmError> {
+        let token = self.peek().clone();
+        let value = self.parse_integer()?;
+        usize::try_from(value).map_err(|_| token.error(format!("expected a non-negative integer, got {}", value)))
+    }
+
+    fn parse_integer(&mut self) -> Result<i64, QasmError> {
+        let token = self.peek().clone();
+        let value = self.parse_expr(None)?.eval(&[]);
+        if (value - value.round()).abs() > 1e-9 {
+            return Err(token.error(format!("expected an integer, got {}", value)));
+        }
+        Ok(value.round() as i64)
+    }
+
+    fn parse_program(&mut self) -> Result<Vec<Instruction>, QasmError> {
+        let (keyword, token) = self.expect_ident()?;
+        if keyword != "OPENQASM" {
+            return Err(token.error("a program must start with 'OPENQASM 2.0;' or 'OPENQASM 3;'"));
+        }
+        let version = self.next();
+        self.version = match version.kind {
+            TokenKind::Int(2) => Version::V2,
+            TokenKind::Real(2.0) => Version::V2,
+            TokenKind::Int(3) => Version::V3,
+            TokenKind::Real(3.0) => Version::V3,
+            _ => return Err(version.error(format!("unsupported OpenQASM version {}", version.describe()))),
+        };
+        self.expect_symbol(";")?;
+
+        let mut instructions = Vec::new();
+        while self.peek().kind != TokenKind::Eof {
+            instructions.extend(self.parse_statement()?);
+        }
+        Ok(instructions)
+    }
+
+    fn parse_statement(&mut self) -> Result<Vec<Instruction>, QasmError> {
+        let (keyword, token) = self.expect_ident()?;
+        let v3 = self.version == Version::V3;
+        let declaration = matches!(
+            keyword.as_str(),
+            "include" | "qreg" | "creg" | "qubit" | "bit" | "gate" | "opaque" | "input" | "const"
+        );
+        if declaration && self.depth > 0 {
+            return Err(token.error(format!("'{}' is only allowed at the top level", keyword)));
+        }
+
+        match keyword.as_str() {
+            "include" => {
+                let file = self.next();
+                let library = if v3 { "stdgates.inc" } else { "qelib1.inc" };
+                match &file.kind {
+                    TokenKind::Str(name) if name == library => self.library = true,
+                    TokenKind::Str(name) => {
+                        return Err(file.error(format!("cannot include \"{}\", only {} is available", name, library)));
+                    }
+                    _ => return Err(file.error(format!("expected a file name, found {}", file.describe()))),
+                }
+                self.expect_symbol(";")?;
+            }
+            "qreg" | "creg" => {
+                let (name, name_token) = self.expect_ident()?;
+                self.expect_symbol("[")?;
+                let size = self.expect_index()?;
+                self.expect_symbol("]")?;
+                self.expect_symbol(";")?;
+                self.declare(keyword == "qreg", name, &name_token, size)?;
+            }
+            "qubit" | "bit" if v3 => {
+                let mut size = 1;
+                if self.is_symbol("[") {
+                    self.next();
+                    size = self.expect_index()?;
+                    self.expect_symbol("]")?;
+                }
+                let (name, name_token) = self.expect_ident()?;
+                self.expect_symbol(";")?;
+                self.declare(keyword == "qubit", name, &name_token, size)?;
+            }
+            "input" if v3 => {
+                self.parse_type()?;
+                let (name, name_token) = self.expect_ident()?;
+                self.expect_symbol(";")?;
+                if self.values.contains_key(&name) {
+                    return Err(name_token.error(format!("'{}' is already defined", name)));
+                }
+                let value = self.given_inputs.get(&name).copied().unwrap_or(0.0);
+                self.inputs.push((name.clone(), value));
+                self.values.insert(name, value);
+            }
+            "const" | "int" | "uint" | "float" | "angle" if v3 => {
+                if keyword == "const" {
+                    self.parse_type()?;
+                } else if self.is_symbol("[") {
+                    self.next();
+                    self.expect_index()?;
+                    self.expect_symbol("]")?;
+                }
+                let (name, name_token) = self.expect_ident()?;
+                self.expect_symbol("=")?;
+                let value = self.parse_expr(None)?.eval(&[]);
+                self.expect_symbol(";")?;
+                if self.values.contains_key(&name) {
+                    return Err(name_token.error(format!("'{}' is already defined", name)));
+                }
+                self.values.insert(name, value);
+            }
+            "gate" => self.parse_gate_definition()?,
+            "opaque" => return Err(token.error("opaque gates have no definition and cannot be simulated")),
+            "if" if !v3 => return self.parse_qasm2_if(),
+            "if" => {
+                let condition = self.parse_condition()?;
+                let then = self.parse_body()?;
+                let otherwise = if self.is_ident("else") {
+                    self.next();
+                    self.parse_body()?
+                } else {
+                    Vec::new()
+                };
+                return Ok(vec![Instruction::If { condition, then, otherwise }]);
+            }
+            "while" if v3 => {
+                let condition = self.parse_condition()?;
+                let body = self.parse_body()?;
+                return Ok(vec![Instruction::While { condition, body }]);
+            }
+            "for" if v3 => return self.parse_for(),
+            _ if v3 && self.cregs.iter().any(|r| r.name == keyword) => {
+                // c = measure q;  or  c[i] = measure q[j];
+                self.pos -= 1;
+                let clbits = self.parse_argument(false)?;
+                self.expect_symbol("=")?;
+                let (measure, measure_token) = self.expect_ident()?;
+                if measure != "measure" {
+                    return Err(measure_token.error("only measurement results can be assigned to bits"));
+                }
+                let qubits = self.parse_argument(true)?;
+                self.expect_symbol(";")?;
+                let instructions = measure_pairs(&qubits, &clbits)?;
+                self.unroll(&measure_token, instructions.len())?;
+                return Ok(instructions);
+            }
+            _ => return self.parse_quantum_operation(&keyword, &token),
+        }
+        Ok(Vec::new())
+    }
+
+    fn declare(&mut self, quantum: bool, name: String, token: &Token, size: usize) -> Result<(), QasmError> {
+        if self.qregs.iter().chain(&self.cregs).any(|r| r.name == name) {
+            return Err(token.error(format!("register '{}' is already declared", name)));
+        }
+        if size == 0 {
+            return Err(token.error("registers need at least one bit"));
+        }
+        if !quantum && size > MAX_CLASSICAL_BITS {
+            return Err(token.error(format!("classical registers are limited to {} bits", MAX_CLASSICAL_BITS)));
+        }
+        let registers = if quantum { &mut self.qregs } else { &mut self.cregs };
+        registers.push(Register::new(&name, size));
+        Ok(())
+    }
+
+    // Skips a classical type such as `float`, `float[64]` or `uint[8]`
+    fn parse_type(&mut self) -> Result<(), QasmError> {
+        let (name, token) = self.expect_ident()?;
+        if !matches!(name.as_str(), "float" | "angle" | "int" | "uint") {
+            return Err(token.error(format!("unsupported type '{}'", name)));
+        }
+        if self.is_symbol("[") {
+            self.next();
+            self.expect_index()?;
+            self.expect_symbol("]")?;
+        }
+        Ok(())
+    }
+
+    // `{ statements }` or a single statement
+    fn parse_body(&mut self) -> Result<Vec<Instruction>, QasmError> {
+        self.depth += 1;
+        let mut instructions = Vec::new();
+        if self.is_symbol("{") {
+            self.next();
+            while !self.is_symbol("}") {
+                if self.peek().kind == TokenKind::Eof {
+                    return Err(self.peek().error("expected '}' before the end of the file"));
+                }
+                instructions.extend(self.parse_statement()?);
+            }
+            self.next();
+        } else {
+            instructions = self.parse_statement()?;
+        }
+        self.depth -= 1;
+        Ok(instructions)
+    }
+
+    // `(c == 2)`, `(c[0])`, `(!c[1])` or `(c >= 1)`, after the keyword
+    fn parse_condition(&mut self) -> Result<Condition, QasmError> {
+        self.expect_symbol("(")?;
+        let negated = self.is_symbol("!");
+        if negated {
+            self.next();
+        }
+        let operand = self.parse_argument(false)?;
+
+        let comparison = match self.peek().kind {
+            TokenKind::Symbol("==") => Some(Comparison::Eq),
+            TokenKind::Symbol("!=") => Some(Comparison::Ne),
+            TokenKind::Symbol("<") => Some(Comparison::Lt),
+            TokenKind::Symbol("<=") => Some(Comparison::Le),
+            TokenKind::Symbol(">") => Some(Comparison::Gt),
+            TokenKind::Symbol(">=") => Some(Comparison::Ge),
+            _ => None,
+        };
+        let condition = match comparison {
+            Some(_) if negated => return Err(operand.token.error("'!' only applies to a plain bit test")),
+            Some(comparison) => {
+                self.next();
+                let token = self.peek().clone();
+                let value = u64::try_from(self.parse_integer()?)
+                    .map_err(|_| token.error("bits cannot be compared with a negative value"))?;
+                Condition { clbits: operand.bits, comparison, value }
+            }
+            None => Condition {
+                clbits: operand.bits,
+                comparison: if negated { Comparison::Eq } else { Comparison::Ne },
+                value: 0,
+            },
+        };
+        self.expect_symbol(")")?;
+        Ok(condition)
+    }
+
+    // OpenQASM 2.0: `if (creg == int) qop`
+    fn parse_qasm2_if(&mut self) -> Result<Vec<Instruction>, QasmError> {
+        self.expect_symbol("(")?;
+        let (name, name_token) = self.expect_ident()?;
+        let register = self
+            .cregs
+            .iter()
+            .position(|r| r.name == name)
+            .ok_or_else(|| name_token.error(format!("unknown classical register '{}'", name)))?;
+        self.expect_symbol("==")?;
+        let token = self.next();
+        let TokenKind::Int(value) = token.kind else {
+            return Err(token.error(format!("expected an integer, found {}", token.describe())));
+        };
+        self.expect_symbol(")")?;
+
+        let (keyword, token) = self.expect_ident()?;
+        if keyword == "barrier" || keyword == "if" {
+            return Err(token.error(format!("'{}' cannot be conditioned", keyword)));
+        }
+        Ok(self
+            .parse_quantum_operation(&keyword, &token)?
+            .into_iter()
+            .filter(|instruction| !matches!(instruction, Instruction::Barrier(_)))
+            .map(|instruction| Instruction::Conditional {
+                register,
+                value,
+                instruction: Box::new(instruction),
+            })
+            .collect())
+    }
+
+    // `for [type] i in [a:b] body`, `[a:step:b]` or `{a, b, c}`; ends are inclusive
+    fn parse_for(&mut self) -> Result<Vec<Instruction>, QasmError> {
+        let (mut variable, mut token) = self.expect_ident()?;
+        if self.is_symbol("[") {
+            self.next();
+            self.expect_index()?;
+            self.expect_symbol("]")?;
+        }
+        if !self.is_ident("in") {
+            (variable, token) = self.expect_ident()?;
+        }
+        let (keyword, keyword_token) = self.expect_ident()?;
+        if keyword != "in" {
+            return Err(keyword_token.error(format!("expected 'in', found '{}'", keyword)));
+        }
+
+        let mut values = Vec::new();
+        if self.is_symbol("{") {
+            self.next();
+            values.push(self.parse_integer()?);
+            while self.is_symbol(",") {
+                self.next();
+                values.push(self.parse_integer()?);
+            }
+            self.expect_symbol("}")?;
+        } else {
+            let open = self.expect_symbol("[")?;
+            let mut bounds = vec![self.parse_integer()?];
+            while self.is_symbol(":") {
+                self.next();
+                bounds.push(self.parse_integer()?);
+            }
+            self.expect_symbol("]")?;
+            let (start, step, end) = match bounds[..] {
+                [start, end] => (start, 1, end),
+                [start, step, end] => (start, step, end),
+                _ => return Err(open.error("a range is [start:end] or [start:step:end]")),
+            };
+            if step == 0 {
+                return Err(open.error("a range step cannot be zero"));
+            }
+            let mut value = start;
+            while (step > 0 && value <= end) || (step < 0 && value >= end) {
+                values.push(value);
+                value += step;
+                if values.len() > MAX_UNROLLED_INSTRUCTIONS {
+                    return Err(open.error(format!("unrolling is limited to {} instructions per program", MAX_UNROLLED_INSTRUCTIONS)));
+                }
+            }
+        }
+
+        if self.values.contains_key(&variable) {
+            return Err(token.error(format!("'{}' is already defined", variable)));
+        }
+        // The body is re-read once per value; an empty loop is still checked once
+        let start = self.pos;
+        let passes: Vec<Option<i64>> = if values.is_empty() { vec![None] } else { values.into_iter().map(Some).collect() };
+        let mut instructions = Vec::new();
+        for value in passes {
+            if value.is_some() {
+                self.unroll(&token, 1)?;
+            }
+            self.pos = start;
+            self.values.insert(variable.clone(), value.unwrap_or(0) as f64);
+            let body = self.parse_body();
+            self.values.remove(&variable);
+            let body = body?;
+            if value.is_some() {
+                instructions.extend(body);
+            }
+        }
+        Ok(instructions)
+    }
+
+    // measure, reset, barrier or a gate call, after its leading identifier
+    fn parse_quantum_operation(&mut self, keyword: &str, keyword_token: &Token) -> Result<Vec<Instruction>, QasmError> {
+        match keyword {
+            "measure" => {
+                let qubits = self.parse_argument(true)?;
+                self.expect_symbol("->")?;
+                let clbits = self.parse_argument(false)?;
+                self.expect_symbol(";")?;
+                let instructions = measure_pairs(&qubits, &clbits)?;
+                self.unroll(keyword_token, instructions.len())?;
+                Ok(instructions)
+            }
+            "reset" => {
+                let argument = self.parse_argument(true)?;
+                self.expect_symbol(";")?;
+                self.unroll(keyword_token, argument.bits.len())?;
+                Ok(argument.bits.into_iter().map(Instruction::Reset).collect())
+            }
+            "barrier" => {
+                let mut qubits: Vec<usize> = Vec::new();
+                for argument in self.parse_argument_list()? {
+                    for bit in argument.bits {
+                        if !qubits.contains(&bit) {
+                            qubits.push(bit);
+                        }
+                    }
+                }
+                self.expect_symbol(";")?;
+                self.unroll(keyword_token, 1)?;
+                Ok(vec![Instruction::Barrier(qubits)])
+            }
+            _ => {
+                self.pos -= 1;
+                let modifiers = self.parse_modifiers(None)?;
+                let (name, token) = self.expect_ident()?;
+                let args = if self.is_symbol("(") { self.parse_expressions(None)? } else { Vec::new() };
+                let arguments = self.parse_argument_list()?;
+                self.expect_symbol(";")?;
+                let params: Vec<f64> = args.iter().map(|e| e.eval(&[])).collect();
+                let mut instructions = Vec::new();
+                for qubits in broadcast(&arguments)? {
+                    let pieces = self.apply_call(&token, &name, &modifiers, &[], &params, &qubits)?;
+                    instructions.extend(pieces.into_iter().filter_map(Piece::into_instruction));
+                }
+                Ok(instructions)
+            }
+        }
+    }
+
+    // `ctrl @`, `ctrl(2) @`, `negctrl @`, `inv @`, `pow(k) @` (OpenQASM 3 only)
+    fn parse_modifiers(&mut self, params: Option<&[String]>) -> Result<Vec<Modifier>, QasmError> {
+        let mut modifiers = Vec::new();
+        if self.version != Version::V3 {
+            return Ok(modifiers);
+        }
+        loop {
+            let modifier = match &self.peek().kind {
+                TokenKind::Ident(name) if matches!(name.as_str(), "ctrl" | "negctrl" | "inv" | "pow") => name.clone(),
+                _ => break,
+            };
+            let token = self.next();
+            let modifier = match modifier.as_str() {
+                "inv" => Modifier::Inv,
+                "pow" => {
+                    self.expect_symbol("(")?;
+                    let exponent = self.parse_expr(params)?;
+                    self.expect_symbol(")")?;
+                    Modifier::Pow(exponent)
+                }
+                _ => {
+                    let mut count = 1;
+                    if self.is_symbol("(") {
+                        self.next();
+                        count = self.expect_index()?;
+                        self.expect_symbol(")")?;
+                    }
+                    if count == 0 {
+                        return Err(token.error("a control modifier needs at least one control"));
+                    }
+                    if modifier == "ctrl" { Modifier::Ctrl(count) } else { Modifier::NegCtrl(count) }
+                }
+            };
+            self.expect_symbol("@")?;
+            modifiers.push(modifier);
+        }
+        Ok(modifiers)
+    }
+
+    // Qubits or bits named by `reg` or `reg[i]`
+    fn parse_argument(&mut self, quantum: bool) -> Result<Argument, QasmError> {
+        let (name, token) = self.expect_ident()?;
+        let registers = if quantum { &self.qregs } else { &self.cregs };
+        let Some(index) = registers.iter().position(|r| r.name == name) else {
+            let kind = if quantum { "quantum" } else { "classical" };
+            return Err(token.error(format!("unknown {} register '{}'", kind, name)));
+        };
+        let offset: usize = registers[..index].iter().map(|r| r.size).sum();
+        let size = registers[index].size;
+
+        if self.is_symbol("[") {
+            self.next();
+            let bit = self.expect_index()?;
+            self.expect_symbol("]")?;
+            if bit >= size {
+                return Err(token.error(format!("index {} is out of range for {}[{}]", bit, name, size)));
+            }
+            Ok(Argument { token, bits: vec![offset + bit], indexed: true })
+        } else {
+            Ok(Argument { token, bits: (offset..offset + size).collect(), indexed: false })
+        }
+    }
+
+    fn parse_argument_list(&mut self) -> Result<Vec<Argument>, QasmError> {
+        let mut arguments = vec![self.parse_argument(true)?];
+        while self.is_symbol(",") {
+            self.next();
+            arguments.push(self.parse_argument(true)?);
+        }
+        Ok(arguments)
+    }
+
+    // `(e1, e2, ...)`; `params` names the parameters visible inside a gate body
+    fn parse_expressions(&mut self, params: Option<&[String]>) -> Result<Vec<Expr>, QasmError> {
+        self.expect_symbol("(")?;
+        let mut exprs = Vec::new();
+        if !self.is_symbol(")") {
+            exprs.push(self.parse_expr(params)?);
+            while self.is_symbol(",") {
+                self.next();
+                exprs.push(self.parse_expr(params)?);
+            }
+        }
+        self.expect_symbol(")")?;
+        Ok(exprs)
+    }
+
+    fn parse_expr(&mut self, params: Option<&[String]>) -> Result<Expr, QasmError> {
+        let mut left = self.parse_term(params)?;
+        while self.is_symbol("+") || self.is_symbol("-") {
+            let TokenKind::Symbol(op) = self.next().kind else { unreachable!() };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.parse_term(params)?));
+        }
+        Ok(left)
+    }
+
+    fn parse_term(&mut self, params: Option<&[String]>) -> Result<Expr, QasmError> {
+        let mut left = self.parse_unary(params)?;
+        while self.is_symbol("*") || self.is_symbol("/") {
+            let TokenKind::Symbol(op) = self.next().kind else { unreachable!() };
+            left = Expr::Binary(op, Box::new(left), Box::new(self.parse_unary(params)?));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self, params: Option<&[String]>) -> Result<Expr, QasmError> {
+        if self.is_symbol("-") {
+            self.next();
+            return Ok(Expr::Neg(Box::new(self.parse_unary(params)?)));
+        }
+        let base = self.parse_atom(params)?;
+        if self.is_symbol("^") {
+            self.next();
+            return Ok(Expr::Binary("^", Box::new(base), Box::new(self.parse_unary(params)?)));
+        }
+        Ok(base)
+    }
+
+    fn parse_atom(&mut self, params: Option<&[String]>) -> Result<Expr, QasmError> {
+        let token = self.next();
+        match &token.kind {
+            TokenKind::Int(value) => Ok(Expr::Number(*value as f64)),
+            TokenKind::Real(value) => Ok(Expr::Number(*value)),
+            TokenKind::Symbol("(") => {
+                let expr = self.parse_expr(params)?;
+                self.expect_symbol(")")?;
+                Ok(expr)
+            }
+            TokenKind::Ident(name) if name == "pi" => Ok(Expr::Number(PI)),
+            TokenKind::Ident(name) if name == "tau" && self.version == Version::V3 => Ok(Expr::Number(TAU)),
+            TokenKind::Ident(name) => {
+                if let Some(f) = function(name) {
+                    self.expect_symbol("(")?;
+                    let argument = self.parse_expr(params)?;
+                    self.expect_symbol(")")?;
+                    return Ok(Expr::Call(f, Box::new(argument)));
+                }
+                if let Some(index) = params.and_then(|names| names.iter().position(|p| p == name)) {
+                    return Ok(Expr::Param(index));
+                }
+                self.values
+                    .get(name)
+                    .map(|&value| Expr::Number(value))
+                    .ok_or_else(|| token.error(format!("unknown parameter '{}'", name)))
+            }
+            _ => Err(token.error(format!("expected an expression, found {}", token.describe()))),
+        }
+    }
+
+    fn identifier_list(&mut self) -> Result<Vec<(String, Token)>, QasmError> {
+        let mut names = vec![self.expect_ident()?];
+        while self.is_symbol(",") {
+            self.next();
+            names.push(self.expect_ident()?);
+        }
+        Ok(names)
+    }
+
+    fn parse_gate_definition(&mut self) -> Result<(), QasmError> {
+        let (name, name_token) = self.expect_ident()?;
+        if self.arity(&name).is_some() {
+            return Err(name_token.error(format!("gate '{}' is already defined", name)));
+        }
+
+        let mut params = Vec::new();
+        if self.is_symbol("(") {
+            self.next();
+            if !self.is_symbol(")") {
+                params = self.identifier_list()?.into_iter().map(|(p, _)| p).collect();
+            }
+            self.expect_symbol(")")?;
+        }
+        let qargs = self.identifier_list()?;
+        for (i, (qarg, token)) in qargs.iter().enumerate() {
+            if qargs[..i].iter().any(|(other, _)| other == qarg) {
+                return Err(token.error(format!("qubit argument '{}' is repeated", qarg)));
+            }
+        }
+
+        self.expect_symbol("{")?;
+        let mut body = Vec::new();
+        while !self.is_symbol("}") {
+            let modifiers = self.parse_modifiers(Some(&params))?;
+            let (callee, callee_token) = self.expect_ident()?;
+            let args = if self.is_symbol("(") { self.parse_expressions(Some(&params))? } else { Vec::new() };
+
+            // gphase takes no qubits
+            let mut qubits = Vec::new();
+            if !self.is_symbol(";") {
+                for (qarg, token) in self.identifier_list()? {
+                    let index = qargs
+                        .iter()
+                        .position(|(q, _)| *q == qarg)
+                        .ok_or_else(|| token.error(format!("unknown qubit argument '{}'", qarg)))?;
+                    if qubits.contains(&index) {
+                        return Err(token.error(format!("qubit argument '{}' is used twice", qarg)));
+                    }
+                    qubits.push(index);
+                }
+            }
+            self.expect_symbol(";")?;
+
+            if callee == "barrier" && modifiers.is_empty() {
+                body.push(BodyStatement::Barrier(qubits));
+                continue;
+            }
+            let arity = self
+                .arity(&callee)
+                .ok_or_else(|| callee_token.error(format!("unknown gate '{}'", callee)))?;
+            let controls: usize = modifiers
+                .iter()
+                .map(|m| match m {
+                    Modifier::Ctrl(n) | Modifier::NegCtrl(n) => *n,
+                    _ => 0,
+                })
+                .sum();
+            check_arity(&callee, &callee_token, (arity.0, arity.1 + controls), args.len(), qubits.len())?;
+            body.push(BodyStatement::Call { name: callee_token, modifiers, args, qubits });
+        }
+        self.expect_symbol("}")?;
+
+        self.gates.insert(name, GateDef {
+            num_params: params.len(),
+            num_qubits: qargs.len(),
+            body,
+        });
+        Ok(())
+    }
+
+    fn arity(&self, name: &str) -> Option<(usize, usize)> {
+        if name == "gphase" && self.version == Version::V3 {
+            return Some((1, 0));
+        }
+        self.gates
+            .get(name)
+            .map(|def| (def.num_params, def.num_qubits))
+            .or_else(|| builtin_arity(name, self.library))
+    }
+
+    // Applies the modifiers (outermost first) around one gate call;
+    // `env` holds the enclosing gate's parameters for pow() exponents
+    fn apply_call(
+        &self,
+        token: &Token,
+        name: &str,
+        modifiers: &[Modifier],
+        env: &[f64],
+        params: &[f64],
+        qubits: &[usize],
+    ) -> Result<Vec<Piece>, QasmError> {
+        let Some((modifier, rest)) = modifiers.split_first() else {
+            return self.expand(name, token, params, qubits);
+        };
+        match modifier {
+            Modifier::Ctrl(n) | Modifier::NegCtrl(n) => {
+                if qubits.len() < *n {
+                    return Err(token.error(format!("'{}' needs {} control qubit(s)", name, n)));
+                }
+                let (controls, targets) = qubits.split_at(*n);
+                let inner = self.apply_call(token, name, rest, env, params, targets)?;
+                let controlled = inner.into_iter().map(|piece| match piece {
+                    Piece::Op(op) => {
+                        let all: Vec<usize> = controls.iter().chain(&op.controls).copied().collect();
+                        Piece::Op(Operation::controlled(op.gate, all, op.targets))
+                    }
+                    // A global phase becomes a phase on the controls
+                    Piece::GlobalPhase(theta) => Piece::Op(Operation::controlled(
+                        Gate::Phase(theta),
+                        controls[..n - 1].to_vec(),
+                        vec![controls[n - 1]],
+                    )),
+                    barrier => barrier,
+                });
+                if matches!(modifier, Modifier::Ctrl(_)) {
+                    return Ok(controlled.collect());
+                }
+                self.unroll(token, 2 * controls.len())?;
+                let flips: Vec<Piece> = controls.iter().map(|&c| Piece::Op(Operation::new(Gate::X, vec![c]))).collect();
+                Ok(flips.clone().into_iter().chain(controlled).chain(flips).collect())
+            }
+            Modifier::Inv => {
+                let inner = self.apply_call(token, name, rest, env, params, qubits)?;
+                Ok(inverse(inner))
+            }
+            Modifier::Pow(exponent) => {
+                let k = exponent.eval(env);
+                if !k.is_finite() {
+                    return Err(token.error(format!("pow({}) needs a finite exponent", k)));
+                }
+                // Integer powers are unrolled like loops, so they count against the same limit
+                if k.abs() > MAX_UNROLLED_INSTRUCTIONS as f64 {
+                    return Err(token.error(format!("unrolling is limited to {} instructions per program", MAX_UNROLLED_INSTRUCTIONS)));
+                }
+                let inner = self.apply_call(token, name, rest, env, params, qubits)?;
+                if (k - k.round()).abs() < 1e-9 {
+                    let repeats = k.round().abs() as usize;
+                    self.unroll(token, inner.len().saturating_mul(repeats.saturating_sub(1)))?;
+                    let base = if k < 0.0 { inverse(inner) } else { inner };
+                    return Ok((0..repeats).flat_map(|_| base.clone()).collect());
+                }
+                // Fractional powers of a single rotation scale its angle
+                match &inner[..] {
+                    [Piece::Op(op)] => {
+                        let gate = match op.gate {
+                            Gate::Rx(t) => Gate::Rx(t * k),
+                            Gate::Ry(t) => Gate::Ry(t * k),
+                            Gate::Rz(t) => Gate::Rz(t * k),
+                            Gate::Phase(t) => Gate::Phase(t * k),
+                            _ => return Err(token.error(format!("pow({}) only works on rotation and phase gates", k))),
+                        };
+                        Ok(vec![Piece::Op(Operation::controlled(gate, op.controls.clone(), op.targets.clone()))])
+                    }
+                    _ => Err(token.error(format!("pow({}) only works on rotation and phase gates", k))),
+                }
+            }
+        }
+    }
+
+    // Pieces of one unmodified gate application
+    fn expand(&self, name: &str, token: &Token, params: &[f64], qubits: &[usize]) -> Result<Vec<Piece>, QasmError> {
+        let arity = self
+            .arity(name)
+            .ok_or_else(|| token.error(format!("unknown gate '{}'", name)))?;
+        check_arity(name, token, arity, params.len(), qubits.len())?;
+
+        if name == "gphase" {
+            self.unroll(token, 1)?;
+            return Ok(vec![Piece::GlobalPhase(params[0])]);
+        }
+        let Some(def) = self.gates.get(name) else {
+            let operations = builtin_operations(name, params, qubits);
+            self.unroll(token, operations.len())?;
+            return Ok(operations.into_iter().map(Piece::Op).collect());
+        };
+        let mut pieces = Vec::new();
+        for statement in &def.body {
+            match statement {
+                BodyStatement::Barrier(local) => pieces.push(Piece::Barrier(local.iter().map(|&i| qubits[i]).collect())),
+                BodyStatement::Call { name: callee, modifiers, args, qubits: local } => {
+                    let TokenKind::Ident(callee_name) = &callee.kind else { unreachable!() };
+                    let values: Vec<f64> = args.iter().map(|e| e.eval(params)).collect();
+                    let mapped: Vec<usize> = local.iter().map(|&i| qubits[i]).collect();
+                    pieces.extend(self.apply_call(callee, callee_name, modifiers, params, &values, &mapped)?);
+                }
+            }
+        }
+        Ok(pieces)
+    }
+}
+
+// Reversed sequence of inverted pieces
+fn inverse(pieces: Vec<Piece>) -> Vec<Piece> {
+    pieces
+        .into_iter()
+        .rev()
+        .map(|piece| match piece {
+            Piece::Op(op) => Piece::Op(Operation::controlled(op.gate.inverse(), op.controls, op.targets)),
+            Piece::GlobalPhase(theta) => Piece::GlobalPhase(-theta),
+            barrier => barrier,
+        })
+        .collect()
+}
+
+fn measure_pairs(qubits: &Argument, clbits: &Argument) -> Result<Vec<Instruction>, QasmError> {
+    if qubits.bits.len() != clbits.bits.len() {
+        return Err(clbits.token.error(format!(
+            "cannot measure {} qubit(s) into {} bit(s)",
+            qubits.bits.len(),
+            clbits.bits.len()
+        )));
+    }
+    Ok(qubits
+        .bits
+        .iter()
+        .zip(&clbits.bits)
+        .map(|(&qubit, &clbit)| Instruction::Measure { qubit, clbit })
+        .collect())
+}
+
+fn check_arity(name: &str, token: &Token, (num_params, num_qubits): (usize, usize), params: usize, qubits: usize) -> Result<(), QasmError> {
+    if params != num_params {
+        return Err(token.error(format!("'{}' takes {} parameter(s), got {}", name, num_params, params)));
+    }
+    if qubits != num_qubits {
+        return Err(token.error(format!("'{}' acts on {} qubit(s), got {}", name, num_qubits, qubits)));
+    }
+    Ok(())
+}
+
+// Expands register arguments into one qubit list per application
+fn broadcast(arguments: &[Argument]) -> Result<Vec<Vec<usize>>, QasmError> {
+    let mut size = None;
+    for argument in arguments.iter().filter(|a| !a.indexed) {
+        match size {
+            Some(n) if n != argument.bits.len() => {
+                return Err(argument.token.error(format!("register sizes differ ({} vs {})", n, argument.bits.len())));
+            }
+            _ => size = Some(argument.bits.len()),
+        }
+    }
+
+    let applications: Vec<Vec<usize>> = (0..size.unwrap_or(1))
+        .map(|i| arguments.iter().map(|a| if a.indexed { a.bits[0] } else { a.bits[i] }).collect())
+        .collect();
+    for qubits in &applications {
+        for (i, q) in qubits.iter().enumerate() {
+            if qubits[i + 1..].contains(q) {
+                return Err(arguments[i].token.error("the same qubit is used twice in one gate"));
+            }
+        }
+    }
+    Ok(applications)
+}
+
+// Parses OpenQASM 2.0 or 3 source. Declared `input`s take their value from
+// `inputs`, or 0 when it has none.
+pub fn parse(source: &str, inputs: &HashMap<String, f64>) -> Result<Program, QasmError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        pos: 0,
+        version: Version::V2,
+        library: false,
+        gates: HashMap::new(),
+        qregs: Vec::new(),
+        cregs: Vec::new(),
+        values: HashMap::new(),
+        given_inputs: inputs,
+        inputs: Vec::new(),
+        depth: 0,
+        unrolled: Cell::new(0),
+    };
+    let instructions = parser.parse_program()?;
+
+    let mut circuit = Circuit::with_registers(parser.qregs, parser.cregs);
+    for instruction in instructions {
+        circuit.push(instruction);
+    }
+    Ok(Program {
+        version: parser.version,
+        circuit,
+        inputs: parser.inputs,
+    })
+}
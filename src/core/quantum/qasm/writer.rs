@@ -0,0 +1,273 @@
+/*
+--------------------------------------------------------------------
+                        OpenQASM Writer
+                        ---------------
+Notes
+-----
+
+- 2.0 output uses qelib1 names (u1/u3 rather than p/u) for the widest
+  compatibility and errors on gates qelib1 cannot express
+- 3 output uses stdgates names and falls back to `ctrl(n) @` for
+  anything with more controls than stdgates covers
+- if/else and while blocks only exist in OpenQASM 3
+- conditions must read a whole register or a single bit
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::circuit::{Circuit, Comparison, Condition, Instruction, Operation};
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::qasm::Version;
+use std::f64::consts::PI;
+
+// Angle as a multiple of pi when it is one, e.g. "pi/2" or "-3*pi/4"
+fn angle(x: f64) -> String {
+    for denominator in [1, 2, 3, 4, 6, 8, 16] {
+        let k = x * denominator as f64 / PI;
+        if (k - k.round()).abs() < 1e-9 && k.round() != 0.0 {
+            let k = k.round() as i64;
+            let sign = if k < 0 { "-" } else { "" };
+            let numerator = if k.abs() == 1 { "pi".to_string() } else { format!("{}*pi", k.abs()) };
+            return if denominator == 1 {
+                format!("{}{}", sign, numerator)
+            } else {
+                format!("{}{}/{}", sign, numerator, denominator)
+            };
+        }
+    }
+    format!("{}", x)
+}
+
+// qelib1 name and parameters of an operation
+fn qelib_name(operation: &Operation) -> Option<(&'static str, Vec<f64>)> {
+    let params = operation.gate.params();
+    let quarter = |sign: f64, divisor: f64| Some(("cu1", vec![sign * PI / divisor]));
+    match (operation.controls.len(), &operation.gate) {
+        (0, Gate::H) => Some(("h", params)),
+        (0, Gate::X) => Some(("x", params)),
+        (0, Gate::Y) => Some(("y", params)),
+        (0, Gate::Z) => Some(("z", params)),
+        (0, Gate::S) => Some(("s", params)),
+        (0, Gate::Sdg) => Some(("sdg", params)),
+        (0, Gate::T) => Some(("t", params)),
+        (0, Gate::Tdg) => Some(("tdg", params)),
+        (0, Gate::SX) => Some(("sx", params)),
+        (0, Gate::SXdg) => Some(("sxdg", params)),
+        (0, Gate::Rx(_)) => Some(("rx", params)),
+        (0, Gate::Ry(_)) => Some(("ry", params)),
+        (0, Gate::Rz(_)) => Some(("rz", params)),
+        (0, Gate::Phase(_)) => Some(("u1", params)),
+        (0, Gate::U(..)) => Some(("u3", params)),
+        (0, Gate::Swap) => Some(("swap", params)),
+        (1, Gate::X) => Some(("cx", params)),
+        (1, Gate::Y) => Some(("cy", params)),
+        (1, Gate::Z) => Some(("cz", params)),
+        (1, Gate::H) => Some(("ch", params)),
+        (1, Gate::SX) => Some(("csx", params)),
+        (1, Gate::S) => quarter(1.0, 2.0),
+        (1, Gate::Sdg) => quarter(-1.0, 2.0),
+        (1, Gate::T) => quarter(1.0, 4.0),
+        (1, Gate::Tdg) => quarter(-1.0, 4.0),
+        (1, Gate::Rx(_)) => Some(("crx", params)),
+        (1, Gate::Ry(_)) => Some(("cry", params)),
+        (1, Gate::Rz(_)) => Some(("crz", params)),
+        (1, Gate::Phase(_)) => Some(("cu1", params)),
+        (1, Gate::U(..)) => Some(("cu3", params)),
+        (1, Gate::Swap) => Some(("cswap", params)),
+        (2, Gate::X) => Some(("ccx", params)),
+        _ => None,
+    }
+}
+
+// stdgates call (modifiers included) for an operation; never fails
+fn stdgates_call(operation: &Operation) -> String {
+    let mut params = operation.gate.params();
+    let name = match (operation.controls.len(), &operation.gate) {
+        (1, Gate::X) => return "cx".to_string(),
+        (1, Gate::Y) => return "cy".to_string(),
+        (1, Gate::Z) => return "cz".to_string(),
+        (1, Gate::H) => return "ch".to_string(),
+        (1, Gate::Rx(_)) => return format!("crx({})", angle(params[0])),
+        (1, Gate::Ry(_)) => return format!("cry({})", angle(params[0])),
+        (1, Gate::Rz(_)) => return format!("crz({})", angle(params[0])),
+        (1, Gate::Phase(_)) => return format!("cp({})", angle(params[0])),
+        (1, Gate::Swap) => return "cswap".to_string(),
+        (2, Gate::X) => return "ccx".to_string(),
+        (_, Gate::H) => "h",
+        (_, Gate::X) => "x",
+        (_, Gate::Y) => "y",
+        (_, Gate::Z) => "z",
+        (_, Gate::S) => "s",
+        (_, Gate::Sdg) => "sdg",
+        (_, Gate::T) => "t",
+        (_, Gate::Tdg) => "tdg",
+        (_, Gate::SX) => "sx",
+        (_, Gate::SXdg) => {
+            params.clear();
+            "inv @ sx"
+        }
+        (_, Gate::Rx(_)) => "rx",
+        (_, Gate::Ry(_)) => "ry",
+        (_, Gate::Rz(_)) => "rz",
+        (_, Gate::Phase(_)) => "p",
+        (_, Gate::U(..)) => "U",
+        (_, Gate::Swap) => "swap",
+    };
+    let args = if params.is_empty() {
+        String::new()
+    } else {
+        format!("({})", params.iter().map(|&p| angle(p)).collect::<Vec<_>>().join(", "))
+    };
+    match operation.controls.len() {
+        0 => format!("{}{}", name, args),
+        1 => format!("ctrl @ {}{}", name, args),
+        n => format!("ctrl({}) @ {}{}", n, name, args),
+    }
+}
+
+fn qubit_name(circuit: &Circuit, mut index: usize) -> String {
+    for register in circuit.qregs() {
+        if index < register.size {
+            return format!("{}[{}]", register.name, index);
+        }
+        index -= register.size;
+    }
+    unreachable!("qubit outside every register")
+}
+
+fn clbit_name(circuit: &Circuit, mut index: usize) -> String {
+    for register in circuit.cregs() {
+        if index < register.size {
+            return format!("{}[{}]", register.name, index);
+        }
+        index -= register.size;
+    }
+    unreachable!("bit outside every register")
+}
+
+fn condition(circuit: &Circuit, condition: &Condition) -> Result<String, String> {
+    let label = circuit.condition_label(condition);
+    if label.starts_with("bits[") {
+        return Err(format!("the condition on {} does not read one register or bit", label));
+    }
+    // A single bit reads as a boolean
+    if condition.clbits.len() == 1 && condition.value == 0 {
+        match condition.comparison {
+            Comparison::Ne => return Ok(label),
+            Comparison::Eq => return Ok(format!("!{}", label)),
+            _ => {}
+        }
+    }
+    Ok(format!("{} {} {}", label, condition.comparison.symbol(), condition.value))
+}
+
+fn write_block(circuit: &Circuit, instructions: &[Instruction], indent: usize, source: &mut String) -> Result<(), String> {
+    for instruction in instructions {
+        write_instruction(circuit, instruction, indent, source)?;
+    }
+    Ok(())
+}
+
+fn write_instruction(circuit: &Circuit, instruction: &Instruction, indent: usize, source: &mut String) -> Result<(), String> {
+    let pad = "    ".repeat(indent);
+    match instruction {
+        Instruction::If { condition: c, then, otherwise } => {
+            *source += &format!("{}if ({}) {{\n", pad, condition(circuit, c)?);
+            write_block(circuit, then, indent + 1, source)?;
+            if !otherwise.is_empty() {
+                *source += &format!("{}}} else {{\n", pad);
+                write_block(circuit, otherwise, indent + 1, source)?;
+            }
+            *source += &format!("{}}}\n", pad);
+        }
+        Instruction::While { condition: c, body } => {
+            *source += &format!("{}while ({}) {{\n", pad, condition(circuit, c)?);
+            write_block(circuit, body, indent + 1, source)?;
+            *source += &format!("{}}}\n", pad);
+        }
+        _ => *source += &format!("{}{}\n", pad, line(circuit, instruction)?),
+    }
+    Ok(())
+}
+
+// One-line OpenQASM 3 statement
+fn line(circuit: &Circuit, instruction: &Instruction) -> Result<String, String> {
+    let qubits = |qs: &[usize]| qs.iter().map(|&q| qubit_name(circuit, q)).collect::<Vec<_>>().join(", ");
+    let line = match instruction {
+        Instruction::Gate(operation) => format!("{} {};", stdgates_call(operation), qubits(&operation.qubits())),
+        Instruction::Measure { qubit, clbit } => {
+            format!("{} = measure {};", clbit_name(circuit, *clbit), qubit_name(circuit, *qubit))
+        }
+        Instruction::Reset(qubit) => format!("reset {};", qubit_name(circuit, *qubit)),
+        Instruction::Barrier(qs) => format!("barrier {};", qubits(qs)),
+        Instruction::Conditional { register, value, instruction } => format!(
+            "if ({} == {}) {}",
+            circuit.cregs()[*register].name,
+            value,
+            line(circuit, instruction)?
+        ),
+        Instruction::If { .. } | Instruction::While { .. } => unreachable!("blocks are written by write_instruction"),
+    };
+    Ok(line)
+}
+
+fn qasm2_line(circuit: &Circuit, instruction: &Instruction) -> Result<String, String> {
+    let qubits = |qs: &[usize]| qs.iter().map(|&q| qubit_name(circuit, q)).collect::<Vec<_>>().join(", ");
+    let line = match instruction {
+        Instruction::Gate(operation) => {
+            let (name, params) = qelib_name(operation)
+                .ok_or_else(|| format!("{} has no OpenQASM 2.0 equivalent", operation.name()))?;
+            let args = if params.is_empty() {
+                String::new()
+            } else {
+                format!("({})", params.iter().map(|&p| angle(p)).collect::<Vec<_>>().join(", "))
+            };
+            format!("{}{} {};", name, args, qubits(&operation.qubits()))
+        }
+        Instruction::Measure { qubit, clbit } => {
+            format!("measure {} -> {};", qubit_name(circuit, *qubit), clbit_name(circuit, *clbit))
+        }
+        Instruction::Reset(qubit) => format!("reset {};", qubit_name(circuit, *qubit)),
+        Instruction::Barrier(qs) => format!("barrier {};", qubits(qs)),
+        Instruction::Conditional { register, value, instruction } => format!(
+            "if({}=={}) {}",
+            circuit.cregs()[*register].name,
+            value,
+            qasm2_line(circuit, instruction)?
+        ),
+        Instruction::If { .. } | Instruction::While { .. } => {
+            return Err("if/else and while blocks need OpenQASM 3".to_string());
+        }
+    };
+    Ok(line)
+}
+
+// OpenQASM source for a circuit, or why it cannot be written in `version`
+pub fn write(circuit: &Circuit, version: Version) -> Result<String, String> {
+    let mut source = match version {
+        Version::V2 => String::from("OPENQASM 2.0;\ninclude \"qelib1.inc\";\n"),
+        Version::V3 => String::from("OPENQASM 3.0;\ninclude \"stdgates.inc\";\n"),
+    };
+    for register in circuit.qregs() {
+        source += &match version {
+            Version::V2 => format!("qreg {}[{}];\n", register.name, register.size),
+            Version::V3 => format!("qubit[{}] {};\n", register.size, register.name),
+        };
+    }
+    for register in circuit.cregs() {
+        source += &match version {
+            Version::V2 => format!("creg {}[{}];\n", register.name, register.size),
+            Version::V3 => format!("bit[{}] {};\n", register.size, register.name),
+        };
+    }
+    for instruction in circuit.instructions() {
+        match version {
+            Version::V2 => {
+                source += &qasm2_line(circuit, instruction)?;
+                source.push('\n');
+            }
+            Version::V3 => write_instruction(circuit, instruction, 0, &mut source)?,
+        }
+    }
+    Ok(source)
+}
@@ -0,0 +1,235 @@
+/*
+--------------------------------------------------------------------
+                        Shor's Algorithm
+                        ----------------
+Notes
+-----
+
+- small N only (15, 21, 35): modular multiplication by a is written as a
+  permutation of the work register's basis states, split into cycles and
+  then transpositions, each one a multi-controlled X between CX gates
+- values y >= N are left alone, so the multiplier stays a permutation
+- order finding is phase estimation on U|y> = |a y mod N> from |1>;
+  counting qubit k controls U^(2^k) = multiplication by a^(2^k) mod N
+- a reading c / 2^t is close to s / r; the continued-fraction
+  convergents of c / 2^t propose r, checked classically with a^r = 1
+- an even r with a^(r/2) != -1 (mod N) gives the factors
+  gcd(a^(r/2) +- 1, N)
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::algorithms::append_inverse_qft;
+use crate::core::quantum::backend::Backend;
+use crate::core::quantum::circuit::{Circuit, Instruction, Operation, Register};
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::state_vector::StateVector;
+
+pub const MODULI: [u64; 3] = [15, 21, 35];
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result
+}
+
+// Smallest r > 0 with a^r = 1 (mod N), by brute force
+pub fn classical_order(a: u64, modulus: u64) -> u64 {
+    (1..=modulus).find(|&r| mod_pow(a, r, modulus) == 1).expect("a must be coprime to N")
+}
+
+// Bases 1 < a < N that share no factor with N
+pub fn coprime_bases(modulus: u64) -> Vec<u64> {
+    (2..modulus).filter(|&a| gcd(a, modulus) == 1).collect()
+}
+
+// Qubits needed to hold 0..N-1
+pub fn work_qubits(modulus: u64) -> usize {
+    (64 - (modulus - 1).leading_zeros()) as usize
+}
+
+// Swaps basis states |a⟩ and |b⟩ of `work`, only when every control is |1⟩
+fn controlled_transposition(circuit: &mut Circuit, work: &[usize], controls: &[usize], a: u64, b: u64) {
+    // Name them so that a has a 0 at the pivot bit and b a 1
+    let pivot = (a ^ b).trailing_zeros() as usize;
+    let (a, b) = if a >> pivot & 1 == 0 { (a, b) } else { (b, a) };
+    let others: Vec<usize> = (0..work.len()).filter(|&i| i != pivot && (a ^ b) >> i & 1 == 1).collect();
+
+    // Map b onto a ^ (1 << pivot) so the two differ in the pivot bit alone
+    let spread: Vec<Operation> = others
+        .iter()
+        .map(|&i| Operation::controlled(Gate::X, vec![work[pivot]], vec![work[i]]))
+        .collect();
+    let zeros: Vec<Operation> = (0..work.len())
+        .filter(|&i| i != pivot && a >> i & 1 == 0)
+        .map(|i| Operation::new(Gate::X, vec![work[i]]))
+        .collect();
+    let mut flip_controls: Vec<usize> = controls.to_vec();
+    flip_controls.extend((0..work.len()).filter(|&i| i != pivot).map(|i| work[i]));
+
+    for op in spread.iter().chain(&zeros) {
+        circuit.push(op.clone());
+    }
+    circuit.push(Operation::controlled(Gate::X, flip_controls, vec![work[pivot]]));
+    for op in zeros.iter().chain(spread.iter().rev()) {
+        circuit.push(op.clone());
+    }
+}
+
+// |y⟩ -> |a y mod N⟩ on `work` (y < N), controlled by `control`
+pub fn append_modular_multiplier(circuit: &mut Circuit, a: u64, modulus: u64, work: &[usize], control: usize) {
+    let mut visited = vec![false; modulus as usize];
+    for start in 1..modulus {
+        if visited[start as usize] {
+            continue;
+        }
+        // The cycle start -> a start -> a^2 start -> ...
+        let mut cycle = vec![start];
+        visited[start as usize] = true;
+        let mut next = a * start % modulus;
+        while next != start {
+            visited[next as usize] = true;
+            cycle.push(next);
+            next = a * next % modulus;
+        }
+        // (c0 c1 ... cm) = swap(c0, c1), then swap(c0, c2), ..., applied in that order
+        for &other in &cycle[1..] {
+            controlled_transposition(circuit, work, &[control], cycle[0], other);
+        }
+    }
+}
+
+// Counting qubits q0..q(t-1), work register above them starting in |1⟩
+fn order_finding_unitary(a: u64, modulus: u64, precision: usize) -> Circuit {
+    let n = work_qubits(modulus);
+    let mut circuit = Circuit::with_registers(
+        vec![Register::new("count", precision), Register::new("work", n)],
+        vec![Register::new("c", precision)],
+    );
+    let work: Vec<usize> = (precision..precision + n).collect();
+    circuit.push(Operation::new(Gate::X, vec![work[0]]));
+    for k in 0..precision {
+        circuit.push(Operation::new(Gate::H, vec![k]));
+    }
+    for k in 0..precision {
+        let factor = mod_pow(a, 1 << k, modulus);
+        // Multiplying by 1 is the identity; later powers often are
+        if factor != 1 {
+            append_modular_multiplier(&mut circuit, factor, modulus, &work, k);
+        }
+    }
+    let counting: Vec<usize> = (0..precision).collect();
+    append_inverse_qft(&mut circuit, &counting);
+    circuit
+}
+
+// Phase estimation of U|y⟩ = |a y mod N⟩; c / 2^t lands near s / r
+pub fn order_finding(a: u64, modulus: u64, precision: usize) -> Circuit {
+    let mut circuit = order_finding_unitary(a, modulus, precision);
+    for k in 0..precision {
+        circuit.push(Instruction::Measure { qubit: k, clbit: k });
+    }
+    circuit
+}
+
+// Exact probability of each counting-register reading
+pub fn phase_distribution(a: u64, modulus: u64, precision: usize) -> Vec<f64> {
+    let state: StateVector = run_unitary(&order_finding_unitary(a, modulus, precision));
+    let mask = (1 << precision) - 1;
+    let mut distribution = vec![0.0; 1 << precision];
+    for (index, p) in state.probabilities().iter().enumerate() {
+        distribution[index & mask] += p;
+    }
+    distribution
+}
+
+// [a0; a1, a2, ...] of numerator / denominator
+pub fn continued_fraction(numerator: u64, denominator: u64) -> Vec<u64> {
+    let (mut p, mut q) = (numerator, denominator);
+    let mut terms = Vec::new();
+    while q != 0 {
+        terms.push(p / q);
+        (p, q) = (q, p % q);
+    }
+    terms
+}
+
+// Successive best approximations h/k of a continued fraction
+pub fn convergents(terms: &[u64]) -> Vec<(u64, u64)> {
+    let (mut h, mut h_prev) = (1u64, 0u64);
+    let (mut k, mut k_prev) = (0u64, 1u64);
+    terms
+        .iter()
+        .map(|&t| {
+            (h, h_prev) = (t * h + h_prev, h);
+            (k, k_prev) = (t * k + k_prev, k);
+            (h, k)
+        })
+        .collect()
+}
+
+// Classical post-processing of one reading, kept step by step for display
+#[derive(Clone, Debug)]
+pub struct Recovery {
+    pub reading: u64,
+    pub phase: f64,
+    pub terms: Vec<u64>,
+    pub convergents: Vec<(u64, u64)>,
+    pub order: Option<u64>,
+    pub factors: Result<(u64, u64), String>,
+}
+
+pub fn recover(reading: u64, precision: usize, a: u64, modulus: u64) -> Recovery {
+    let denominator = 1u64 << precision;
+    let terms = continued_fraction(reading, denominator);
+    let convergents = convergents(&terms);
+
+    // The first convergent denominator below N with a^r = 1 is the order
+    let order = convergents
+        .iter()
+        .map(|&(_, k)| k)
+        .find(|&r| r > 0 && r < modulus && mod_pow(a, r, modulus) == 1);
+    let factors = match order {
+        _ if reading == 0 => Err("A reading of 0 is the s = 0 peak and carries no information".to_string()),
+        None => Err("No convergent denominator r < N satisfies a^r = 1 (mod N)".to_string()),
+        Some(r) if !r.is_multiple_of(2) => Err(format!("r = {} is odd, so a^(r/2) is not an integer power", r)),
+        Some(r) => {
+            let half = mod_pow(a, r / 2, modulus);
+            if half == modulus - 1 {
+                Err(format!("a^(r/2) = {} = -1 (mod N) only gives trivial factors", half))
+            } else {
+                let p = gcd(half + 1, modulus);
+                let q = gcd(half + modulus - 1, modulus);
+                if p > 1 && p < modulus {
+                    Ok((p.min(modulus / p), p.max(modulus / p)))
+                } else if q > 1 && q < modulus {
+                    Ok((q.min(modulus / q), q.max(modulus / q)))
+                } else {
+                    Err("Both gcds are trivial".to_string())
+                }
+            }
+        }
+    };
+
+    Recovery {
+        reading,
+        phase: reading as f64 / denominator as f64,
+        terms,
+        convergents,
+        order,
+        factors,
+    }
+}
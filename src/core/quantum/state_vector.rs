@@ -0,0 +1,111 @@
+/*
+--------------------------------------------------------------------
+                        State Vector
+                        ------------
+Notes
+-----
+
+- stores all 2^n complex amplitudes, so keep n small (<= ~20)
+- basis index bit k is the value of qubit k
+- gates are applied in place by pairing/grouping amplitudes
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::quantum::backend::{Backend, apply_unitary};
+use ndarray::{Array2, ArrayViewMut1};
+use num_complex::Complex64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateVector {
+    num_qubits: usize,
+    amplitudes: Vec<Complex64>,
+}
+
+impl StateVector {
+    // Normalizes the amplitudes; their count must be a power of two
+    pub fn from_amplitudes(mut amplitudes: Vec<Complex64>) -> Self {
+        assert!(amplitudes.len().is_power_of_two(), "{} amplitudes is not 2^n", amplitudes.len());
+        let norm = amplitudes.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt();
+        amplitudes.iter_mut().for_each(|a| *a /= norm);
+        Self {
+            num_qubits: amplitudes.len().trailing_zeros() as usize,
+            amplitudes,
+        }
+    }
+
+    pub fn amplitudes(&self) -> &[Complex64] {
+        &self.amplitudes
+    }
+
+    // ⟨self|other⟩
+    pub fn inner(&self, other: &StateVector) -> Complex64 {
+        self.amplitudes.iter().zip(&other.amplitudes).map(|(a, b)| a.conj() * b).sum()
+    }
+}
+
+impl Backend for StateVector {
+    fn new(num_qubits: usize) -> Self {
+        let mut amplitudes = vec![Complex64::new(0.0, 0.0); 1 << num_qubits];
+        amplitudes[0] = Complex64::new(1.0, 0.0);
+        Self { num_qubits, amplitudes }
+    }
+
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_matrix(&mut self, matrix: &Array2<Complex64>, targets: &[usize], controls: &[usize]) {
+        apply_unitary(ArrayViewMut1::from(&mut self.amplitudes[..]), matrix, targets, controls);
+    }
+
+    fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).collect()
+    }
+
+    fn bloch_vector(&self, qubit: usize) -> [f64; 3] {
+        let bit = 1 << qubit;
+        let mut rho_01 = Complex64::new(0.0, 0.0);
+        let mut p0 = 0.0;
+        let mut p1 = 0.0;
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            if index & bit == 0 {
+                p0 += amplitude.norm_sqr();
+                rho_01 += amplitude * self.amplitudes[index | bit].conj();
+            } else {
+                p1 += amplitude.norm_sqr();
+            }
+        }
+        [2.0 * rho_01.re, -2.0 * rho_01.im, p0 - p1]
+    }
+
+    fn measure(&mut self, qubit: usize, sample: f64) -> bool {
+        let bit = 1 << qubit;
+        let p1: f64 = self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index & bit != 0)
+            .map(|(_, a)| a.norm_sqr())
+            .sum();
+        let outcome = sample < p1;
+        let norm = if outcome { p1 } else { 1.0 - p1 }.sqrt();
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if (index & bit != 0) == outcome {
+                *amplitude /= norm;
+            } else {
+                *amplitude = Complex64::new(0.0, 0.0);
+            }
+        }
+        outcome
+    }
+}
+
+// Ket label with qubit 0 on the right, e.g. index 1 of 2 qubits -> |01⟩
+pub fn basis_label(index: usize, num_qubits: usize) -> String {
+    let bits: String = (0..num_qubits)
+        .rev()
+        .map(|q| if index >> q & 1 == 1 { '1' } else { '0' })
+        .collect();
+    format!("|{}⟩", bits)
+}
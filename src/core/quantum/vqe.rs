@@ -0,0 +1,298 @@
+/*
+--------------------------------------------------------------------
+                        Variational Quantum Eigensolver
+                        -------------------------------
+Notes
+-----
+
+- molecules are two electrons in two spatial orbitals (a minimal active
+  space), four spin orbitals p = 2 * orbital + spin under Jordan-Wigner
+- the fermionic Hamiltonian is built as a 16 x 16 matrix from the
+  integrals and decomposed into Pauli strings, so no mapping algebra is
+  written out by hand
+- integrals are in Hartree with chemists' notation (pq|rs) over canonical
+  RHF orbitals; core energy = nuclear repulsion (+ frozen core)
+- both molecules are STO-3G, integrals computed at full precision:
+    H2 at 0.7414 Å: RHF -1.116684387, FCI -1.137270175 Ha
+    LiH at 1.6 Å, Li 1s frozen, HOMO 2σ and LUMO 3σ active:
+      RHF -7.861864770, CAS(2,2) -7.862128833 Ha
+      (all-electron FCI is -7.882324379 Ha; the rest of the correlation
+      lives in orbitals outside the active space)
+- in H2 the two orbitals differ in symmetry, so h_12, (11|12) and
+  (12|22) vanish; LiH's 2σ and 3σ do not, so singles mix in as well and
+  the double-excitation ansatz ends 1.5e-5 Ha above the exact energy
+- the Hartree-Fock reference fills spin orbitals 0 and 1
+- ansätze are ParameterizedCircuits, so gradient descent runs on
+  parameter-shift, finite-difference or adjoint gradients
+- hardware-efficient parameters start small and random: all zeros is a
+  stationary point (Brillouin's theorem), where gradients vanish
+
+--------------------------------------------------------------------
+*/
+
+use crate::core::ai::optim::optimizer::{Optimizer, OptimizerKind};
+use crate::core::quantum::circuit::{Circuit, Operation};
+use crate::core::quantum::executor::run_unitary;
+use crate::core::quantum::gates::Gate;
+use crate::core::quantum::gradients::{ExpectationObjective, GradientMethod};
+use crate::core::quantum::parameters::{Axis, ParameterizedCircuit};
+use crate::core::quantum::pauli::Hamiltonian;
+use crate::core::quantum::state_vector::StateVector;
+use ndarray::{Array1, Array2};
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SPIN_ORBITALS: usize = 4;
+const OCCUPIED: [usize; 2] = [0, 1];
+const VIRTUAL: [usize; 2] = [2, 3];
+
+// Integrals of a two-orbital active space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActiveSpace {
+    pub core_energy: f64,
+    // h_11, h_12, h_22
+    pub one_body: [f64; 3],
+    // (11|11), (22|22), (11|22)
+    pub coulomb: [f64; 3],
+    // (12|21)
+    pub exchange: f64,
+    // (11|12), (12|22)
+    pub mixed: [f64; 2],
+}
+
+impl ActiveSpace {
+    fn one_body(&self, i: usize, j: usize) -> f64 {
+        self.one_body[i + j]
+    }
+
+    // (ij|kl) for real orbitals, using its 8-fold symmetry
+    fn two_body(&self, i: usize, j: usize, k: usize, l: usize) -> f64 {
+        match (i == j, k == l) {
+            (true, true) if i == k => self.coulomb[i],
+            (true, true) => self.coulomb[2],
+            (false, false) => self.exchange,
+            // One mixed pair; the other pair says which orbital it is
+            (true, false) => self.mixed[i],
+            (false, true) => self.mixed[k],
+        }
+    }
+
+    pub fn hamiltonian(&self) -> Hamiltonian {
+        let dim = 1 << SPIN_ORBITALS;
+        let mut m = Array2::<Complex64>::zeros((dim, dim));
+        let spin_orbital = |orbital: usize, spin: usize| 2 * orbital + spin;
+
+        for column in 0..dim {
+            let mut add = |ops: &[(usize, bool)], weight: f64| {
+                if weight != 0.0
+                    && let Some((sign, row)) = apply_ladder(ops, column)
+                {
+                    m[[row, column]] += Complex64::new(weight * sign, 0.0);
+                }
+            };
+            add(&[], self.core_energy);
+            for (i, j) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                for spin in 0..2 {
+                    add(&[(spin_orbital(i, spin), true), (spin_orbital(j, spin), false)], self.one_body(i, j));
+                }
+            }
+            // 1/2 sum (ij|kl) a+_i,s a+_k,t a_l,t a_j,s
+            for (i, j, k, l) in (0..16).map(|code| (code & 1, code >> 1 & 1, code >> 2 & 1, code >> 3 & 1)) {
+                for (s, t) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+                    let ops = [
+                        (spin_orbital(i, s), true),
+                        (spin_orbital(k, t), true),
+                        (spin_orbital(l, t), false),
+                        (spin_orbital(j, s), false),
+                    ];
+                    add(&ops, 0.5 * self.two_body(i, j, k, l));
+                }
+            }
+        }
+        Hamiltonian::from_matrix(&m)
+    }
+}
+
+// Applies (spin orbital, create?) operators right to left to a basis state, with Jordan-Wigner signs
+fn apply_ladder(ops: &[(usize, bool)], mut state: usize) -> Option<(f64, usize)> {
+    let mut sign = 1.0;
+    for &(p, create) in ops.iter().rev() {
+        let occupied = state >> p & 1 == 1;
+        if occupied == create {
+            return None;
+        }
+        if !(state & ((1 << p) - 1)).count_ones().is_multiple_of(2) {
+            sign = -sign;
+        }
+        state ^= 1 << p;
+    }
+    Some((sign, state))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Molecule {
+    H2,
+    LiH,
+}
+
+impl Molecule {
+    pub const ALL: [Molecule; 2] = [Molecule::H2, Molecule::LiH];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Molecule::H2 => "H₂",
+            Molecule::LiH => "LiH",
+        }
+    }
+
+    pub fn active_space(self) -> ActiveSpace {
+        match self {
+            Molecule::H2 => ActiveSpace {
+                core_energy: 0.713_753_993_7,
+                one_body: [-1.252_463_573_6, 0.0, -0.475_948_715_2],
+                coulomb: [0.674_488_766_4, 0.697_393_767_4, 0.663_468_096_4],
+                exchange: 0.181_288_808_2,
+                mixed: [0.0, 0.0],
+            },
+            Molecule::LiH => ActiveSpace {
+                core_energy: -6.804_012_298_2,
+                one_body: [-0.772_581_724_7, -0.048_579_572_0, -0.355_939_534_8],
+                coulomb: [0.487_310_977_8, 0.337_882_276_2, 0.223_610_035_0],
+                exchange: 0.013_063_974_1,
+                mixed: [0.048_579_572_0, -0.007_484_165_5],
+            },
+        }
+    }
+
+    pub fn hamiltonian(self) -> Hamiltonian {
+        self.active_space().hamiltonian()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ansatz {
+    // Ry-Rz on every qubit, then a CX ladder, repeated; a final rotation layer
+    HardwareEfficient { layers: usize },
+    // UCC-style: one parameter rotating the Hartree-Fock state into the double excitation
+    DoubleExcitation,
+}
+
+impl Ansatz {
+    pub fn name(self) -> &'static str {
+        match self {
+            Ansatz::HardwareEfficient { .. } => "Hardware-efficient",
+            Ansatz::DoubleExcitation => "UCC double excitation",
+        }
+    }
+
+    pub fn num_parameters(self) -> usize {
+        match self {
+            Ansatz::HardwareEfficient { layers } => 2 * SPIN_ORBITALS * (layers + 1),
+            Ansatz::DoubleExcitation => 1,
+        }
+    }
+
+    pub fn initial_parameters(self, seed: u64) -> Array1<f64> {
+        match self {
+            Ansatz::HardwareEfficient { .. } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                Array1::from_shape_fn(self.num_parameters(), |_| rng.random_range(-0.1..0.1))
+            }
+            Ansatz::DoubleExcitation => Array1::zeros(1),
+        }
+    }
+
+    pub fn template(self) -> ParameterizedCircuit {
+        let mut circuit = ParameterizedCircuit::new(SPIN_ORBITALS);
+        match self {
+            Ansatz::HardwareEfficient { layers } => {
+                for &q in &OCCUPIED {
+                    circuit.push(Operation::new(Gate::X, vec![q]));
+                }
+                for layer in 0..=layers {
+                    for q in 0..SPIN_ORBITALS {
+                        circuit.rotation(Axis::Y, &format!("θ{}", 2 * (layer * SPIN_ORBITALS + q)), 1.0, vec![], q);
+                        circuit.rotation(Axis::Z, &format!("θ{}", 2 * (layer * SPIN_ORBITALS + q) + 1), 1.0, vec![], q);
+                    }
+                    if layer < layers {
+                        for q in 0..SPIN_ORBITALS - 1 {
+                            circuit.push(Operation::controlled(Gate::X, vec![q], vec![q + 1]));
+                        }
+                    }
+                }
+            }
+            Ansatz::DoubleExcitation => {
+                // cos(θ/2)|0011⟩ + sin(θ/2)|1100⟩: the first virtual orbital decides which pair is filled
+                let pivot = VIRTUAL[0];
+                circuit.rotation(Axis::Y, "θ", 1.0, vec![], pivot);
+                for &v in &VIRTUAL[1..] {
+                    circuit.push(Operation::controlled(Gate::X, vec![pivot], vec![v]));
+                }
+                circuit.push(Operation::new(Gate::X, vec![pivot]));
+                for &o in &OCCUPIED {
+                    circuit.push(Operation::controlled(Gate::X, vec![pivot], vec![o]));
+                }
+                circuit.push(Operation::new(Gate::X, vec![pivot]));
+            }
+        }
+        circuit
+    }
+
+    pub fn circuit(self, params: &Array1<f64>) -> Circuit {
+        self.template().bind(params)
+    }
+
+    pub fn state(self, params: &Array1<f64>) -> StateVector {
+        run_unitary(&self.circuit(params))
+    }
+}
+
+pub struct Vqe {
+    pub molecule: Molecule,
+    pub ansatz: Ansatz,
+    pub hamiltonian: Hamiltonian,
+    pub template: ParameterizedCircuit,
+    // Used by gradient-based optimizers
+    pub gradient: GradientMethod,
+    optimizer: Box<dyn Optimizer>,
+    pub params: Array1<f64>,
+    // Energy after every optimizer step, starting with the initial parameters
+    pub history: Vec<f64>,
+    pub evaluations: usize,
+}
+
+impl Vqe {
+    pub fn new(molecule: Molecule, ansatz: Ansatz, optimizer: OptimizerKind, step_size: f64, seed: u64) -> Self {
+        let hamiltonian = molecule.hamiltonian();
+        let params = ansatz.initial_parameters(seed);
+        let initial = hamiltonian.expectation(&ansatz.state(&params));
+        Self {
+            molecule,
+            ansatz,
+            hamiltonian,
+            template: ansatz.template(),
+            gradient: GradientMethod::ParameterShift,
+            optimizer: optimizer.build(step_size, seed),
+            params,
+            history: vec![initial],
+            evaluations: 1,
+        }
+    }
+
+    pub fn energy(&self) -> f64 {
+        *self.history.last().expect("history starts with the initial energy")
+    }
+
+    pub fn step(&mut self) -> f64 {
+        let mut objective = ExpectationObjective::new(&self.template, &self.hamiltonian, self.gradient);
+        let energy = self.optimizer.step(&mut objective, &mut self.params);
+        self.evaluations += objective.evaluations;
+        self.history.push(energy);
+        energy
+    }
+
+    pub fn state(&self) -> StateVector {
+        self.ansatz.state(&self.params)
+    }
+}
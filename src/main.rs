@@ -3,10 +3,6 @@ mod app;
 mod core;
 
 use tracing::info;
-use tracing_subscriber;
-
-use eframe;
-
 use app::myapp::MyApp;
 
 fn main() -> eframe::Result<()> {